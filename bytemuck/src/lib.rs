@@ -6,14 +6,50 @@ use bytemuck::Pod;
 pub use jito_account_traits_derive::AccountDeserialize;
 use solana_program::{msg, program_error::ProgramError};
 
+/// Length of the account header: the discriminator, the layout version and six reserved bytes
+pub const ACCOUNT_HEADER_LEN: usize = 8;
+
+/// Index of the layout version within the account header
+pub const LAYOUT_VERSION_INDEX: usize = 1;
+
 pub trait Discriminator {
     const DISCRIMINATOR: u8;
 }
 
 pub trait AccountDeserialize: Sized + Pod + Discriminator {
+    /// The layout version of the struct, stored in the byte following the discriminator.
+    /// It shall be bumped whenever the struct changes in a way that requires a [`Migrate`] step.
+    const LAYOUT_VERSION: u8 = 0;
+
+    /// Reads the layout version of the account data without deserializing it
+    ///
+    /// # Arguments
+    /// * `data` - The account data
+    ///
+    /// # Returns
+    /// * `Result<u8, ProgramError>` - The layout version or an error if the discriminator is invalid
+    fn layout_version(data: &[u8]) -> Result<u8, ProgramError> {
+        if data.first() != Some(&Self::DISCRIMINATOR) {
+            msg!("Discriminator is invalid; expected {}", Self::DISCRIMINATOR);
+            return Err(ProgramError::InvalidAccountData);
+        }
+        data.get(LAYOUT_VERSION_INDEX)
+            .copied()
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// Writes the discriminator and the current layout version into the account header
+    ///
+    /// # Arguments
+    /// * `data` - The account data to write the header into
+    fn write_header(data: &mut [u8]) {
+        data[0] = Self::DISCRIMINATOR;
+        data[LAYOUT_VERSION_INDEX] = Self::LAYOUT_VERSION;
+    }
+
     /// Deserialize the account data into a struct.
-    /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
-    /// The rest of the data is deserialized into the struct.
+    /// It assumes the first byte is the discriminator, the second byte is the layout version and
+    /// the next six bytes are reserved. The rest of the data is deserialized into the struct.
     ///
     /// # Arguments
     /// * `data` - The account data to deserialize
@@ -29,12 +65,14 @@ pub trait AccountDeserialize: Sized + Pod + Discriminator {
             );
             return Err(ProgramError::InvalidAccountData);
         }
-        bytemuck::try_from_bytes(&data[8..]).map_err(|_| ProgramError::InvalidAccountData)
+        Self::check_layout_version(data)?;
+        bytemuck::try_from_bytes(&data[ACCOUNT_HEADER_LEN..])
+            .map_err(|_| ProgramError::InvalidAccountData)
     }
 
     /// Deserialize the account data into a mutable struct.
-    /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
-    /// The rest of the data is deserialized into the struct.
+    /// It assumes the first byte is the discriminator, the second byte is the layout version and
+    /// the next six bytes are reserved. The rest of the data is deserialized into the struct.
     ///
     /// # Arguments
     /// * `data` - The account data to deserialize
@@ -50,6 +88,126 @@ pub trait AccountDeserialize: Sized + Pod + Discriminator {
             );
             return Err(ProgramError::InvalidAccountData);
         }
-        bytemuck::try_from_bytes_mut(&mut data[8..]).map_err(|_| ProgramError::InvalidAccountData)
+        Self::check_layout_version(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[ACCOUNT_HEADER_LEN..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Checks the account data was written with the current layout version
+    fn check_layout_version(data: &[u8]) -> Result<(), ProgramError> {
+        let layout_version = data.get(LAYOUT_VERSION_INDEX).copied();
+        if layout_version != Some(Self::LAYOUT_VERSION) {
+            msg!(
+                "Layout version is invalid; expected {}, got {:?}. The account may need to be migrated",
+                Self::LAYOUT_VERSION,
+                layout_version
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+/// Upgrades account data written with an older layout version to the current layout.
+///
+/// Each step upgrades the data from one layout version to the next one. Before a step runs,
+/// the account is resized to the length of the next layout and any new bytes are zeroed.
+/// Layouts may only grow.
+pub trait Migrate: AccountDeserialize {
+    /// Returns the length of the account data, header included, for the given layout version
+    ///
+    /// # Arguments
+    /// * `layout_version` - The layout version
+    ///
+    /// # Returns
+    /// * `Option<usize>` - The length of the account data or None if the layout version is unknown
+    fn layout_len(layout_version: u8) -> Option<usize> {
+        if layout_version != Self::LAYOUT_VERSION {
+            return None;
+        }
+        ACCOUNT_HEADER_LEN.checked_add(std::mem::size_of::<Self>())
+    }
+
+    /// Upgrades the account data from `layout_version` to `layout_version + 1` in place.
+    /// The layout version in the header is updated by the caller.
+    ///
+    /// # Arguments
+    /// * `data` - The account data, already resized to the length of the next layout
+    /// * `layout_version` - The layout version the data is currently written with
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    fn migrate_layout(_data: &mut [u8], layout_version: u8) -> Result<(), ProgramError> {
+        msg!("No migration from layout version {}", layout_version);
+        Err(ProgramError::InvalidAccountData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{Pod, Zeroable};
+    use solana_program::program_error::ProgramError;
+
+    use super::{
+        AccountDeserialize, Discriminator, Migrate, ACCOUNT_HEADER_LEN, LAYOUT_VERSION_INDEX,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+    #[repr(C)]
+    struct TestAccount {
+        value: [u8; 8],
+    }
+
+    impl Discriminator for TestAccount {
+        const DISCRIMINATOR: u8 = 7;
+    }
+
+    impl AccountDeserialize for TestAccount {
+        const LAYOUT_VERSION: u8 = 2;
+    }
+
+    impl Migrate for TestAccount {}
+
+    fn test_account_data() -> Vec<u8> {
+        let mut data = vec![0; ACCOUNT_HEADER_LEN + std::mem::size_of::<TestAccount>()];
+        TestAccount::write_header(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_write_header() {
+        let data = test_account_data();
+        assert_eq!(data[0], TestAccount::DISCRIMINATOR);
+        assert_eq!(data[LAYOUT_VERSION_INDEX], TestAccount::LAYOUT_VERSION);
+        assert_eq!(
+            TestAccount::layout_version(&data).unwrap(),
+            TestAccount::LAYOUT_VERSION
+        );
+        assert!(TestAccount::try_from_slice_unchecked(&data).is_ok());
+    }
+
+    #[test]
+    fn test_outdated_layout_version_fails() {
+        let mut data = test_account_data();
+        data[LAYOUT_VERSION_INDEX] = 1;
+
+        assert_eq!(TestAccount::layout_version(&data).unwrap(), 1);
+        assert_eq!(
+            TestAccount::try_from_slice_unchecked(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            TestAccount::try_from_slice_unchecked_mut(&mut data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_default_layout_len() {
+        assert_eq!(
+            TestAccount::layout_len(TestAccount::LAYOUT_VERSION),
+            Some(ACCOUNT_HEADER_LEN + std::mem::size_of::<TestAccount>())
+        );
+        assert_eq!(TestAccount::layout_len(1), None);
     }
 }
@@ -14,7 +14,8 @@ use solana_rpc_client_api::{
     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
 use solana_sdk::{
-    instruction::Instruction, pubkey::Pubkey, signers::Signers, transaction::Transaction,
+    account::Account, instruction::Instruction, pubkey::Pubkey, signers::Signers,
+    transaction::Transaction,
 };
 
 pub mod cli_args;
@@ -150,6 +151,21 @@ pub(crate) trait CliHandler {
         Ok(account)
     }
 
+    /// Fetches an account, returning `None` if it does not exist
+    async fn get_optional_account(
+        &self,
+        account_pubkey: &Pubkey,
+    ) -> anyhow::Result<Option<Account>> {
+        let rpc_client = self.get_rpc_client();
+
+        let account = rpc_client
+            .get_account_with_commitment(account_pubkey, rpc_client.commitment())
+            .await?
+            .value;
+
+        Ok(account)
+    }
+
     /// Processes a transaction by either printing it as Base58 or sending it.
     ///
     /// This method handles the logic for processing a set of instructions as a transaction.
@@ -31,6 +31,29 @@ pub enum ConfigActions {
         /// The new admin's pubkey
         new_admin: Pubkey,
     },
+    /// Set who may create NCNs and operators: open, allowlist or fee
+    SetCreationPolicy {
+        /// The creation policy: open, allowlist or fee
+        creation_policy: String,
+
+        /// The lamports charged to create one, required by the fee policy
+        #[arg(long)]
+        creation_fee_lamports: Option<u64>,
+
+        /// The account receiving the creation fee, required by the fee policy
+        #[arg(long)]
+        creation_fee_treasury: Option<Pubkey>,
+    },
+    /// Allow a creator to create NCNs and operators under the allowlist policy
+    AddAllowedCreator {
+        /// The creator pubkey
+        creator: Pubkey,
+    },
+    /// Remove a creator from the allowlist
+    RemoveAllowedCreator {
+        /// The creator pubkey
+        creator: Pubkey,
+    },
 }
 
 #[derive(Subcommand)]
@@ -46,6 +69,23 @@ pub enum NcnActions {
     NcnWarmupOperator { ncn: String, operator: String },
     /// NCN Cooldown Operator State
     NcnCooldownOperator { ncn: String, operator: String },
+    /// NCN Jail Operator, making it ineligible immediately until the given epoch
+    NcnJailOperator {
+        ncn: String,
+        operator: String,
+        /// The reason code, defined by the NCN
+        reason_code: u8,
+        /// The first epoch the operator is no longer jailed
+        until_epoch: u64,
+    },
+    /// NCN Unjail Operator
+    NcnUnjailOperator { ncn: String, operator: String },
+    /// NCN Set Transition Epochs, the number of epochs new warmups and cooldowns take
+    NcnSetTransitionEpochs {
+        ncn: String,
+        warmup_epochs: u8,
+        cooldown_epochs: u8,
+    },
     /// Initialize NCN Vault Ticket
     InitializeNcnVaultTicket { ncn: String, vault: String },
     /// Warmup NCN Vault Ticket
@@ -60,6 +100,27 @@ pub enum NcnActions {
         #[arg(long)]
         should_create_token_account: bool,
     },
+    /// Create or update the NCN metadata. Only the provided fields are changed.
+    NcnSetMetadata {
+        /// The NCN pubkey
+        ncn: Pubkey,
+
+        /// The NCN name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// The NCN website
+        #[arg(long)]
+        website: Option<String>,
+
+        /// The URI of the NCN logo
+        #[arg(long)]
+        logo_uri: Option<String>,
+
+        /// How to contact the NCN
+        #[arg(long)]
+        contact: Option<String>,
+    },
     /// Get NCN
     Get { pubkey: String },
     /// List all NCNs
@@ -117,6 +178,31 @@ pub enum NcnActions {
         #[arg(long)]
         set_ncn_program_admin: bool,
     },
+    /// NCN Set Program, binding the NCN to a program whose signer PDA can act as its operator,
+    /// vault and slasher admins
+    NcnSetProgram {
+        ncn: String,
+        /// The program to bind the NCN to, unbinds the NCN when omitted
+        #[arg(long)]
+        ncn_program: Option<String>,
+    },
+    /// NCN Set Operator Bond, the mint and minimum amount operators shall bond to warm up with
+    /// the NCN. A minimum of zero stops requiring a bond.
+    NcnSetOperatorBond {
+        ncn: String,
+        operator_bond_mint: String,
+        min_operator_bond: u64,
+        /// The most slashers can slash from an operator's bond per epoch, zero disables slashing
+        max_bond_slashable_per_epoch: u64,
+    },
+    /// Slash Operator Bond, as a slasher of the NCN, into the slasher's associated token account
+    SlashOperatorBond {
+        ncn: String,
+        operator: String,
+        /// The vault the slasher ticket was registered for
+        vault: String,
+        amount: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -161,7 +247,7 @@ pub enum OperatorActions {
         #[arg(long)]
         set_metadata_admin: bool,
     },
-    /// Sets the operator fee
+    /// Sets the operator fee, taking effect once a full epoch has passed
     OperatorSetFees {
         operator: String,
         operator_fee_bps: u16,
@@ -174,6 +260,36 @@ pub enum OperatorActions {
         #[arg(long)]
         should_create_token_account: bool,
     },
+    /// Operator Withdraw Bond posted with an NCN into the signer's associated token account
+    OperatorWithdrawBond {
+        operator: String,
+        ncn: String,
+        amount: u64,
+        /// The mint of the bond, defaults to the NCN's bond mint
+        #[arg(long)]
+        token_mint: Option<String>,
+    },
+    /// Create or update the operator metadata. Only the provided fields are changed.
+    OperatorSetMetadata {
+        /// The Operator pubkey
+        operator: Pubkey,
+
+        /// The operator name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// The operator website
+        #[arg(long)]
+        website: Option<String>,
+
+        /// The URI of the operator logo
+        #[arg(long)]
+        logo_uri: Option<String>,
+
+        /// How to contact the operator
+        #[arg(long)]
+        contact: Option<String>,
+    },
     /// Get operator
     Get { pubkey: String },
     /// List all operators
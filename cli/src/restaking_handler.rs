@@ -1,26 +1,37 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use anyhow::{anyhow, Result};
 use borsh::BorshDeserialize;
+use jito_jsm_core::creation_policy::CreatorAllowlistEntryAccount;
 use jito_restaking_client::{
     instructions::{
-        CooldownNcnVaultTicketBuilder, CooldownOperatorVaultTicketBuilder, InitializeConfigBuilder,
-        InitializeNcnBuilder, InitializeNcnOperatorStateBuilder, InitializeNcnVaultTicketBuilder,
-        InitializeOperatorBuilder, InitializeOperatorVaultTicketBuilder,
-        NcnCooldownOperatorBuilder, NcnDelegateTokenAccountBuilder, NcnSetAdminBuilder,
-        NcnSetSecondaryAdminBuilder, NcnWarmupOperatorBuilder, OperatorCooldownNcnBuilder,
-        OperatorDelegateTokenAccountBuilder, OperatorSetAdminBuilder, OperatorSetFeeBuilder,
-        OperatorSetSecondaryAdminBuilder, OperatorWarmupNcnBuilder, SetConfigAdminBuilder,
+        CloseCreatorAllowlistEntryBuilder, CooldownNcnVaultTicketBuilder,
+        CooldownOperatorVaultTicketBuilder, InitializeConfigBuilder,
+        InitializeCreatorAllowlistEntryBuilder, InitializeNcnBuilder, InitializeNcnMetadataBuilder,
+        InitializeNcnOperatorStateBuilder, InitializeNcnVaultTicketBuilder,
+        InitializeOperatorBuilder, InitializeOperatorMetadataBuilder,
+        InitializeOperatorVaultTicketBuilder, NcnCooldownOperatorBuilder,
+        NcnDelegateTokenAccountBuilder, NcnJailOperatorBuilder, NcnSetAdminBuilder,
+        NcnSetOperatorBondBuilder, NcnSetProgramBuilder, NcnSetSecondaryAdminBuilder,
+        NcnSetTransitionEpochsBuilder, NcnUnjailOperatorBuilder, NcnWarmupOperatorBuilder,
+        OperatorCooldownNcnBuilder, OperatorDelegateTokenAccountBuilder, OperatorSetAdminBuilder,
+        OperatorSetFeeBuilder, OperatorSetSecondaryAdminBuilder, OperatorWarmupNcnBuilder,
+        OperatorWithdrawBondBuilder, SetConfigAdminBuilder, SetCreationPolicyBuilder,
+        SlashOperatorBondBuilder, UpdateNcnMetadataBuilder, UpdateOperatorMetadataBuilder,
         WarmupNcnVaultTicketBuilder, WarmupOperatorVaultTicketBuilder,
     },
     types::{NcnAdminRole, OperatorAdminRole},
 };
 use jito_restaking_client_common::log::PrettyDisplay;
+use jito_restaking_core::metadata::MetadataAccount;
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
-    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    config::Config, creator_allowlist_entry::CreatorAllowlistEntry, ncn::Ncn,
+    ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_metadata::OperatorMetadata,
     operator_vault_ticket::OperatorVaultTicket,
 };
+use jito_restaking_sdk::instruction::CreationPolicy;
 use log::info;
 use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
@@ -104,6 +115,27 @@ impl RestakingCliHandler {
             RestakingCommands::Config {
                 action: ConfigActions::SetAdmin { new_admin },
             } => self.set_config_admin(new_admin).await,
+            RestakingCommands::Config {
+                action:
+                    ConfigActions::SetCreationPolicy {
+                        creation_policy,
+                        creation_fee_lamports,
+                        creation_fee_treasury,
+                    },
+            } => {
+                self.set_creation_policy(
+                    &creation_policy,
+                    creation_fee_lamports,
+                    creation_fee_treasury,
+                )
+                .await
+            }
+            RestakingCommands::Config {
+                action: ConfigActions::AddAllowedCreator { creator },
+            } => self.add_allowed_creator(&creator).await,
+            RestakingCommands::Config {
+                action: ConfigActions::RemoveAllowedCreator { creator },
+            } => self.remove_allowed_creator(&creator).await,
             RestakingCommands::Ncn {
                 action:
                     NcnActions::Initialize {
@@ -119,6 +151,61 @@ impl RestakingCliHandler {
             RestakingCommands::Ncn {
                 action: NcnActions::NcnCooldownOperator { ncn, operator },
             } => self.ncn_cooldown_operator(ncn, operator).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnJailOperator {
+                        ncn,
+                        operator,
+                        reason_code,
+                        until_epoch,
+                    },
+            } => {
+                self.ncn_jail_operator(ncn, operator, reason_code, until_epoch)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action: NcnActions::NcnUnjailOperator { ncn, operator },
+            } => self.ncn_unjail_operator(ncn, operator).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetTransitionEpochs {
+                        ncn,
+                        warmup_epochs,
+                        cooldown_epochs,
+                    },
+            } => {
+                self.ncn_set_transition_epochs(ncn, warmup_epochs, cooldown_epochs)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action: NcnActions::NcnSetProgram { ncn, ncn_program },
+            } => self.ncn_set_program(ncn, ncn_program).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetOperatorBond {
+                        ncn,
+                        operator_bond_mint,
+                        min_operator_bond,
+                        max_bond_slashable_per_epoch,
+                    },
+            } => {
+                self.ncn_set_operator_bond(
+                    ncn,
+                    operator_bond_mint,
+                    min_operator_bond,
+                    max_bond_slashable_per_epoch,
+                )
+                .await
+            }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::SlashOperatorBond {
+                        ncn,
+                        operator,
+                        vault,
+                        amount,
+                    },
+            } => self.slash_operator_bond(ncn, operator, vault, amount).await,
             RestakingCommands::Ncn {
                 action: NcnActions::InitializeNcnVaultTicket { ncn, vault },
             } => self.initialize_ncn_vault_ticket(ncn, vault).await,
@@ -145,6 +232,19 @@ impl RestakingCliHandler {
                 )
                 .await
             }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetMetadata {
+                        ncn,
+                        name,
+                        website,
+                        logo_uri,
+                        contact,
+                    },
+            } => {
+                self.ncn_set_metadata(&ncn, name, website, logo_uri, contact)
+                    .await
+            }
             RestakingCommands::Ncn {
                 action: NcnActions::Get { pubkey },
             } => self.get_ncn(pubkey).await,
@@ -271,6 +371,31 @@ impl RestakingCliHandler {
                 )
                 .await
             }
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorWithdrawBond {
+                        operator,
+                        ncn,
+                        amount,
+                        token_mint,
+                    },
+            } => {
+                self.operator_withdraw_bond(operator, ncn, amount, token_mint)
+                    .await
+            }
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorSetMetadata {
+                        operator,
+                        name,
+                        website,
+                        logo_uri,
+                        contact,
+                    },
+            } => {
+                self.operator_set_metadata(&operator, name, website, logo_uri, contact)
+                    .await
+            }
             RestakingCommands::Operator {
                 action: OperatorActions::Get { pubkey },
             } => self.get_operator(pubkey).await,
@@ -435,7 +560,8 @@ impl RestakingCliHandler {
         ix_builder
             .operator(operator)
             .old_admin(old_admin_signer.pubkey())
-            .new_admin(new_admin_signer.pubkey());
+            .new_admin(new_admin_signer.pubkey())
+            .config(Config::find_program_address(&self.restaking_program_id).0);
         let mut ix = ix_builder.instruction();
         ix.program_id = self.restaking_program_id;
 
@@ -482,7 +608,7 @@ impl RestakingCliHandler {
         ix.program_id = self.restaking_program_id;
 
         info!(
-            "Setting fees to {:?} to Operator {}",
+            "Setting fees to {:?} to Operator {}, effective after a full epoch",
             operator_fee_bps, operator,
         );
 
@@ -542,6 +668,7 @@ impl RestakingCliHandler {
                 .new_admin(new_admin)
                 .operator(operator)
                 .admin(signer.pubkey())
+                .config(Config::find_program_address(&self.restaking_program_id).0)
                 .operator_admin_role(*role)
                 .instruction();
             let mut ix = ix_builder.instruction();
@@ -618,6 +745,67 @@ impl RestakingCliHandler {
         Ok(())
     }
 
+    #[allow(clippy::future_not_send)]
+    pub async fn operator_withdraw_bond(
+        &self,
+        operator: String,
+        ncn: String,
+        amount: u64,
+        token_mint: Option<String>,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let operator = Pubkey::from_str(&operator)?;
+        let ncn = Pubkey::from_str(&ncn)?;
+        let token_mint = match token_mint {
+            Some(token_mint) => Pubkey::from_str(&token_mint)?,
+            None => {
+                self.get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+                    .await?
+                    .operator_bond_mint
+            }
+        };
+
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+        let operator_bond = get_associated_token_address(&ncn_operator_state, &token_mint);
+        let destination = get_associated_token_address(&signer.pubkey(), &token_mint);
+
+        let create_destination_ix = create_associated_token_account_idempotent(
+            &signer.pubkey(),
+            &signer.pubkey(),
+            &token_mint,
+            &spl_token::id(),
+        );
+
+        let mut ix_builder = OperatorWithdrawBondBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .operator_bond(operator_bond)
+            .destination(destination)
+            .admin(signer.pubkey())
+            .amount(amount);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!(
+            "Withdrawing {} of mint {} from the operator bond",
+            amount, token_mint
+        );
+
+        self.process_transaction(&[create_destination_ix, ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        Ok(())
+    }
+
     #[allow(clippy::future_not_send)]
     pub async fn ncn_delegate_token_account(
         &self,
@@ -841,6 +1029,8 @@ impl RestakingCliHandler {
         let (ncn_operator_state, _, _) =
             NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
 
+        let operator_bond = self.operator_bond(&ncn, &ncn_operator_state).await?;
+
         let mut ix_builder = NcnWarmupOperatorBuilder::new();
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
@@ -848,6 +1038,7 @@ impl RestakingCliHandler {
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
             .admin(signer.pubkey())
+            .operator_bond(operator_bond)
             .instruction();
         let mut ix = ix_builder.instruction();
         ix.program_id = self.restaking_program_id;
@@ -911,6 +1102,307 @@ impl RestakingCliHandler {
         Ok(())
     }
 
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_jail_operator(
+        &self,
+        ncn: String,
+        operator: String,
+        reason_code: u8,
+        until_epoch: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+
+        let mut ix_builder = NcnJailOperatorBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .ncn_operator_admin(signer.pubkey())
+            .reason_code(reason_code)
+            .until_epoch(until_epoch);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("NCN Jail Operator");
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnOperatorState>(
+                    &ncn_operator_state,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_unjail_operator(&self, ncn: String, operator: String) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+
+        let mut ix_builder = NcnUnjailOperatorBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .ncn_operator_admin(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("NCN Unjail Operator");
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnOperatorState>(
+                    &ncn_operator_state,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_set_transition_epochs(
+        &self,
+        ncn: String,
+        warmup_epochs: u8,
+        cooldown_epochs: u8,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+
+        let mut ix_builder = NcnSetTransitionEpochsBuilder::new();
+        ix_builder
+            .ncn(ncn)
+            .admin(signer.pubkey())
+            .warmup_epochs(warmup_epochs)
+            .cooldown_epochs(cooldown_epochs);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("NCN Set Transition Epochs");
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_set_program(&self, ncn: String, ncn_program: Option<String>) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_program = ncn_program
+            .map(|ncn_program| Pubkey::from_str(&ncn_program))
+            .transpose()?
+            .unwrap_or_default();
+
+        let mut ix_builder = NcnSetProgramBuilder::new();
+        ix_builder
+            .ncn(ncn)
+            .ncn_program_admin(signer.pubkey())
+            .ncn_program(ncn_program);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("NCN Set Program");
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// The bond token account of the operator with the NCN, if the NCN requires a bond
+    #[allow(clippy::future_not_send)]
+    async fn operator_bond(
+        &self,
+        ncn: &Pubkey,
+        ncn_operator_state: &Pubkey,
+    ) -> Result<Option<Pubkey>> {
+        let ncn_account = self
+            .get_account::<jito_restaking_client::accounts::Ncn>(ncn)
+            .await?;
+        if ncn_account.min_operator_bond == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(get_associated_token_address(
+            ncn_operator_state,
+            &ncn_account.operator_bond_mint,
+        )))
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_set_operator_bond(
+        &self,
+        ncn: String,
+        operator_bond_mint: String,
+        min_operator_bond: u64,
+        max_bond_slashable_per_epoch: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator_bond_mint = Pubkey::from_str(&operator_bond_mint)?;
+
+        let mut ix_builder = NcnSetOperatorBondBuilder::new();
+        ix_builder
+            .ncn(ncn)
+            .operator_bond_mint(operator_bond_mint)
+            .admin(signer.pubkey())
+            .min_operator_bond(min_operator_bond)
+            .max_bond_slashable_per_epoch(max_bond_slashable_per_epoch);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("NCN Set Operator Bond");
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn slash_operator_bond(
+        &self,
+        ncn: String,
+        operator: String,
+        vault: String,
+        amount: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+        let vault = Pubkey::from_str(&vault)?;
+
+        let ncn_account = self
+            .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+            .await?;
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+        let (ncn_vault_slasher_ticket, _, _) = NcnVaultSlasherTicket::find_program_address(
+            &self.restaking_program_id,
+            &ncn,
+            &vault,
+            &signer.pubkey(),
+        );
+        let operator_bond =
+            get_associated_token_address(&ncn_operator_state, &ncn_account.operator_bond_mint);
+        let destination =
+            get_associated_token_address(&signer.pubkey(), &ncn_account.operator_bond_mint);
+
+        let create_destination_ix = create_associated_token_account_idempotent(
+            &signer.pubkey(),
+            &signer.pubkey(),
+            &ncn_account.operator_bond_mint,
+            &spl_token::id(),
+        );
+
+        let mut ix_builder = SlashOperatorBondBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .vault(vault)
+            .slasher(signer.pubkey())
+            .ncn_vault_slasher_ticket(ncn_vault_slasher_ticket)
+            .ncn_operator_state(ncn_operator_state)
+            .operator_bond(operator_bond)
+            .destination(destination)
+            .amount(amount);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Slashing {} from the bond of operator {}", amount, operator);
+
+        self.process_transaction(&[create_destination_ix, ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnOperatorState>(
+                    &ncn_operator_state,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::future_not_send)]
     pub async fn operator_warmup_ncn(&self, operator: String, ncn: String) -> Result<()> {
         let signer = self
@@ -925,6 +1417,8 @@ impl RestakingCliHandler {
         let (ncn_operator_state, _, _) =
             NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
 
+        let operator_bond = self.operator_bond(&ncn, &ncn_operator_state).await?;
+
         let mut ix_builder = OperatorWarmupNcnBuilder::new();
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
@@ -932,6 +1426,7 @@ impl RestakingCliHandler {
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
             .admin(signer.pubkey())
+            .operator_bond(operator_bond)
             .instruction();
         let mut ix = ix_builder.instruction();
         ix.program_id = self.restaking_program_id;
@@ -1040,12 +1535,15 @@ impl RestakingCliHandler {
         let base_signer = CliSigner::new(Some(base), None);
         let ncn = Ncn::find_program_address(&self.restaking_program_id, &base_signer.pubkey()).0;
 
+        let creation_policy_account = self.creation_policy_account(&signer.pubkey()).await?;
+
         let mut ix_builder = InitializeNcnBuilder::new();
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
             .ncn(ncn)
             .admin(signer.pubkey())
             .base(base_signer.pubkey())
+            .creation_policy_account(creation_policy_account)
             .instruction();
         let mut ix = ix_builder.instruction();
         ix.program_id = self.restaking_program_id;
@@ -1077,12 +1575,15 @@ impl RestakingCliHandler {
         let operator =
             Operator::find_program_address(&self.restaking_program_id, &base_signer.pubkey()).0;
 
+        let creation_policy_account = self.creation_policy_account(&signer.pubkey()).await?;
+
         let mut ix_builder = InitializeOperatorBuilder::new();
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
             .operator(operator)
             .admin(signer.pubkey())
             .base(base_signer.pubkey())
+            .creation_policy_account(creation_policy_account)
             .operator_fee_bps(operator_fee_bps)
             .instruction();
         let mut ix = ix_builder.instruction();
@@ -1280,6 +1781,14 @@ impl RestakingCliHandler {
 
         self.print_out(None, Some(&pubkey), &ncn)?;
 
+        let ncn_metadata = NcnMetadata::find_program_address(&self.restaking_program_id, &pubkey).0;
+        if let Some(account) = self.get_optional_account(&ncn_metadata).await? {
+            let ncn_metadata = jito_restaking_client::accounts::NcnMetadata::deserialize(
+                &mut account.data.as_slice(),
+            )?;
+            self.print_out(None, Some(&pubkey), &ncn_metadata)?;
+        }
+
         Ok(())
     }
 
@@ -1291,10 +1800,25 @@ impl RestakingCliHandler {
         let accounts = rpc_client
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
+        let config = self.get_rpc_program_accounts_config::<NcnMetadata>(None)?;
+        let ncn_metadata_accounts = rpc_client
+            .get_program_accounts_with_config(&self.restaking_program_id, config)
+            .await?;
+        let mut ncn_metadata = HashMap::new();
+        for (_, account) in ncn_metadata_accounts.iter() {
+            let metadata = jito_restaking_client::accounts::NcnMetadata::deserialize(
+                &mut account.data.as_slice(),
+            )?;
+            ncn_metadata.insert(metadata.ncn, metadata);
+        }
+
         for (index, (ncn_pubkey, ncn)) in accounts.iter().enumerate() {
             let ncn = jito_restaking_client::accounts::Ncn::deserialize(&mut ncn.data.as_slice())?;
 
             self.print_out(Some(index), Some(ncn_pubkey), &ncn)?;
+            if let Some(metadata) = ncn_metadata.get(ncn_pubkey) {
+                self.print_out(Some(index), Some(ncn_pubkey), metadata)?;
+            }
         }
         Ok(())
     }
@@ -1361,6 +1885,15 @@ impl RestakingCliHandler {
             jito_restaking_client::accounts::Operator::deserialize(&mut account.data.as_slice())?;
         self.print_out(None, Some(&pubkey), &operator)?;
 
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&self.restaking_program_id, &pubkey).0;
+        if let Some(account) = self.get_optional_account(&operator_metadata).await? {
+            let operator_metadata = jito_restaking_client::accounts::OperatorMetadata::deserialize(
+                &mut account.data.as_slice(),
+            )?;
+            self.print_out(None, Some(&pubkey), &operator_metadata)?;
+        }
+
         Ok(())
     }
 
@@ -1371,11 +1904,26 @@ impl RestakingCliHandler {
         let accounts = rpc_client
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
+        let config = self.get_rpc_program_accounts_config::<OperatorMetadata>(None)?;
+        let operator_metadata_accounts = rpc_client
+            .get_program_accounts_with_config(&self.restaking_program_id, config)
+            .await?;
+        let mut operator_metadata = HashMap::new();
+        for (_, account) in operator_metadata_accounts.iter() {
+            let metadata = jito_restaking_client::accounts::OperatorMetadata::deserialize(
+                &mut account.data.as_slice(),
+            )?;
+            operator_metadata.insert(metadata.operator, metadata);
+        }
+
         for (index, (operator_pubkey, operator)) in accounts.iter().enumerate() {
             let operator = jito_restaking_client::accounts::Operator::deserialize(
                 &mut operator.data.as_slice(),
             )?;
             self.print_out(Some(index), Some(operator_pubkey), &operator)?;
+            if let Some(metadata) = operator_metadata.get(operator_pubkey) {
+                self.print_out(Some(index), Some(operator_pubkey), metadata)?;
+            }
         }
         Ok(())
     }
@@ -1430,4 +1978,281 @@ impl RestakingCliHandler {
 
         Ok(())
     }
+
+    /// Returns the account the config's creation policy requires from `creator` when creating
+    /// NCNs and operators: its creator allowlist entry or the creation fee treasury
+    #[allow(clippy::future_not_send)]
+    async fn creation_policy_account(&self, creator: &Pubkey) -> Result<Option<Pubkey>> {
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let config = self
+            .get_account::<jito_restaking_client::accounts::Config>(&config_address)
+            .await?;
+
+        let creation_policy_account = match CreationPolicy::try_from(config.creation_policy)? {
+            CreationPolicy::Open => None,
+            CreationPolicy::Allowlist => Some(
+                CreatorAllowlistEntry::find_program_address(&self.restaking_program_id, creator).0,
+            ),
+            CreationPolicy::Fee => {
+                info!(
+                    "Paying a creation fee of {} lamports",
+                    config.creation_fee_lamports
+                );
+                Some(config.creation_fee_treasury)
+            }
+        };
+
+        Ok(creation_policy_account)
+    }
+
+    /// Sets who may create NCNs and operators
+    ///
+    /// The creation fee and treasury are required by the fee policy and ignored otherwise. This
+    /// operation can only be performed by the config admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_creation_policy(
+        &self,
+        creation_policy: &str,
+        creation_fee_lamports: Option<u64>,
+        creation_fee_treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let creation_policy = match creation_policy {
+            "open" => jito_restaking_client::types::CreationPolicy::Open,
+            "allowlist" => jito_restaking_client::types::CreationPolicy::Allowlist,
+            "fee" => jito_restaking_client::types::CreationPolicy::Fee,
+            _ => {
+                return Err(anyhow!(
+                    "Creation policy must be one of open, allowlist or fee"
+                ))
+            }
+        };
+        if creation_policy == jito_restaking_client::types::CreationPolicy::Fee
+            && (creation_fee_lamports.is_none() || creation_fee_treasury.is_none())
+        {
+            return Err(anyhow!(
+                "The fee policy requires --creation-fee-lamports and --creation-fee-treasury"
+            ));
+        }
+
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let mut ix_builder = SetCreationPolicyBuilder::new();
+        ix_builder
+            .config(config_address)
+            .admin(signer.pubkey())
+            .creation_fee_treasury(creation_fee_treasury.unwrap_or_default())
+            .creation_policy(creation_policy)
+            .creation_fee_lamports(creation_fee_lamports.unwrap_or_default());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Setting restaking config creation policy: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::Config>(&config_address)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Creates or updates the metadata of an NCN
+    ///
+    /// The metadata account is initialized on first use, leaving any field not provided empty.
+    /// Afterwards only the provided fields are updated. This operation can only be performed by
+    /// the NCN metadata admin.
+    #[allow(clippy::future_not_send)]
+    async fn ncn_set_metadata(
+        &self,
+        ncn: &Pubkey,
+        name: Option<String>,
+        website: Option<String>,
+        logo_uri: Option<String>,
+        contact: Option<String>,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let ncn_metadata = NcnMetadata::find_program_address(&self.restaking_program_id, ncn).0;
+        let mut ix = if self.get_optional_account(&ncn_metadata).await?.is_none() {
+            let mut ix_builder = InitializeNcnMetadataBuilder::new();
+            ix_builder
+                .ncn(*ncn)
+                .ncn_metadata(ncn_metadata)
+                .metadata_admin(signer.pubkey())
+                .payer(signer.pubkey())
+                .name(name.unwrap_or_default())
+                .website(website.unwrap_or_default())
+                .logo_uri(logo_uri.unwrap_or_default())
+                .contact(contact.unwrap_or_default());
+            info!("Initializing metadata for NCN {}", ncn);
+            ix_builder.instruction()
+        } else {
+            let mut ix_builder = UpdateNcnMetadataBuilder::new();
+            ix_builder
+                .ncn(*ncn)
+                .ncn_metadata(ncn_metadata)
+                .metadata_admin(signer.pubkey());
+            if let Some(name) = name {
+                ix_builder.name(name);
+            }
+            if let Some(website) = website {
+                ix_builder.website(website);
+            }
+            if let Some(logo_uri) = logo_uri {
+                ix_builder.logo_uri(logo_uri);
+            }
+            if let Some(contact) = contact {
+                ix_builder.contact(contact);
+            }
+            info!("Updating metadata for NCN {}", ncn);
+            ix_builder.instruction()
+        };
+        ix.program_id = self.restaking_program_id;
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnMetadata>(&ncn_metadata)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Creates or updates the metadata of an operator
+    ///
+    /// The metadata account is initialized on first use, leaving any field not provided empty.
+    /// Afterwards only the provided fields are updated. This operation can only be performed by
+    /// the operator metadata admin.
+    #[allow(clippy::future_not_send)]
+    async fn operator_set_metadata(
+        &self,
+        operator: &Pubkey,
+        name: Option<String>,
+        website: Option<String>,
+        logo_uri: Option<String>,
+        contact: Option<String>,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&self.restaking_program_id, operator).0;
+        let mut ix = if self
+            .get_optional_account(&operator_metadata)
+            .await?
+            .is_none()
+        {
+            let mut ix_builder = InitializeOperatorMetadataBuilder::new();
+            ix_builder
+                .operator(*operator)
+                .operator_metadata(operator_metadata)
+                .metadata_admin(signer.pubkey())
+                .payer(signer.pubkey())
+                .name(name.unwrap_or_default())
+                .website(website.unwrap_or_default())
+                .logo_uri(logo_uri.unwrap_or_default())
+                .contact(contact.unwrap_or_default());
+            info!("Initializing metadata for operator {}", operator);
+            ix_builder.instruction()
+        } else {
+            let mut ix_builder = UpdateOperatorMetadataBuilder::new();
+            ix_builder
+                .operator(*operator)
+                .operator_metadata(operator_metadata)
+                .metadata_admin(signer.pubkey());
+            if let Some(name) = name {
+                ix_builder.name(name);
+            }
+            if let Some(website) = website {
+                ix_builder.website(website);
+            }
+            if let Some(logo_uri) = logo_uri {
+                ix_builder.logo_uri(logo_uri);
+            }
+            if let Some(contact) = contact {
+                ix_builder.contact(contact);
+            }
+            info!("Updating metadata for operator {}", operator);
+            ix_builder.instruction()
+        };
+        ix.program_id = self.restaking_program_id;
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorMetadata>(
+                    &operator_metadata,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Adds a creator to the allowlist used by the allowlist creation policy
+    #[allow(clippy::future_not_send)]
+    async fn add_allowed_creator(&self, creator: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let creator_allowlist_entry =
+            CreatorAllowlistEntry::find_program_address(&self.restaking_program_id, creator).0;
+        let mut ix_builder = InitializeCreatorAllowlistEntryBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .creator_allowlist_entry(creator_allowlist_entry)
+            .creator(*creator)
+            .admin(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Allowlisting creator {}", creator);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::CreatorAllowlistEntry>(
+                    &creator_allowlist_entry,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Removes a creator from the allowlist used by the allowlist creation policy
+    #[allow(clippy::future_not_send)]
+    async fn remove_allowed_creator(&self, creator: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let mut ix_builder = CloseCreatorAllowlistEntryBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .creator_allowlist_entry(
+                CreatorAllowlistEntry::find_program_address(&self.restaking_program_id, creator).0,
+            )
+            .creator(*creator)
+            .admin(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Removing creator {} from the allowlist", creator);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await
+    }
 }
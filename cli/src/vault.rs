@@ -36,11 +36,47 @@ pub enum ConfigActions {
         /// The program fee
         new_fee_bps: u16,
     },
+    /// Set the program fee charged to a single vault instead of the config's program fee
+    SetProgramFeeOverride {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The program fee in basis points
+        program_fee_bps: u16,
+    },
+    /// Clear the program fee override of a vault, charging it the config's program fee again
+    ClearProgramFeeOverride {
+        /// The vault pubkey
+        vault: Pubkey,
+    },
     /// Set the program fee wallet
     SetProgramFeeWallet {
         /// The program fee wallet
         program_fee_wallet: Pubkey,
     },
+    /// Set who may create vaults: open, allowlist or fee
+    SetCreationPolicy {
+        /// The creation policy: open, allowlist or fee
+        creation_policy: String,
+
+        /// The lamports charged to create one, required by the fee policy
+        #[arg(long)]
+        creation_fee_lamports: Option<u64>,
+
+        /// The account receiving the creation fee, required by the fee policy
+        #[arg(long)]
+        creation_fee_treasury: Option<Pubkey>,
+    },
+    /// Allow a creator to create vaults under the allowlist policy
+    AddAllowedCreator {
+        /// The creator pubkey
+        creator: Pubkey,
+    },
+    /// Remove a creator from the allowlist
+    RemoveAllowedCreator {
+        /// The creator pubkey
+        creator: Pubkey,
+    },
 }
 
 /// Vault commands
@@ -103,6 +139,11 @@ pub enum VaultActions {
         /// Optional NCN epoch to close
         ncn_epoch: Option<u64>,
     },
+    /// Updates the vault and all of its operator delegations in a single transaction
+    UpdateVaultFull {
+        /// Vault account
+        vault: String,
+    },
     /// Mints VRT tokens
     MintVRT {
         /// Vault account
@@ -111,6 +152,12 @@ pub enum VaultActions {
         amount_in: u64,
         /// Minimum amount of VRT to mint
         min_amount_out: u64,
+        /// The referrer the deposit is attributed to
+        #[arg(long)]
+        referrer: Option<Pubkey>,
+        /// The beneficiary receiving the minted VRT, defaults to the depositor
+        #[arg(long)]
+        beneficiary: Option<Pubkey>,
     },
     /// Sets up the delegations for an operator
     InitializeOperatorDelegation {
@@ -182,6 +229,25 @@ pub enum VaultActions {
         /// Vault account
         vault: String,
     },
+    /// Starts the withdrawal process, receiving transferable claim tokens instead of a ticket
+    EnqueueWithdrawalClaim {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// Amount of VRT to withdraw
+        amount: u64,
+    },
+    /// Burns withdrawal claim tokens, ending the withdrawal process
+    BurnWithdrawalClaim {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The epoch the claim tokens were minted in
+        epoch: u64,
+
+        /// Amount of claim tokens to burn
+        amount: u64,
+    },
     /// Gets the update state tracker for a vault
     GetVaultUpdateStateTracker {
         /// Vault account
@@ -249,6 +315,14 @@ pub enum VaultActions {
         #[arg(long)]
         reward_fee_bps: Option<u16>,
     },
+    /// Sets the share of the deposit fee minted to the referrer of a deposit
+    SetReferralFee {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The referral share of the deposit fee in BPS
+        referral_fee_bps: u16,
+    },
     /// Sets the vault's pause state
     SetIsPaused {
         /// The vault pubkey
@@ -258,6 +332,86 @@ pub enum VaultActions {
         #[arg(long)]
         set_pause: bool,
     },
+    /// Sets the maximum share of the vault's deposits a single operator may be delegated
+    SetMaxOperatorShare {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The max operator share in BPS, 0 removes the limit
+        max_operator_share_bps: u16,
+    },
+    /// Sets the maximum amount that may be delegated to an operator
+    SetOperatorDelegationCap {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The operator pubkey
+        operator: Pubkey,
+
+        /// The max delegation amount, 0 removes the cap
+        max_delegation_amount: u64,
+    },
+    /// Sets the share of each operator's delegated security an NCN may count and slash
+    SetNcnAllocationCap {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The NCN pubkey
+        ncn: Pubkey,
+
+        /// The max allocation in BPS, 0 removes the cap
+        max_allocation_bps: u16,
+    },
+    /// Sets the number of epochs depositors must hold their VRT before enqueueing a withdrawal
+    SetMinHoldingPeriod {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The minimum holding period in epochs, 0 removes the holding period
+        min_holding_epochs: u64,
+    },
+    /// Splits the vault fees between recipients, making the fee split the vault's fee wallet
+    SetFeeSplit {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// The fee recipients
+        #[arg(long, value_delimiter = ',')]
+        recipients: Vec<Pubkey>,
+
+        /// The share of each recipient in BPS, in the same order as the recipients
+        #[arg(long, value_delimiter = ',')]
+        bps: Vec<u16>,
+    },
+    /// Distributes the fees accumulated by the vault's fee split to its recipients
+    DistributeFeeSplit {
+        /// The vault pubkey
+        vault: Pubkey,
+    },
+    /// Gets the fee split of a vault
+    GetFeeSplit {
+        /// The vault pubkey
+        vault: Pubkey,
+    },
+    /// Starts winding down the vault so it can be decommissioned
+    WindDownVault {
+        /// The vault pubkey
+        vault: Pubkey,
+    },
+    /// Waives the holding period of a vault which is winding down
+    WaiveHoldingPeriod {
+        /// The vault pubkey
+        vault: Pubkey,
+    },
+    /// Closes a vault which has finished winding down
+    CloseVault {
+        /// The vault pubkey
+        vault: Pubkey,
+
+        /// Vault update state tracker accounts left open which shall be closed as well
+        #[arg(long, value_delimiter = ',')]
+        vault_update_state_trackers: Vec<Pubkey>,
+    },
     /// Set Secondary Admin
     SetSecondaryAdmin {
         /// The vault pubkey
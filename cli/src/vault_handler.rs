@@ -3,37 +3,52 @@ use std::{path::PathBuf, str::FromStr};
 use anyhow::{anyhow, Result};
 use borsh::BorshDeserialize;
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::get_epoch;
+use jito_jsm_core::{creation_policy::CreatorAllowlistEntryAccount, get_epoch};
 use jito_restaking_client_common::log::PrettyDisplay;
 use jito_restaking_core::{
     ncn_vault_ticket::NcnVaultTicket, operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_vault_client::{
     instructions::{
-        AddDelegationBuilder, BurnWithdrawalTicketBuilder, ChangeWithdrawalTicketOwnerBuilder,
+        AddDelegationBuilder, BurnWithdrawalClaimBuilder, BurnWithdrawalTicketBuilder,
+        ChangeWithdrawalTicketOwnerBuilder, CloseCreatorAllowlistEntryBuilder, CloseVaultBuilder,
         CloseVaultUpdateStateTrackerBuilder, CooldownDelegationBuilder,
         CooldownVaultNcnTicketBuilder, CrankVaultUpdateStateTrackerBuilder,
-        CreateTokenMetadataBuilder, DelegateTokenAccountBuilder, EnqueueWithdrawalBuilder,
-        InitializeConfigBuilder, InitializeVaultBuilder, InitializeVaultNcnTicketBuilder,
-        InitializeVaultOperatorDelegationBuilder, InitializeVaultUpdateStateTrackerBuilder,
-        MintToBuilder, SetAdminBuilder, SetConfigAdminBuilder, SetDepositCapacityBuilder,
-        SetFeesBuilder, SetIsPausedBuilder, SetProgramFeeBuilder, SetProgramFeeWalletBuilder,
-        SetSecondaryAdminBuilder, UpdateTokenMetadataBuilder, UpdateVaultBalanceBuilder,
-        WarmupVaultNcnTicketBuilder,
+        CreateTokenMetadataBuilder, DelegateTokenAccountBuilder, DistributeFeeSplitBuilder,
+        EnqueueWithdrawalBuilder, EnqueueWithdrawalClaimBuilder, InitializeConfigBuilder,
+        InitializeCreatorAllowlistEntryBuilder, InitializeVaultBuilder,
+        InitializeVaultNcnTicketBuilder, InitializeVaultOperatorDelegationBuilder,
+        InitializeVaultUpdateStateTrackerBuilder, MintToBuilder, SetAdminBuilder,
+        SetConfigAdminBuilder, SetCreationPolicyBuilder, SetDepositCapacityBuilder,
+        SetFeeSplitBuilder, SetFeesBuilder, SetIsPausedBuilder, SetMaxOperatorShareBuilder,
+        SetMinHoldingPeriodBuilder, SetNcnAllocationCapBuilder, SetOperatorDelegationCapBuilder,
+        SetProgramFeeBuilder, SetProgramFeeOverrideBuilder, SetProgramFeeWalletBuilder,
+        SetReferralFeeBuilder, SetSecondaryAdminBuilder, UpdateTokenMetadataBuilder,
+        UpdateVaultBalanceBuilder, UpdateVaultFullBuilder, WaiveHoldingPeriodBuilder,
+        WarmupVaultNcnTicketBuilder, WindDownVaultBuilder,
     },
     types::{VaultAdminRole, WithdrawalAllocationMethod},
 };
 use jito_vault_core::{
-    burn_vault::BurnVault, config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    burn_vault::BurnVault,
+    config::Config,
+    creator_allowlist_entry::CreatorAllowlistEntry,
+    vault::Vault,
+    vault_deposit_receipt::VaultDepositReceipt,
+    vault_fee_split::{VaultFeeSplit, MAX_FEE_SPLIT_RECIPIENTS},
+    vault_ncn_ticket::VaultNcnTicket,
     vault_operator_delegation::VaultOperatorDelegation,
+    vault_referral::VaultReferral,
     vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
     vault_update_state_tracker::VaultUpdateStateTracker,
+    vault_withdrawal_claim_mint::VaultWithdrawalClaimMint,
 };
-use jito_vault_sdk::inline_mpl_token_metadata;
+use jito_vault_sdk::{inline_mpl_token_metadata, instruction::CreationPolicy};
 use log::{debug, info};
-use solana_program::pubkey::Pubkey;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey, system_program};
 use solana_rpc_client::rpc_client::SerializableTransaction;
 use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
     signature::{read_keypair_file, Keypair, Signer},
     transaction::Transaction,
 };
@@ -128,9 +143,43 @@ impl VaultCliHandler {
             VaultCommands::Config {
                 action: ConfigActions::SetProgramFee { new_fee_bps },
             } => self.set_program_fee(new_fee_bps).await,
+            VaultCommands::Config {
+                action:
+                    ConfigActions::SetProgramFeeOverride {
+                        vault,
+                        program_fee_bps,
+                    },
+            } => {
+                self.set_program_fee_override(&vault, Some(program_fee_bps))
+                    .await
+            }
+            VaultCommands::Config {
+                action: ConfigActions::ClearProgramFeeOverride { vault },
+            } => self.set_program_fee_override(&vault, None).await,
             VaultCommands::Config {
                 action: ConfigActions::SetProgramFeeWallet { program_fee_wallet },
             } => self.set_program_fee_wallet(&program_fee_wallet).await,
+            VaultCommands::Config {
+                action:
+                    ConfigActions::SetCreationPolicy {
+                        creation_policy,
+                        creation_fee_lamports,
+                        creation_fee_treasury,
+                    },
+            } => {
+                self.set_creation_policy(
+                    &creation_policy,
+                    creation_fee_lamports,
+                    creation_fee_treasury,
+                )
+                .await
+            }
+            VaultCommands::Config {
+                action: ConfigActions::AddAllowedCreator { creator },
+            } => self.add_allowed_creator(&creator).await,
+            VaultCommands::Config {
+                action: ConfigActions::RemoveAllowedCreator { creator },
+            } => self.remove_allowed_creator(&creator).await,
             VaultCommands::Vault {
                 action:
                     VaultActions::Initialize {
@@ -184,14 +233,22 @@ impl VaultCliHandler {
                 self.close_vault_update_state_tracker(vault, ncn_epoch)
                     .await
             }
+            VaultCommands::Vault {
+                action: VaultActions::UpdateVaultFull { vault },
+            } => self.update_vault_full(vault).await,
             VaultCommands::Vault {
                 action:
                     VaultActions::MintVRT {
                         vault,
                         amount_in,
                         min_amount_out,
+                        referrer,
+                        beneficiary,
                     },
-            } => self.mint_vrt(vault, amount_in, min_amount_out).await,
+            } => {
+                self.mint_vrt(vault, amount_in, min_amount_out, referrer, beneficiary)
+                    .await
+            }
             VaultCommands::Vault {
                 action: VaultActions::InitializeVaultNcnTicket { vault, ncn },
             } => self.initialize_vault_ncn_ticket(vault, ncn).await,
@@ -248,6 +305,17 @@ impl VaultCliHandler {
             VaultCommands::Vault {
                 action: VaultActions::BurnWithdrawalTicket { vault },
             } => self.burn_withdrawal_ticket(vault).await,
+            VaultCommands::Vault {
+                action: VaultActions::EnqueueWithdrawalClaim { vault, amount },
+            } => self.enqueue_withdrawal_claim(&vault, amount).await,
+            VaultCommands::Vault {
+                action:
+                    VaultActions::BurnWithdrawalClaim {
+                        vault,
+                        epoch,
+                        amount,
+                    },
+            } => self.burn_withdrawal_claim(&vault, epoch, amount).await,
             VaultCommands::Vault {
                 action: VaultActions::GetVaultUpdateStateTracker { vault },
             } => self.get_vault_update_state_tracker(vault).await,
@@ -295,9 +363,85 @@ impl VaultCliHandler {
                 self.set_fees(&vault, deposit_fee_bps, withdrawal_fee_bps, reward_fee_bps)
                     .await
             }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetReferralFee {
+                        vault,
+                        referral_fee_bps,
+                    },
+            } => self.set_referral_fee(&vault, referral_fee_bps).await,
             VaultCommands::Vault {
                 action: VaultActions::SetIsPaused { vault, set_pause },
             } => self.set_is_paused(&vault, set_pause).await,
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetMaxOperatorShare {
+                        vault,
+                        max_operator_share_bps,
+                    },
+            } => {
+                self.set_max_operator_share(&vault, max_operator_share_bps)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetOperatorDelegationCap {
+                        vault,
+                        operator,
+                        max_delegation_amount,
+                    },
+            } => {
+                self.set_operator_delegation_cap(&vault, &operator, max_delegation_amount)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetNcnAllocationCap {
+                        vault,
+                        ncn,
+                        max_allocation_bps,
+                    },
+            } => {
+                self.set_ncn_allocation_cap(&vault, &ncn, max_allocation_bps)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetMinHoldingPeriod {
+                        vault,
+                        min_holding_epochs,
+                    },
+            } => {
+                self.set_min_holding_period(&vault, min_holding_epochs)
+                    .await
+            }
+            VaultCommands::Vault {
+                action:
+                    VaultActions::SetFeeSplit {
+                        vault,
+                        recipients,
+                        bps,
+                    },
+            } => self.set_fee_split(&vault, &recipients, &bps).await,
+            VaultCommands::Vault {
+                action: VaultActions::DistributeFeeSplit { vault },
+            } => self.distribute_fee_split(&vault).await,
+            VaultCommands::Vault {
+                action: VaultActions::GetFeeSplit { vault },
+            } => self.get_fee_split(&vault).await,
+            VaultCommands::Vault {
+                action: VaultActions::WindDownVault { vault },
+            } => self.wind_down_vault(&vault).await,
+            VaultCommands::Vault {
+                action: VaultActions::WaiveHoldingPeriod { vault },
+            } => self.waive_holding_period(&vault).await,
+            VaultCommands::Vault {
+                action:
+                    VaultActions::CloseVault {
+                        vault,
+                        vault_update_state_trackers,
+                    },
+            } => self.close_vault(&vault, &vault_update_state_trackers).await,
             VaultCommands::Vault {
                 action:
                     VaultActions::SetSecondaryAdmin {
@@ -441,6 +585,8 @@ impl VaultCliHandler {
         let burn_vault_vrt_token_account =
             get_associated_token_address(&burn_vault, &vrt_mint_signer.pubkey());
 
+        let creation_policy_account = self.creation_policy_account(&admin).await?;
+
         let mut ix_builder = InitializeVaultBuilder::new();
         ix_builder
             .config(Config::find_program_address(&self.vault_program_id).0)
@@ -449,6 +595,7 @@ impl VaultCliHandler {
             .st_mint(token_mint)
             .admin(admin)
             .base(base_signer.pubkey())
+            .creation_policy_account(creation_policy_account)
             .admin_st_token_account(admin_st_token_account)
             .vault_st_token_account(vault_st_token_account)
             .burn_vault(burn_vault)
@@ -799,9 +946,67 @@ impl VaultCliHandler {
         Ok(())
     }
 
+    /// Updates a vault and all of its operator delegations in a single transaction
+    ///
+    /// Every operator delegation of the vault is fetched and passed to the instruction, which is
+    /// only possible for vaults with a small number of operators. Larger vaults shall use the
+    /// vault update state tracker commands instead.
+    #[allow(clippy::future_not_send)]
+    pub async fn update_vault_full(&self, vault: String) -> Result<()> {
+        let signer = self.signer()?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault = Pubkey::from_str(&vault)?;
+
+        let config =
+            self.get_rpc_program_accounts_config::<VaultOperatorDelegation>(Some((&vault, 8)))?;
+        let vault_operator_delegations = rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)
+            .await?;
+
+        let mut ix_builder = UpdateVaultFullBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .vault(vault)
+            .withdrawal_allocation_method(WithdrawalAllocationMethod::Greedy);
+        for (vault_operator_delegation, _) in vault_operator_delegations.iter() {
+            ix_builder.add_remaining_account(AccountMeta::new(*vault_operator_delegation, false));
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Updating vault: {:?}", ix_builder);
+
+        self.process_transaction(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+                ix,
+            ],
+            &signer.pubkey(),
+            &[signer],
+        )
+        .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Vault>(&vault)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
     // ---------- FUNCTIONS --------------
     #[allow(clippy::future_not_send)]
-    pub async fn mint_vrt(&self, vault: String, amount_in: u64, min_amount_out: u64) -> Result<()> {
+    pub async fn mint_vrt(
+        &self,
+        vault: String,
+        amount_in: u64,
+        min_amount_out: u64,
+        referrer: Option<Pubkey>,
+        beneficiary: Option<Pubkey>,
+    ) -> Result<()> {
         let signer = self
             .cli_config
             .signer
@@ -815,10 +1020,11 @@ impl VaultCliHandler {
         let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
 
         let depositor = signer.pubkey();
+        let beneficiary = beneficiary.unwrap_or(depositor);
         let depositor_token_account =
             get_associated_token_address(&depositor, &vault_account.supported_mint);
         let depositor_vrt_token_account =
-            get_associated_token_address(&depositor, &vault_account.vrt_mint);
+            get_associated_token_address(&beneficiary, &vault_account.vrt_mint);
 
         let vault_token_account =
             get_associated_token_address(&vault, &vault_account.supported_mint);
@@ -834,7 +1040,7 @@ impl VaultCliHandler {
         );
         let depositor_vrt_ata_ix = create_associated_token_account_idempotent(
             &depositor,
-            &depositor,
+            &beneficiary,
             &vault_account.vrt_mint,
             &spl_token::ID,
         );
@@ -860,23 +1066,51 @@ impl VaultCliHandler {
             .depositor_vrt_token_account(depositor_vrt_token_account)
             .vault_token_account(vault_token_account)
             .vault_fee_token_account(vault_fee_token_account)
+            .vault_deposit_receipt(Some(
+                VaultDepositReceipt::find_program_address(
+                    &self.vault_program_id,
+                    &vault,
+                    &beneficiary,
+                )
+                .0,
+            ))
+            .system_program(Some(system_program::id()))
             .amount_in(amount_in)
             .min_amount_out(min_amount_out)
             .vault(vault);
 
+        let mut ixs = vec![
+            depositor_ata_ix,
+            depositor_vrt_ata_ix,
+            vault_ata_ix,
+            vault_fee_ata_ix,
+        ];
+        if let Some(referrer) = referrer {
+            ix_builder
+                .vault_referral(Some(
+                    VaultReferral::find_program_address(&self.vault_program_id, &vault, &referrer)
+                        .0,
+                ))
+                .referrer(Some(referrer))
+                .referrer_vrt_token_account(Some(get_associated_token_address(
+                    &referrer,
+                    &vault_account.vrt_mint,
+                )));
+            // The referrer only receives VRT when the vault shares its deposit fee
+            if vault_account.referral_fee_bps() > 0 {
+                ixs.push(create_associated_token_account_idempotent(
+                    &depositor,
+                    &referrer,
+                    &vault_account.vrt_mint,
+                    &spl_token::ID,
+                ));
+            }
+        }
+        ixs.push(ix_builder.instruction());
+
         let blockhash = rpc_client.get_latest_blockhash().await?;
-        let tx = Transaction::new_signed_with_payer(
-            &[
-                depositor_ata_ix,
-                depositor_vrt_ata_ix,
-                vault_ata_ix,
-                vault_fee_ata_ix,
-                ix_builder.instruction(),
-            ],
-            Some(&signer.pubkey()),
-            &[signer],
-            blockhash,
-        );
+        let tx =
+            Transaction::new_signed_with_payer(&ixs, Some(&signer.pubkey()), &[signer], blockhash);
         info!("Mint to transaction: {:?}", tx.get_signature());
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
@@ -1316,6 +1550,14 @@ impl VaultCliHandler {
             .staker(signer.pubkey())
             .staker_vrt_token_account(staker_vrt_token_account)
             .base(signer.pubkey())
+            .vault_deposit_receipt(Some(
+                VaultDepositReceipt::find_program_address(
+                    &self.vault_program_id,
+                    &vault,
+                    &signer.pubkey(),
+                )
+                .0,
+            ))
             .amount(amount);
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_program_id;
@@ -1478,6 +1720,149 @@ impl VaultCliHandler {
         Ok(())
     }
 
+    /// Enqueues a withdrawal as claim tokens minted for the current epoch
+    #[allow(clippy::future_not_send)]
+    pub async fn enqueue_withdrawal_claim(&self, vault: &Pubkey, amount: u64) -> Result<()> {
+        let signer = self.signer()?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault_account_raw = rpc_client.get_account(vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+
+        let slot = rpc_client.get_slot().await?;
+        let epoch = config_account.get_epoch_from_slot(slot)?;
+
+        let vault_withdrawal_claim_mint =
+            VaultWithdrawalClaimMint::find_program_address(&self.vault_program_id, vault, epoch).0;
+
+        let mut ix_builder = EnqueueWithdrawalClaimBuilder::new();
+        ix_builder
+            .config(config)
+            .vault(*vault)
+            .vrt_mint(vault_account.vrt_mint)
+            .vault_withdrawal_claim_mint(vault_withdrawal_claim_mint)
+            .vault_withdrawal_claim_mint_vrt_token_account(get_associated_token_address(
+                &vault_withdrawal_claim_mint,
+                &vault_account.vrt_mint,
+            ))
+            .staker(signer.pubkey())
+            .staker_vrt_token_account(get_associated_token_address(
+                &signer.pubkey(),
+                &vault_account.vrt_mint,
+            ))
+            .staker_claim_token_account(get_associated_token_address(
+                &signer.pubkey(),
+                &vault_withdrawal_claim_mint,
+            ))
+            .vault_deposit_receipt(Some(
+                VaultDepositReceipt::find_program_address(
+                    &self.vault_program_id,
+                    vault,
+                    &signer.pubkey(),
+                )
+                .0,
+            ))
+            .associated_token_program(spl_associated_token_account::id())
+            .amount(amount);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!(
+            "Enqueueing withdrawal claim: amount = {amount}, vault = {vault}, epoch = {epoch}, claim mint = {vault_withdrawal_claim_mint}"
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Burns withdrawal claim tokens minted in `epoch` for the vault's supported token
+    #[allow(clippy::future_not_send)]
+    pub async fn burn_withdrawal_claim(
+        &self,
+        vault: &Pubkey,
+        epoch: u64,
+        amount: u64,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+        let rpc_client = self.get_rpc_client();
+
+        let vault_account_raw = rpc_client.get_account(vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+
+        let config = Config::find_program_address(&self.vault_program_id).0;
+        let config_account_raw = rpc_client.get_account(&config).await?;
+        let config_account = Config::try_from_slice_unchecked(&config_account_raw.data)?;
+
+        let vault_withdrawal_claim_mint =
+            VaultWithdrawalClaimMint::find_program_address(&self.vault_program_id, vault, epoch).0;
+
+        let claimant_token_account_ix = create_associated_token_account_idempotent(
+            &signer.pubkey(),
+            &signer.pubkey(),
+            &vault_account.supported_mint,
+            &spl_token::ID,
+        );
+        let program_fee_token_account_ix = create_associated_token_account_idempotent(
+            &signer.pubkey(),
+            &config_account.program_fee_wallet,
+            &vault_account.vrt_mint,
+            &spl_token::ID,
+        );
+
+        let mut ix_builder = BurnWithdrawalClaimBuilder::new();
+        ix_builder
+            .config(config)
+            .vault(*vault)
+            .vault_token_account(get_associated_token_address(
+                vault,
+                &vault_account.supported_mint,
+            ))
+            .vrt_mint(vault_account.vrt_mint)
+            .vault_withdrawal_claim_mint(vault_withdrawal_claim_mint)
+            .vault_withdrawal_claim_mint_vrt_token_account(get_associated_token_address(
+                &vault_withdrawal_claim_mint,
+                &vault_account.vrt_mint,
+            ))
+            .claimant(signer.pubkey())
+            .claimant_claim_token_account(get_associated_token_address(
+                &signer.pubkey(),
+                &vault_withdrawal_claim_mint,
+            ))
+            .claimant_token_account(get_associated_token_address(
+                &signer.pubkey(),
+                &vault_account.supported_mint,
+            ))
+            .vault_fee_token_account(get_associated_token_address(
+                &vault_account.fee_wallet,
+                &vault_account.vrt_mint,
+            ))
+            .program_fee_token_account(get_associated_token_address(
+                &config_account.program_fee_wallet,
+                &vault_account.vrt_mint,
+            ))
+            .epoch(epoch)
+            .amount(amount);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Burning withdrawal claim: amount = {amount}, vault = {vault}, epoch = {epoch}");
+
+        self.process_transaction(
+            &[claimant_token_account_ix, program_fee_token_account_ix, ix],
+            &signer.pubkey(),
+            &[signer],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     // ------- GET ACCOUNTS --------------------
     #[allow(clippy::future_not_send)]
     pub async fn get_vault(&self, pubkey: String) -> Result<()> {
@@ -1842,27 +2227,26 @@ impl VaultCliHandler {
         Ok(())
     }
 
-    /// Sets the pause state for a specific vault
+    /// Sets the share of the deposit fee minted to the referrer of a deposit
     ///
-    /// Enables or disables operations on a vault by setting its pause state.
-    /// When paused, most interactions with the vault will be rejected.
-    /// This operation can only be performed by the vault admin.
+    /// The referrer's share is taken from the deposit fee, so depositors pay the same fee
+    /// with or without a referrer. This operation can only be performed by the vault fee admin.
     #[allow(clippy::future_not_send)]
-    async fn set_is_paused(&self, vault: &Pubkey, set_pause: bool) -> Result<()> {
+    async fn set_referral_fee(&self, vault: &Pubkey, referral_fee_bps: u16) -> Result<()> {
         let signer = self.signer()?;
 
         let config_address = Config::find_program_address(&self.vault_program_id).0;
-        let mut ix_builder = SetIsPausedBuilder::new();
+        let mut ix_builder = SetReferralFeeBuilder::new();
         ix_builder
             .config(config_address)
             .vault(*vault)
             .admin(signer.pubkey())
-            .is_paused(set_pause);
+            .referral_fee_bps(referral_fee_bps);
 
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_program_id;
 
-        info!("Setting Is Paused: {:?}", ix_builder);
+        info!("Setting Referral Fee: {:?}", ix_builder);
 
         self.process_transaction(&[ix], &signer.pubkey(), &[signer])
             .await?;
@@ -1877,35 +2261,34 @@ impl VaultCliHandler {
         Ok(())
     }
 
-    /// Sets a new program fee (in basis points) for the Config
+    /// Sets the pause state for a specific vault
     ///
-    /// Updates the fee percentage (specified in basis points) that the program
-    /// collects for vault operation. This operation can only be performed by the
-    /// current admin of the config.
+    /// Enables or disables operations on a vault by setting its pause state.
+    /// When paused, most interactions with the vault will be rejected.
+    /// This operation can only be performed by the vault admin.
     #[allow(clippy::future_not_send)]
-    async fn set_program_fee(&self, new_fee_bps: u16) -> Result<()> {
+    async fn set_is_paused(&self, vault: &Pubkey, set_pause: bool) -> Result<()> {
         let signer = self.signer()?;
 
         let config_address = Config::find_program_address(&self.vault_program_id).0;
-        let mut ix_builder = SetProgramFeeBuilder::new();
+        let mut ix_builder = SetIsPausedBuilder::new();
         ix_builder
             .config(config_address)
+            .vault(*vault)
             .admin(signer.pubkey())
-            .new_fee_bps(new_fee_bps);
+            .is_paused(set_pause);
+
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_program_id;
 
-        info!(
-            "Setting vault config program fee bps parameters: {:?}",
-            ix_builder
-        );
+        info!("Setting Is Paused: {:?}", ix_builder);
 
         self.process_transaction(&[ix], &signer.pubkey(), &[signer])
             .await?;
 
         if !self.print_tx {
             let account = self
-                .get_account::<jito_vault_client::accounts::Config>(&config_address)
+                .get_account::<jito_vault_client::accounts::Vault>(vault)
                 .await?;
             info!("{}", account.pretty_display());
         }
@@ -1913,24 +2296,534 @@ impl VaultCliHandler {
         Ok(())
     }
 
-    /// Sets a new program fee wallet for the Config
+    /// Sets the maximum share of a vault's deposits that may be delegated to a single operator
     ///
-    /// Updates the wallet address that receives program fees collected by the Jito Vault Program.
-    /// This operation can only be performed by the current program fee admin.
+    /// New delegations that would push an operator above this share are rejected.
+    /// This operation can only be performed by the vault admin.
     #[allow(clippy::future_not_send)]
-    async fn set_program_fee_wallet(&self, new_fee_wallet: &Pubkey) -> Result<()> {
+    async fn set_max_operator_share(
+        &self,
+        vault: &Pubkey,
+        max_operator_share_bps: u16,
+    ) -> Result<()> {
         let signer = self.signer()?;
 
         let config_address = Config::find_program_address(&self.vault_program_id).0;
-        let mut ix_builder = SetProgramFeeWalletBuilder::new();
+        let mut ix_builder = SetMaxOperatorShareBuilder::new();
         ix_builder
             .config(config_address)
-            .program_fee_admin(signer.pubkey())
-            .new_fee_wallet(*new_fee_wallet);
+            .vault(*vault)
+            .admin(signer.pubkey())
+            .max_operator_share_bps(max_operator_share_bps);
+
         let mut ix = ix_builder.instruction();
         ix.program_id = self.vault_program_id;
 
-        info!(
+        info!("Setting Max Operator Share: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Vault>(vault)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Sets the maximum amount that may be delegated to an operator
+    ///
+    /// New delegations that would push the operator's delegation above the cap are rejected.
+    /// This operation can only be performed by the vault admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_operator_delegation_cap(
+        &self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        max_delegation_amount: u64,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let vault_operator_delegation =
+            VaultOperatorDelegation::find_program_address(&self.vault_program_id, vault, operator)
+                .0;
+
+        let mut ix_builder = SetOperatorDelegationCapBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .operator(*operator)
+            .vault_operator_delegation(vault_operator_delegation)
+            .admin(signer.pubkey())
+            .max_delegation_amount(max_delegation_amount);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Setting Operator Delegation Cap: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::VaultOperatorDelegation>(
+                    &vault_operator_delegation,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Sets the share of each operator's delegated security an NCN may count and slash
+    ///
+    /// The operator delegations themselves are unchanged, the cap only bounds the NCN's view.
+    /// This operation can only be performed by the vault NCN admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_ncn_allocation_cap(
+        &self,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+        max_allocation_bps: u16,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let vault_ncn_ticket =
+            VaultNcnTicket::find_program_address(&self.vault_program_id, vault, ncn).0;
+
+        let mut ix_builder = SetNcnAllocationCapBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .ncn(*ncn)
+            .vault_ncn_ticket(vault_ncn_ticket)
+            .admin(signer.pubkey())
+            .max_allocation_bps(max_allocation_bps);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Setting NCN Allocation Cap: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::VaultNcnTicket>(&vault_ncn_ticket)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Sets the number of epochs depositors must hold their VRT before enqueueing a withdrawal
+    ///
+    /// The holding period counts from each depositor's latest deposit.
+    /// This operation can only be performed by the vault admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_min_holding_period(&self, vault: &Pubkey, min_holding_epochs: u64) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = SetMinHoldingPeriodBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .admin(signer.pubkey())
+            .min_holding_epochs(min_holding_epochs);
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Setting Min Holding Period: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Vault>(vault)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Sets the fee split of a vault
+    ///
+    /// The fee split becomes the vault's fee wallet, so all vault fees accumulate in its VRT token
+    /// account until distributed. This operation can only be performed by the vault admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_fee_split(
+        &self,
+        vault: &Pubkey,
+        recipients: &[Pubkey],
+        bps: &[u16],
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        if recipients.len() != bps.len() {
+            return Err(anyhow!("Each recipient needs exactly one share"));
+        }
+        if recipients.len() > MAX_FEE_SPLIT_RECIPIENTS {
+            return Err(anyhow!(
+                "A fee split has at most {MAX_FEE_SPLIT_RECIPIENTS} recipients"
+            ));
+        }
+        let mut recipient_bps = [0; MAX_FEE_SPLIT_RECIPIENTS];
+        recipient_bps[..bps.len()].copy_from_slice(bps);
+
+        let vault_account = self
+            .get_account::<jito_vault_client::accounts::Vault>(vault)
+            .await?;
+        let vault_fee_split = VaultFeeSplit::find_program_address(&self.vault_program_id, vault).0;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = SetFeeSplitBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .vrt_mint(vault_account.vrt_mint)
+            .vault_fee_split(vault_fee_split)
+            .vault_fee_split_token_account(get_associated_token_address(
+                &vault_fee_split,
+                &vault_account.vrt_mint,
+            ))
+            .admin(signer.pubkey())
+            .associated_token_program(spl_associated_token_account::id())
+            .recipient_bps(recipient_bps)
+            .add_remaining_accounts(
+                &recipients
+                    .iter()
+                    .map(|recipient| AccountMeta::new_readonly(*recipient, false))
+                    .collect::<Vec<_>>(),
+            );
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Setting Fee Split: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::VaultFeeSplit>(&vault_fee_split)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Distributes the fees accumulated by the fee split of a vault
+    ///
+    /// Anyone can distribute the fees. The recipients' VRT token accounts are created if needed.
+    #[allow(clippy::future_not_send)]
+    async fn distribute_fee_split(&self, vault: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let vault_account = self
+            .get_account::<jito_vault_client::accounts::Vault>(vault)
+            .await?;
+        let vault_fee_split = VaultFeeSplit::find_program_address(&self.vault_program_id, vault).0;
+        let vault_fee_split_account = self
+            .get_account::<jito_vault_client::accounts::VaultFeeSplit>(&vault_fee_split)
+            .await?;
+        let recipients: Vec<Pubkey> = vault_fee_split_account
+            .recipients
+            .iter()
+            .take(vault_fee_split_account.recipients_len as usize)
+            .map(|recipient| recipient.recipient)
+            .collect();
+
+        let mut ixs: Vec<_> = recipients
+            .iter()
+            .map(|recipient| {
+                create_associated_token_account_idempotent(
+                    &signer.pubkey(),
+                    recipient,
+                    &vault_account.vrt_mint,
+                    &spl_token::ID,
+                )
+            })
+            .collect();
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = DistributeFeeSplitBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .vrt_mint(vault_account.vrt_mint)
+            .vault_fee_split(vault_fee_split)
+            .vault_fee_split_token_account(get_associated_token_address(
+                &vault_fee_split,
+                &vault_account.vrt_mint,
+            ))
+            .add_remaining_accounts(
+                &recipients
+                    .iter()
+                    .map(|recipient| {
+                        AccountMeta::new(
+                            get_associated_token_address(recipient, &vault_account.vrt_mint),
+                            false,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+        ixs.push(ix);
+
+        info!("Distributing Fee Split: {:?}", ix_builder);
+
+        self.process_transaction(&ixs, &signer.pubkey(), &[signer])
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    async fn get_fee_split(&self, vault: &Pubkey) -> Result<()> {
+        let vault_fee_split = VaultFeeSplit::find_program_address(&self.vault_program_id, vault).0;
+        let account = self
+            .get_account::<jito_vault_client::accounts::VaultFeeSplit>(&vault_fee_split)
+            .await?;
+        self.print_out(None, Some(&vault_fee_split), &account)?;
+
+        Ok(())
+    }
+
+    /// Waives the holding period of a vault that is winding down
+    ///
+    /// Stakers can then enqueue withdrawals regardless of when they last deposited.
+    /// This operation can only be performed by the vault admin.
+    #[allow(clippy::future_not_send)]
+    async fn waive_holding_period(&self, vault: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = WaiveHoldingPeriodBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .admin(signer.pubkey());
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Waiving Holding Period: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Vault>(vault)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Starts winding down a specific vault
+    ///
+    /// Once winding down, the vault rejects deposits and new delegations, the next
+    /// vault update cools down every operator delegation and stakers can withdraw
+    /// without the mint burn admin. This operation can only be performed by the vault admin.
+    #[allow(clippy::future_not_send)]
+    async fn wind_down_vault(&self, vault: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = WindDownVaultBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .admin(signer.pubkey());
+
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Winding down Vault: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Vault>(vault)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Closes a vault which has finished winding down
+    ///
+    /// Requires every delegation to be cooled down and all VRT other than the VRT locked
+    /// in the burn vault to be redeemed. Leftover supported tokens and the rent of the vault,
+    /// its token account and the given update state trackers go to the vault admin.
+    #[allow(clippy::future_not_send)]
+    async fn close_vault(
+        &self,
+        vault: &Pubkey,
+        vault_update_state_trackers: &[Pubkey],
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+
+        let vault_account_raw = self.get_rpc_client().get_account(vault).await?;
+        let vault_account = Vault::try_from_slice_unchecked(&vault_account_raw.data)?;
+
+        let burn_vault =
+            BurnVault::find_program_address(&self.vault_program_id, &vault_account.base).0;
+        let admin_token_account =
+            get_associated_token_address(&signer.pubkey(), &vault_account.supported_mint);
+
+        let admin_ata_ix = create_associated_token_account_idempotent(
+            &signer.pubkey(),
+            &signer.pubkey(),
+            &vault_account.supported_mint,
+            &spl_token::ID,
+        );
+
+        let mut ix_builder = CloseVaultBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .vault_token_account(get_associated_token_address(
+                vault,
+                &vault_account.supported_mint,
+            ))
+            .burn_vault_vrt_token_account(get_associated_token_address(
+                &burn_vault,
+                &vault_account.vrt_mint,
+            ))
+            .admin(signer.pubkey())
+            .admin_token_account(admin_token_account);
+        for vault_update_state_tracker in vault_update_state_trackers {
+            ix_builder.add_remaining_account(AccountMeta::new(*vault_update_state_tracker, false));
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Closing Vault: {:?}", ix_builder);
+
+        self.process_transaction(&[admin_ata_ix, ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        Ok(())
+    }
+
+    /// Sets a new program fee (in basis points) for the Config
+    ///
+    /// Updates the fee percentage (specified in basis points) that the program
+    /// collects for vault operation. This operation can only be performed by the
+    /// current admin of the config.
+    #[allow(clippy::future_not_send)]
+    async fn set_program_fee(&self, new_fee_bps: u16) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = SetProgramFeeBuilder::new();
+        ix_builder
+            .config(config_address)
+            .admin(signer.pubkey())
+            .new_fee_bps(new_fee_bps);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!(
+            "Setting vault config program fee bps parameters: {:?}",
+            ix_builder
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Config>(&config_address)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Sets or clears the program fee override of a vault
+    ///
+    /// The vault's program fee picks up the change on its next update. The override can be
+    /// changed at most once per epoch. This operation can only be performed by the config admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_program_fee_override(
+        &self,
+        vault: &Pubkey,
+        program_fee_bps: Option<u16>,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = SetProgramFeeOverrideBuilder::new();
+        ix_builder
+            .config(config_address)
+            .vault(*vault)
+            .admin(signer.pubkey());
+        if let Some(program_fee_bps) = program_fee_bps {
+            ix_builder.program_fee_bps(program_fee_bps);
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Setting vault program fee override: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Vault>(vault)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Sets a new program fee wallet for the Config
+    ///
+    /// Updates the wallet address that receives program fees collected by the Jito Vault Program.
+    /// This operation can only be performed by the current program fee admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_program_fee_wallet(&self, new_fee_wallet: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = SetProgramFeeWalletBuilder::new();
+        ix_builder
+            .config(config_address)
+            .program_fee_admin(signer.pubkey())
+            .new_fee_wallet(*new_fee_wallet);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!(
             "Setting vault config program fee wallet parameters: {:?}",
             ix_builder
         );
@@ -2083,4 +2976,142 @@ impl VaultCliHandler {
 
         Ok(())
     }
+
+    /// Returns the account the config's creation policy requires from `creator` when creating
+    /// vaults: its creator allowlist entry or the creation fee treasury
+    #[allow(clippy::future_not_send)]
+    async fn creation_policy_account(&self, creator: &Pubkey) -> Result<Option<Pubkey>> {
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let config = self
+            .get_account::<jito_vault_client::accounts::Config>(&config_address)
+            .await?;
+
+        let creation_policy_account = match CreationPolicy::try_from(config.creation_policy)? {
+            CreationPolicy::Open => None,
+            CreationPolicy::Allowlist => {
+                Some(CreatorAllowlistEntry::find_program_address(&self.vault_program_id, creator).0)
+            }
+            CreationPolicy::Fee => {
+                info!(
+                    "Paying a creation fee of {} lamports",
+                    config.creation_fee_lamports
+                );
+                Some(config.creation_fee_treasury)
+            }
+        };
+
+        Ok(creation_policy_account)
+    }
+
+    /// Sets who may create vaults
+    ///
+    /// The creation fee and treasury are required by the fee policy and ignored otherwise. This
+    /// operation can only be performed by the config admin.
+    #[allow(clippy::future_not_send)]
+    async fn set_creation_policy(
+        &self,
+        creation_policy: &str,
+        creation_fee_lamports: Option<u64>,
+        creation_fee_treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        let signer = self.signer()?;
+
+        let creation_policy = match creation_policy {
+            "open" => jito_vault_client::types::CreationPolicy::Open,
+            "allowlist" => jito_vault_client::types::CreationPolicy::Allowlist,
+            "fee" => jito_vault_client::types::CreationPolicy::Fee,
+            _ => {
+                return Err(anyhow!(
+                    "Creation policy must be one of open, allowlist or fee"
+                ))
+            }
+        };
+        if creation_policy == jito_vault_client::types::CreationPolicy::Fee
+            && (creation_fee_lamports.is_none() || creation_fee_treasury.is_none())
+        {
+            return Err(anyhow!(
+                "The fee policy requires --creation-fee-lamports and --creation-fee-treasury"
+            ));
+        }
+
+        let config_address = Config::find_program_address(&self.vault_program_id).0;
+        let mut ix_builder = SetCreationPolicyBuilder::new();
+        ix_builder
+            .config(config_address)
+            .admin(signer.pubkey())
+            .creation_fee_treasury(creation_fee_treasury.unwrap_or_default())
+            .creation_policy(creation_policy)
+            .creation_fee_lamports(creation_fee_lamports.unwrap_or_default());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Setting vault config creation policy: {:?}", ix_builder);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::Config>(&config_address)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Adds a creator to the allowlist used by the allowlist creation policy
+    #[allow(clippy::future_not_send)]
+    async fn add_allowed_creator(&self, creator: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let creator_allowlist_entry =
+            CreatorAllowlistEntry::find_program_address(&self.vault_program_id, creator).0;
+        let mut ix_builder = InitializeCreatorAllowlistEntryBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .creator_allowlist_entry(creator_allowlist_entry)
+            .creator(*creator)
+            .admin(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Allowlisting creator {}", creator);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_vault_client::accounts::CreatorAllowlistEntry>(
+                    &creator_allowlist_entry,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    /// Removes a creator from the allowlist used by the allowlist creation policy
+    #[allow(clippy::future_not_send)]
+    async fn remove_allowed_creator(&self, creator: &Pubkey) -> Result<()> {
+        let signer = self.signer()?;
+
+        let mut ix_builder = CloseCreatorAllowlistEntryBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.vault_program_id).0)
+            .creator_allowlist_entry(
+                CreatorAllowlistEntry::find_program_address(&self.vault_program_id, creator).0,
+            )
+            .creator(*creator)
+            .admin(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        info!("Removing creator {} from the allowlist", creator);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await
+    }
 }
@@ -13,3 +13,9 @@ pub fn section_header(title: &str) -> String {
 pub fn field(name: &str, value: impl std::fmt::Display) -> String {
     format!("  {}: {}\n", name, value)
 }
+
+/// Decodes a zero padded UTF-8 field, such as the name stored in a metadata account
+pub fn padded_str(value: &[u8]) -> std::borrow::Cow<'_, str> {
+    let len = value.iter().position(|b| *b == 0).unwrap_or(value.len());
+    String::from_utf8_lossy(&value[..len])
+}
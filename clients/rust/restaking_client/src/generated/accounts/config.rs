@@ -27,8 +27,15 @@ pub struct Config {
     pub operator_count: u64,
     pub epoch_length: u64,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub creation_fee_treasury: Pubkey,
+    pub creation_fee_lamports: u64,
+    pub creation_policy: u8,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 222],
 }
 
 impl Config {
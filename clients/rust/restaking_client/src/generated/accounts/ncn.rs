@@ -63,8 +63,27 @@ pub struct Ncn {
     pub vault_count: u64,
     pub slasher_count: u64,
     pub bump: u8,
+    pub warmup_epochs: u8,
+    pub cooldown_epochs: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn_program: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn_program_signer: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator_bond_mint: Pubkey,
+    pub min_operator_bond: u64,
+    pub max_bond_slashable_per_epoch: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 149],
 }
 
 impl Ncn {
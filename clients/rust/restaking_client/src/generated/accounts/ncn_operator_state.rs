@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::JailRecord;
 use crate::generated::types::SlotToggle;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -28,8 +29,16 @@ pub struct NcnOperatorState {
     pub ncn_opt_in_state: SlotToggle,
     pub operator_opt_in_state: SlotToggle,
     pub bump: u8,
+    pub jailed_until_epoch: u64,
+    pub jail_reason_code: u8,
+    pub last_jail_slot: u64,
+    pub last_unjail_slot: u64,
+    pub jail_count: u64,
+    pub bond_slashed_epoch: u64,
+    pub bond_slashed_amount: u64,
+    pub jail_history: [JailRecord; 4],
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 146],
 }
 
 impl NcnOperatorState {
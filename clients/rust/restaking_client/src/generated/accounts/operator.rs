@@ -53,8 +53,16 @@ pub struct Operator {
     pub vault_count: u64,
     pub operator_fee_bps: u16,
     pub bump: u8,
+    pub next_operator_fee_bps: u16,
+    pub next_operator_fee_epoch: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub next_voter: Pubkey,
+    pub next_voter_epoch: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved_space: [u8; 261],
+    pub reserved_space: [u8; 211],
 }
 
 impl Operator {
@@ -46,6 +46,9 @@ pub enum JitoRestakingError {
     /// 1010 - NcnVaultTicketFailedWarmup
     #[error("NcnVaultTicketFailedWarmup")]
     NcnVaultTicketFailedWarmup = 0x3F2,
+    /// 1011 - NcnMetadataAdminInvalid
+    #[error("NcnMetadataAdminInvalid")]
+    NcnMetadataAdminInvalid = 0x3F3,
     /// 2000 - OperatorNcnAdminInvalid
     #[error("OperatorNcnAdminInvalid")]
     OperatorNcnAdminInvalid = 0x7D0,
@@ -91,6 +94,57 @@ pub enum JitoRestakingError {
     /// 2014 - ConfigAdminInvalid
     #[error("ConfigAdminInvalid")]
     ConfigAdminInvalid = 0x7DE,
+    /// 2015 - CreatorNotAllowlisted
+    #[error("CreatorNotAllowlisted")]
+    CreatorNotAllowlisted = 0x7DF,
+    /// 2016 - CreationFeeTreasuryInvalid
+    #[error("CreationFeeTreasuryInvalid")]
+    CreationFeeTreasuryInvalid = 0x7E0,
+    /// 2017 - OperatorFeeBumpTooLarge
+    #[error("OperatorFeeBumpTooLarge")]
+    OperatorFeeBumpTooLarge = 0x7E1,
+    /// 2018 - OperatorMetadataAdminInvalid
+    #[error("OperatorMetadataAdminInvalid")]
+    OperatorMetadataAdminInvalid = 0x7E2,
+    /// 2019 - MetadataFieldInvalid
+    #[error("MetadataFieldInvalid")]
+    MetadataFieldInvalid = 0x7E3,
+    /// 2020 - EpochSnapshotTooManyVaults
+    #[error("EpochSnapshotTooManyVaults")]
+    EpochSnapshotTooManyVaults = 0x7E4,
+    /// 2021 - EpochSnapshotOperatorNotIncluded
+    #[error("EpochSnapshotOperatorNotIncluded")]
+    EpochSnapshotOperatorNotIncluded = 0x7E5,
+    /// 2022 - EpochSnapshotVaultNotIncluded
+    #[error("EpochSnapshotVaultNotIncluded")]
+    EpochSnapshotVaultNotIncluded = 0x7E6,
+    /// 2023 - OperatorSnapshotInactive
+    #[error("OperatorSnapshotInactive")]
+    OperatorSnapshotInactive = 0x7E7,
+    /// 2024 - OperatorSnapshotVaultAlreadyRecorded
+    #[error("OperatorSnapshotVaultAlreadyRecorded")]
+    OperatorSnapshotVaultAlreadyRecorded = 0x7E8,
+    /// 2025 - NcnOperatorJailEpochInvalid
+    #[error("NcnOperatorJailEpochInvalid")]
+    NcnOperatorJailEpochInvalid = 0x7E9,
+    /// 2026 - NcnOperatorNotJailed
+    #[error("NcnOperatorNotJailed")]
+    NcnOperatorNotJailed = 0x7EA,
+    /// 2027 - NcnTransitionEpochsInvalid
+    #[error("NcnTransitionEpochsInvalid")]
+    NcnTransitionEpochsInvalid = 0x7EB,
+    /// 2028 - NcnProgramAdminInvalid
+    #[error("NcnProgramAdminInvalid")]
+    NcnProgramAdminInvalid = 0x7EC,
+    /// 2029 - NcnOperatorBondInsufficient
+    #[error("NcnOperatorBondInsufficient")]
+    NcnOperatorBondInsufficient = 0x7ED,
+    /// 2030 - NcnVaultSlasherTicketInactive
+    #[error("NcnVaultSlasherTicketInactive")]
+    NcnVaultSlasherTicketInactive = 0x7EE,
+    /// 2031 - NcnOperatorBondSlashCapExceeded
+    #[error("NcnOperatorBondSlashCapExceeded")]
+    NcnOperatorBondSlashCapExceeded = 0x7EF,
     /// 3000 - ArithmeticOverflow
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 0xBB8,
@@ -19,6 +19,8 @@ pub struct InitializeNcn {
     pub base: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeNcn {
@@ -30,7 +32,7 @@ impl InitializeNcn {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.config,
             false,
@@ -48,6 +50,17 @@ impl InitializeNcn {
             self.system_program,
             false,
         ));
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                creation_policy_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = InitializeNcnInstructionData::new().try_to_vec().unwrap();
 
@@ -85,6 +98,7 @@ impl Default for InitializeNcnInstructionData {
 ///   2. `[writable, signer]` admin
 ///   3. `[signer]` base
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable, optional]` creation_policy_account
 #[derive(Clone, Debug, Default)]
 pub struct InitializeNcnBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -92,6 +106,7 @@ pub struct InitializeNcnBuilder {
     admin: Option<solana_program::pubkey::Pubkey>,
     base: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    creation_policy_account: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -125,6 +140,16 @@ impl InitializeNcnBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    #[inline(always)]
+    pub fn creation_policy_account(
+        &mut self,
+        creation_policy_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.creation_policy_account = creation_policy_account;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -153,6 +178,7 @@ impl InitializeNcnBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            creation_policy_account: self.creation_policy_account,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -170,6 +196,8 @@ pub struct InitializeNcnCpiAccounts<'a, 'b> {
     pub base: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_ncn` CPI instruction.
@@ -186,6 +214,8 @@ pub struct InitializeNcnCpi<'a, 'b> {
     pub base: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> InitializeNcnCpi<'a, 'b> {
@@ -200,6 +230,7 @@ impl<'a, 'b> InitializeNcnCpi<'a, 'b> {
             admin: accounts.admin,
             base: accounts.base,
             system_program: accounts.system_program,
+            creation_policy_account: accounts.creation_policy_account,
         }
     }
     #[inline(always)]
@@ -235,7 +266,7 @@ impl<'a, 'b> InitializeNcnCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.config.key,
             false,
@@ -256,6 +287,17 @@ impl<'a, 'b> InitializeNcnCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *creation_policy_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -270,13 +312,16 @@ impl<'a, 'b> InitializeNcnCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.base.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            account_infos.push(creation_policy_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -298,6 +343,7 @@ impl<'a, 'b> InitializeNcnCpi<'a, 'b> {
 ///   2. `[writable, signer]` admin
 ///   3. `[signer]` base
 ///   4. `[]` system_program
+///   5. `[writable, optional]` creation_policy_account
 #[derive(Clone, Debug)]
 pub struct InitializeNcnCpiBuilder<'a, 'b> {
     instruction: Box<InitializeNcnCpiBuilderInstruction<'a, 'b>>,
@@ -312,6 +358,7 @@ impl<'a, 'b> InitializeNcnCpiBuilder<'a, 'b> {
             admin: None,
             base: None,
             system_program: None,
+            creation_policy_account: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -347,6 +394,16 @@ impl<'a, 'b> InitializeNcnCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    #[inline(always)]
+    pub fn creation_policy_account(
+        &mut self,
+        creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.creation_policy_account = creation_policy_account;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -403,6 +460,8 @@ impl<'a, 'b> InitializeNcnCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            creation_policy_account: self.instruction.creation_policy_account,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -419,6 +478,7 @@ struct InitializeNcnCpiBuilderInstruction<'a, 'b> {
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -19,6 +19,8 @@ pub struct InitializeOperator {
     pub base: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeOperator {
@@ -34,7 +36,7 @@ impl InitializeOperator {
         args: InitializeOperatorInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.config,
             false,
@@ -53,6 +55,17 @@ impl InitializeOperator {
             self.system_program,
             false,
         ));
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                creation_policy_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitializeOperatorInstructionData::new()
             .try_to_vec()
@@ -100,6 +113,7 @@ pub struct InitializeOperatorInstructionArgs {
 ///   2. `[writable, signer]` admin
 ///   3. `[signer]` base
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable, optional]` creation_policy_account
 #[derive(Clone, Debug, Default)]
 pub struct InitializeOperatorBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -107,6 +121,7 @@ pub struct InitializeOperatorBuilder {
     admin: Option<solana_program::pubkey::Pubkey>,
     base: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    creation_policy_account: Option<solana_program::pubkey::Pubkey>,
     operator_fee_bps: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -141,6 +156,16 @@ impl InitializeOperatorBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    #[inline(always)]
+    pub fn creation_policy_account(
+        &mut self,
+        creation_policy_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.creation_policy_account = creation_policy_account;
+        self
+    }
     #[inline(always)]
     pub fn operator_fee_bps(&mut self, operator_fee_bps: u16) -> &mut Self {
         self.operator_fee_bps = Some(operator_fee_bps);
@@ -174,6 +199,7 @@ impl InitializeOperatorBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            creation_policy_account: self.creation_policy_account,
         };
         let args = InitializeOperatorInstructionArgs {
             operator_fee_bps: self
@@ -197,6 +223,8 @@ pub struct InitializeOperatorCpiAccounts<'a, 'b> {
     pub base: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_operator` CPI instruction.
@@ -213,6 +241,8 @@ pub struct InitializeOperatorCpi<'a, 'b> {
     pub base: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: InitializeOperatorInstructionArgs,
 }
@@ -230,6 +260,7 @@ impl<'a, 'b> InitializeOperatorCpi<'a, 'b> {
             admin: accounts.admin,
             base: accounts.base,
             system_program: accounts.system_program,
+            creation_policy_account: accounts.creation_policy_account,
             __args: args,
         }
     }
@@ -266,7 +297,7 @@ impl<'a, 'b> InitializeOperatorCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.config.key,
             false,
@@ -287,6 +318,17 @@ impl<'a, 'b> InitializeOperatorCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *creation_policy_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -305,13 +347,16 @@ impl<'a, 'b> InitializeOperatorCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.base.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            account_infos.push(creation_policy_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -333,6 +378,7 @@ impl<'a, 'b> InitializeOperatorCpi<'a, 'b> {
 ///   2. `[writable, signer]` admin
 ///   3. `[signer]` base
 ///   4. `[]` system_program
+///   5. `[writable, optional]` creation_policy_account
 #[derive(Clone, Debug)]
 pub struct InitializeOperatorCpiBuilder<'a, 'b> {
     instruction: Box<InitializeOperatorCpiBuilderInstruction<'a, 'b>>,
@@ -347,6 +393,7 @@ impl<'a, 'b> InitializeOperatorCpiBuilder<'a, 'b> {
             admin: None,
             base: None,
             system_program: None,
+            creation_policy_account: None,
             operator_fee_bps: None,
             __remaining_accounts: Vec::new(),
         });
@@ -386,6 +433,16 @@ impl<'a, 'b> InitializeOperatorCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    #[inline(always)]
+    pub fn creation_policy_account(
+        &mut self,
+        creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.creation_policy_account = creation_policy_account;
+        self
+    }
     #[inline(always)]
     pub fn operator_fee_bps(&mut self, operator_fee_bps: u16) -> &mut Self {
         self.instruction.operator_fee_bps = Some(operator_fee_bps);
@@ -454,6 +511,8 @@ impl<'a, 'b> InitializeOperatorCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            creation_policy_account: self.instruction.creation_policy_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -471,6 +530,7 @@ struct InitializeOperatorCpiBuilderInstruction<'a, 'b> {
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     base: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_fee_bps: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -19,6 +19,8 @@ pub struct NcnWarmupOperator {
     pub ncn_operator_state: solana_program::pubkey::Pubkey,
 
     pub admin: solana_program::pubkey::Pubkey,
+    /// The bond token account of the operator, required when the NCN requires a bond
+    pub operator_bond: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl NcnWarmupOperator {
@@ -30,7 +32,7 @@ impl NcnWarmupOperator {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -49,6 +51,17 @@ impl NcnWarmupOperator {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.admin, true,
         ));
+        if let Some(operator_bond) = self.operator_bond {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operator_bond,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = NcnWarmupOperatorInstructionData::new()
             .try_to_vec()
@@ -88,6 +101,7 @@ impl Default for NcnWarmupOperatorInstructionData {
 ///   2. `[]` operator
 ///   3. `[writable]` ncn_operator_state
 ///   4. `[signer]` admin
+///   5. `[optional]` operator_bond
 #[derive(Clone, Debug, Default)]
 pub struct NcnWarmupOperatorBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -95,6 +109,7 @@ pub struct NcnWarmupOperatorBuilder {
     operator: Option<solana_program::pubkey::Pubkey>,
     ncn_operator_state: Option<solana_program::pubkey::Pubkey>,
     admin: Option<solana_program::pubkey::Pubkey>,
+    operator_bond: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -130,6 +145,16 @@ impl NcnWarmupOperatorBuilder {
         self.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// The bond token account of the operator, required when the NCN requires a bond
+    #[inline(always)]
+    pub fn operator_bond(
+        &mut self,
+        operator_bond: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.operator_bond = operator_bond;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -158,6 +183,7 @@ impl NcnWarmupOperatorBuilder {
                 .ncn_operator_state
                 .expect("ncn_operator_state is not set"),
             admin: self.admin.expect("admin is not set"),
+            operator_bond: self.operator_bond,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -175,6 +201,8 @@ pub struct NcnWarmupOperatorCpiAccounts<'a, 'b> {
     pub ncn_operator_state: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The bond token account of the operator, required when the NCN requires a bond
+    pub operator_bond: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `ncn_warmup_operator` CPI instruction.
@@ -191,6 +219,8 @@ pub struct NcnWarmupOperatorCpi<'a, 'b> {
     pub ncn_operator_state: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The bond token account of the operator, required when the NCN requires a bond
+    pub operator_bond: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> NcnWarmupOperatorCpi<'a, 'b> {
@@ -205,6 +235,7 @@ impl<'a, 'b> NcnWarmupOperatorCpi<'a, 'b> {
             operator: accounts.operator,
             ncn_operator_state: accounts.ncn_operator_state,
             admin: accounts.admin,
+            operator_bond: accounts.operator_bond,
         }
     }
     #[inline(always)]
@@ -240,7 +271,7 @@ impl<'a, 'b> NcnWarmupOperatorCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -261,6 +292,17 @@ impl<'a, 'b> NcnWarmupOperatorCpi<'a, 'b> {
             *self.admin.key,
             true,
         ));
+        if let Some(operator_bond) = self.operator_bond {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operator_bond.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -277,13 +319,16 @@ impl<'a, 'b> NcnWarmupOperatorCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.ncn_operator_state.clone());
         account_infos.push(self.admin.clone());
+        if let Some(operator_bond) = self.operator_bond {
+            account_infos.push(operator_bond.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -305,6 +350,7 @@ impl<'a, 'b> NcnWarmupOperatorCpi<'a, 'b> {
 ///   2. `[]` operator
 ///   3. `[writable]` ncn_operator_state
 ///   4. `[signer]` admin
+///   5. `[optional]` operator_bond
 #[derive(Clone, Debug)]
 pub struct NcnWarmupOperatorCpiBuilder<'a, 'b> {
     instruction: Box<NcnWarmupOperatorCpiBuilderInstruction<'a, 'b>>,
@@ -319,6 +365,7 @@ impl<'a, 'b> NcnWarmupOperatorCpiBuilder<'a, 'b> {
             operator: None,
             ncn_operator_state: None,
             admin: None,
+            operator_bond: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -357,6 +404,16 @@ impl<'a, 'b> NcnWarmupOperatorCpiBuilder<'a, 'b> {
         self.instruction.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// The bond token account of the operator, required when the NCN requires a bond
+    #[inline(always)]
+    pub fn operator_bond(
+        &mut self,
+        operator_bond: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operator_bond = operator_bond;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -413,6 +470,8 @@ impl<'a, 'b> NcnWarmupOperatorCpiBuilder<'a, 'b> {
                 .expect("ncn_operator_state is not set"),
 
             admin: self.instruction.admin.expect("admin is not set"),
+
+            operator_bond: self.instruction.operator_bond,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -429,6 +488,7 @@ struct NcnWarmupOperatorCpiBuilderInstruction<'a, 'b> {
     operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_operator_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operator_bond: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
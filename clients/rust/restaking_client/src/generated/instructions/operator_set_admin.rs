@@ -15,6 +15,8 @@ pub struct OperatorSetAdmin {
     pub old_admin: solana_program::pubkey::Pubkey,
 
     pub new_admin: solana_program::pubkey::Pubkey,
+
+    pub config: solana_program::pubkey::Pubkey,
 }
 
 impl OperatorSetAdmin {
@@ -26,7 +28,7 @@ impl OperatorSetAdmin {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.operator,
             false,
@@ -39,6 +41,10 @@ impl OperatorSetAdmin {
             self.new_admin,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = OperatorSetAdminInstructionData::new().try_to_vec().unwrap();
 
@@ -74,11 +80,13 @@ impl Default for OperatorSetAdminInstructionData {
 ///   0. `[writable]` operator
 ///   1. `[signer]` old_admin
 ///   2. `[signer]` new_admin
+///   3. `[]` config
 #[derive(Clone, Debug, Default)]
 pub struct OperatorSetAdminBuilder {
     operator: Option<solana_program::pubkey::Pubkey>,
     old_admin: Option<solana_program::pubkey::Pubkey>,
     new_admin: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -101,6 +109,11 @@ impl OperatorSetAdminBuilder {
         self.new_admin = Some(new_admin);
         self
     }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -125,6 +138,7 @@ impl OperatorSetAdminBuilder {
             operator: self.operator.expect("operator is not set"),
             old_admin: self.old_admin.expect("old_admin is not set"),
             new_admin: self.new_admin.expect("new_admin is not set"),
+            config: self.config.expect("config is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -138,6 +152,8 @@ pub struct OperatorSetAdminCpiAccounts<'a, 'b> {
     pub old_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub new_admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `operator_set_admin` CPI instruction.
@@ -150,6 +166,8 @@ pub struct OperatorSetAdminCpi<'a, 'b> {
     pub old_admin: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub new_admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> OperatorSetAdminCpi<'a, 'b> {
@@ -162,6 +180,7 @@ impl<'a, 'b> OperatorSetAdminCpi<'a, 'b> {
             operator: accounts.operator,
             old_admin: accounts.old_admin,
             new_admin: accounts.new_admin,
+            config: accounts.config,
         }
     }
     #[inline(always)]
@@ -197,7 +216,7 @@ impl<'a, 'b> OperatorSetAdminCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.operator.key,
             false,
@@ -210,6 +229,10 @@ impl<'a, 'b> OperatorSetAdminCpi<'a, 'b> {
             *self.new_admin.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -224,11 +247,12 @@ impl<'a, 'b> OperatorSetAdminCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.old_admin.clone());
         account_infos.push(self.new_admin.clone());
+        account_infos.push(self.config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -248,6 +272,7 @@ impl<'a, 'b> OperatorSetAdminCpi<'a, 'b> {
 ///   0. `[writable]` operator
 ///   1. `[signer]` old_admin
 ///   2. `[signer]` new_admin
+///   3. `[]` config
 #[derive(Clone, Debug)]
 pub struct OperatorSetAdminCpiBuilder<'a, 'b> {
     instruction: Box<OperatorSetAdminCpiBuilderInstruction<'a, 'b>>,
@@ -260,6 +285,7 @@ impl<'a, 'b> OperatorSetAdminCpiBuilder<'a, 'b> {
             operator: None,
             old_admin: None,
             new_admin: None,
+            config: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -288,6 +314,14 @@ impl<'a, 'b> OperatorSetAdminCpiBuilder<'a, 'b> {
         self.instruction.new_admin = Some(new_admin);
         self
     }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -337,6 +371,8 @@ impl<'a, 'b> OperatorSetAdminCpiBuilder<'a, 'b> {
             old_admin: self.instruction.old_admin.expect("old_admin is not set"),
 
             new_admin: self.instruction.new_admin.expect("new_admin is not set"),
+
+            config: self.instruction.config.expect("config is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -351,6 +387,7 @@ struct OperatorSetAdminCpiBuilderInstruction<'a, 'b> {
     operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     old_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -16,6 +16,8 @@ pub struct OperatorSetSecondaryAdmin {
     pub admin: solana_program::pubkey::Pubkey,
 
     pub new_admin: solana_program::pubkey::Pubkey,
+
+    pub config: solana_program::pubkey::Pubkey,
 }
 
 impl OperatorSetSecondaryAdmin {
@@ -31,7 +33,7 @@ impl OperatorSetSecondaryAdmin {
         args: OperatorSetSecondaryAdminInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.operator,
             false,
@@ -43,6 +45,10 @@ impl OperatorSetSecondaryAdmin {
             self.new_admin,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = OperatorSetSecondaryAdminInstructionData::new()
             .try_to_vec()
@@ -88,11 +94,13 @@ pub struct OperatorSetSecondaryAdminInstructionArgs {
 ///   0. `[writable]` operator
 ///   1. `[signer]` admin
 ///   2. `[]` new_admin
+///   3. `[]` config
 #[derive(Clone, Debug, Default)]
 pub struct OperatorSetSecondaryAdminBuilder {
     operator: Option<solana_program::pubkey::Pubkey>,
     admin: Option<solana_program::pubkey::Pubkey>,
     new_admin: Option<solana_program::pubkey::Pubkey>,
+    config: Option<solana_program::pubkey::Pubkey>,
     operator_admin_role: Option<OperatorAdminRole>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -117,6 +125,11 @@ impl OperatorSetSecondaryAdminBuilder {
         self
     }
     #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
     pub fn operator_admin_role(&mut self, operator_admin_role: OperatorAdminRole) -> &mut Self {
         self.operator_admin_role = Some(operator_admin_role);
         self
@@ -145,6 +158,7 @@ impl OperatorSetSecondaryAdminBuilder {
             operator: self.operator.expect("operator is not set"),
             admin: self.admin.expect("admin is not set"),
             new_admin: self.new_admin.expect("new_admin is not set"),
+            config: self.config.expect("config is not set"),
         };
         let args = OperatorSetSecondaryAdminInstructionArgs {
             operator_admin_role: self
@@ -164,6 +178,8 @@ pub struct OperatorSetSecondaryAdminCpiAccounts<'a, 'b> {
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub new_admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `operator_set_secondary_admin` CPI instruction.
@@ -176,6 +192,8 @@ pub struct OperatorSetSecondaryAdminCpi<'a, 'b> {
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub new_admin: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: OperatorSetSecondaryAdminInstructionArgs,
 }
@@ -191,6 +209,7 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpi<'a, 'b> {
             operator: accounts.operator,
             admin: accounts.admin,
             new_admin: accounts.new_admin,
+            config: accounts.config,
             __args: args,
         }
     }
@@ -227,7 +246,7 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.operator.key,
             false,
@@ -240,6 +259,10 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpi<'a, 'b> {
             *self.new_admin.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -258,11 +281,12 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.admin.clone());
         account_infos.push(self.new_admin.clone());
+        account_infos.push(self.config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -282,6 +306,7 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpi<'a, 'b> {
 ///   0. `[writable]` operator
 ///   1. `[signer]` admin
 ///   2. `[]` new_admin
+///   3. `[]` config
 #[derive(Clone, Debug)]
 pub struct OperatorSetSecondaryAdminCpiBuilder<'a, 'b> {
     instruction: Box<OperatorSetSecondaryAdminCpiBuilderInstruction<'a, 'b>>,
@@ -294,6 +319,7 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpiBuilder<'a, 'b> {
             operator: None,
             admin: None,
             new_admin: None,
+            config: None,
             operator_admin_role: None,
             __remaining_accounts: Vec::new(),
         });
@@ -321,6 +347,14 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
     pub fn operator_admin_role(&mut self, operator_admin_role: OperatorAdminRole) -> &mut Self {
         self.instruction.operator_admin_role = Some(operator_admin_role);
         self
@@ -381,6 +415,8 @@ impl<'a, 'b> OperatorSetSecondaryAdminCpiBuilder<'a, 'b> {
             admin: self.instruction.admin.expect("admin is not set"),
 
             new_admin: self.instruction.new_admin.expect("new_admin is not set"),
+
+            config: self.instruction.config.expect("config is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -396,6 +432,7 @@ struct OperatorSetSecondaryAdminCpiBuilderInstruction<'a, 'b> {
     operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     operator_admin_role: Option<OperatorAdminRole>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -0,0 +1,460 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct UpdateNcnMetadata {
+    pub ncn: solana_program::pubkey::Pubkey,
+
+    pub ncn_metadata: solana_program::pubkey::Pubkey,
+
+    pub metadata_admin: solana_program::pubkey::Pubkey,
+}
+
+impl UpdateNcnMetadata {
+    pub fn instruction(
+        &self,
+        args: UpdateNcnMetadataInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: UpdateNcnMetadataInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.ncn, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.ncn_metadata,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.metadata_admin,
+            true,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = UpdateNcnMetadataInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_RESTAKING_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct UpdateNcnMetadataInstructionData {
+    discriminator: u8,
+}
+
+impl UpdateNcnMetadataInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 30 }
+    }
+}
+
+impl Default for UpdateNcnMetadataInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateNcnMetadataInstructionArgs {
+    pub name: Option<String>,
+    pub website: Option<String>,
+    pub logo_uri: Option<String>,
+    pub contact: Option<String>,
+}
+
+/// Instruction builder for `UpdateNcnMetadata`.
+///
+/// ### Accounts:
+///
+///   0. `[]` ncn
+///   1. `[writable]` ncn_metadata
+///   2. `[signer]` metadata_admin
+#[derive(Clone, Debug, Default)]
+pub struct UpdateNcnMetadataBuilder {
+    ncn: Option<solana_program::pubkey::Pubkey>,
+    ncn_metadata: Option<solana_program::pubkey::Pubkey>,
+    metadata_admin: Option<solana_program::pubkey::Pubkey>,
+    name: Option<String>,
+    website: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl UpdateNcnMetadataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_metadata(&mut self, ncn_metadata: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.ncn_metadata = Some(ncn_metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata_admin(&mut self, metadata_admin: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.metadata_admin = Some(metadata_admin);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn website(&mut self, website: String) -> &mut Self {
+        self.website = Some(website);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn logo_uri(&mut self, logo_uri: String) -> &mut Self {
+        self.logo_uri = Some(logo_uri);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn contact(&mut self, contact: String) -> &mut Self {
+        self.contact = Some(contact);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = UpdateNcnMetadata {
+            ncn: self.ncn.expect("ncn is not set"),
+            ncn_metadata: self.ncn_metadata.expect("ncn_metadata is not set"),
+            metadata_admin: self.metadata_admin.expect("metadata_admin is not set"),
+        };
+        let args = UpdateNcnMetadataInstructionArgs {
+            name: self.name.clone(),
+            website: self.website.clone(),
+            logo_uri: self.logo_uri.clone(),
+            contact: self.contact.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `update_ncn_metadata` CPI accounts.
+pub struct UpdateNcnMetadataCpiAccounts<'a, 'b> {
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub metadata_admin: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `update_ncn_metadata` CPI instruction.
+pub struct UpdateNcnMetadataCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub ncn_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub metadata_admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: UpdateNcnMetadataInstructionArgs,
+}
+
+impl<'a, 'b> UpdateNcnMetadataCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: UpdateNcnMetadataCpiAccounts<'a, 'b>,
+        args: UpdateNcnMetadataInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            ncn: accounts.ncn,
+            ncn_metadata: accounts.ncn_metadata,
+            metadata_admin: accounts.metadata_admin,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.ncn.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.ncn_metadata.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.metadata_admin.key,
+            true,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = UpdateNcnMetadataInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_RESTAKING_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.ncn.clone());
+        account_infos.push(self.ncn_metadata.clone());
+        account_infos.push(self.metadata_admin.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `UpdateNcnMetadata` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` ncn
+///   1. `[writable]` ncn_metadata
+///   2. `[signer]` metadata_admin
+#[derive(Clone, Debug)]
+pub struct UpdateNcnMetadataCpiBuilder<'a, 'b> {
+    instruction: Box<UpdateNcnMetadataCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> UpdateNcnMetadataCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(UpdateNcnMetadataCpiBuilderInstruction {
+            __program: program,
+            ncn: None,
+            ncn_metadata: None,
+            metadata_admin: None,
+            name: None,
+            website: None,
+            logo_uri: None,
+            contact: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn ncn(&mut self, ncn: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.ncn = Some(ncn);
+        self
+    }
+    #[inline(always)]
+    pub fn ncn_metadata(
+        &mut self,
+        ncn_metadata: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.ncn_metadata = Some(ncn_metadata);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata_admin(
+        &mut self,
+        metadata_admin: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.metadata_admin = Some(metadata_admin);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.instruction.name = Some(name);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn website(&mut self, website: String) -> &mut Self {
+        self.instruction.website = Some(website);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn logo_uri(&mut self, logo_uri: String) -> &mut Self {
+        self.instruction.logo_uri = Some(logo_uri);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn contact(&mut self, contact: String) -> &mut Self {
+        self.instruction.contact = Some(contact);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = UpdateNcnMetadataInstructionArgs {
+            name: self.instruction.name.clone(),
+            website: self.instruction.website.clone(),
+            logo_uri: self.instruction.logo_uri.clone(),
+            contact: self.instruction.contact.clone(),
+        };
+        let instruction = UpdateNcnMetadataCpi {
+            __program: self.instruction.__program,
+
+            ncn: self.instruction.ncn.expect("ncn is not set"),
+
+            ncn_metadata: self
+                .instruction
+                .ncn_metadata
+                .expect("ncn_metadata is not set"),
+
+            metadata_admin: self
+                .instruction
+                .metadata_admin
+                .expect("metadata_admin is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct UpdateNcnMetadataCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    ncn: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    metadata_admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    name: Option<String>,
+    website: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
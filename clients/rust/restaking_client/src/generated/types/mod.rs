@@ -5,10 +5,18 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#creation_policy;
+pub(crate) mod r#jail_record;
+pub(crate) mod r#metadata_fields;
 pub(crate) mod r#ncn_admin_role;
 pub(crate) mod r#operator_admin_role;
 pub(crate) mod r#slot_toggle;
+pub(crate) mod r#vault_operator_stake;
 
+pub use self::r#creation_policy::*;
+pub use self::r#jail_record::*;
+pub use self::r#metadata_fields::*;
 pub use self::r#ncn_admin_role::*;
 pub use self::r#operator_admin_role::*;
 pub use self::r#slot_toggle::*;
+pub use self::r#vault_operator_stake::*;
@@ -22,6 +22,17 @@ impl PrettyDisplay for Config {
         output.push_str(&section_header("Epoch Information"));
         output.push_str(&field("Epoch Length", self.epoch_length));
 
+        output.push_str(&section_header("Creation Policy"));
+        let creation_policy = match self.creation_policy {
+            0 => "Open".to_string(),
+            1 => "Allowlist".to_string(),
+            2 => "Fee".to_string(),
+            other => format!("Unknown ({other})"),
+        };
+        output.push_str(&field("Creation Policy", creation_policy));
+        output.push_str(&field("Creation Fee Lamports", self.creation_fee_lamports));
+        output.push_str(&field("Creation Fee Treasury", self.creation_fee_treasury));
+
         output
     }
 }
@@ -43,7 +54,10 @@ mod tests {
             operator_count: 10,
             epoch_length: 432000,
             bump: 254,
-            reserved: [0; 263],
+            creation_fee_treasury: Pubkey::new_unique(),
+            creation_fee_lamports: 1_000_000,
+            creation_policy: 2,
+            reserved: [0; 222],
         };
 
         let output = config.pretty_display();
@@ -54,5 +68,8 @@ mod tests {
         assert!(output.contains(&config.epoch_length.to_string()));
         assert!(output.contains(&config.ncn_count.to_string()));
         assert!(output.contains(&config.operator_count.to_string()));
+        assert!(output.contains("Fee"));
+        assert!(output.contains(&config.creation_fee_lamports.to_string()));
+        assert!(output.contains(&config.creation_fee_treasury.to_string()));
     }
 }
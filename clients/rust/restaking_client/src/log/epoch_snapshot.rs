@@ -0,0 +1,60 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::EpochSnapshot;
+
+impl PrettyDisplay for EpochSnapshot {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Epoch Snapshot Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("NCN", self.ncn));
+        output.push_str(&field("Epoch", self.epoch));
+        output.push_str(&field("Slot Created", self.slot_created));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&section_header("Progress"));
+        output.push_str(&field("Operator Count", self.operator_count));
+        output.push_str(&field("Vault Count", self.vault_count));
+        output.push_str(&field("Operators Snapshotted", self.operators_snapshotted));
+        output.push_str(&field("Active Operator Count", self.active_operator_count));
+        output.push_str(&field(
+            "Complete",
+            self.operators_snapshotted == self.operator_count,
+        ));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::accounts::EpochSnapshot;
+
+    #[test]
+    fn test_epoch_snapshot_pretty_display_structure() {
+        let epoch_snapshot = EpochSnapshot {
+            discriminator: 12345,
+            ncn: Pubkey::new_unique(),
+            epoch: 7,
+            slot_created: 3_000,
+            operator_count: 4,
+            vault_count: 2,
+            operators_snapshotted: 4,
+            active_operator_count: 3,
+            bump: 5,
+            reserved: [0; 263],
+        };
+
+        let output = epoch_snapshot.pretty_display();
+
+        assert!(output.contains(&epoch_snapshot.ncn.to_string()));
+        assert!(output.contains(&epoch_snapshot.slot_created.to_string()));
+        assert!(output.contains("Active Operator Count: 3\n"));
+        assert!(output.contains("Complete: true\n"));
+    }
+}
@@ -0,0 +1,18 @@
+use jito_restaking_client_common::log::{field, padded_str, section_header};
+
+use crate::types::MetadataFields;
+
+/// Renders the [`MetadataFields`] shared by the NCN and operator metadata accounts
+pub(crate) fn metadata_fields_display(metadata: &MetadataFields) -> String {
+    let mut output = String::new();
+
+    output.push_str(&section_header("Profile"));
+    output.push_str(&field("Update Count", metadata.update_count));
+    output.push_str(&field("Last Update Slot", metadata.last_update_slot));
+    output.push_str(&field("Name", padded_str(&metadata.name)));
+    output.push_str(&field("Website", padded_str(&metadata.website)));
+    output.push_str(&field("Logo URI", padded_str(&metadata.logo_uri)));
+    output.push_str(&field("Contact", padded_str(&metadata.contact)));
+
+    output
+}
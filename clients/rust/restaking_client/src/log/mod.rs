@@ -1,7 +1,13 @@
 pub(crate) mod config;
+pub(crate) mod creator_allowlist_entry;
+pub(crate) mod epoch_snapshot;
+pub(crate) mod metadata_fields;
 pub(crate) mod ncn;
+pub(crate) mod ncn_metadata;
 pub(crate) mod ncn_operator_state;
 pub(crate) mod ncn_vault_slasher_ticket;
 pub(crate) mod ncn_vault_ticket;
 pub(crate) mod operator;
+pub(crate) mod operator_metadata;
+pub(crate) mod operator_snapshot;
 pub(crate) mod operator_vault_ticket;
@@ -1,4 +1,5 @@
 use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+use solana_program::pubkey::Pubkey;
 
 use crate::accounts::Ncn;
 
@@ -28,6 +29,30 @@ impl PrettyDisplay for Ncn {
         output.push_str(&field("Vault Count", self.vault_count));
         output.push_str(&field("Slasher Count", self.slasher_count));
 
+        output.push_str(&section_header("Transitions"));
+        output.push_str(&field("Warmup Epochs", self.warmup_epochs));
+        output.push_str(&field("Cooldown Epochs", self.cooldown_epochs));
+
+        output.push_str(&section_header("NCN Program"));
+        if self.ncn_program == Pubkey::default() {
+            output.push_str(&field("NCN Program", "None"));
+        } else {
+            output.push_str(&field("NCN Program", self.ncn_program));
+            output.push_str(&field("NCN Program Signer", self.ncn_program_signer));
+        }
+
+        output.push_str(&section_header("Operator Bond"));
+        if self.min_operator_bond == 0 && self.max_bond_slashable_per_epoch == 0 {
+            output.push_str(&field("Operator Bond", "None"));
+        } else {
+            output.push_str(&field("Operator Bond Mint", self.operator_bond_mint));
+            output.push_str(&field("Min Operator Bond", self.min_operator_bond));
+            output.push_str(&field(
+                "Max Bond Slashable Per Epoch",
+                self.max_bond_slashable_per_epoch,
+            ));
+        }
+
         output
     }
 }
@@ -57,7 +82,14 @@ mod tests {
             vault_count: 2,
             slasher_count: 3,
             bump: 254,
-            reserved: [0; 263],
+            warmup_epochs: 2,
+            cooldown_epochs: 3,
+            ncn_program: Pubkey::new_unique(),
+            ncn_program_signer: Pubkey::new_unique(),
+            operator_bond_mint: Pubkey::new_unique(),
+            min_operator_bond: 1_000,
+            max_bond_slashable_per_epoch: 100,
+            reserved: [0; 149],
         };
 
         let output = ncn.pretty_display();
@@ -76,5 +108,12 @@ mod tests {
         assert!(output.contains(&ncn.vault_count.to_string()));
         assert!(output.contains(&ncn.slasher_count.to_string()));
         assert!(output.contains(&ncn.bump.to_string()));
+        assert!(output.contains("Warmup Epochs"));
+        assert!(output.contains("Cooldown Epochs"));
+        assert!(output.contains(&ncn.ncn_program.to_string()));
+        assert!(output.contains(&ncn.ncn_program_signer.to_string()));
+        assert!(output.contains(&ncn.operator_bond_mint.to_string()));
+        assert!(output.contains("Min Operator Bond: 1000\n"));
+        assert!(output.contains("Max Bond Slashable Per Epoch: 100\n"));
     }
 }
@@ -31,6 +31,30 @@ impl PrettyDisplay for NcnOperatorState {
             self.operator_opt_in_state.slot_removed,
         ));
 
+        output.push_str(&section_header("Jail"));
+        output.push_str(&field("Jailed Until Epoch", self.jailed_until_epoch));
+        output.push_str(&field("Jail Reason Code", self.jail_reason_code));
+        output.push_str(&field("Last Jail Slot", self.last_jail_slot));
+        output.push_str(&field("Last Unjail Slot", self.last_unjail_slot));
+        output.push_str(&field("Jail Count", self.jail_count));
+
+        // The jail history is a ring, jailing number `n` being stored at `n % len`
+        let jail_history_len = self.jail_history.len() as u64;
+        for jailing in self.jail_count.saturating_sub(jail_history_len)..self.jail_count {
+            let jail_record = &self.jail_history[(jailing % jail_history_len) as usize];
+            output.push_str(&field(
+                &format!("Jailing {}", jailing + 1),
+                format!(
+                    "reason code {} at slot {} until epoch {}",
+                    jail_record.reason_code, jail_record.jail_slot, jail_record.until_epoch
+                ),
+            ));
+        }
+
+        output.push_str(&section_header("Bond Slashing"));
+        output.push_str(&field("Bond Slashed Epoch", self.bond_slashed_epoch));
+        output.push_str(&field("Bond Slashed Amount", self.bond_slashed_amount));
+
         output
     }
 }
@@ -40,7 +64,10 @@ mod tests {
     use anchor_lang::prelude::Pubkey;
     use jito_restaking_client_common::log::PrettyDisplay;
 
-    use crate::{accounts::NcnOperatorState, types::SlotToggle};
+    use crate::{
+        accounts::NcnOperatorState,
+        types::{JailRecord, SlotToggle},
+    };
 
     #[test]
     fn test_ncn_operator_state_pretty_display_structure() {
@@ -52,15 +79,48 @@ mod tests {
             ncn_opt_in_state: SlotToggle {
                 slot_added: 0,
                 slot_removed: 1,
-                reserved: [0; 32],
+                warmup_epochs: 0,
+                cooldown_epochs: 0,
+                reserved: [0; 30],
             },
             operator_opt_in_state: SlotToggle {
                 slot_added: 0,
                 slot_removed: 1,
-                reserved: [0; 32],
+                warmup_epochs: 0,
+                cooldown_epochs: 0,
+                reserved: [0; 30],
             },
             bump: 254,
-            reserved: [0; 263],
+            jailed_until_epoch: 12,
+            jail_reason_code: 7,
+            last_jail_slot: 1_000,
+            last_unjail_slot: 0,
+            jail_count: 2,
+            bond_slashed_epoch: 11,
+            bond_slashed_amount: 500,
+            jail_history: [
+                JailRecord {
+                    reason_code: 3,
+                    jail_slot: 500,
+                    until_epoch: 6,
+                },
+                JailRecord {
+                    reason_code: 7,
+                    jail_slot: 1_000,
+                    until_epoch: 12,
+                },
+                JailRecord {
+                    reason_code: 0,
+                    jail_slot: 0,
+                    until_epoch: 0,
+                },
+                JailRecord {
+                    reason_code: 0,
+                    jail_slot: 0,
+                    until_epoch: 0,
+                },
+            ],
+            reserved: [0; 146],
         };
 
         let output = ncn_operator_state.pretty_display();
@@ -83,5 +143,13 @@ mod tests {
                 .to_string()
         ));
         assert!(output.contains(&ncn_operator_state.bump.to_string()));
+        assert!(output.contains("Jailed Until Epoch: 12\n"));
+        assert!(output.contains("Jail Reason Code: 7\n"));
+        assert!(output.contains("Jail Count: 2\n"));
+        assert!(output.contains("Jailing 1: reason code 3 at slot 500 until epoch 6\n"));
+        assert!(output.contains("Jailing 2: reason code 7 at slot 1000 until epoch 12\n"));
+        assert!(!output.contains("Jailing 3"));
+        assert!(output.contains("Bond Slashed Epoch: 11\n"));
+        assert!(output.contains("Bond Slashed Amount: 500\n"));
     }
 }
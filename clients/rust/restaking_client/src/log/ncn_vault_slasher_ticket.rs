@@ -16,6 +16,14 @@ impl PrettyDisplay for NcnVaultSlasherTicket {
             "Max Slashable Per Epoch",
             self.max_slashable_per_epoch,
         ));
+        output.push_str(&field(
+            "Next Max Slashable Per Epoch",
+            self.next_max_slashable_per_epoch,
+        ));
+        output.push_str(&field(
+            "Next Max Slashable Epoch",
+            self.next_max_slashable_epoch,
+        ));
         output.push_str(&field("Index", self.index));
         output.push_str(&field("Bump", self.bump));
 
@@ -46,10 +54,14 @@ mod tests {
             state: SlotToggle {
                 slot_added: 0,
                 slot_removed: 1,
-                reserved: [0; 32],
+                warmup_epochs: 0,
+                cooldown_epochs: 0,
+                reserved: [0; 30],
             },
             bump: 2,
-            reserved: [0; 263],
+            next_max_slashable_per_epoch: 5,
+            next_max_slashable_epoch: 6,
+            reserved: [0; 247],
         };
 
         let output = ncn_vault_slasher_ticket.pretty_display();
@@ -58,6 +70,16 @@ mod tests {
         assert!(output.contains(&ncn_vault_slasher_ticket.vault.to_string()));
         assert!(output.contains(&ncn_vault_slasher_ticket.slasher.to_string()));
         assert!(output.contains(&ncn_vault_slasher_ticket.max_slashable_per_epoch.to_string()));
+        assert!(output.contains(
+            &ncn_vault_slasher_ticket
+                .next_max_slashable_per_epoch
+                .to_string()
+        ));
+        assert!(output.contains(
+            &ncn_vault_slasher_ticket
+                .next_max_slashable_epoch
+                .to_string()
+        ));
         assert!(output.contains(&ncn_vault_slasher_ticket.index.to_string()));
         assert!(output.contains(&ncn_vault_slasher_ticket.state.slot_added.to_string()));
         assert!(output.contains(&ncn_vault_slasher_ticket.state.slot_removed.to_string()));
@@ -39,7 +39,9 @@ mod tests {
             state: SlotToggle {
                 slot_added: 0,
                 slot_removed: 1,
-                reserved: [0; 32],
+                warmup_epochs: 0,
+                cooldown_epochs: 0,
+                reserved: [0; 30],
             },
             bump: 2,
             reserved: [0; 263],
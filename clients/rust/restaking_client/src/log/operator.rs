@@ -26,6 +26,22 @@ impl PrettyDisplay for Operator {
         output.push_str(&field("Vault Count", self.vault_count));
         output.push_str(&field("Operator Fee BPS", self.operator_fee_bps));
 
+        output.push_str(&section_header("Pending Fee Change"));
+        if self.next_operator_fee_epoch == 0 {
+            output.push_str(&field("Next Operator Fee BPS", "None"));
+        } else {
+            output.push_str(&field("Next Operator Fee BPS", self.next_operator_fee_bps));
+            output.push_str(&field("Effective Epoch", self.next_operator_fee_epoch));
+        }
+
+        output.push_str(&section_header("Pending Voter Change"));
+        if self.next_voter_epoch == 0 {
+            output.push_str(&field("Next Voter", "None"));
+        } else {
+            output.push_str(&field("Next Voter", self.next_voter));
+            output.push_str(&field("Effective Epoch", self.next_voter_epoch));
+        }
+
         output
     }
 }
@@ -53,7 +69,11 @@ mod tests {
             vault_count: 3,
             operator_fee_bps: 4,
             bump: 5,
-            reserved_space: [0; 261],
+            next_operator_fee_bps: 6,
+            next_operator_fee_epoch: 7,
+            next_voter: Pubkey::new_unique(),
+            next_voter_epoch: 8,
+            reserved_space: [0; 211],
         };
 
         let output = operator.pretty_display();
@@ -70,5 +90,9 @@ mod tests {
         assert!(output.contains(&operator.vault_count.to_string()));
         assert!(output.contains(&operator.operator_fee_bps.to_string()));
         assert!(output.contains(&operator.bump.to_string()));
+        assert!(output.contains(&operator.next_operator_fee_bps.to_string()));
+        assert!(output.contains(&operator.next_operator_fee_epoch.to_string()));
+        assert!(output.contains(&operator.next_voter.to_string()));
+        assert!(output.contains(&operator.next_voter_epoch.to_string()));
     }
 }
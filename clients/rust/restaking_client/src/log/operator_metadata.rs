@@ -0,0 +1,62 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use super::metadata_fields::metadata_fields_display;
+use crate::accounts::OperatorMetadata;
+
+impl PrettyDisplay for OperatorMetadata {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Operator Metadata Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Operator", self.operator));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&metadata_fields_display(&self.metadata));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::{accounts::OperatorMetadata, types::MetadataFields};
+
+    fn padded<const N: usize>(value: &str) -> [u8; N] {
+        let mut field = [0; N];
+        field[..value.len()].copy_from_slice(value.as_bytes());
+        field
+    }
+
+    #[test]
+    fn test_operator_metadata_pretty_display_structure() {
+        let operator_metadata = OperatorMetadata {
+            discriminator: 12345,
+            operator: Pubkey::new_unique(),
+            metadata: MetadataFields {
+                update_count: 2,
+                last_update_slot: 3,
+                name: padded("Jito"),
+                website: padded("https://jito.network"),
+                logo_uri: padded("https://jito.network/logo.png"),
+                contact: padded("hello@jito.network"),
+            },
+            bump: 4,
+            reserved: [0; 263],
+        };
+
+        let output = operator_metadata.pretty_display();
+
+        assert!(output.contains(&operator_metadata.operator.to_string()));
+        assert!(output.contains(&operator_metadata.metadata.update_count.to_string()));
+        assert!(output.contains(&operator_metadata.metadata.last_update_slot.to_string()));
+        assert!(output.contains(&operator_metadata.bump.to_string()));
+        assert!(output.contains("Name: Jito\n"));
+        assert!(output.contains("https://jito.network/logo.png"));
+        assert!(output.contains("hello@jito.network"));
+    }
+}
@@ -0,0 +1,79 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::OperatorSnapshot;
+
+impl PrettyDisplay for OperatorSnapshot {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Operator Snapshot Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("NCN", self.ncn));
+        output.push_str(&field("Operator", self.operator));
+        output.push_str(&field("Epoch", self.epoch));
+        output.push_str(&field("Slot Created", self.slot_created));
+        output.push_str(&field("Active", self.is_active));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&section_header("Progress"));
+        output.push_str(&field("Vault Count", self.vault_count));
+        output.push_str(&field("Vaults Snapshotted", self.vaults_snapshotted));
+
+        output.push_str(&section_header("Vault Stakes"));
+        for vault_operator_stake in self
+            .vault_operator_stakes
+            .iter()
+            .take(self.vault_count as usize)
+            .filter(|vault_operator_stake| vault_operator_stake.is_snapshotted)
+        {
+            output.push_str(&field(
+                &vault_operator_stake.vault.to_string(),
+                format!(
+                    "{} ({})",
+                    vault_operator_stake.stake, vault_operator_stake.st_mint
+                ),
+            ));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::{accounts::OperatorSnapshot, types::VaultOperatorStake};
+
+    #[test]
+    fn test_operator_snapshot_pretty_display_structure() {
+        let vault_operator_stakes = core::array::from_fn(|i| VaultOperatorStake {
+            vault: Pubkey::new_unique(),
+            st_mint: Pubkey::new_unique(),
+            stake: 1_000 * (i as u64 + 1),
+            is_snapshotted: i < 1,
+        });
+        let operator_snapshot = OperatorSnapshot {
+            discriminator: 12345,
+            ncn: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+            epoch: 7,
+            slot_created: 3_000,
+            is_active: true,
+            vault_count: 2,
+            vaults_snapshotted: 1,
+            vault_operator_stakes,
+            bump: 5,
+            reserved: [0; 263],
+        };
+
+        let output = operator_snapshot.pretty_display();
+
+        assert!(output.contains(&operator_snapshot.operator.to_string()));
+        assert!(output.contains(&operator_snapshot.vault_operator_stakes[0].vault.to_string()));
+        assert!(!output.contains(&operator_snapshot.vault_operator_stakes[1].vault.to_string()));
+        assert!(output.contains("Vaults Snapshotted: 1\n"));
+    }
+}
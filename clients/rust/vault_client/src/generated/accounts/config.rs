@@ -40,8 +40,15 @@ pub struct Config {
     )]
     pub fee_admin: Pubkey,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub creation_fee_treasury: Pubkey,
+    pub creation_fee_lamports: u64,
+    pub creation_policy: u8,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 229],
+    pub reserved: [u8; 188],
 }
 
 impl Config {
@@ -6,19 +6,27 @@
 //!
 
 pub(crate) mod r#config;
+pub(crate) mod r#creator_allowlist_entry;
 pub(crate) mod r#vault;
+pub(crate) mod r#vault_deposit_receipt;
+pub(crate) mod r#vault_fee_split;
 pub(crate) mod r#vault_ncn_slasher_operator_ticket;
 pub(crate) mod r#vault_ncn_slasher_ticket;
 pub(crate) mod r#vault_ncn_ticket;
 pub(crate) mod r#vault_operator_delegation;
+pub(crate) mod r#vault_referral;
 pub(crate) mod r#vault_staker_withdrawal_ticket;
 pub(crate) mod r#vault_update_state_tracker;
 
 pub use self::r#config::*;
+pub use self::r#creator_allowlist_entry::*;
 pub use self::r#vault::*;
+pub use self::r#vault_deposit_receipt::*;
+pub use self::r#vault_fee_split::*;
 pub use self::r#vault_ncn_slasher_operator_ticket::*;
 pub use self::r#vault_ncn_slasher_ticket::*;
 pub use self::r#vault_ncn_ticket::*;
 pub use self::r#vault_operator_delegation::*;
+pub use self::r#vault_referral::*;
 pub use self::r#vault_staker_withdrawal_ticket::*;
 pub use self::r#vault_update_state_tracker::*;
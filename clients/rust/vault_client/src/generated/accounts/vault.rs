@@ -106,8 +106,16 @@ pub struct Vault {
     pub bump: u8,
     pub is_paused: bool,
     pub last_start_state_update_slot: u64,
+    pub is_winding_down: bool,
+    pub max_operator_share_bps: u16,
+    pub min_holding_epochs: u64,
+    pub is_holding_period_waived: bool,
+    pub has_program_fee_override: bool,
+    pub program_fee_override_bps: u16,
+    pub last_program_fee_override_change_slot: u64,
+    pub referral_fee_bps: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 251],
+    pub reserved: [u8; 226],
 }
 
 impl Vault {
@@ -33,8 +33,10 @@ pub struct VaultNcnSlasherTicket {
     pub index: u64,
     pub state: SlotToggle,
     pub bump: u8,
+    pub next_max_slashable_per_epoch: u64,
+    pub next_max_slashable_epoch: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 247],
 }
 
 impl VaultNcnSlasherTicket {
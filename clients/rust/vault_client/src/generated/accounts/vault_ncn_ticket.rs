@@ -27,8 +27,9 @@ pub struct VaultNcnTicket {
     pub index: u64,
     pub state: SlotToggle,
     pub bump: u8,
+    pub max_allocation_bps: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 261],
 }
 
 impl VaultNcnTicket {
@@ -28,8 +28,9 @@ pub struct VaultOperatorDelegation {
     pub last_update_slot: u64,
     pub index: u64,
     pub bump: u8,
+    pub max_delegation_amount: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 255],
 }
 
 impl VaultOperatorDelegation {
@@ -193,6 +193,66 @@ pub enum JitoVaultError {
     /// 1059 - NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate
     #[error("NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate")]
     NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate = 0x423,
+    /// 1060 - VaultIsWindingDown
+    #[error("VaultIsWindingDown")]
+    VaultIsWindingDown = 0x424,
+    /// 1061 - VaultIsNotWindingDown
+    #[error("VaultIsNotWindingDown")]
+    VaultIsNotWindingDown = 0x425,
+    /// 1062 - VaultHasOutstandingVrt
+    #[error("VaultHasOutstandingVrt")]
+    VaultHasOutstandingVrt = 0x426,
+    /// 1063 - VaultHasOutstandingDelegation
+    #[error("VaultHasOutstandingDelegation")]
+    VaultHasOutstandingDelegation = 0x427,
+    /// 1064 - VaultMaxOperatorShareInvalid
+    #[error("VaultMaxOperatorShareInvalid")]
+    VaultMaxOperatorShareInvalid = 0x428,
+    /// 1065 - VaultMaxOperatorShareExceeded
+    #[error("VaultMaxOperatorShareExceeded")]
+    VaultMaxOperatorShareExceeded = 0x429,
+    /// 1066 - VaultOperatorDelegationCapExceeded
+    #[error("VaultOperatorDelegationCapExceeded")]
+    VaultOperatorDelegationCapExceeded = 0x42A,
+    /// 1067 - VaultNcnAllocationCapInvalid
+    #[error("VaultNcnAllocationCapInvalid")]
+    VaultNcnAllocationCapInvalid = 0x42B,
+    /// 1068 - VaultTooManyOperatorsForFullUpdate
+    #[error("VaultTooManyOperatorsForFullUpdate")]
+    VaultTooManyOperatorsForFullUpdate = 0x42C,
+    /// 1069 - VaultHoldingPeriodNotElapsed
+    #[error("VaultHoldingPeriodNotElapsed")]
+    VaultHoldingPeriodNotElapsed = 0x42D,
+    /// 1070 - VaultWithdrawalClaimNotWithdrawable
+    #[error("VaultWithdrawalClaimNotWithdrawable")]
+    VaultWithdrawalClaimNotWithdrawable = 0x42E,
+    /// 1071 - VaultFeeSplitInvalidRecipients
+    #[error("VaultFeeSplitInvalidRecipients")]
+    VaultFeeSplitInvalidRecipients = 0x42F,
+    /// 1072 - VaultFeeSplitInvalidBps
+    #[error("VaultFeeSplitInvalidBps")]
+    VaultFeeSplitInvalidBps = 0x430,
+    /// 1073 - VaultProgramFeeOverrideChangeTooSoon
+    #[error("VaultProgramFeeOverrideChangeTooSoon")]
+    VaultProgramFeeOverrideChangeTooSoon = 0x431,
+    /// 1074 - VaultCreatorNotAllowlisted
+    #[error("VaultCreatorNotAllowlisted")]
+    VaultCreatorNotAllowlisted = 0x432,
+    /// 1075 - VaultCreationFeeTreasuryInvalid
+    #[error("VaultCreationFeeTreasuryInvalid")]
+    VaultCreationFeeTreasuryInvalid = 0x433,
+    /// 1076 - VaultReferrerInvalid
+    #[error("VaultReferrerInvalid")]
+    VaultReferrerInvalid = 0x434,
+    /// 1077 - VaultDepositReceiptMissing
+    #[error("VaultDepositReceiptMissing")]
+    VaultDepositReceiptMissing = 0x435,
+    /// 1078 - VaultBeneficiarySignatureRequired
+    #[error("VaultBeneficiarySignatureRequired")]
+    VaultBeneficiarySignatureRequired = 0x436,
+    /// 1079 - VaultMinHoldingEpochsInvalid
+    #[error("VaultMinHoldingEpochsInvalid")]
+    VaultMinHoldingEpochsInvalid = 0x437,
     /// 3000 - ArithmeticOverflow
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 0xBB8,
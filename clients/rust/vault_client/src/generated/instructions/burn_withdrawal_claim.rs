@@ -0,0 +1,876 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct BurnWithdrawalClaim {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub vault_token_account: solana_program::pubkey::Pubkey,
+
+    pub vrt_mint: solana_program::pubkey::Pubkey,
+
+    pub vault_withdrawal_claim_mint: solana_program::pubkey::Pubkey,
+
+    pub vault_withdrawal_claim_mint_vrt_token_account: solana_program::pubkey::Pubkey,
+
+    pub claimant: solana_program::pubkey::Pubkey,
+
+    pub claimant_claim_token_account: solana_program::pubkey::Pubkey,
+
+    pub claimant_token_account: solana_program::pubkey::Pubkey,
+
+    pub vault_fee_token_account: solana_program::pubkey::Pubkey,
+
+    pub program_fee_token_account: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+    /// Signer for burning
+    pub burn_signer: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl BurnWithdrawalClaim {
+    pub fn instruction(
+        &self,
+        args: BurnWithdrawalClaimInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: BurnWithdrawalClaimInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vrt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_withdrawal_claim_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_withdrawal_claim_mint_vrt_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.claimant,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.claimant_claim_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.claimant_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_fee_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.program_fee_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        if let Some(burn_signer) = self.burn_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                burn_signer,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = BurnWithdrawalClaimInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct BurnWithdrawalClaimInstructionData {
+    discriminator: u8,
+}
+
+impl BurnWithdrawalClaimInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 42 }
+    }
+}
+
+impl Default for BurnWithdrawalClaimInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BurnWithdrawalClaimInstructionArgs {
+    pub epoch: u64,
+    pub amount: u64,
+}
+
+/// Instruction builder for `BurnWithdrawalClaim`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[writable]` vault_token_account
+///   3. `[writable]` vrt_mint
+///   4. `[writable]` vault_withdrawal_claim_mint
+///   5. `[writable]` vault_withdrawal_claim_mint_vrt_token_account
+///   6. `[signer]` claimant
+///   7. `[writable]` claimant_claim_token_account
+///   8. `[writable]` claimant_token_account
+///   9. `[writable]` vault_fee_token_account
+///   10. `[writable]` program_fee_token_account
+///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   12. `[signer, optional]` burn_signer
+#[derive(Clone, Debug, Default)]
+pub struct BurnWithdrawalClaimBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    vault_token_account: Option<solana_program::pubkey::Pubkey>,
+    vrt_mint: Option<solana_program::pubkey::Pubkey>,
+    vault_withdrawal_claim_mint: Option<solana_program::pubkey::Pubkey>,
+    vault_withdrawal_claim_mint_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    claimant: Option<solana_program::pubkey::Pubkey>,
+    claimant_claim_token_account: Option<solana_program::pubkey::Pubkey>,
+    claimant_token_account: Option<solana_program::pubkey::Pubkey>,
+    vault_fee_token_account: Option<solana_program::pubkey::Pubkey>,
+    program_fee_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    burn_signer: Option<solana_program::pubkey::Pubkey>,
+    epoch: Option<u64>,
+    amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl BurnWithdrawalClaimBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_token_account(
+        &mut self,
+        vault_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_token_account = Some(vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn vrt_mint(&mut self, vrt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vrt_mint = Some(vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint(
+        &mut self,
+        vault_withdrawal_claim_mint: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_withdrawal_claim_mint = Some(vault_withdrawal_claim_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint_vrt_token_account(
+        &mut self,
+        vault_withdrawal_claim_mint_vrt_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_withdrawal_claim_mint_vrt_token_account =
+            Some(vault_withdrawal_claim_mint_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn claimant(&mut self, claimant: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.claimant = Some(claimant);
+        self
+    }
+    #[inline(always)]
+    pub fn claimant_claim_token_account(
+        &mut self,
+        claimant_claim_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.claimant_claim_token_account = Some(claimant_claim_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn claimant_token_account(
+        &mut self,
+        claimant_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.claimant_token_account = Some(claimant_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_fee_token_account(
+        &mut self,
+        vault_fee_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_fee_token_account = Some(vault_fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn program_fee_token_account(
+        &mut self,
+        program_fee_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.program_fee_token_account = Some(program_fee_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for burning
+    #[inline(always)]
+    pub fn burn_signer(
+        &mut self,
+        burn_signer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.burn_signer = burn_signer;
+        self
+    }
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+        self.epoch = Some(epoch);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = BurnWithdrawalClaim {
+            config: self.config.expect("config is not set"),
+            vault: self.vault.expect("vault is not set"),
+            vault_token_account: self
+                .vault_token_account
+                .expect("vault_token_account is not set"),
+            vrt_mint: self.vrt_mint.expect("vrt_mint is not set"),
+            vault_withdrawal_claim_mint: self
+                .vault_withdrawal_claim_mint
+                .expect("vault_withdrawal_claim_mint is not set"),
+            vault_withdrawal_claim_mint_vrt_token_account: self
+                .vault_withdrawal_claim_mint_vrt_token_account
+                .expect("vault_withdrawal_claim_mint_vrt_token_account is not set"),
+            claimant: self.claimant.expect("claimant is not set"),
+            claimant_claim_token_account: self
+                .claimant_claim_token_account
+                .expect("claimant_claim_token_account is not set"),
+            claimant_token_account: self
+                .claimant_token_account
+                .expect("claimant_token_account is not set"),
+            vault_fee_token_account: self
+                .vault_fee_token_account
+                .expect("vault_fee_token_account is not set"),
+            program_fee_token_account: self
+                .program_fee_token_account
+                .expect("program_fee_token_account is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            burn_signer: self.burn_signer,
+        };
+        let args = BurnWithdrawalClaimInstructionArgs {
+            epoch: self.epoch.clone().expect("epoch is not set"),
+            amount: self.amount.clone().expect("amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `burn_withdrawal_claim` CPI accounts.
+pub struct BurnWithdrawalClaimCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint_vrt_token_account:
+        &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub claimant: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub claimant_claim_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub claimant_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for burning
+    pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `burn_withdrawal_claim` CPI instruction.
+pub struct BurnWithdrawalClaimCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint_vrt_token_account:
+        &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub claimant: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub claimant_claim_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub claimant_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for burning
+    pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: BurnWithdrawalClaimInstructionArgs,
+}
+
+impl<'a, 'b> BurnWithdrawalClaimCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: BurnWithdrawalClaimCpiAccounts<'a, 'b>,
+        args: BurnWithdrawalClaimInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            vault: accounts.vault,
+            vault_token_account: accounts.vault_token_account,
+            vrt_mint: accounts.vrt_mint,
+            vault_withdrawal_claim_mint: accounts.vault_withdrawal_claim_mint,
+            vault_withdrawal_claim_mint_vrt_token_account: accounts
+                .vault_withdrawal_claim_mint_vrt_token_account,
+            claimant: accounts.claimant,
+            claimant_claim_token_account: accounts.claimant_claim_token_account,
+            claimant_token_account: accounts.claimant_token_account,
+            vault_fee_token_account: accounts.vault_fee_token_account,
+            program_fee_token_account: accounts.program_fee_token_account,
+            token_program: accounts.token_program,
+            burn_signer: accounts.burn_signer,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vrt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_withdrawal_claim_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_withdrawal_claim_mint_vrt_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.claimant.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.claimant_claim_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.claimant_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_fee_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.program_fee_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        if let Some(burn_signer) = self.burn_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *burn_signer.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = BurnWithdrawalClaimInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.vault_token_account.clone());
+        account_infos.push(self.vrt_mint.clone());
+        account_infos.push(self.vault_withdrawal_claim_mint.clone());
+        account_infos.push(self.vault_withdrawal_claim_mint_vrt_token_account.clone());
+        account_infos.push(self.claimant.clone());
+        account_infos.push(self.claimant_claim_token_account.clone());
+        account_infos.push(self.claimant_token_account.clone());
+        account_infos.push(self.vault_fee_token_account.clone());
+        account_infos.push(self.program_fee_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        if let Some(burn_signer) = self.burn_signer {
+            account_infos.push(burn_signer.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `BurnWithdrawalClaim` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[writable]` vault_token_account
+///   3. `[writable]` vrt_mint
+///   4. `[writable]` vault_withdrawal_claim_mint
+///   5. `[writable]` vault_withdrawal_claim_mint_vrt_token_account
+///   6. `[signer]` claimant
+///   7. `[writable]` claimant_claim_token_account
+///   8. `[writable]` claimant_token_account
+///   9. `[writable]` vault_fee_token_account
+///   10. `[writable]` program_fee_token_account
+///   11. `[]` token_program
+///   12. `[signer, optional]` burn_signer
+#[derive(Clone, Debug)]
+pub struct BurnWithdrawalClaimCpiBuilder<'a, 'b> {
+    instruction: Box<BurnWithdrawalClaimCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> BurnWithdrawalClaimCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(BurnWithdrawalClaimCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            vault: None,
+            vault_token_account: None,
+            vrt_mint: None,
+            vault_withdrawal_claim_mint: None,
+            vault_withdrawal_claim_mint_vrt_token_account: None,
+            claimant: None,
+            claimant_claim_token_account: None,
+            claimant_token_account: None,
+            vault_fee_token_account: None,
+            program_fee_token_account: None,
+            token_program: None,
+            burn_signer: None,
+            epoch: None,
+            amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_token_account(
+        &mut self,
+        vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_token_account = Some(vault_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn vrt_mint(
+        &mut self,
+        vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vrt_mint = Some(vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint(
+        &mut self,
+        vault_withdrawal_claim_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_withdrawal_claim_mint = Some(vault_withdrawal_claim_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint_vrt_token_account(
+        &mut self,
+        vault_withdrawal_claim_mint_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction
+            .vault_withdrawal_claim_mint_vrt_token_account =
+            Some(vault_withdrawal_claim_mint_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn claimant(
+        &mut self,
+        claimant: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.claimant = Some(claimant);
+        self
+    }
+    #[inline(always)]
+    pub fn claimant_claim_token_account(
+        &mut self,
+        claimant_claim_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.claimant_claim_token_account = Some(claimant_claim_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn claimant_token_account(
+        &mut self,
+        claimant_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.claimant_token_account = Some(claimant_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_fee_token_account(
+        &mut self,
+        vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_fee_token_account = Some(vault_fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn program_fee_token_account(
+        &mut self,
+        program_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program_fee_token_account = Some(program_fee_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for burning
+    #[inline(always)]
+    pub fn burn_signer(
+        &mut self,
+        burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.burn_signer = burn_signer;
+        self
+    }
+    #[inline(always)]
+    pub fn epoch(&mut self, epoch: u64) -> &mut Self {
+        self.instruction.epoch = Some(epoch);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = BurnWithdrawalClaimInstructionArgs {
+            epoch: self.instruction.epoch.clone().expect("epoch is not set"),
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
+        let instruction = BurnWithdrawalClaimCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            vault_token_account: self
+                .instruction
+                .vault_token_account
+                .expect("vault_token_account is not set"),
+
+            vrt_mint: self.instruction.vrt_mint.expect("vrt_mint is not set"),
+
+            vault_withdrawal_claim_mint: self
+                .instruction
+                .vault_withdrawal_claim_mint
+                .expect("vault_withdrawal_claim_mint is not set"),
+
+            vault_withdrawal_claim_mint_vrt_token_account: self
+                .instruction
+                .vault_withdrawal_claim_mint_vrt_token_account
+                .expect("vault_withdrawal_claim_mint_vrt_token_account is not set"),
+
+            claimant: self.instruction.claimant.expect("claimant is not set"),
+
+            claimant_claim_token_account: self
+                .instruction
+                .claimant_claim_token_account
+                .expect("claimant_claim_token_account is not set"),
+
+            claimant_token_account: self
+                .instruction
+                .claimant_token_account
+                .expect("claimant_token_account is not set"),
+
+            vault_fee_token_account: self
+                .instruction
+                .vault_fee_token_account
+                .expect("vault_fee_token_account is not set"),
+
+            program_fee_token_account: self
+                .instruction
+                .program_fee_token_account
+                .expect("program_fee_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            burn_signer: self.instruction.burn_signer,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct BurnWithdrawalClaimCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vrt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_withdrawal_claim_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_withdrawal_claim_mint_vrt_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    claimant: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    claimant_claim_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    claimant_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    epoch: Option<u64>,
+    amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
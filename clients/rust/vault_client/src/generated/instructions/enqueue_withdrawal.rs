@@ -29,6 +29,8 @@ pub struct EnqueueWithdrawal {
     pub system_program: solana_program::pubkey::Pubkey,
     /// Signer for burning
     pub burn_signer: Option<solana_program::pubkey::Pubkey>,
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    pub vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl EnqueueWithdrawal {
@@ -44,7 +46,7 @@ impl EnqueueWithdrawal {
         args: EnqueueWithdrawalInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -90,6 +92,17 @@ impl EnqueueWithdrawal {
                 false,
             ));
         }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                vault_deposit_receipt,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = EnqueueWithdrawalInstructionData::new()
             .try_to_vec()
@@ -142,6 +155,7 @@ pub struct EnqueueWithdrawalInstructionArgs {
 ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   9. `[signer, optional]` burn_signer
+///   10. `[optional]` vault_deposit_receipt
 #[derive(Clone, Debug, Default)]
 pub struct EnqueueWithdrawalBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -154,6 +168,7 @@ pub struct EnqueueWithdrawalBuilder {
     token_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     burn_signer: Option<solana_program::pubkey::Pubkey>,
+    vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -229,6 +244,16 @@ impl EnqueueWithdrawalBuilder {
         self.burn_signer = burn_signer;
         self
     }
+    /// `[optional account]`
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    #[inline(always)]
+    pub fn vault_deposit_receipt(
+        &mut self,
+        vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_deposit_receipt = vault_deposit_receipt;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -275,6 +300,7 @@ impl EnqueueWithdrawalBuilder {
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
             burn_signer: self.burn_signer,
+            vault_deposit_receipt: self.vault_deposit_receipt,
         };
         let args = EnqueueWithdrawalInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -306,6 +332,8 @@ pub struct EnqueueWithdrawalCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for burning
     pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    pub vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `enqueue_withdrawal` CPI instruction.
@@ -333,6 +361,8 @@ pub struct EnqueueWithdrawalCpi<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for burning
     pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    pub vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: EnqueueWithdrawalInstructionArgs,
 }
@@ -356,6 +386,7 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
             token_program: accounts.token_program,
             system_program: accounts.system_program,
             burn_signer: accounts.burn_signer,
+            vault_deposit_receipt: accounts.vault_deposit_receipt,
             __args: args,
         }
     }
@@ -392,7 +423,7 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -440,6 +471,17 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *vault_deposit_receipt.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -458,7 +500,7 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -472,6 +514,9 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
         if let Some(burn_signer) = self.burn_signer {
             account_infos.push(burn_signer.clone());
         }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            account_infos.push(vault_deposit_receipt.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -498,6 +543,7 @@ impl<'a, 'b> EnqueueWithdrawalCpi<'a, 'b> {
 ///   7. `[]` token_program
 ///   8. `[]` system_program
 ///   9. `[signer, optional]` burn_signer
+///   10. `[optional]` vault_deposit_receipt
 #[derive(Clone, Debug)]
 pub struct EnqueueWithdrawalCpiBuilder<'a, 'b> {
     instruction: Box<EnqueueWithdrawalCpiBuilderInstruction<'a, 'b>>,
@@ -517,6 +563,7 @@ impl<'a, 'b> EnqueueWithdrawalCpiBuilder<'a, 'b> {
             token_program: None,
             system_program: None,
             burn_signer: None,
+            vault_deposit_receipt: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -602,6 +649,16 @@ impl<'a, 'b> EnqueueWithdrawalCpiBuilder<'a, 'b> {
         self.instruction.burn_signer = burn_signer;
         self
     }
+    /// `[optional account]`
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    #[inline(always)]
+    pub fn vault_deposit_receipt(
+        &mut self,
+        vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_deposit_receipt = vault_deposit_receipt;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -688,6 +745,8 @@ impl<'a, 'b> EnqueueWithdrawalCpiBuilder<'a, 'b> {
                 .expect("system_program is not set"),
 
             burn_signer: self.instruction.burn_signer,
+
+            vault_deposit_receipt: self.instruction.vault_deposit_receipt,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -711,6 +770,7 @@ struct EnqueueWithdrawalCpiBuilderInstruction<'a, 'b> {
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
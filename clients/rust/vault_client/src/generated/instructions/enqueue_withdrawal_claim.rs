@@ -0,0 +1,873 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct EnqueueWithdrawalClaim {
+    pub config: solana_program::pubkey::Pubkey,
+
+    pub vault: solana_program::pubkey::Pubkey,
+
+    pub vrt_mint: solana_program::pubkey::Pubkey,
+
+    pub vault_withdrawal_claim_mint: solana_program::pubkey::Pubkey,
+
+    pub vault_withdrawal_claim_mint_vrt_token_account: solana_program::pubkey::Pubkey,
+
+    pub staker: solana_program::pubkey::Pubkey,
+
+    pub staker_vrt_token_account: solana_program::pubkey::Pubkey,
+
+    pub staker_claim_token_account: solana_program::pubkey::Pubkey,
+
+    pub token_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub associated_token_program: solana_program::pubkey::Pubkey,
+    /// Signer for burning
+    pub burn_signer: Option<solana_program::pubkey::Pubkey>,
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    pub vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl EnqueueWithdrawalClaim {
+    pub fn instruction(
+        &self,
+        args: EnqueueWithdrawalClaimInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: EnqueueWithdrawalClaimInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.vrt_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_withdrawal_claim_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault_withdrawal_claim_mint_vrt_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker_vrt_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.staker_claim_token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.associated_token_program,
+            false,
+        ));
+        if let Some(burn_signer) = self.burn_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                burn_signer,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                vault_deposit_receipt,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = EnqueueWithdrawalClaimInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct EnqueueWithdrawalClaimInstructionData {
+    discriminator: u8,
+}
+
+impl EnqueueWithdrawalClaimInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 41 }
+    }
+}
+
+impl Default for EnqueueWithdrawalClaimInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnqueueWithdrawalClaimInstructionArgs {
+    pub amount: u64,
+}
+
+/// Instruction builder for `EnqueueWithdrawalClaim`.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[]` vrt_mint
+///   3. `[writable]` vault_withdrawal_claim_mint
+///   4. `[writable]` vault_withdrawal_claim_mint_vrt_token_account
+///   5. `[writable, signer]` staker
+///   6. `[writable]` staker_vrt_token_account
+///   7. `[writable]` staker_claim_token_account
+///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   10. `[]` associated_token_program
+///   11. `[signer, optional]` burn_signer
+///   12. `[optional]` vault_deposit_receipt
+#[derive(Clone, Debug, Default)]
+pub struct EnqueueWithdrawalClaimBuilder {
+    config: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    vrt_mint: Option<solana_program::pubkey::Pubkey>,
+    vault_withdrawal_claim_mint: Option<solana_program::pubkey::Pubkey>,
+    vault_withdrawal_claim_mint_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    staker: Option<solana_program::pubkey::Pubkey>,
+    staker_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    staker_claim_token_account: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    burn_signer: Option<solana_program::pubkey::Pubkey>,
+    vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl EnqueueWithdrawalClaimBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vrt_mint(&mut self, vrt_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vrt_mint = Some(vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint(
+        &mut self,
+        vault_withdrawal_claim_mint: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_withdrawal_claim_mint = Some(vault_withdrawal_claim_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint_vrt_token_account(
+        &mut self,
+        vault_withdrawal_claim_mint_vrt_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.vault_withdrawal_claim_mint_vrt_token_account =
+            Some(vault_withdrawal_claim_mint_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker(&mut self, staker: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.staker = Some(staker);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_vrt_token_account(
+        &mut self,
+        staker_vrt_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.staker_vrt_token_account = Some(staker_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_claim_token_account(
+        &mut self,
+        staker_claim_token_account: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.staker_claim_token_account = Some(staker_claim_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for burning
+    #[inline(always)]
+    pub fn burn_signer(
+        &mut self,
+        burn_signer: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.burn_signer = burn_signer;
+        self
+    }
+    /// `[optional account]`
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    #[inline(always)]
+    pub fn vault_deposit_receipt(
+        &mut self,
+        vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_deposit_receipt = vault_deposit_receipt;
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = EnqueueWithdrawalClaim {
+            config: self.config.expect("config is not set"),
+            vault: self.vault.expect("vault is not set"),
+            vrt_mint: self.vrt_mint.expect("vrt_mint is not set"),
+            vault_withdrawal_claim_mint: self
+                .vault_withdrawal_claim_mint
+                .expect("vault_withdrawal_claim_mint is not set"),
+            vault_withdrawal_claim_mint_vrt_token_account: self
+                .vault_withdrawal_claim_mint_vrt_token_account
+                .expect("vault_withdrawal_claim_mint_vrt_token_account is not set"),
+            staker: self.staker.expect("staker is not set"),
+            staker_vrt_token_account: self
+                .staker_vrt_token_account
+                .expect("staker_vrt_token_account is not set"),
+            staker_claim_token_account: self
+                .staker_claim_token_account
+                .expect("staker_claim_token_account is not set"),
+            token_program: self.token_program.unwrap_or(solana_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            associated_token_program: self
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+            burn_signer: self.burn_signer,
+            vault_deposit_receipt: self.vault_deposit_receipt,
+        };
+        let args = EnqueueWithdrawalClaimInstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `enqueue_withdrawal_claim` CPI accounts.
+pub struct EnqueueWithdrawalClaimCpiAccounts<'a, 'b> {
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint_vrt_token_account:
+        &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_claim_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for burning
+    pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    pub vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `enqueue_withdrawal_claim` CPI instruction.
+pub struct EnqueueWithdrawalClaimCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub vault_withdrawal_claim_mint_vrt_token_account:
+        &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub staker_claim_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Signer for burning
+    pub burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    pub vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: EnqueueWithdrawalClaimInstructionArgs,
+}
+
+impl<'a, 'b> EnqueueWithdrawalClaimCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: EnqueueWithdrawalClaimCpiAccounts<'a, 'b>,
+        args: EnqueueWithdrawalClaimInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            config: accounts.config,
+            vault: accounts.vault,
+            vrt_mint: accounts.vrt_mint,
+            vault_withdrawal_claim_mint: accounts.vault_withdrawal_claim_mint,
+            vault_withdrawal_claim_mint_vrt_token_account: accounts
+                .vault_withdrawal_claim_mint_vrt_token_account,
+            staker: accounts.staker,
+            staker_vrt_token_account: accounts.staker_vrt_token_account,
+            staker_claim_token_account: accounts.staker_claim_token_account,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+            associated_token_program: accounts.associated_token_program,
+            burn_signer: accounts.burn_signer,
+            vault_deposit_receipt: accounts.vault_deposit_receipt,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.vrt_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_withdrawal_claim_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault_withdrawal_claim_mint_vrt_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker_vrt_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.staker_claim_token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.associated_token_program.key,
+            false,
+        ));
+        if let Some(burn_signer) = self.burn_signer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *burn_signer.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *vault_deposit_receipt.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = EnqueueWithdrawalClaimInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::JITO_VAULT_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.config.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.vrt_mint.clone());
+        account_infos.push(self.vault_withdrawal_claim_mint.clone());
+        account_infos.push(self.vault_withdrawal_claim_mint_vrt_token_account.clone());
+        account_infos.push(self.staker.clone());
+        account_infos.push(self.staker_vrt_token_account.clone());
+        account_infos.push(self.staker_claim_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.associated_token_program.clone());
+        if let Some(burn_signer) = self.burn_signer {
+            account_infos.push(burn_signer.clone());
+        }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            account_infos.push(vault_deposit_receipt.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `EnqueueWithdrawalClaim` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` config
+///   1. `[writable]` vault
+///   2. `[]` vrt_mint
+///   3. `[writable]` vault_withdrawal_claim_mint
+///   4. `[writable]` vault_withdrawal_claim_mint_vrt_token_account
+///   5. `[writable, signer]` staker
+///   6. `[writable]` staker_vrt_token_account
+///   7. `[writable]` staker_claim_token_account
+///   8. `[]` token_program
+///   9. `[]` system_program
+///   10. `[]` associated_token_program
+///   11. `[signer, optional]` burn_signer
+///   12. `[optional]` vault_deposit_receipt
+#[derive(Clone, Debug)]
+pub struct EnqueueWithdrawalClaimCpiBuilder<'a, 'b> {
+    instruction: Box<EnqueueWithdrawalClaimCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> EnqueueWithdrawalClaimCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(EnqueueWithdrawalClaimCpiBuilderInstruction {
+            __program: program,
+            config: None,
+            vault: None,
+            vrt_mint: None,
+            vault_withdrawal_claim_mint: None,
+            vault_withdrawal_claim_mint_vrt_token_account: None,
+            staker: None,
+            staker_vrt_token_account: None,
+            staker_claim_token_account: None,
+            token_program: None,
+            system_program: None,
+            associated_token_program: None,
+            burn_signer: None,
+            vault_deposit_receipt: None,
+            amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn config(
+        &mut self,
+        config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn vrt_mint(
+        &mut self,
+        vrt_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vrt_mint = Some(vrt_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint(
+        &mut self,
+        vault_withdrawal_claim_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vault_withdrawal_claim_mint = Some(vault_withdrawal_claim_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn vault_withdrawal_claim_mint_vrt_token_account(
+        &mut self,
+        vault_withdrawal_claim_mint_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction
+            .vault_withdrawal_claim_mint_vrt_token_account =
+            Some(vault_withdrawal_claim_mint_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker(
+        &mut self,
+        staker: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker = Some(staker);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_vrt_token_account(
+        &mut self,
+        staker_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker_vrt_token_account = Some(staker_vrt_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn staker_claim_token_account(
+        &mut self,
+        staker_claim_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.staker_claim_token_account = Some(staker_claim_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = Some(associated_token_program);
+        self
+    }
+    /// `[optional account]`
+    /// Signer for burning
+    #[inline(always)]
+    pub fn burn_signer(
+        &mut self,
+        burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.burn_signer = burn_signer;
+        self
+    }
+    /// `[optional account]`
+    /// The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist
+    #[inline(always)]
+    pub fn vault_deposit_receipt(
+        &mut self,
+        vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_deposit_receipt = vault_deposit_receipt;
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = EnqueueWithdrawalClaimInstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
+        let instruction = EnqueueWithdrawalClaimCpi {
+            __program: self.instruction.__program,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            vrt_mint: self.instruction.vrt_mint.expect("vrt_mint is not set"),
+
+            vault_withdrawal_claim_mint: self
+                .instruction
+                .vault_withdrawal_claim_mint
+                .expect("vault_withdrawal_claim_mint is not set"),
+
+            vault_withdrawal_claim_mint_vrt_token_account: self
+                .instruction
+                .vault_withdrawal_claim_mint_vrt_token_account
+                .expect("vault_withdrawal_claim_mint_vrt_token_account is not set"),
+
+            staker: self.instruction.staker.expect("staker is not set"),
+
+            staker_vrt_token_account: self
+                .instruction
+                .staker_vrt_token_account
+                .expect("staker_vrt_token_account is not set"),
+
+            staker_claim_token_account: self
+                .instruction
+                .staker_claim_token_account
+                .expect("staker_claim_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            associated_token_program: self
+                .instruction
+                .associated_token_program
+                .expect("associated_token_program is not set"),
+
+            burn_signer: self.instruction.burn_signer,
+
+            vault_deposit_receipt: self.instruction.vault_deposit_receipt,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EnqueueWithdrawalClaimCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vrt_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_withdrawal_claim_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_withdrawal_claim_mint_vrt_token_account:
+        Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    staker_claim_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    burn_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -35,6 +35,8 @@ pub struct InitializeVault {
     pub token_program: solana_program::pubkey::Pubkey,
 
     pub associated_token_program: solana_program::pubkey::Pubkey,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeVault {
@@ -50,7 +52,7 @@ impl InitializeVault {
         args: InitializeVaultInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.config,
             false,
@@ -100,6 +102,17 @@ impl InitializeVault {
             self.associated_token_program,
             false,
         ));
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                creation_policy_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitializeVaultInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -157,6 +170,7 @@ pub struct InitializeVaultInstructionArgs {
 ///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   12. `[]` associated_token_program
+///   13. `[writable, optional]` creation_policy_account
 #[derive(Clone, Debug, Default)]
 pub struct InitializeVaultBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -172,6 +186,7 @@ pub struct InitializeVaultBuilder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    creation_policy_account: Option<solana_program::pubkey::Pubkey>,
     deposit_fee_bps: Option<u16>,
     withdrawal_fee_bps: Option<u16>,
     reward_fee_bps: Option<u16>,
@@ -263,6 +278,16 @@ impl InitializeVaultBuilder {
         self.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    #[inline(always)]
+    pub fn creation_policy_account(
+        &mut self,
+        creation_policy_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.creation_policy_account = creation_policy_account;
+        self
+    }
     #[inline(always)]
     pub fn deposit_fee_bps(&mut self, deposit_fee_bps: u16) -> &mut Self {
         self.deposit_fee_bps = Some(deposit_fee_bps);
@@ -334,6 +359,7 @@ impl InitializeVaultBuilder {
             associated_token_program: self
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+            creation_policy_account: self.creation_policy_account,
         };
         let args = InitializeVaultInstructionArgs {
             deposit_fee_bps: self
@@ -386,6 +412,8 @@ pub struct InitializeVaultCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_vault` CPI instruction.
@@ -418,6 +446,8 @@ pub struct InitializeVaultCpi<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    pub creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: InitializeVaultInstructionArgs,
 }
@@ -443,6 +473,7 @@ impl<'a, 'b> InitializeVaultCpi<'a, 'b> {
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
+            creation_policy_account: accounts.creation_policy_account,
             __args: args,
         }
     }
@@ -479,7 +510,7 @@ impl<'a, 'b> InitializeVaultCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.config.key,
             false,
@@ -532,6 +563,17 @@ impl<'a, 'b> InitializeVaultCpi<'a, 'b> {
             *self.associated_token_program.key,
             false,
         ));
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *creation_policy_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -548,7 +590,7 @@ impl<'a, 'b> InitializeVaultCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -563,6 +605,9 @@ impl<'a, 'b> InitializeVaultCpi<'a, 'b> {
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        if let Some(creation_policy_account) = self.creation_policy_account {
+            account_infos.push(creation_policy_account.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -592,6 +637,7 @@ impl<'a, 'b> InitializeVaultCpi<'a, 'b> {
 ///   10. `[]` system_program
 ///   11. `[]` token_program
 ///   12. `[]` associated_token_program
+///   13. `[writable, optional]` creation_policy_account
 #[derive(Clone, Debug)]
 pub struct InitializeVaultCpiBuilder<'a, 'b> {
     instruction: Box<InitializeVaultCpiBuilderInstruction<'a, 'b>>,
@@ -614,6 +660,7 @@ impl<'a, 'b> InitializeVaultCpiBuilder<'a, 'b> {
             system_program: None,
             token_program: None,
             associated_token_program: None,
+            creation_policy_account: None,
             deposit_fee_bps: None,
             withdrawal_fee_bps: None,
             reward_fee_bps: None,
@@ -718,6 +765,16 @@ impl<'a, 'b> InitializeVaultCpiBuilder<'a, 'b> {
         self.instruction.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    /// The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy
+    #[inline(always)]
+    pub fn creation_policy_account(
+        &mut self,
+        creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.creation_policy_account = creation_policy_account;
+        self
+    }
     #[inline(always)]
     pub fn deposit_fee_bps(&mut self, deposit_fee_bps: u16) -> &mut Self {
         self.instruction.deposit_fee_bps = Some(deposit_fee_bps);
@@ -857,6 +914,8 @@ impl<'a, 'b> InitializeVaultCpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+
+            creation_policy_account: self.instruction.creation_policy_account,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -882,6 +941,7 @@ struct InitializeVaultCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    creation_policy_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     deposit_fee_bps: Option<u16>,
     withdrawal_fee_bps: Option<u16>,
     reward_fee_bps: Option<u16>,
@@ -21,7 +21,7 @@ pub struct MintTo {
     pub depositor_token_account: solana_program::pubkey::Pubkey,
 
     pub vault_token_account: solana_program::pubkey::Pubkey,
-
+    /// The VRT ATA of the beneficiary receiving the minted VRT, usually the depositor
     pub depositor_vrt_token_account: solana_program::pubkey::Pubkey,
 
     pub vault_fee_token_account: solana_program::pubkey::Pubkey,
@@ -29,6 +29,18 @@ pub struct MintTo {
     pub token_program: solana_program::pubkey::Pubkey,
     /// Signer for minting
     pub mint_signer: Option<solana_program::pubkey::Pubkey>,
+    /// The deposit receipt of the beneficiary, required while the vault has a holding period
+    pub vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    /// Required to create the deposit receipt or the referral account
+    pub system_program: Option<solana_program::pubkey::Pubkey>,
+    /// The referral account of the referrer, created on their first referred deposit
+    pub vault_referral: Option<solana_program::pubkey::Pubkey>,
+    /// The referrer the deposit is attributed to
+    pub referrer: Option<solana_program::pubkey::Pubkey>,
+    /// The referrer's VRT token account receiving the referral share of the deposit fee
+    pub referrer_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// The beneficiary, whose signature lets a deposit restart their holding period
+    pub beneficiary: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl MintTo {
@@ -44,7 +56,7 @@ impl MintTo {
         args: MintToInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -91,6 +103,71 @@ impl MintTo {
                 false,
             ));
         }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                vault_deposit_receipt,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_referral) = self.vault_referral {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                vault_referral,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                referrer, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(referrer_vrt_token_account) = self.referrer_vrt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                referrer_vrt_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(beneficiary) = self.beneficiary {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                beneficiary,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = MintToInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -142,6 +219,12 @@ pub struct MintToInstructionArgs {
 ///   7. `[writable]` vault_fee_token_account
 ///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   9. `[signer, optional]` mint_signer
+///   10. `[writable, optional]` vault_deposit_receipt
+///   11. `[optional]` system_program
+///   12. `[writable, optional]` vault_referral
+///   13. `[optional]` referrer
+///   14. `[writable, optional]` referrer_vrt_token_account
+///   15. `[signer, optional]` beneficiary
 #[derive(Clone, Debug, Default)]
 pub struct MintToBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -154,6 +237,12 @@ pub struct MintToBuilder {
     vault_fee_token_account: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     mint_signer: Option<solana_program::pubkey::Pubkey>,
+    vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    vault_referral: Option<solana_program::pubkey::Pubkey>,
+    referrer: Option<solana_program::pubkey::Pubkey>,
+    referrer_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    beneficiary: Option<solana_program::pubkey::Pubkey>,
     amount_in: Option<u64>,
     min_amount_out: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -199,6 +288,7 @@ impl MintToBuilder {
         self.vault_token_account = Some(vault_token_account);
         self
     }
+    /// The VRT ATA of the beneficiary receiving the minted VRT, usually the depositor
     #[inline(always)]
     pub fn depositor_vrt_token_account(
         &mut self,
@@ -231,6 +321,63 @@ impl MintToBuilder {
         self.mint_signer = mint_signer;
         self
     }
+    /// `[optional account]`
+    /// The deposit receipt of the beneficiary, required while the vault has a holding period
+    #[inline(always)]
+    pub fn vault_deposit_receipt(
+        &mut self,
+        vault_deposit_receipt: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_deposit_receipt = vault_deposit_receipt;
+        self
+    }
+    /// `[optional account]`
+    /// Required to create the deposit receipt or the referral account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    /// The referral account of the referrer, created on their first referred deposit
+    #[inline(always)]
+    pub fn vault_referral(
+        &mut self,
+        vault_referral: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_referral = vault_referral;
+        self
+    }
+    /// `[optional account]`
+    /// The referrer the deposit is attributed to
+    #[inline(always)]
+    pub fn referrer(&mut self, referrer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.referrer = referrer;
+        self
+    }
+    /// `[optional account]`
+    /// The referrer's VRT token account receiving the referral share of the deposit fee
+    #[inline(always)]
+    pub fn referrer_vrt_token_account(
+        &mut self,
+        referrer_vrt_token_account: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.referrer_vrt_token_account = referrer_vrt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The beneficiary, whose signature lets a deposit restart their holding period
+    #[inline(always)]
+    pub fn beneficiary(
+        &mut self,
+        beneficiary: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.beneficiary = beneficiary;
+        self
+    }
     #[inline(always)]
     pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
         self.amount_in = Some(amount_in);
@@ -282,6 +429,12 @@ impl MintToBuilder {
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
             mint_signer: self.mint_signer,
+            vault_deposit_receipt: self.vault_deposit_receipt,
+            system_program: self.system_program,
+            vault_referral: self.vault_referral,
+            referrer: self.referrer,
+            referrer_vrt_token_account: self.referrer_vrt_token_account,
+            beneficiary: self.beneficiary,
         };
         let args = MintToInstructionArgs {
             amount_in: self.amount_in.clone().expect("amount_in is not set"),
@@ -308,7 +461,7 @@ pub struct MintToCpiAccounts<'a, 'b> {
     pub depositor_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-
+    /// The VRT ATA of the beneficiary receiving the minted VRT, usually the depositor
     pub depositor_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
@@ -316,6 +469,18 @@ pub struct MintToCpiAccounts<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for minting
     pub mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The deposit receipt of the beneficiary, required while the vault has a holding period
+    pub vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required to create the deposit receipt or the referral account
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The referral account of the referrer, created on their first referred deposit
+    pub vault_referral: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The referrer the deposit is attributed to
+    pub referrer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The referrer's VRT token account receiving the referral share of the deposit fee
+    pub referrer_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The beneficiary, whose signature lets a deposit restart their holding period
+    pub beneficiary: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `mint_to` CPI instruction.
@@ -334,7 +499,7 @@ pub struct MintToCpi<'a, 'b> {
     pub depositor_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub vault_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-
+    /// The VRT ATA of the beneficiary receiving the minted VRT, usually the depositor
     pub depositor_vrt_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub vault_fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
@@ -342,6 +507,18 @@ pub struct MintToCpi<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Signer for minting
     pub mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The deposit receipt of the beneficiary, required while the vault has a holding period
+    pub vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Required to create the deposit receipt or the referral account
+    pub system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The referral account of the referrer, created on their first referred deposit
+    pub vault_referral: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The referrer the deposit is attributed to
+    pub referrer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The referrer's VRT token account receiving the referral share of the deposit fee
+    pub referrer_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The beneficiary, whose signature lets a deposit restart their holding period
+    pub beneficiary: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: MintToInstructionArgs,
 }
@@ -364,6 +541,12 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
             vault_fee_token_account: accounts.vault_fee_token_account,
             token_program: accounts.token_program,
             mint_signer: accounts.mint_signer,
+            vault_deposit_receipt: accounts.vault_deposit_receipt,
+            system_program: accounts.system_program,
+            vault_referral: accounts.vault_referral,
+            referrer: accounts.referrer,
+            referrer_vrt_token_account: accounts.referrer_vrt_token_account,
+            beneficiary: accounts.beneficiary,
             __args: args,
         }
     }
@@ -400,7 +583,7 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -448,6 +631,72 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault_deposit_receipt.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(vault_referral) = self.vault_referral {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault_referral.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(referrer) = self.referrer {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *referrer.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(referrer_vrt_token_account) = self.referrer_vrt_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *referrer_vrt_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
+        if let Some(beneficiary) = self.beneficiary {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *beneficiary.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_VAULT_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -464,7 +713,7 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(16 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.vault.clone());
@@ -478,6 +727,24 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
         if let Some(mint_signer) = self.mint_signer {
             account_infos.push(mint_signer.clone());
         }
+        if let Some(vault_deposit_receipt) = self.vault_deposit_receipt {
+            account_infos.push(vault_deposit_receipt.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
+        if let Some(vault_referral) = self.vault_referral {
+            account_infos.push(vault_referral.clone());
+        }
+        if let Some(referrer) = self.referrer {
+            account_infos.push(referrer.clone());
+        }
+        if let Some(referrer_vrt_token_account) = self.referrer_vrt_token_account {
+            account_infos.push(referrer_vrt_token_account.clone());
+        }
+        if let Some(beneficiary) = self.beneficiary {
+            account_infos.push(beneficiary.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -504,6 +771,12 @@ impl<'a, 'b> MintToCpi<'a, 'b> {
 ///   7. `[writable]` vault_fee_token_account
 ///   8. `[]` token_program
 ///   9. `[signer, optional]` mint_signer
+///   10. `[writable, optional]` vault_deposit_receipt
+///   11. `[optional]` system_program
+///   12. `[writable, optional]` vault_referral
+///   13. `[optional]` referrer
+///   14. `[writable, optional]` referrer_vrt_token_account
+///   15. `[signer, optional]` beneficiary
 #[derive(Clone, Debug)]
 pub struct MintToCpiBuilder<'a, 'b> {
     instruction: Box<MintToCpiBuilderInstruction<'a, 'b>>,
@@ -523,6 +796,12 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
             vault_fee_token_account: None,
             token_program: None,
             mint_signer: None,
+            vault_deposit_receipt: None,
+            system_program: None,
+            vault_referral: None,
+            referrer: None,
+            referrer_vrt_token_account: None,
+            beneficiary: None,
             amount_in: None,
             min_amount_out: None,
             __remaining_accounts: Vec::new(),
@@ -574,6 +853,7 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
         self.instruction.vault_token_account = Some(vault_token_account);
         self
     }
+    /// The VRT ATA of the beneficiary receiving the minted VRT, usually the depositor
     #[inline(always)]
     pub fn depositor_vrt_token_account(
         &mut self,
@@ -608,6 +888,66 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
         self.instruction.mint_signer = mint_signer;
         self
     }
+    /// `[optional account]`
+    /// The deposit receipt of the beneficiary, required while the vault has a holding period
+    #[inline(always)]
+    pub fn vault_deposit_receipt(
+        &mut self,
+        vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_deposit_receipt = vault_deposit_receipt;
+        self
+    }
+    /// `[optional account]`
+    /// Required to create the deposit receipt or the referral account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
+    /// `[optional account]`
+    /// The referral account of the referrer, created on their first referred deposit
+    #[inline(always)]
+    pub fn vault_referral(
+        &mut self,
+        vault_referral: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_referral = vault_referral;
+        self
+    }
+    /// `[optional account]`
+    /// The referrer the deposit is attributed to
+    #[inline(always)]
+    pub fn referrer(
+        &mut self,
+        referrer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer = referrer;
+        self
+    }
+    /// `[optional account]`
+    /// The referrer's VRT token account receiving the referral share of the deposit fee
+    #[inline(always)]
+    pub fn referrer_vrt_token_account(
+        &mut self,
+        referrer_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.referrer_vrt_token_account = referrer_vrt_token_account;
+        self
+    }
+    /// `[optional account]`
+    /// The beneficiary, whose signature lets a deposit restart their holding period
+    #[inline(always)]
+    pub fn beneficiary(
+        &mut self,
+        beneficiary: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.beneficiary = beneficiary;
+        self
+    }
     #[inline(always)]
     pub fn amount_in(&mut self, amount_in: u64) -> &mut Self {
         self.instruction.amount_in = Some(amount_in);
@@ -708,6 +1048,18 @@ impl<'a, 'b> MintToCpiBuilder<'a, 'b> {
                 .expect("token_program is not set"),
 
             mint_signer: self.instruction.mint_signer,
+
+            vault_deposit_receipt: self.instruction.vault_deposit_receipt,
+
+            system_program: self.instruction.system_program,
+
+            vault_referral: self.instruction.vault_referral,
+
+            referrer: self.instruction.referrer,
+
+            referrer_vrt_token_account: self.instruction.referrer_vrt_token_account,
+
+            beneficiary: self.instruction.beneficiary,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -730,6 +1082,12 @@ struct MintToCpiBuilderInstruction<'a, 'b> {
     vault_fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_deposit_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault_referral: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    referrer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    referrer_vrt_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    beneficiary: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount_in: Option<u64>,
     min_amount_out: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
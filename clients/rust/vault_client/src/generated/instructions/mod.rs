@@ -6,8 +6,11 @@
 //!
 
 pub(crate) mod r#add_delegation;
+pub(crate) mod r#burn_withdrawal_claim;
 pub(crate) mod r#burn_withdrawal_ticket;
 pub(crate) mod r#change_withdrawal_ticket_owner;
+pub(crate) mod r#close_creator_allowlist_entry;
+pub(crate) mod r#close_vault;
 pub(crate) mod r#close_vault_update_state_tracker;
 pub(crate) mod r#cooldown_delegation;
 pub(crate) mod r#cooldown_vault_ncn_slasher_ticket;
@@ -15,8 +18,11 @@ pub(crate) mod r#cooldown_vault_ncn_ticket;
 pub(crate) mod r#crank_vault_update_state_tracker;
 pub(crate) mod r#create_token_metadata;
 pub(crate) mod r#delegate_token_account;
+pub(crate) mod r#distribute_fee_split;
 pub(crate) mod r#enqueue_withdrawal;
+pub(crate) mod r#enqueue_withdrawal_claim;
 pub(crate) mod r#initialize_config;
+pub(crate) mod r#initialize_creator_allowlist_entry;
 pub(crate) mod r#initialize_vault;
 pub(crate) mod r#initialize_vault_ncn_slasher_operator_ticket;
 pub(crate) mod r#initialize_vault_ncn_slasher_ticket;
@@ -24,23 +30,39 @@ pub(crate) mod r#initialize_vault_ncn_ticket;
 pub(crate) mod r#initialize_vault_operator_delegation;
 pub(crate) mod r#initialize_vault_update_state_tracker;
 pub(crate) mod r#initialize_vault_with_mint;
+pub(crate) mod r#migrate_account;
 pub(crate) mod r#mint_to;
 pub(crate) mod r#set_admin;
 pub(crate) mod r#set_config_admin;
+pub(crate) mod r#set_creation_policy;
 pub(crate) mod r#set_deposit_capacity;
+pub(crate) mod r#set_fee_split;
 pub(crate) mod r#set_fees;
 pub(crate) mod r#set_is_paused;
+pub(crate) mod r#set_max_operator_share;
+pub(crate) mod r#set_min_holding_period;
+pub(crate) mod r#set_ncn_allocation_cap;
+pub(crate) mod r#set_operator_delegation_cap;
 pub(crate) mod r#set_program_fee;
+pub(crate) mod r#set_program_fee_override;
 pub(crate) mod r#set_program_fee_wallet;
+pub(crate) mod r#set_referral_fee;
 pub(crate) mod r#set_secondary_admin;
 pub(crate) mod r#update_token_metadata;
 pub(crate) mod r#update_vault_balance;
+pub(crate) mod r#update_vault_full;
+pub(crate) mod r#update_vault_ncn_slasher_ticket;
+pub(crate) mod r#waive_holding_period;
 pub(crate) mod r#warmup_vault_ncn_slasher_ticket;
 pub(crate) mod r#warmup_vault_ncn_ticket;
+pub(crate) mod r#wind_down_vault;
 
 pub use self::r#add_delegation::*;
+pub use self::r#burn_withdrawal_claim::*;
 pub use self::r#burn_withdrawal_ticket::*;
 pub use self::r#change_withdrawal_ticket_owner::*;
+pub use self::r#close_creator_allowlist_entry::*;
+pub use self::r#close_vault::*;
 pub use self::r#close_vault_update_state_tracker::*;
 pub use self::r#cooldown_delegation::*;
 pub use self::r#cooldown_vault_ncn_slasher_ticket::*;
@@ -48,8 +70,11 @@ pub use self::r#cooldown_vault_ncn_ticket::*;
 pub use self::r#crank_vault_update_state_tracker::*;
 pub use self::r#create_token_metadata::*;
 pub use self::r#delegate_token_account::*;
+pub use self::r#distribute_fee_split::*;
 pub use self::r#enqueue_withdrawal::*;
+pub use self::r#enqueue_withdrawal_claim::*;
 pub use self::r#initialize_config::*;
+pub use self::r#initialize_creator_allowlist_entry::*;
 pub use self::r#initialize_vault::*;
 pub use self::r#initialize_vault_ncn_slasher_operator_ticket::*;
 pub use self::r#initialize_vault_ncn_slasher_ticket::*;
@@ -57,16 +82,29 @@ pub use self::r#initialize_vault_ncn_ticket::*;
 pub use self::r#initialize_vault_operator_delegation::*;
 pub use self::r#initialize_vault_update_state_tracker::*;
 pub use self::r#initialize_vault_with_mint::*;
+pub use self::r#migrate_account::*;
 pub use self::r#mint_to::*;
 pub use self::r#set_admin::*;
 pub use self::r#set_config_admin::*;
+pub use self::r#set_creation_policy::*;
 pub use self::r#set_deposit_capacity::*;
+pub use self::r#set_fee_split::*;
 pub use self::r#set_fees::*;
 pub use self::r#set_is_paused::*;
+pub use self::r#set_max_operator_share::*;
+pub use self::r#set_min_holding_period::*;
+pub use self::r#set_ncn_allocation_cap::*;
+pub use self::r#set_operator_delegation_cap::*;
 pub use self::r#set_program_fee::*;
+pub use self::r#set_program_fee_override::*;
 pub use self::r#set_program_fee_wallet::*;
+pub use self::r#set_referral_fee::*;
 pub use self::r#set_secondary_admin::*;
 pub use self::r#update_token_metadata::*;
 pub use self::r#update_vault_balance::*;
+pub use self::r#update_vault_full::*;
+pub use self::r#update_vault_ncn_slasher_ticket::*;
+pub use self::r#waive_holding_period::*;
 pub use self::r#warmup_vault_ncn_slasher_ticket::*;
 pub use self::r#warmup_vault_ncn_ticket::*;
+pub use self::r#wind_down_vault::*;
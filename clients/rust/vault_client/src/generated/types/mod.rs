@@ -6,16 +6,20 @@
 //!
 
 pub(crate) mod r#create_metadata_account_args_v3;
+pub(crate) mod r#creation_policy;
 pub(crate) mod r#data_v2;
 pub(crate) mod r#delegation_state;
+pub(crate) mod r#fee_split_recipient;
 pub(crate) mod r#slot_toggle;
 pub(crate) mod r#update_metadata_account_args_v2;
 pub(crate) mod r#vault_admin_role;
 pub(crate) mod r#withdrawal_allocation_method;
 
 pub use self::r#create_metadata_account_args_v3::*;
+pub use self::r#creation_policy::*;
 pub use self::r#data_v2::*;
 pub use self::r#delegation_state::*;
+pub use self::r#fee_split_recipient::*;
 pub use self::r#slot_toggle::*;
 pub use self::r#update_metadata_account_args_v2::*;
 pub use self::r#vault_admin_role::*;
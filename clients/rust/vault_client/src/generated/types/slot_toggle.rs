@@ -13,5 +13,7 @@ use borsh::BorshSerialize;
 pub struct SlotToggle {
     pub slot_added: u64,
     pub slot_removed: u64,
-    pub reserved: [u8; 32],
+    pub warmup_epochs: u8,
+    pub cooldown_epochs: u8,
+    pub reserved: [u8; 30],
 }
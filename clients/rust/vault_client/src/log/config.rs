@@ -33,6 +33,17 @@ impl PrettyDisplay for Config {
         output.push_str(&section_header("Epoch Information"));
         output.push_str(&field("Epoch Length", self.epoch_length));
 
+        output.push_str(&section_header("Creation Policy"));
+        let creation_policy = match self.creation_policy {
+            0 => "Open".to_string(),
+            1 => "Allowlist".to_string(),
+            2 => "Fee".to_string(),
+            other => format!("Unknown ({other})"),
+        };
+        output.push_str(&field("Creation Policy", creation_policy));
+        output.push_str(&field("Creation Fee Lamports", self.creation_fee_lamports));
+        output.push_str(&field("Creation Fee Treasury", self.creation_fee_treasury));
+
         output
     }
 }
@@ -59,7 +70,10 @@ mod tests {
             program_fee_wallet: Pubkey::new_unique(),
             fee_admin: Pubkey::new_unique(),
             bump: 248,
-            reserved: [0; 229],
+            creation_fee_treasury: Pubkey::new_unique(),
+            creation_fee_lamports: 1_000_000,
+            creation_policy: 2,
+            reserved: [0; 188],
         };
 
         let output = config.pretty_display();
@@ -74,5 +88,8 @@ mod tests {
         assert!(output.contains(&config.program_fee_wallet.to_string()));
         assert!(output.contains(&config.fee_admin.to_string()));
         assert!(output.contains(&config.bump.to_string()));
+        assert!(output.contains("Fee"));
+        assert!(output.contains(&config.creation_fee_lamports.to_string()));
+        assert!(output.contains(&config.creation_fee_treasury.to_string()));
     }
 }
@@ -0,0 +1,40 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::CreatorAllowlistEntry;
+
+impl PrettyDisplay for CreatorAllowlistEntry {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Creator Allowlist Entry Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Creator", self.creator));
+        output.push_str(&field("Bump", self.bump));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::accounts::CreatorAllowlistEntry;
+
+    #[test]
+    fn test_creator_allowlist_entry_pretty_display_structure() {
+        let creator_allowlist_entry = CreatorAllowlistEntry {
+            discriminator: 12345,
+            creator: Pubkey::new_unique(),
+            bump: 2,
+            reserved: [0; 263],
+        };
+
+        let output = creator_allowlist_entry.pretty_display();
+
+        assert!(output.contains(&creator_allowlist_entry.creator.to_string()));
+        assert!(output.contains(&creator_allowlist_entry.bump.to_string()));
+    }
+}
@@ -1,9 +1,13 @@
 pub(crate) mod config;
+pub(crate) mod creator_allowlist_entry;
 pub mod metadata;
 pub(crate) mod vault;
+pub(crate) mod vault_deposit_receipt;
+pub(crate) mod vault_fee_split;
 pub(crate) mod vault_ncn_slasher_operator_ticket;
 pub(crate) mod vault_ncn_slasher_ticket;
 pub(crate) mod vault_ncn_ticket;
 pub(crate) mod vault_operator_delegation;
+pub(crate) mod vault_referral;
 pub(crate) mod vault_staker_withdrawal_ticket;
 pub(crate) mod vault_update_state_tracker;
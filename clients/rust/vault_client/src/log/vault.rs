@@ -13,6 +13,7 @@ impl PrettyDisplay for Vault {
         output.push_str(&field("Vault Index", self.vault_index));
         output.push_str(&field("Bump", self.bump));
         output.push_str(&field("Is Paused", self.is_paused));
+        output.push_str(&field("Is Winding Down", self.is_winding_down));
 
         output.push_str(&section_header("Token Information"));
         output.push_str(&field("VRT Mint", self.vrt_mint));
@@ -82,6 +83,26 @@ impl PrettyDisplay for Vault {
         ));
         output.push_str(&field("Reward Fee BPS", self.reward_fee_bps));
         output.push_str(&field("Program Fee BPS", self.program_fee_bps));
+        let program_fee_override_bps = if self.has_program_fee_override {
+            self.program_fee_override_bps.to_string()
+        } else {
+            "None".to_string()
+        };
+        output.push_str(&field("Program Fee Override BPS", program_fee_override_bps));
+        output.push_str(&field(
+            "Last Program Fee Override Change Slot",
+            self.last_program_fee_override_change_slot,
+        ));
+        output.push_str(&field("Referral Fee BPS", self.referral_fee_bps));
+        output.push_str(&field(
+            "Max Operator Share BPS",
+            self.max_operator_share_bps,
+        ));
+        output.push_str(&field("Min Holding Epochs", self.min_holding_epochs));
+        output.push_str(&field(
+            "Is Holding Period Waived",
+            self.is_holding_period_waived,
+        ));
 
         output
     }
@@ -139,7 +160,15 @@ mod tests {
             bump: 22,
             is_paused: false,
             last_start_state_update_slot: 23,
-            reserved: [0; 251],
+            is_winding_down: false,
+            max_operator_share_bps: 24,
+            min_holding_epochs: 25,
+            is_holding_period_waived: false,
+            has_program_fee_override: true,
+            program_fee_override_bps: 26,
+            last_program_fee_override_change_slot: 27,
+            referral_fee_bps: 28,
+            reserved: [0; 226],
         };
 
         let output = vault.pretty_display();
@@ -184,6 +213,11 @@ mod tests {
         assert!(output.contains(&vault.next_withdrawal_fee_bps.to_string()));
         assert!(output.contains(&vault.reward_fee_bps.to_string()));
         assert!(output.contains(&vault.program_fee_bps.to_string()));
+        assert!(output.contains(&vault.max_operator_share_bps.to_string()));
+        assert!(output.contains(&vault.min_holding_epochs.to_string()));
+        assert!(output.contains(&vault.program_fee_override_bps.to_string()));
+        assert!(output.contains(&vault.last_program_fee_override_change_slot.to_string()));
+        assert!(output.contains(&vault.referral_fee_bps.to_string()));
         assert!(output.contains(&vault.last_fee_change_slot.to_string()));
         assert!(output.contains(&vault.last_full_state_update_slot.to_string()));
         assert!(output.contains(&vault.last_start_state_update_slot.to_string()));
@@ -0,0 +1,49 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::VaultDepositReceipt;
+
+impl PrettyDisplay for VaultDepositReceipt {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Vault Deposit Receipt Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Vault", self.vault));
+        output.push_str(&field("Depositor", self.depositor));
+        output.push_str(&field("Last Deposit Slot", self.last_deposit_slot));
+        output.push_str(&field("VRT Amount", self.vrt_amount));
+        output.push_str(&field("Bump", self.bump));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::accounts::VaultDepositReceipt;
+
+    #[test]
+    fn test_vault_deposit_receipt_pretty_display_structure() {
+        let vault_deposit_receipt = VaultDepositReceipt {
+            discriminator: 12345,
+            vault: Pubkey::new_unique(),
+            depositor: Pubkey::new_unique(),
+            last_deposit_slot: 1,
+            vrt_amount: 3,
+            bump: 2,
+            reserved: [0; 255],
+        };
+
+        let output = vault_deposit_receipt.pretty_display();
+
+        assert!(output.contains(&vault_deposit_receipt.vault.to_string()));
+        assert!(output.contains(&vault_deposit_receipt.depositor.to_string()));
+        assert!(output.contains(&vault_deposit_receipt.last_deposit_slot.to_string()));
+        assert!(output.contains(&vault_deposit_receipt.vrt_amount.to_string()));
+        assert!(output.contains(&vault_deposit_receipt.bump.to_string()));
+    }
+}
@@ -0,0 +1,57 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::VaultFeeSplit;
+
+impl PrettyDisplay for VaultFeeSplit {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Vault Fee Split Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Vault", self.vault));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&section_header("Recipients"));
+        for recipient in self.recipients.iter().take(self.recipients_len as usize) {
+            output.push_str(&field(
+                &recipient.recipient.to_string(),
+                format!("{} bps", recipient.bps),
+            ));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::{accounts::VaultFeeSplit, types::FeeSplitRecipient};
+
+    #[test]
+    fn test_vault_fee_split_pretty_display_structure() {
+        let recipients = core::array::from_fn(|i| FeeSplitRecipient {
+            recipient: Pubkey::new_unique(),
+            bps: if i < 2 { 5_000 } else { 0 },
+        });
+        let vault_fee_split = VaultFeeSplit {
+            discriminator: 12345,
+            vault: Pubkey::new_unique(),
+            recipients,
+            recipients_len: 2,
+            bump: 3,
+            reserved: [0; 263],
+        };
+
+        let output = vault_fee_split.pretty_display();
+
+        assert!(output.contains(&vault_fee_split.vault.to_string()));
+        assert!(output.contains(&vault_fee_split.recipients[0].recipient.to_string()));
+        assert!(output.contains(&vault_fee_split.recipients[1].recipient.to_string()));
+        assert!(!output.contains(&vault_fee_split.recipients[2].recipient.to_string()));
+        assert!(output.contains("5000 bps"));
+    }
+}
@@ -16,6 +16,14 @@ impl PrettyDisplay for VaultNcnSlasherTicket {
             "Max Slashable per Epoch",
             self.max_slashable_per_epoch,
         ));
+        output.push_str(&field(
+            "Next Max Slashable per Epoch",
+            self.next_max_slashable_per_epoch,
+        ));
+        output.push_str(&field(
+            "Next Max Slashable Epoch",
+            self.next_max_slashable_epoch,
+        ));
         output.push_str(&field("Index", self.index));
         output.push_str(&field("Bump", self.bump));
 
@@ -46,10 +54,14 @@ mod tests {
             state: SlotToggle {
                 slot_added: 3,
                 slot_removed: 4,
-                reserved: [0; 32],
+                warmup_epochs: 0,
+                cooldown_epochs: 0,
+                reserved: [0; 30],
             },
             bump: 5,
-            reserved: [0; 263],
+            next_max_slashable_per_epoch: 5,
+            next_max_slashable_epoch: 6,
+            reserved: [0; 247],
         };
 
         let output = vault_ncn_slasher_ticket.pretty_display();
@@ -58,6 +70,16 @@ mod tests {
         assert!(output.contains(&vault_ncn_slasher_ticket.ncn.to_string()));
         assert!(output.contains(&vault_ncn_slasher_ticket.slasher.to_string()));
         assert!(output.contains(&vault_ncn_slasher_ticket.max_slashable_per_epoch.to_string()));
+        assert!(output.contains(
+            &vault_ncn_slasher_ticket
+                .next_max_slashable_per_epoch
+                .to_string()
+        ));
+        assert!(output.contains(
+            &vault_ncn_slasher_ticket
+                .next_max_slashable_epoch
+                .to_string()
+        ));
         assert!(output.contains(&vault_ncn_slasher_ticket.index.to_string()));
         assert!(output.contains(&vault_ncn_slasher_ticket.state.slot_added.to_string()));
         assert!(output.contains(&vault_ncn_slasher_ticket.state.slot_removed.to_string()));
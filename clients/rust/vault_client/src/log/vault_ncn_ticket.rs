@@ -13,6 +13,7 @@ impl PrettyDisplay for VaultNcnTicket {
         output.push_str(&field("NCN", self.ncn));
         output.push_str(&field("Index", self.index));
         output.push_str(&field("Bump", self.bump));
+        output.push_str(&field("Max Allocation BPS", self.max_allocation_bps));
 
         output.push_str(&section_header("State"));
         output.push_str(&field("Slot Added", self.state.slot_added));
@@ -39,10 +40,13 @@ mod tests {
             state: SlotToggle {
                 slot_added: 1,
                 slot_removed: 2,
-                reserved: [0; 32],
+                warmup_epochs: 0,
+                cooldown_epochs: 0,
+                reserved: [0; 30],
             },
             bump: 3,
-            reserved: [0; 263],
+            max_allocation_bps: 2_500,
+            reserved: [0; 261],
         };
 
         let output = vault_ncn_ticket.pretty_display();
@@ -53,5 +57,6 @@ mod tests {
         assert!(output.contains(&vault_ncn_ticket.state.slot_added.to_string()));
         assert!(output.contains(&vault_ncn_ticket.state.slot_removed.to_string()));
         assert!(output.contains(&vault_ncn_ticket.bump.to_string()));
+        assert!(output.contains(&vault_ncn_ticket.max_allocation_bps.to_string()));
     }
 }
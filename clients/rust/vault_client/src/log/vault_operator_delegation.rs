@@ -14,6 +14,7 @@ impl PrettyDisplay for VaultOperatorDelegation {
         output.push_str(&field("Last Update Slot", self.last_update_slot));
         output.push_str(&field("Index", self.index));
         output.push_str(&field("Bump", self.bump));
+        output.push_str(&field("Max Delegation Amount", self.max_delegation_amount));
 
         output.push_str(&section_header("Delegation State"));
         output.push_str(&field(
@@ -52,7 +53,8 @@ mod tests {
             last_update_slot: 4,
             index: 5,
             bump: 6,
-            reserved: [0; 263],
+            max_delegation_amount: 7,
+            reserved: [0; 255],
         };
 
         let output = vault_operator_delegation.pretty_display();
@@ -78,6 +80,7 @@ mod tests {
                 .to_string()
         ));
         assert!(output.contains(&vault_operator_delegation.last_update_slot.to_string()));
+        assert!(output.contains(&vault_operator_delegation.max_delegation_amount.to_string()));
         assert!(output.contains(&vault_operator_delegation.index.to_string()));
         assert!(output.contains(&vault_operator_delegation.bump.to_string()));
     }
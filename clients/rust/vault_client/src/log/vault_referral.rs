@@ -0,0 +1,60 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::VaultReferral;
+
+impl PrettyDisplay for VaultReferral {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Vault Referral Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("Vault", self.vault));
+        output.push_str(&field("Referrer", self.referrer));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&section_header("Referred Deposits"));
+        output.push_str(&field(
+            "Referred Deposit Amount",
+            self.referred_deposit_amount,
+        ));
+        output.push_str(&field(
+            "Referred Deposit Count",
+            self.referred_deposit_count,
+        ));
+        output.push_str(&field("Referral Fees Earned", self.referral_fees_earned));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::accounts::VaultReferral;
+
+    #[test]
+    fn test_vault_referral_pretty_display_structure() {
+        let vault_referral = VaultReferral {
+            discriminator: 12345,
+            vault: Pubkey::new_unique(),
+            referrer: Pubkey::new_unique(),
+            referred_deposit_amount: 1,
+            referred_deposit_count: 2,
+            referral_fees_earned: 3,
+            bump: 4,
+            reserved: [0; 247],
+        };
+
+        let output = vault_referral.pretty_display();
+
+        assert!(output.contains(&vault_referral.vault.to_string()));
+        assert!(output.contains(&vault_referral.referrer.to_string()));
+        assert!(output.contains(&vault_referral.referred_deposit_amount.to_string()));
+        assert!(output.contains(&vault_referral.referred_deposit_count.to_string()));
+        assert!(output.contains(&vault_referral.referral_fees_earned.to_string()));
+        assert!(output.contains(&vault_referral.bump.to_string()));
+    }
+}
@@ -11,7 +11,7 @@ impl PrettyDisplay for VaultUpdateStateTracker {
         output.push_str(&section_header("Basic Information"));
         output.push_str(&field("Vault", self.vault));
         output.push_str(&field("NCN Epoch", self.ncn_epoch));
-        output.push_str(&field("Last Updated Index", self.last_updated_index));
+        output.push_str(&field("Operators Updated", self.operators_updated));
 
         let withdrawal_allocation_method = match self.withdrawal_allocation_method {
             0 => "Greedy",
@@ -50,7 +50,7 @@ mod tests {
             discriminator: 12345,
             vault: Pubkey::new_unique(),
             ncn_epoch: 1,
-            last_updated_index: 2,
+            operators_updated: 2,
             delegation_state: DelegationState {
                 staked_amount: 3,
                 enqueued_for_cooldown_amount: 4,
@@ -65,7 +65,7 @@ mod tests {
 
         assert!(output.contains(&vault_update_state_tracker.vault.to_string()));
         assert!(output.contains(&vault_update_state_tracker.ncn_epoch.to_string()));
-        assert!(output.contains(&vault_update_state_tracker.last_updated_index.to_string()));
+        assert!(output.contains(&vault_update_state_tracker.operators_updated.to_string()));
         assert!(output.contains(
             &vault_update_state_tracker
                 .delegation_state
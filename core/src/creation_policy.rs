@@ -0,0 +1,254 @@
+//! Creation policies gating who may create vaults, NCNs and operators, shared by the vault and
+//! restaking programs
+
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use jito_bytemuck::AccountDeserialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+
+use crate::create_account;
+
+/// Who may create vaults, NCNs and operators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[repr(u8)]
+pub enum CreationPolicy {
+    /// Anyone may create an account
+    Open,
+    /// Only creators with a creator allowlist entry may create an account
+    Allowlist,
+    /// Anyone may create an account by paying the creation fee to the creation fee treasury
+    Fee,
+}
+
+impl TryFrom<u8> for CreationPolicy {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Open),
+            1 => Ok(Self::Allowlist),
+            2 => Ok(Self::Fee),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+/// Why a creator doesn't satisfy a [`CreationPolicy`], mapped by each program to its own error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreationPolicyError {
+    /// The creator has no creator allowlist entry under [`CreationPolicy::Allowlist`]
+    CreatorNotAllowlisted,
+    /// The creation fee treasury is missing or wrong under [`CreationPolicy::Fee`]
+    CreationFeeTreasuryInvalid,
+    /// Paying the creation fee failed
+    Program(ProgramError),
+}
+
+/// A creator allowlist entry account, allowing its creator to create accounts under
+/// [`CreationPolicy::Allowlist`]. Each program defines its own account with its own discriminator.
+pub trait CreatorAllowlistEntryAccount: AccountDeserialize {
+    fn new(creator: Pubkey, bump: u8) -> Self;
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `creator` - The allowlisted creator
+    fn seeds(creator: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"creator_allowlist_entry".to_vec(),
+            creator.to_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `creator` - The allowlisted creator
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    fn find_program_address(program_id: &Pubkey, creator: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(creator);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the creator allowlist entry account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `creator_allowlist_entry` - The creator allowlist entry account
+    /// * `creator` - The allowlisted creator
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    fn load(
+        program_id: &Pubkey,
+        creator_allowlist_entry: &AccountInfo,
+        creator: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if creator_allowlist_entry.owner.ne(program_id) {
+            msg!("Creator allowlist entry has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if creator_allowlist_entry.data_is_empty() {
+            msg!("Creator allowlist entry data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !creator_allowlist_entry.is_writable {
+            msg!("Creator allowlist entry is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if creator_allowlist_entry.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Creator allowlist entry discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let expected_pubkey = Self::find_program_address(program_id, creator).0;
+        if creator_allowlist_entry.key.ne(&expected_pubkey) {
+            msg!("Creator allowlist entry is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+/// Creates the creator's allowlist entry at the canonical PDA, paid for by the config admin.
+/// The caller checks the config admin.
+pub fn initialize_creator_allowlist_entry<'a, 'info, T: CreatorAllowlistEntryAccount>(
+    program_id: &Pubkey,
+    creator_allowlist_entry: &'a AccountInfo<'info>,
+    creator: &'a AccountInfo<'info>,
+    config_admin: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    // The creator allowlist entry shall be at the canonical PDA
+    let (
+        creator_allowlist_entry_pubkey,
+        creator_allowlist_entry_bump,
+        mut creator_allowlist_entry_seeds,
+    ) = T::find_program_address(program_id, creator.key);
+    creator_allowlist_entry_seeds.push(vec![creator_allowlist_entry_bump]);
+    if creator_allowlist_entry
+        .key
+        .ne(&creator_allowlist_entry_pubkey)
+    {
+        msg!("Creator allowlist entry is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing creator allowlist entry at address {}",
+        creator_allowlist_entry.key
+    );
+    create_account(
+        config_admin,
+        creator_allowlist_entry,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<T>() as u64)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        &creator_allowlist_entry_seeds,
+    )?;
+
+    let mut creator_allowlist_entry_data = creator_allowlist_entry.try_borrow_mut_data()?;
+    T::write_header(&mut creator_allowlist_entry_data);
+    let creator_allowlist_entry =
+        T::try_from_slice_unchecked_mut(&mut creator_allowlist_entry_data)?;
+    *creator_allowlist_entry = T::new(*creator.key, creator_allowlist_entry_bump);
+
+    Ok(())
+}
+
+/// Checks that `creator` may create an account under `creation_policy`
+///
+/// - [`CreationPolicy::Open`]: anyone may create an account
+/// - [`CreationPolicy::Allowlist`]: `creation_policy_account` shall be the creator's allowlist
+///   entry
+/// - [`CreationPolicy::Fee`]: `creation_policy_account` shall be `creation_fee_treasury`, and the
+///   creator pays it `creation_fee_lamports`
+pub fn check_creation_policy<'a, 'info, T: CreatorAllowlistEntryAccount>(
+    program_id: &Pubkey,
+    creation_policy: CreationPolicy,
+    creation_fee_treasury: &Pubkey,
+    creation_fee_lamports: u64,
+    creator: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    creation_policy_account: Option<&'a AccountInfo<'info>>,
+) -> Result<(), CreationPolicyError> {
+    match creation_policy {
+        CreationPolicy::Open => Ok(()),
+        CreationPolicy::Allowlist => {
+            let Some(creator_allowlist_entry) = creation_policy_account else {
+                msg!("Creator allowlist entry is required by the creation policy");
+                return Err(CreationPolicyError::CreatorNotAllowlisted);
+            };
+            T::load(program_id, creator_allowlist_entry, creator.key, false).map_err(|_| {
+                msg!("Creator {} is not allowlisted", creator.key);
+                CreationPolicyError::CreatorNotAllowlisted
+            })
+        }
+        CreationPolicy::Fee => {
+            let Some(creation_fee_treasury_info) = creation_policy_account else {
+                msg!("Creation fee treasury is required by the creation policy");
+                return Err(CreationPolicyError::CreationFeeTreasuryInvalid);
+            };
+            if creation_fee_treasury_info.key.ne(creation_fee_treasury) {
+                msg!("Creation fee treasury does not match the config");
+                return Err(CreationPolicyError::CreationFeeTreasuryInvalid);
+            }
+
+            if creation_fee_lamports > 0 {
+                msg!("Paying creation fee of {} lamports", creation_fee_lamports);
+                invoke(
+                    &system_instruction::transfer(
+                        creator.key,
+                        creation_fee_treasury_info.key,
+                        creation_fee_lamports,
+                    ),
+                    &[
+                        creator.clone(),
+                        creation_fee_treasury_info.clone(),
+                        system_program.clone(),
+                    ],
+                )
+                .map_err(CreationPolicyError::Program)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creation_policy_try_from() {
+        for creation_policy in [
+            CreationPolicy::Open,
+            CreationPolicy::Allowlist,
+            CreationPolicy::Fee,
+        ] {
+            assert_eq!(
+                CreationPolicy::try_from(creation_policy as u8),
+                Ok(creation_policy)
+            );
+        }
+        assert_eq!(
+            CreationPolicy::try_from(3),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
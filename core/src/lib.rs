@@ -1,7 +1,9 @@
 use error::CoreError;
+use jito_bytemuck::{Migrate, LAYOUT_VERSION_INDEX};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -9,6 +11,7 @@ use solana_program::{
     system_instruction,
 };
 
+pub mod creation_policy;
 pub mod error;
 pub mod loader;
 pub mod slot_toggle;
@@ -130,6 +133,64 @@ pub fn realloc<'a, 'info>(
     Ok(())
 }
 
+/// Migrates a program account to the current layout version of `T`
+/// # Arguments
+/// * `account` - The account to migrate, already checked to be owned by the program
+/// * `payer` - The account that will pay for the lamports if the account grows
+/// * `rent` - The rent sysvar
+/// # Returns
+/// * `ProgramResult` - The result of the operation
+pub fn migrate_account<'a, 'info, T: Migrate>(
+    account: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    rent: &Rent,
+) -> ProgramResult {
+    let mut layout_version = T::layout_version(&account.data.borrow())?;
+    if layout_version > T::LAYOUT_VERSION {
+        msg!(
+            "Layout version {} is newer than the current layout version {}",
+            layout_version,
+            T::LAYOUT_VERSION
+        );
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if layout_version == T::LAYOUT_VERSION {
+        msg!("Account is already at layout version {}", layout_version);
+        return Ok(());
+    }
+
+    while layout_version < T::LAYOUT_VERSION {
+        let next_layout_version = layout_version
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let current_len = account.data_len();
+        let next_len =
+            T::layout_len(next_layout_version).ok_or(ProgramError::InvalidAccountData)?;
+        if next_len < current_len {
+            msg!("Account layouts can not shrink");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if next_len > current_len {
+            realloc(account, next_len, payer, rent)?;
+        }
+
+        let mut data = account.try_borrow_mut_data()?;
+        // The account may have been shrunk earlier in the transaction
+        data[current_len..].fill(0);
+        T::migrate_layout(&mut data, layout_version)?;
+        data[LAYOUT_VERSION_INDEX] = next_layout_version;
+
+        msg!(
+            "Migrated account from layout version {} to {}",
+            layout_version,
+            next_layout_version
+        );
+        layout_version = next_layout_version;
+    }
+
+    Ok(())
+}
+
 pub fn get_epoch(slot: u64, epoch_length: u64) -> Result<u64, CoreError> {
     let epoch = slot
         .checked_div(epoch_length)
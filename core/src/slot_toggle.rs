@@ -10,6 +10,9 @@ use solana_program::program_error::ProgramError;
 
 use crate::get_epoch;
 
+/// The number of **full** epochs a warmup or cooldown takes when none is configured
+pub const DEFAULT_TRANSITION_EPOCHS: u8 = 1;
+
 /// SlotToggle is a state tracker that allows for activation and deactivation of certain features
 /// based on slot time.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
@@ -19,8 +22,12 @@ pub struct SlotToggle {
     slot_added: PodU64,
     /// The slot at which the feature was removed
     slot_removed: PodU64,
+    /// The number of full epochs the warmup takes, zero meaning [`DEFAULT_TRANSITION_EPOCHS`]
+    warmup_epochs: u8,
+    /// The number of full epochs the cooldown takes, zero meaning [`DEFAULT_TRANSITION_EPOCHS`]
+    cooldown_epochs: u8,
 
-    reserved: [u8; 32],
+    reserved: [u8; 30],
 }
 
 /// The state of the SlotToggle
@@ -43,7 +50,9 @@ impl SlotToggle {
         Self {
             slot_added: PodU64::from(slot),
             slot_removed: PodU64::from(slot),
-            reserved: [0; 32],
+            warmup_epochs: 0,
+            cooldown_epochs: 0,
+            reserved: [0; 30],
         }
     }
 
@@ -57,9 +66,27 @@ impl SlotToggle {
         self.slot_removed.into()
     }
 
+    /// Get the number of full epochs the latest warmup takes
+    pub const fn warmup_epochs(&self) -> u8 {
+        if self.warmup_epochs == 0 {
+            DEFAULT_TRANSITION_EPOCHS
+        } else {
+            self.warmup_epochs
+        }
+    }
+
+    /// Get the number of full epochs the latest cooldown takes
+    pub const fn cooldown_epochs(&self) -> u8 {
+        if self.cooldown_epochs == 0 {
+            DEFAULT_TRANSITION_EPOCHS
+        } else {
+            self.cooldown_epochs
+        }
+    }
+
     /// Activate the feature at the given slot, which can only happen if the feature is inactive.
     /// Once activated, it immediately transitions to warming up state, which takes place for
-    /// one **full** epoch before transitioning to active state.
+    /// [`Self::warmup_epochs`] **full** epochs before transitioning to active state.
     ///
     /// # Arguments
     /// * `slot` - The slot at which the feature is being activated
@@ -85,7 +112,7 @@ impl SlotToggle {
 
     /// Deactivate the feature at the given slot, which can only happen if the feature is active.
     /// Once deactivated, it immediately transitions to cooldown state, which takes place for
-    /// one **full** epoch before transitioning to inactive state.
+    /// [`Self::cooldown_epochs`] **full** epochs before transitioning to inactive state.
     ///
     /// # Arguments
     /// * `slot` - The slot at which the feature is being deactivated
@@ -103,6 +130,52 @@ impl SlotToggle {
         }
     }
 
+    /// Activate the feature like [`Self::activate`], with a warmup of `warmup_epochs` full
+    /// epochs. The warmup duration is only changed if the feature was activated.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot at which the feature is being activated
+    /// * `epoch_length` - The length of an epoch in slots
+    /// * `warmup_epochs` - The number of full epochs the warmup takes
+    ///
+    /// # Returns
+    /// * `bool` - Whether the feature was successfully activated
+    pub fn activate_with_warmup_epochs(
+        &mut self,
+        slot: u64,
+        epoch_length: u64,
+        warmup_epochs: u8,
+    ) -> Result<bool, ProgramError> {
+        if !self.activate(slot, epoch_length)? {
+            return Ok(false);
+        }
+        self.warmup_epochs = warmup_epochs;
+        Ok(true)
+    }
+
+    /// Deactivate the feature like [`Self::deactivate`], with a cooldown of `cooldown_epochs`
+    /// full epochs. The cooldown duration is only changed if the feature was deactivated.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot at which the feature is being deactivated
+    /// * `epoch_length` - The length of an epoch in slots
+    /// * `cooldown_epochs` - The number of full epochs the cooldown takes
+    ///
+    /// # Returns
+    /// * `bool` - Whether the feature was successfully deactivated
+    pub fn deactivate_with_cooldown_epochs(
+        &mut self,
+        slot: u64,
+        epoch_length: u64,
+        cooldown_epochs: u8,
+    ) -> Result<bool, ProgramError> {
+        if !self.deactivate(slot, epoch_length)? {
+            return Ok(false);
+        }
+        self.cooldown_epochs = cooldown_epochs;
+        Ok(true)
+    }
+
     /// Check if the feature is active or in cooldown state at the given slot.
     pub fn is_active_or_cooldown(
         &self,
@@ -144,7 +217,7 @@ impl SlotToggle {
                 let slot_removed_epoch = get_epoch(slot_removed, epoch_length)?;
                 if current_epoch
                     > slot_removed_epoch
-                        .checked_add(1)
+                        .checked_add(u64::from(self.cooldown_epochs()))
                         .ok_or(ProgramError::ArithmeticOverflow)?
                 {
                     Ok(SlotToggleState::Inactive)
@@ -156,7 +229,7 @@ impl SlotToggle {
                 let slot_added_epoch = get_epoch(slot_added, epoch_length)?;
                 if current_epoch
                     > slot_added_epoch
-                        .checked_add(1)
+                        .checked_add(u64::from(self.warmup_epochs()))
                         .ok_or(ProgramError::ArithmeticOverflow)?
                 {
                     Ok(SlotToggleState::Active)
@@ -179,7 +252,9 @@ mod tests {
         let slot_toggle_size = std::mem::size_of::<SlotToggle>();
         let sum_of_fields = size_of::<PodU64>() + // slot_added
             size_of::<PodU64>() + // slot_removed
-            32; // reserved
+            size_of::<u8>() + // warmup_epochs
+            size_of::<u8>() + // cooldown_epochs
+            30; // reserved
         assert_eq!(slot_toggle_size, sum_of_fields);
     }
 
@@ -347,4 +422,51 @@ mod tests {
         let inactive_slot = active_slot + (epoch_length * 2);
         assert!(!toggle.is_active(inactive_slot, epoch_length).unwrap());
     }
+
+    #[test]
+    fn test_multi_epoch_warmup_and_cooldown() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+        let mut toggle = SlotToggle::new(creation_slot);
+        assert_eq!(toggle.warmup_epochs(), 1);
+        assert_eq!(toggle.cooldown_epochs(), 1);
+
+        // Warm up for three full epochs
+        let activation_slot = creation_slot + 1;
+        assert!(toggle
+            .activate_with_warmup_epochs(activation_slot, epoch_length, 3)
+            .unwrap());
+        assert_eq!(toggle.warmup_epochs(), 3);
+        assert_eq!(
+            toggle
+                .state(activation_slot + epoch_length * 3, epoch_length)
+                .unwrap(),
+            SlotToggleState::WarmUp
+        );
+        let active_slot = activation_slot + epoch_length * 4;
+        assert_eq!(
+            toggle.state(active_slot, epoch_length).unwrap(),
+            SlotToggleState::Active
+        );
+
+        // A failed transition keeps the configured duration
+        assert!(!toggle
+            .activate_with_warmup_epochs(active_slot, epoch_length, 5)
+            .unwrap());
+        assert_eq!(toggle.warmup_epochs(), 3);
+
+        // Cool down for two full epochs
+        assert!(toggle
+            .deactivate_with_cooldown_epochs(active_slot, epoch_length, 2)
+            .unwrap());
+        assert!(toggle
+            .is_active_or_cooldown(active_slot + epoch_length * 2, epoch_length)
+            .unwrap());
+        assert_eq!(
+            toggle
+                .state(active_slot + epoch_length * 3, epoch_length)
+                .unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
 }
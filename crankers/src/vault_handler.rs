@@ -6,7 +6,7 @@ use jito_bytemuck::AccountDeserialize;
 use jito_vault_client::{
     instructions::{
         CloseVaultUpdateStateTrackerBuilder, CrankVaultUpdateStateTrackerBuilder,
-        InitializeVaultUpdateStateTrackerBuilder,
+        InitializeVaultUpdateStateTrackerBuilder, UpdateVaultFullBuilder,
     },
     types::WithdrawalAllocationMethod,
 };
@@ -22,14 +22,21 @@ use solana_rpc_client_api::{
     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
 use solana_sdk::{
-    commitment_config::CommitmentConfig, compute_budget::ComputeBudgetInstruction,
-    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
     transaction::Transaction,
 };
 use tokio::time::sleep;
 
 use crate::core::get_latest_blockhash_with_retry;
 
+/// Compute unit limit for updating every operator delegation of a vault in one transaction
+const FULL_UPDATE_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
 pub struct VaultHandler {
     rpc_url: String,
     vault_program_id: Pubkey,
@@ -219,14 +226,14 @@ impl VaultHandler {
     ///
     /// # Returns
     ///
-    /// Returns an `anyhow::Result<VaultUpdateStateTracker>` containing the deserialized state tracker
-    /// for the given vault and epoch. If successful, the state tracker is returned; otherwise,
-    /// an error is returned with contextual information.
+    /// Returns an `anyhow::Result<(VaultUpdateStateTracker, Vec<u8>)>` containing the deserialized
+    /// state tracker and its operator bitmap for the given vault and epoch. If successful, the state
+    /// tracker is returned; otherwise, an error is returned with contextual information.
     pub async fn get_update_state_tracker(
         &self,
         vault: &Pubkey,
         ncn_epoch: u64,
-    ) -> anyhow::Result<VaultUpdateStateTracker> {
+    ) -> anyhow::Result<(VaultUpdateStateTracker, Vec<u8>)> {
         let rpc_client = self.get_rpc_client();
 
         let pubkey =
@@ -234,8 +241,9 @@ impl VaultHandler {
                 .0;
 
         match rpc_client.get_account(&pubkey).await {
-            Ok(account) => match VaultUpdateStateTracker::try_from_slice_unchecked(&account.data) {
-                Ok(tracker) => Ok(*tracker),
+            Ok(account) => match VaultUpdateStateTracker::try_from_slice_with_bitmap(&account.data)
+            {
+                Ok((tracker, bitmap)) => Ok((*tracker, bitmap.to_vec())),
                 Err(e) => {
                     let context = format!("Failed deserializing VaultUpdateStateTracker: {pubkey}");
                     Err(anyhow::Error::new(e).context(context))
@@ -266,9 +274,17 @@ impl VaultHandler {
 
         log::info!("Updating vault: {vault}");
 
+        // Small vaults are updated in a single transaction, unless a tracker was already started
+        let tracker_exists = self.get_update_state_tracker(vault, epoch).await.is_ok();
+        if !tracker_exists && operators.len() as u64 <= Vault::MAX_FULL_UPDATE_OPERATORS {
+            self.update_vault_full(payer, vault, operators).await?;
+            log::info!("Fully updated vault: {vault}");
+            return Ok(());
+        }
+
         // Initialize
-        if let Err(e) = self.get_update_state_tracker(vault, epoch).await {
-            log::info!("Get tracker failed, initializing. Expecting AccountNotFound: {e}");
+        if !tracker_exists {
+            log::info!("No tracker found, initializing");
             self.initialize_vault_update_state_tracker(payer, vault, tracker_pubkey)
                 .await?;
         }
@@ -282,8 +298,8 @@ impl VaultHandler {
         log::info!("Cranked vault: {vault}");
 
         // Close
-        let tracker = self.get_update_state_tracker(vault, epoch).await?;
-        if operators.is_empty() || tracker.all_operators_updated(operators.len() as u64)? {
+        let (tracker, _) = self.get_update_state_tracker(vault, epoch).await?;
+        if tracker.all_operators_updated(operators.len() as u64) {
             self.close_vault_update_state_tracker(payer, vault, epoch, tracker_pubkey)
                 .await?;
         } else {
@@ -298,6 +314,46 @@ impl VaultHandler {
         Ok(())
     }
 
+    /// Updates a vault and all of its operator delegations in a single transaction, without a
+    /// [`VaultUpdateStateTracker`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `anyhow::Result<()>` indicating success or failure of the update.
+    pub async fn update_vault_full(
+        &self,
+        payer: &Keypair,
+        vault: &Pubkey,
+        operators: &[Pubkey],
+    ) -> anyhow::Result<()> {
+        let mut ix_builder = UpdateVaultFullBuilder::new();
+        ix_builder
+            .config(self.config_address)
+            .vault(*vault)
+            .withdrawal_allocation_method(WithdrawalAllocationMethod::Greedy);
+        for operator in operators {
+            let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+                &self.vault_program_id,
+                vault,
+                operator,
+            )
+            .0;
+            ix_builder.add_remaining_account(AccountMeta::new(vault_operator_delegation, false));
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        self.send_and_confirm_transaction_with_retry(
+            payer,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(FULL_UPDATE_COMPUTE_UNIT_LIMIT),
+                ix,
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
     /// Initializes a vault update state tracker for a given epoch and vault.
     ///
     /// # Returns
@@ -338,43 +394,32 @@ impl VaultHandler {
         operators: &[Pubkey],
         tracker_pubkey: Pubkey,
     ) -> anyhow::Result<()> {
-        let tracker = self.get_update_state_tracker(vault, epoch).await?;
+        let (tracker, bitmap) = self.get_update_state_tracker(vault, epoch).await?;
 
-        if operators.is_empty() || tracker.all_operators_updated(operators.len() as u64)? {
+        if tracker.all_operators_updated(operators.len() as u64) {
             return Ok(());
         }
 
-        let end_index = (epoch as usize)
+        // Start at a different operator every epoch so no operator is consistently cranked last
+        let start_index = (epoch as usize)
             .checked_rem(operators.len())
             .context("No operators to crank")?;
 
-        // Skip updated operators if cranking has already started
-        let start_index = if tracker.last_updated_index() == u64::MAX {
-            end_index
-        } else {
-            tracker.last_updated_index() as usize
-        };
-
-        let operators_iter = if start_index < end_index {
-            // Crank from start index to end index
-            operators
-                .iter()
-                .take(end_index)
-                .skip(start_index)
-                .collect::<Vec<_>>()
-                .into_iter()
-        } else {
-            // Crank through operators from start index to operators.len() and then 0 to end_index
-            operators
-                .iter()
-                .skip(start_index)
-                .chain(operators.iter().take(end_index))
-                .collect::<Vec<_>>()
-                .into_iter()
-        };
+        // Skip operators that have already been updated
+        let mut operators_to_crank = Vec::with_capacity(operators.len());
+        for (index, operator) in operators
+            .iter()
+            .enumerate()
+            .skip(start_index)
+            .chain(operators.iter().enumerate().take(start_index))
+        {
+            if !VaultUpdateStateTracker::is_operator_updated(&bitmap, index as u64)? {
+                operators_to_crank.push(operator);
+            }
+        }
 
-        // Need to send each transaction in serial since strict sequence is required
-        for operator in operators_iter {
+        // Operators can be cranked in any order, so the transactions are sent concurrently
+        let tasks = operators_to_crank.into_iter().map(|operator| {
             let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
                 &self.vault_program_id,
                 vault,
@@ -393,7 +438,10 @@ impl VaultHandler {
             ix.program_id = self.vault_program_id;
 
             self.send_and_confirm_transaction_with_retry(payer, vec![ix])
-                .await?;
+        });
+
+        for result in futures::future::join_all(tasks).await {
+            result?;
         }
 
         Ok(())
@@ -1,11 +1,13 @@
 use std::fmt::{Debug, Formatter};
 
+use jito_bytemuck::LAYOUT_VERSION_INDEX;
 use jito_vault_sdk::inline_mpl_token_metadata;
 use solana_program::{
     clock::Clock, native_token::sol_to_lamports, pubkey::Pubkey, system_instruction::transfer,
 };
 use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
+    account::Account,
     commitment_config::CommitmentLevel,
     signature::{Keypair, Signer},
     transaction::Transaction,
@@ -19,7 +21,8 @@ use spl_token_2022::{
 };
 
 use crate::fixtures::{
-    restaking_client::{OperatorRoot, RestakingProgramClient},
+    ncn_program,
+    restaking_client::{NcnRoot, OperatorRoot, RestakingProgramClient},
     vault_client::{VaultProgramClient, VaultRoot},
     TestResult,
 };
@@ -42,6 +45,8 @@ pub struct ConfiguredVault {
     pub vault_root: VaultRoot,
     #[allow(dead_code)]
     pub restaking_config_admin: Keypair,
+    #[allow(dead_code)]
+    pub ncn_root: NcnRoot,
     pub operator_roots: Vec<OperatorRoot>,
 }
 
@@ -58,6 +63,11 @@ impl TestBuilder {
             jito_restaking_program::id(),
             processor!(jito_restaking_program::process_instruction),
         );
+        program_test.add_program(
+            "ncn_program",
+            ncn_program::ID,
+            processor!(ncn_program::process_instruction),
+        );
         program_test.prefer_bpf(true);
         program_test.add_program("mpl_token_metadata", inline_mpl_token_metadata::id(), None);
 
@@ -367,6 +377,30 @@ impl TestBuilder {
         Ok(())
     }
 
+    /// Overwrites the layout version stored in the header of a program account
+    pub async fn set_layout_version(
+        &mut self,
+        account: &Pubkey,
+        layout_version: u8,
+    ) -> Result<(), BanksClientError> {
+        let mut account_data = self
+            .context
+            .banks_client
+            .get_account(*account)
+            .await?
+            .ok_or(BanksClientError::ClientError("failed to get account"))?;
+        account_data.data[LAYOUT_VERSION_INDEX] = layout_version;
+        self.context.set_account(account, &account_data.into());
+        Ok(())
+    }
+
+    pub async fn get_account(
+        &mut self,
+        account: &Pubkey,
+    ) -> Result<Option<Account>, BanksClientError> {
+        self.context.banks_client.get_account(*account).await
+    }
+
     pub async fn get_current_slot(&mut self) -> Result<u64, BanksClientError> {
         let clock: Clock = self.context.banks_client.get_sysvar().await?;
         Ok(clock.slot)
@@ -502,6 +536,7 @@ impl TestBuilder {
             vault_root,
             vault_config_admin,
             restaking_config_admin,
+            ncn_root,
             operator_roots,
         })
     }
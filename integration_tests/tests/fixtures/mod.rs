@@ -4,6 +4,7 @@ use solana_sdk::transaction::TransactionError;
 use thiserror::Error;
 
 pub mod fixture;
+pub mod ncn_program;
 pub mod restaking_client;
 pub mod vault_client;
 
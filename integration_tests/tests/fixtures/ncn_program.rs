@@ -0,0 +1,66 @@
+//! A minimal stand-in for an NCN's on-chain program, used to exercise NCNs bound through
+//! `NcnSetProgram`.
+//!
+//! The instruction data is the NCN address followed by a restaking instruction. The first account
+//! is the restaking program and the remaining accounts are forwarded to it, with the NCN program
+//! signer PDA signing via `invoke_signed`.
+
+use jito_restaking_core::ncn::Ncn;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub const ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (restaking_program, forwarded_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if instruction_data.len() < 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (ncn, data) = instruction_data.split_at(32);
+    let ncn = Pubkey::try_from(ncn).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let (signer, bump, mut seeds) = Ncn::find_program_signer_address(program_id, &ncn);
+    seeds.push(vec![bump]);
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+
+    let ix = Instruction {
+        program_id: *restaking_program.key,
+        accounts: forwarded_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer || account.key.eq(&signer),
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    invoke_signed(&ix, accounts, &[&seed_slices])
+}
+
+/// Wraps a restaking instruction so it's sent through the NCN program, which signs for its PDA
+pub fn ncn_program_cpi(ncn: &Pubkey, ix: Instruction) -> Instruction {
+    let (signer, _, _) = Ncn::find_program_signer_address(&ID, ncn);
+    let mut accounts = vec![AccountMeta::new_readonly(ix.program_id, false)];
+    accounts.extend(ix.accounts.into_iter().map(|meta| AccountMeta {
+        is_signer: meta.is_signer && meta.pubkey.ne(&signer),
+        ..meta
+    }));
+    Instruction {
+        program_id: ID,
+        accounts,
+        data: [ncn.to_bytes().as_slice(), ix.data.as_slice()].concat(),
+    }
+}
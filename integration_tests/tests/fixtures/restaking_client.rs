@@ -1,22 +1,31 @@
 use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::creation_policy::CreatorAllowlistEntryAccount;
+use jito_restaking_core::metadata::MetadataAccount;
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    config::Config, creator_allowlist_entry::CreatorAllowlistEntry, epoch_snapshot::EpochSnapshot,
+    ncn::Ncn, ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
     ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
-    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+    operator::Operator, operator_metadata::OperatorMetadata, operator_snapshot::OperatorSnapshot,
+    operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_restaking_sdk::{
     error::RestakingError,
-    instruction::OperatorAdminRole,
+    instruction::{CreationPolicy, OperatorAdminRole},
     sdk::{
         cooldown_ncn_vault_ticket, initialize_config, initialize_ncn,
         initialize_ncn_operator_state, initialize_ncn_vault_slasher_ticket,
         initialize_ncn_vault_ticket, initialize_operator, initialize_operator_vault_ticket,
-        ncn_cooldown_operator, ncn_set_admin, ncn_warmup_operator, operator_cooldown_ncn,
-        operator_set_admin, operator_set_fee, operator_set_secondary_admin, operator_warmup_ncn,
-        set_config_admin, warmup_ncn_vault_slasher_ticket, warmup_ncn_vault_ticket,
-        warmup_operator_vault_ticket,
+        migrate_account, ncn_cooldown_operator, ncn_set_admin, ncn_set_operator_bond,
+        ncn_set_program, ncn_warmup_operator, operator_cooldown_ncn, operator_set_admin,
+        operator_set_fee, operator_set_secondary_admin, operator_warmup_ncn,
+        operator_withdraw_bond, set_config_admin, slash_operator_bond,
+        warmup_ncn_vault_slasher_ticket, warmup_ncn_vault_ticket, warmup_operator_vault_ticket,
     },
 };
+use jito_vault_core::{
+    config::Config as VaultConfig, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+};
 use solana_program::{
     instruction::InstructionError, native_token::sol_to_lamports, pubkey::Pubkey,
     system_instruction::transfer,
@@ -28,7 +37,7 @@ use solana_sdk::{
     transaction::{Transaction, TransactionError},
 };
 
-use crate::fixtures::{TestError, TestResult};
+use crate::fixtures::{ncn_program::ncn_program_cpi, TestError, TestResult};
 
 #[derive(Debug)]
 pub struct NcnRoot {
@@ -65,6 +74,78 @@ impl RestakingProgramClient {
         Ok(*Ncn::try_from_slice_unchecked(account.data.as_slice())?)
     }
 
+    pub async fn get_epoch_snapshot(
+        &mut self,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> TestResult<EpochSnapshot> {
+        let epoch_snapshot =
+            EpochSnapshot::find_program_address(&jito_restaking_program::id(), ncn, epoch).0;
+        let account = self
+            .banks_client
+            .get_account_with_commitment(epoch_snapshot, CommitmentLevel::Processed)
+            .await?
+            .unwrap();
+
+        Ok(*EpochSnapshot::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_operator_snapshot(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> TestResult<OperatorSnapshot> {
+        let operator_snapshot = OperatorSnapshot::find_program_address(
+            &jito_restaking_program::id(),
+            ncn,
+            operator,
+            epoch,
+        )
+        .0;
+        let account = self
+            .banks_client
+            .get_account_with_commitment(operator_snapshot, CommitmentLevel::Processed)
+            .await?
+            .unwrap();
+
+        Ok(*OperatorSnapshot::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_ncn_metadata(&mut self, ncn: &Pubkey) -> TestResult<NcnMetadata> {
+        let ncn_metadata = NcnMetadata::find_program_address(&jito_restaking_program::id(), ncn).0;
+        let account = self
+            .banks_client
+            .get_account_with_commitment(ncn_metadata, CommitmentLevel::Processed)
+            .await?
+            .unwrap();
+
+        Ok(*NcnMetadata::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_operator_metadata(
+        &mut self,
+        operator: &Pubkey,
+    ) -> TestResult<OperatorMetadata> {
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&jito_restaking_program::id(), operator).0;
+        let account = self
+            .banks_client
+            .get_account_with_commitment(operator_metadata, CommitmentLevel::Processed)
+            .await?
+            .unwrap();
+
+        Ok(*OperatorMetadata::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
     pub async fn get_config(&mut self, account: &Pubkey) -> TestResult<Config> {
         let account = self.banks_client.get_account(*account).await?.unwrap();
         Ok(*Config::try_from_slice_unchecked(account.data.as_slice())?)
@@ -434,6 +515,7 @@ impl RestakingProgramClient {
             )
             .0,
             &ncn_root.ncn_admin,
+            None,
         )
         .await
     }
@@ -484,6 +566,245 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn ncn_jail_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator: &Pubkey,
+        reason_code: u8,
+        until_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::ncn_jail_operator(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                reason_code,
+                until_epoch,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_unjail_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::ncn_unjail_operator(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_transition_epochs(
+        &mut self,
+        ncn: &Pubkey,
+        admin: &Keypair,
+        warmup_epochs: u8,
+        cooldown_epochs: u8,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::ncn_set_transition_epochs(
+                &jito_restaking_program::id(),
+                ncn,
+                &admin.pubkey(),
+                warmup_epochs,
+                cooldown_epochs,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_program(
+        &mut self,
+        ncn: &Pubkey,
+        admin: &Keypair,
+        ncn_program: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_program(
+                &jito_restaking_program::id(),
+                ncn,
+                &admin.pubkey(),
+                ncn_program,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_operator_bond(
+        &mut self,
+        ncn: &Pubkey,
+        operator_bond_mint: &Pubkey,
+        admin: &Keypair,
+        min_operator_bond: u64,
+        max_bond_slashable_per_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_operator_bond(
+                &jito_restaking_program::id(),
+                ncn,
+                operator_bond_mint,
+                &admin.pubkey(),
+                min_operator_bond,
+                max_bond_slashable_per_epoch,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_withdraw_bond(
+        &mut self,
+        ncn: &Pubkey,
+        operator_root: &OperatorRoot,
+        operator_bond: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_withdraw_bond(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &operator_root.operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    &operator_root.operator_pubkey,
+                )
+                .0,
+                operator_bond,
+                destination,
+                &operator_root.operator_admin.pubkey(),
+                &spl_token::id(),
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn slash_operator_bond(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Keypair,
+        operator_bond: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[slash_operator_bond(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                operator,
+                vault,
+                &slasher.pubkey(),
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    vault,
+                    &slasher.pubkey(),
+                )
+                .0,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    operator,
+                )
+                .0,
+                operator_bond,
+                destination,
+                &spl_token::id(),
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[slasher, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Warms up the operator through the NCN's program, with its signer PDA as the operator admin
+    pub async fn ncn_program_warmup_operator(
+        &mut self,
+        ncn: &Pubkey,
+        ncn_program_signer: &Pubkey,
+        operator_pubkey: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_program_cpi(
+                ncn,
+                ncn_warmup_operator(
+                    &jito_restaking_program::id(),
+                    &Config::find_program_address(&jito_restaking_program::id()).0,
+                    ncn,
+                    operator_pubkey,
+                    &NcnOperatorState::find_program_address(
+                        &jito_restaking_program::id(),
+                        ncn,
+                        operator_pubkey,
+                    )
+                    .0,
+                    ncn_program_signer,
+                    None,
+                ),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn ncn_warmup_operator(
         &mut self,
         config: &Pubkey,
@@ -491,6 +812,7 @@ impl RestakingProgramClient {
         operator_pubkey: &Pubkey,
         ncn_operator_state: &Pubkey,
         admin: &Keypair,
+        operator_bond: Option<&Pubkey>,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -502,6 +824,7 @@ impl RestakingProgramClient {
                 operator_pubkey,
                 ncn_operator_state,
                 &admin.pubkey(),
+                operator_bond,
             )],
             Some(&self.payer.pubkey()),
             &[admin, &self.payer],
@@ -526,6 +849,7 @@ impl RestakingProgramClient {
             )
             .0,
             &operator_root.operator_admin,
+            None,
         )
         .await
     }
@@ -537,6 +861,7 @@ impl RestakingProgramClient {
         operator_pubkey: &Pubkey,
         ncn_operator_state: &Pubkey,
         admin: &Keypair,
+        operator_bond: Option<&Pubkey>,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -548,6 +873,7 @@ impl RestakingProgramClient {
                 operator_pubkey,
                 ncn_operator_state,
                 &admin.pubkey(),
+                operator_bond,
             )],
             Some(&self.payer.pubkey()),
             &[admin, &self.payer],
@@ -722,6 +1048,374 @@ impl RestakingProgramClient {
         .await
     }
 
+    pub async fn ncn_set_max_slashable_per_epoch(
+        &mut self,
+        ncn: &Pubkey,
+        vault: &Pubkey,
+        slasher: &Pubkey,
+        admin: &Keypair,
+        max_slashable_per_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::ncn_set_max_slashable_per_epoch(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                vault,
+                slasher,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    vault,
+                    slasher,
+                )
+                .0,
+                &admin.pubkey(),
+                &VaultConfig::find_program_address(&jito_vault_program::id()).0,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    ncn,
+                    slasher,
+                )
+                .0,
+                &jito_vault_program::id(),
+                max_slashable_per_epoch,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Returns the account the config's creation policy requires from `creator` when creating
+    /// NCNs and operators
+    pub async fn creation_policy_account(
+        &mut self,
+        creator: &Pubkey,
+    ) -> TestResult<Option<Pubkey>> {
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        // tests exercising a missing config don't need a creation policy account
+        if self
+            .banks_client
+            .get_account(config_pubkey)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+        let config = self.get_config(&config_pubkey).await?;
+        let creation_policy_account = match config.creation_policy().unwrap() {
+            CreationPolicy::Open => None,
+            CreationPolicy::Allowlist => Some(
+                CreatorAllowlistEntry::find_program_address(&jito_restaking_program::id(), creator)
+                    .0,
+            ),
+            CreationPolicy::Fee => Some(config.creation_fee_treasury),
+        };
+        Ok(creation_policy_account)
+    }
+
+    pub async fn set_creation_policy(
+        &mut self,
+        config_admin: &Keypair,
+        creation_fee_treasury: &Pubkey,
+        creation_policy: CreationPolicy,
+        creation_fee_lamports: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::set_creation_policy(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &config_admin.pubkey(),
+                creation_fee_treasury,
+                creation_policy,
+                creation_fee_lamports,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initialize_creator_allowlist_entry(
+        &mut self,
+        config_admin: &Keypair,
+        creator: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::initialize_creator_allowlist_entry(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &CreatorAllowlistEntry::find_program_address(
+                    &jito_restaking_program::id(),
+                    creator,
+                )
+                .0,
+                creator,
+                &config_admin.pubkey(),
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn close_creator_allowlist_entry(
+        &mut self,
+        config_admin: &Keypair,
+        creator: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::close_creator_allowlist_entry(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &CreatorAllowlistEntry::find_program_address(
+                    &jito_restaking_program::id(),
+                    creator,
+                )
+                .0,
+                creator,
+                &config_admin.pubkey(),
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn initialize_ncn_metadata(
+        &mut self,
+        ncn: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::initialize_ncn_metadata(
+                &jito_restaking_program::id(),
+                ncn,
+                &NcnMetadata::find_program_address(&jito_restaking_program::id(), ncn).0,
+                &metadata_admin.pubkey(),
+                &self.payer.pubkey(),
+                name.to_string(),
+                website.to_string(),
+                logo_uri.to_string(),
+                contact.to_string(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[metadata_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_ncn_metadata(
+        &mut self,
+        ncn: &Pubkey,
+        metadata_admin: &Keypair,
+        name: Option<&str>,
+        website: Option<&str>,
+        logo_uri: Option<&str>,
+        contact: Option<&str>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::update_ncn_metadata(
+                &jito_restaking_program::id(),
+                ncn,
+                &NcnMetadata::find_program_address(&jito_restaking_program::id(), ncn).0,
+                &metadata_admin.pubkey(),
+                name.map(String::from),
+                website.map(String::from),
+                logo_uri.map(String::from),
+                contact.map(String::from),
+            )],
+            Some(&metadata_admin.pubkey()),
+            &[metadata_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn initialize_operator_metadata(
+        &mut self,
+        operator: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::initialize_operator_metadata(
+                &jito_restaking_program::id(),
+                operator,
+                &OperatorMetadata::find_program_address(&jito_restaking_program::id(), operator).0,
+                &metadata_admin.pubkey(),
+                &self.payer.pubkey(),
+                name.to_string(),
+                website.to_string(),
+                logo_uri.to_string(),
+                contact.to_string(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[metadata_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_operator_metadata(
+        &mut self,
+        operator: &Pubkey,
+        metadata_admin: &Keypair,
+        name: Option<&str>,
+        website: Option<&str>,
+        logo_uri: Option<&str>,
+        contact: Option<&str>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::update_operator_metadata(
+                &jito_restaking_program::id(),
+                operator,
+                &OperatorMetadata::find_program_address(&jito_restaking_program::id(), operator).0,
+                &metadata_admin.pubkey(),
+                name.map(String::from),
+                website.map(String::from),
+                logo_uri.map(String::from),
+                contact.map(String::from),
+            )],
+            Some(&metadata_admin.pubkey()),
+            &[metadata_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initialize_epoch_snapshot(&mut self, ncn: &Pubkey, epoch: u64) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::initialize_epoch_snapshot(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &EpochSnapshot::find_program_address(&jito_restaking_program::id(), ncn, epoch).0,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initialize_operator_snapshot(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::initialize_operator_snapshot(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                operator,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    operator,
+                )
+                .0,
+                &EpochSnapshot::find_program_address(&jito_restaking_program::id(), ncn, epoch).0,
+                &OperatorSnapshot::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    operator,
+                    epoch,
+                )
+                .0,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn snapshot_vault_operator_delegation(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_restaking_sdk::sdk::snapshot_vault_operator_delegation(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &VaultConfig::find_program_address(&jito_vault_program::id()).0,
+                ncn,
+                operator,
+                vault,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    operator,
+                )
+                .0,
+                &NcnVaultTicket::find_program_address(&jito_restaking_program::id(), ncn, vault).0,
+                &OperatorVaultTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    operator,
+                    vault,
+                )
+                .0,
+                &VaultNcnTicket::find_program_address(&jito_vault_program::id(), vault, ncn).0,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    operator,
+                )
+                .0,
+                &EpochSnapshot::find_program_address(&jito_restaking_program::id(), ncn, epoch).0,
+                &OperatorSnapshot::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    operator,
+                    epoch,
+                )
+                .0,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn initialize_ncn(
         &mut self,
         config: &Pubkey,
@@ -729,6 +1423,7 @@ impl RestakingProgramClient {
         ncn_admin: &Keypair,
         ncn_base: &Keypair,
     ) -> TestResult<()> {
+        let creation_policy_account = self.creation_policy_account(&ncn_admin.pubkey()).await?;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -738,6 +1433,7 @@ impl RestakingProgramClient {
                 ncn,
                 &ncn_admin.pubkey(),
                 &ncn_base.pubkey(),
+                creation_policy_account.as_ref(),
             )],
             Some(&ncn_admin.pubkey()),
             &[&ncn_admin, &ncn_base],
@@ -872,6 +1568,7 @@ impl RestakingProgramClient {
                 operator,
                 &old_admin.pubkey(),
                 &new_admin.pubkey(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
             )],
             Some(&old_admin.pubkey()),
             &[old_admin, new_admin],
@@ -895,6 +1592,7 @@ impl RestakingProgramClient {
                 operator,
                 &old_admin.pubkey(),
                 &new_admin.pubkey(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
                 operator_admin_role,
             )],
             Some(&old_admin.pubkey()),
@@ -912,6 +1610,7 @@ impl RestakingProgramClient {
         base: &Keypair,
         operator_fee_bps: u16,
     ) -> TestResult<()> {
+        let creation_policy_account = self.creation_policy_account(&admin.pubkey()).await?;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self.process_transaction(&Transaction::new_signed_with_payer(
@@ -921,6 +1620,7 @@ impl RestakingProgramClient {
                 operator,
                 &admin.pubkey(),
                 &base.pubkey(),
+                creation_policy_account.as_ref(),
                 operator_fee_bps,
             )],
             Some(&admin.pubkey()),
@@ -1082,6 +1782,21 @@ impl RestakingProgramClient {
         ))
         .await
     }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[migrate_account(
+                &jito_restaking_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
 }
 
 #[track_caller]
@@ -2,22 +2,25 @@ use std::{fmt, fmt::Debug};
 
 use borsh::BorshDeserialize;
 use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::creation_policy::CreatorAllowlistEntryAccount;
 use jito_restaking_core::{
     ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
     operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_vault_core::{
-    burn_vault::BurnVault, config::Config, vault::Vault,
+    burn_vault::BurnVault, config::Config, creator_allowlist_entry::CreatorAllowlistEntry,
+    vault::Vault, vault_deposit_receipt::VaultDepositReceipt, vault_fee_split::VaultFeeSplit,
     vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
     vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
-    vault_operator_delegation::VaultOperatorDelegation,
+    vault_operator_delegation::VaultOperatorDelegation, vault_referral::VaultReferral,
     vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
     vault_update_state_tracker::VaultUpdateStateTracker,
+    vault_withdrawal_claim_mint::VaultWithdrawalClaimMint,
 };
 use jito_vault_sdk::{
     error::VaultError,
     inline_mpl_token_metadata,
-    instruction::{VaultAdminRole, WithdrawalAllocationMethod},
+    instruction::{CreationPolicy, VaultAdminRole, WithdrawalAllocationMethod},
     sdk::{
         add_delegation, cooldown_delegation, cooldown_vault_ncn_ticket, initialize_config,
         initialize_vault, set_deposit_capacity, warmup_vault_ncn_slasher_ticket,
@@ -156,6 +159,44 @@ impl VaultProgramClient {
         Ok(withdrawal_ticket)
     }
 
+    pub async fn get_vault_deposit_receipt(
+        &mut self,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+    ) -> Result<VaultDepositReceipt, TestError> {
+        let account =
+            VaultDepositReceipt::find_program_address(&jito_vault_program::id(), vault, depositor)
+                .0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*VaultDepositReceipt::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_vault_fee_split(
+        &mut self,
+        vault: &Pubkey,
+    ) -> Result<VaultFeeSplit, TestError> {
+        let account = VaultFeeSplit::find_program_address(&jito_vault_program::id(), vault).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*VaultFeeSplit::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_vault_referral(
+        &mut self,
+        vault: &Pubkey,
+        referrer: &Pubkey,
+    ) -> Result<VaultReferral, TestError> {
+        let account =
+            VaultReferral::find_program_address(&jito_vault_program::id(), vault, referrer).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*VaultReferral::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
     pub async fn get_vault_ncn_slasher_ticket(
         &mut self,
         vault: &Pubkey,
@@ -208,9 +249,7 @@ impl VaultProgramClient {
             VaultUpdateStateTracker::find_program_address(&jito_vault_program::id(), vault, epoch)
                 .0;
         let account = self.banks_client.get_account(account).await?.unwrap();
-        Ok(*VaultUpdateStateTracker::try_from_slice_unchecked(
-            account.data.as_slice(),
-        )?)
+        Ok(*VaultUpdateStateTracker::try_from_slice_with_bitmap(account.data.as_slice())?.0)
     }
 
     pub async fn get_token_metadata(
@@ -293,6 +332,90 @@ impl VaultProgramClient {
         reward_fee_bps: u16,
         decimals: u8,
         program_fee_wallet: &Pubkey,
+    ) -> Result<VaultRoot, TestError> {
+        self.do_initialize_vault_with_admin(
+            Keypair::new(),
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+            decimals,
+            program_fee_wallet,
+        )
+        .await
+    }
+
+    pub async fn do_initialize_vault_with_admin(
+        &mut self,
+        vault_admin: Keypair,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        decimals: u8,
+        program_fee_wallet: &Pubkey,
+    ) -> Result<VaultRoot, TestError> {
+        let st_mint = Keypair::new();
+
+        self.airdrop(&vault_admin.pubkey(), 100.0).await?;
+        self.create_token_mint(&st_mint, &spl_token::id()).await?;
+        self.create_ata(&st_mint.pubkey(), &vault_admin.pubkey())
+            .await?;
+        self.mint_spl_to(
+            &st_mint.pubkey(),
+            &vault_admin.pubkey(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT,
+        )
+        .await?;
+
+        self.initialize_vault_with_funded_admin(
+            vault_admin,
+            &st_mint.pubkey(),
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+            decimals,
+            program_fee_wallet,
+        )
+        .await
+    }
+
+    /// Initializes a vault whose supported mint is wSOL
+    pub async fn do_initialize_native_vault(
+        &mut self,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        program_fee_wallet: &Pubkey,
+    ) -> Result<VaultRoot, TestError> {
+        let vault_admin = Keypair::new();
+
+        self.airdrop(&vault_admin.pubkey(), 100.0).await?;
+        self.wrap_sol(&vault_admin, Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT)
+            .await?;
+
+        self.initialize_vault_with_funded_admin(
+            vault_admin,
+            &spl_token::native_mint::id(),
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            reward_fee_bps,
+            9,
+            program_fee_wallet,
+        )
+        .await
+    }
+
+    /// Initializes a vault for `st_mint`, the admin shall hold the initialization amount in their
+    /// `st_mint` ATA
+    #[allow(clippy::too_many_arguments)]
+    async fn initialize_vault_with_funded_admin(
+        &mut self,
+        vault_admin: Keypair,
+        st_mint: &Pubkey,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        decimals: u8,
+        program_fee_wallet: &Pubkey,
     ) -> Result<VaultRoot, TestError> {
         let vault_base = Keypair::new();
 
@@ -302,15 +425,9 @@ impl VaultProgramClient {
             Vault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
 
         let vrt_mint = Keypair::new();
-        let vault_admin = Keypair::new();
-        let st_mint = Keypair::new();
 
-        self.airdrop(&vault_admin.pubkey(), 100.0).await?;
-        self.create_token_mint(&st_mint, &spl_token::id()).await?;
-
-        let admin_st_token_account =
-            get_associated_token_address(&vault_admin.pubkey(), &st_mint.pubkey());
-        let vault_st_token_account = get_associated_token_address(&vault_pubkey, &st_mint.pubkey());
+        let admin_st_token_account = get_associated_token_address(&vault_admin.pubkey(), st_mint);
+        let vault_st_token_account = get_associated_token_address(&vault_pubkey, st_mint);
 
         let burn_vault =
             BurnVault::find_program_address(&jito_vault_program::id(), &vault_base.pubkey()).0;
@@ -319,22 +436,13 @@ impl VaultProgramClient {
             get_associated_token_address(&burn_vault, &vrt_mint.pubkey());
 
         // Needs to be created before initialize vault
-        self.create_ata(&st_mint.pubkey(), &vault_pubkey).await?;
-        self.create_ata(&st_mint.pubkey(), &vault_admin.pubkey())
-            .await?;
-
-        self.mint_spl_to(
-            &st_mint.pubkey(),
-            &vault_admin.pubkey(),
-            initialize_token_amount,
-        )
-        .await?;
+        self.create_ata(st_mint, &vault_pubkey).await?;
 
         self.initialize_vault(
             &Config::find_program_address(&jito_vault_program::id()).0,
             &vault_pubkey,
             &vrt_mint,
-            &st_mint,
+            st_mint,
             &admin_st_token_account,
             &vault_st_token_account,
             &burn_vault,
@@ -687,6 +795,43 @@ impl VaultProgramClient {
         .await
     }
 
+    pub async fn update_vault_ncn_slasher_ticket(
+        &mut self,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+        slasher: &Pubkey,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::update_vault_ncn_slasher_ticket(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                ncn,
+                slasher,
+                &NcnVaultSlasherTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    vault,
+                    slasher,
+                )
+                .0,
+                &VaultNcnSlasherTicket::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    ncn,
+                    slasher,
+                )
+                .0,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_add_delegation(
         &mut self,
         vault_root: &VaultRoot,
@@ -711,12 +856,105 @@ impl VaultProgramClient {
         Ok(())
     }
 
+    /// Returns the account the config's creation policy requires from `creator` when creating
+    /// vaults
+    pub async fn creation_policy_account(
+        &mut self,
+        creator: &Pubkey,
+    ) -> TestResult<Option<Pubkey>> {
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        // tests exercising a missing config don't need a creation policy account
+        if self
+            .banks_client
+            .get_account(config_pubkey)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+        let config = self.get_config(&config_pubkey).await?;
+        let creation_policy_account = match config.creation_policy().unwrap() {
+            CreationPolicy::Open => None,
+            CreationPolicy::Allowlist => Some(
+                CreatorAllowlistEntry::find_program_address(&jito_vault_program::id(), creator).0,
+            ),
+            CreationPolicy::Fee => Some(config.creation_fee_treasury),
+        };
+        Ok(creation_policy_account)
+    }
+
+    pub async fn set_creation_policy(
+        &mut self,
+        config_admin: &Keypair,
+        creation_fee_treasury: &Pubkey,
+        creation_policy: CreationPolicy,
+        creation_fee_lamports: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_creation_policy(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &config_admin.pubkey(),
+                creation_fee_treasury,
+                creation_policy,
+                creation_fee_lamports,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initialize_creator_allowlist_entry(
+        &mut self,
+        config_admin: &Keypair,
+        creator: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::initialize_creator_allowlist_entry(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &CreatorAllowlistEntry::find_program_address(&jito_vault_program::id(), creator).0,
+                creator,
+                &config_admin.pubkey(),
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn close_creator_allowlist_entry(
+        &mut self,
+        config_admin: &Keypair,
+        creator: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::close_creator_allowlist_entry(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &CreatorAllowlistEntry::find_program_address(&jito_vault_program::id(), creator).0,
+                creator,
+                &config_admin.pubkey(),
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn initialize_vault(
         &mut self,
         config: &Pubkey,
         vault: &Pubkey,
         vrt_mint: &Keypair,
-        st_mint: &Keypair,
+        st_mint: &Pubkey,
         admin_st_token_account: &Pubkey,
         vault_st_token_account: &Pubkey,
         burn_vault: &Pubkey,
@@ -729,6 +967,7 @@ impl VaultProgramClient {
         decimals: u8,
         initialize_token_amount: u64,
     ) -> Result<(), TestError> {
+        let creation_policy_account = self.creation_policy_account(&vault_admin.pubkey()).await?;
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
         self._process_transaction(&Transaction::new_signed_with_payer(
@@ -737,13 +976,14 @@ impl VaultProgramClient {
                 config,
                 vault,
                 &vrt_mint.pubkey(),
-                &st_mint.pubkey(),
+                st_mint,
                 admin_st_token_account,
                 vault_st_token_account,
                 burn_vault,
                 burn_vault_vrt_token_account,
                 &vault_admin.pubkey(),
                 &vault_base.pubkey(),
+                creation_policy_account.as_ref(),
                 deposit_fee_bps,
                 withdrawal_fee_bps,
                 reward_fee_bps,
@@ -940,6 +1180,28 @@ impl VaultProgramClient {
         .await
     }
 
+    pub async fn set_program_fee_override(
+        &mut self,
+        vault: &Pubkey,
+        config_admin: &Keypair,
+        program_fee_bps: Option<u16>,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_program_fee_override(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &config_admin.pubkey(),
+                program_fee_bps,
+            )],
+            Some(&config_admin.pubkey()),
+            &[config_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_enqueue_withdrawal(
         &mut self,
         vault_root: &VaultRoot,
@@ -1112,6 +1374,47 @@ impl VaultProgramClient {
         Ok(())
     }
 
+    pub async fn do_update_vault_full(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        operators: &[Pubkey],
+    ) -> TestResult<()> {
+        let vault_operator_delegations: Vec<Pubkey> = operators
+            .iter()
+            .map(|operator| {
+                VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    vault_pubkey,
+                    operator,
+                )
+                .0
+            })
+            .collect();
+        self.update_vault_full(vault_pubkey, &vault_operator_delegations)
+            .await
+    }
+
+    pub async fn update_vault_full(
+        &mut self,
+        vault_pubkey: &Pubkey,
+        vault_operator_delegations: &[Pubkey],
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::update_vault_full(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault_pubkey,
+                vault_operator_delegations,
+                WithdrawalAllocationMethod::Greedy,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn do_crank_vault_update_state_tracker(
         &mut self,
         vault: &Pubkey,
@@ -1269,6 +1572,14 @@ impl VaultProgramClient {
                 staker_vrt_token_account,
                 &base.pubkey(),
                 mint_burn_admin.map(|s| s.pubkey()).as_ref(),
+                Some(
+                    &VaultDepositReceipt::find_program_address(
+                        &jito_vault_program::id(),
+                        vault,
+                        &staker.pubkey(),
+                    )
+                    .0,
+                ),
                 amount,
             )],
             Some(&staker.pubkey()),
@@ -1312,22 +1623,61 @@ impl VaultProgramClient {
         Ok(())
     }
 
-    pub async fn burn_withdrawal_ticket(
+    /// Burns a withdrawal ticket of a wSOL vault, see
+    /// [`jito_vault_sdk::sdk::burn_withdrawal_ticket_native`]
+    pub async fn do_burn_withdrawal_ticket_native(
         &mut self,
-        config: &Pubkey,
-        vault: &Pubkey,
-        vault_token_account: &Pubkey,
-        vrt_mint: &Pubkey,
-        staker: &Pubkey,
-        staker_token_account: &Pubkey,
-        vault_staker_withdrawal_ticket: &Pubkey,
-        vault_staker_withdrawal_ticket_token_account: &Pubkey,
-        vault_fee_token_account: &Pubkey,
-        program_fee_vrt_token_account: &Pubkey,
-        mint_burn_admin: Option<&Keypair>,
-    ) -> Result<(), TestError> {
-        let blockhash = self.banks_client.get_latest_blockhash().await?;
-        let mut signers = vec![&self.payer];
+        vault_root: &VaultRoot,
+        staker: &Keypair,
+        vault_staker_withdrawal_ticket_base: &Pubkey,
+        program_fee_wallet: &Pubkey,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            vault_staker_withdrawal_ticket_base,
+        )
+        .0;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &jito_vault_sdk::sdk::burn_withdrawal_ticket_native(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &vault.vrt_mint,
+                &staker.pubkey(),
+                &vault_staker_withdrawal_ticket,
+                &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                &get_associated_token_address(program_fee_wallet, &vault.vrt_mint),
+                None,
+            ),
+            Some(&staker.pubkey()),
+            &[staker],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn burn_withdrawal_ticket(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        vault_token_account: &Pubkey,
+        vrt_mint: &Pubkey,
+        staker: &Pubkey,
+        staker_token_account: &Pubkey,
+        vault_staker_withdrawal_ticket: &Pubkey,
+        vault_staker_withdrawal_ticket_token_account: &Pubkey,
+        vault_fee_token_account: &Pubkey,
+        program_fee_vrt_token_account: &Pubkey,
+        mint_burn_admin: Option<&Keypair>,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![&self.payer];
 
         if let Some(admin) = mint_burn_admin {
             signers.push(admin);
@@ -1355,6 +1705,135 @@ impl VaultProgramClient {
         .await
     }
 
+    /// Enqueues a tokenized withdrawal, returning the epoch of the claim mint
+    pub async fn do_enqueue_withdrawal_claim(
+        &mut self,
+        vault_root: &VaultRoot,
+        staker: &Keypair,
+        mint_burn_admin: Option<&Keypair>,
+        amount: u64,
+    ) -> Result<u64, TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+        let slot = self.banks_client.get_sysvar::<Clock>().await?.slot;
+        let epoch = config.get_epoch_from_slot(slot).unwrap();
+
+        let vault_withdrawal_claim_mint = VaultWithdrawalClaimMint::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            epoch,
+        )
+        .0;
+
+        self.enqueue_withdrawal_claim(
+            &vault_root.vault_pubkey,
+            &vault.vrt_mint,
+            &vault_withdrawal_claim_mint,
+            staker,
+            mint_burn_admin,
+            amount,
+        )
+        .await?;
+
+        Ok(epoch)
+    }
+
+    pub async fn enqueue_withdrawal_claim(
+        &mut self,
+        vault: &Pubkey,
+        vrt_mint: &Pubkey,
+        vault_withdrawal_claim_mint: &Pubkey,
+        staker: &Keypair,
+        mint_burn_admin: Option<&Keypair>,
+        amount: u64,
+    ) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![staker];
+        if let Some(admin) = mint_burn_admin {
+            signers.push(admin);
+        };
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::enqueue_withdrawal_claim(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                vrt_mint,
+                vault_withdrawal_claim_mint,
+                &get_associated_token_address(vault_withdrawal_claim_mint, vrt_mint),
+                &staker.pubkey(),
+                &get_associated_token_address(&staker.pubkey(), vrt_mint),
+                &get_associated_token_address(&staker.pubkey(), vault_withdrawal_claim_mint),
+                mint_burn_admin.map(|s| s.pubkey()).as_ref(),
+                Some(
+                    &VaultDepositReceipt::find_program_address(
+                        &jito_vault_program::id(),
+                        vault,
+                        &staker.pubkey(),
+                    )
+                    .0,
+                ),
+                amount,
+            )],
+            Some(&staker.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_burn_withdrawal_claim(
+        &mut self,
+        vault_root: &VaultRoot,
+        claimant: &Keypair,
+        epoch: u64,
+        amount: u64,
+        mint_burn_admin: Option<&Keypair>,
+    ) -> Result<(), TestError> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let config = self
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await?;
+        let vault_withdrawal_claim_mint = VaultWithdrawalClaimMint::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            epoch,
+        )
+        .0;
+
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![claimant];
+        if let Some(admin) = mint_burn_admin {
+            signers.push(admin);
+        };
+
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::burn_withdrawal_claim(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &vault.vrt_mint,
+                &vault_withdrawal_claim_mint,
+                &get_associated_token_address(&vault_withdrawal_claim_mint, &vault.vrt_mint),
+                &claimant.pubkey(),
+                &get_associated_token_address(&claimant.pubkey(), &vault_withdrawal_claim_mint),
+                &get_associated_token_address(&claimant.pubkey(), &vault.supported_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                &get_associated_token_address(&config.program_fee_wallet, &vault.vrt_mint),
+                mint_burn_admin.map(|s| s.pubkey()).as_ref(),
+                epoch,
+                amount,
+            )],
+            Some(&claimant.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn add_delegation(
         &mut self,
         config: &Pubkey,
@@ -1435,6 +1914,145 @@ impl VaultProgramClient {
                 depositor_vrt_token_account,
                 vault_fee_token_account,
                 mint_signer.map(|s| s.pubkey()).as_ref(),
+                Some(
+                    &VaultDepositReceipt::find_program_address(
+                        &jito_vault_program::id(),
+                        vault,
+                        &depositor.pubkey(),
+                    )
+                    .0,
+                ),
+                None,
+                None,
+                None,
+                None,
+                amount_in,
+                min_amount_out,
+            )],
+            Some(&depositor.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Mints VRT to the depositor without passing any of the optional accounts
+    pub async fn do_mint_to_without_deposit_receipt(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::mint_to(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault.vrt_mint,
+                &depositor.pubkey(),
+                &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                amount_in,
+                min_amount_out,
+            )],
+            Some(&depositor.pubkey()),
+            &[depositor],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Deposits lamports into a wSOL vault, see [`jito_vault_sdk::sdk::mint_to_native`]
+    pub async fn do_mint_to_native(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &jito_vault_sdk::sdk::mint_to_native(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault.vrt_mint,
+                &depositor.pubkey(),
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                None,
+                Some(
+                    &VaultDepositReceipt::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        &depositor.pubkey(),
+                    )
+                    .0,
+                ),
+                amount_in,
+                min_amount_out,
+            ),
+            Some(&depositor.pubkey()),
+            &[depositor],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Mints VRT to `beneficiary`, debiting the depositor's supported tokens. The beneficiary's
+    /// signature is only needed to restart their holding period.
+    pub async fn do_mint_to_beneficiary(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        beneficiary: &Pubkey,
+        beneficiary_signer: Option<&Keypair>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![depositor];
+        if let Some(signer) = beneficiary_signer {
+            signers.push(signer);
+        }
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::mint_to(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault.vrt_mint,
+                &depositor.pubkey(),
+                &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &get_associated_token_address(beneficiary, &vault.vrt_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                None,
+                Some(
+                    &VaultDepositReceipt::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        beneficiary,
+                    )
+                    .0,
+                ),
+                None,
+                None,
+                None,
+                beneficiary_signer.map(|signer| signer.pubkey()).as_ref(),
                 amount_in,
                 min_amount_out,
             )],
@@ -1445,6 +2063,81 @@ impl VaultProgramClient {
         .await
     }
 
+    /// Mints VRT to the depositor, attributing the deposit to `referrer`
+    pub async fn do_mint_to_with_referrer(
+        &mut self,
+        vault_root: &VaultRoot,
+        depositor: &Keypair,
+        referrer: &Pubkey,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let referrer_vrt_token_account = get_associated_token_address(referrer, &vault.vrt_mint);
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::mint_to(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault.vrt_mint,
+                &depositor.pubkey(),
+                &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                None,
+                Some(
+                    &VaultDepositReceipt::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        &depositor.pubkey(),
+                    )
+                    .0,
+                ),
+                Some(
+                    &VaultReferral::find_program_address(
+                        &jito_vault_program::id(),
+                        &vault_root.vault_pubkey,
+                        referrer,
+                    )
+                    .0,
+                ),
+                Some(referrer),
+                Some(&referrer_vrt_token_account),
+                None,
+                amount_in,
+                min_amount_out,
+            )],
+            Some(&depositor.pubkey()),
+            &[depositor],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_referral_fee(
+        &mut self,
+        vault: &Pubkey,
+        fee_admin: &Keypair,
+        referral_fee_bps: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_referral_fee(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &fee_admin.pubkey(),
+                referral_fee_bps,
+            )],
+            Some(&fee_admin.pubkey()),
+            &[fee_admin],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn initialize_vault_ncn_slasher_ticket(
         &mut self,
         config: &Pubkey,
@@ -1723,6 +2416,29 @@ impl VaultProgramClient {
             .await
     }
 
+    /// Wraps `lamports` into the owner's wSOL ATA
+    pub async fn wrap_sol(&mut self, owner: &Keypair, lamports: u64) -> Result<(), TestError> {
+        let wsol_token_account =
+            get_associated_token_address(&owner.pubkey(), &spl_token::native_mint::id());
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[
+                create_associated_token_account_idempotent(
+                    &owner.pubkey(),
+                    &owner.pubkey(),
+                    &spl_token::native_mint::id(),
+                    &spl_token::id(),
+                ),
+                transfer(&owner.pubkey(), &wsol_token_account, lamports),
+                spl_token::instruction::sync_native(&spl_token::id(), &wsol_token_account).unwrap(),
+            ],
+            Some(&owner.pubkey()),
+            &[owner],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn get_reward_fee_token_account(
         &mut self,
         vault: &Pubkey,
@@ -1828,6 +2544,263 @@ impl VaultProgramClient {
         .await
     }
 
+    pub async fn set_max_operator_share(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        max_operator_share_bps: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_max_operator_share(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+                max_operator_share_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_operator_delegation_cap(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        admin: &Keypair,
+        max_delegation_amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_operator_delegation_cap(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    vault,
+                    operator,
+                )
+                .0,
+                &admin.pubkey(),
+                max_delegation_amount,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_ncn_allocation_cap(
+        &mut self,
+        vault: &Pubkey,
+        ncn: &Pubkey,
+        admin: &Keypair,
+        max_allocation_bps: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_ncn_allocation_cap(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                ncn,
+                &VaultNcnTicket::find_program_address(&jito_vault_program::id(), vault, ncn).0,
+                &admin.pubkey(),
+                max_allocation_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn wind_down_vault(&mut self, vault: &Pubkey, admin: &Keypair) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::wind_down_vault(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_min_holding_period(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        min_holding_epochs: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_min_holding_period(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+                min_holding_epochs,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn waive_holding_period(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::waive_holding_period(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &admin.pubkey(),
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn set_fee_split(
+        &mut self,
+        vault: &Pubkey,
+        admin: &Keypair,
+        recipients: &[Pubkey],
+        recipient_bps: [u16; 8],
+    ) -> TestResult<()> {
+        let vrt_mint = self.get_vault(vault).await?.vrt_mint;
+        let vault_fee_split =
+            VaultFeeSplit::find_program_address(&jito_vault_program::id(), vault).0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::set_fee_split(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &vrt_mint,
+                &vault_fee_split,
+                &get_associated_token_address(&vault_fee_split, &vrt_mint),
+                &admin.pubkey(),
+                recipients,
+                recipient_bps,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Distributes the fee split to the VRT associated token accounts of `recipients`
+    pub async fn distribute_fee_split(
+        &mut self,
+        vault: &Pubkey,
+        recipients: &[Pubkey],
+    ) -> TestResult<()> {
+        let vrt_mint = self.get_vault(vault).await?.vrt_mint;
+        let vault_fee_split =
+            VaultFeeSplit::find_program_address(&jito_vault_program::id(), vault).0;
+        let recipient_token_accounts: Vec<Pubkey> = recipients
+            .iter()
+            .map(|recipient| get_associated_token_address(recipient, &vrt_mint))
+            .collect();
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::distribute_fee_split(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                &vrt_mint,
+                &vault_fee_split,
+                &get_associated_token_address(&vault_fee_split, &vrt_mint),
+                &recipient_token_accounts,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn do_close_vault(
+        &mut self,
+        vault_root: &VaultRoot,
+        vault_update_state_trackers: &[Pubkey],
+    ) -> TestResult<()> {
+        let vault = self.get_vault(&vault_root.vault_pubkey).await?;
+        let burn_vault = BurnVault::find_program_address(&jito_vault_program::id(), &vault.base).0;
+        self.close_vault(
+            &vault_root.vault_pubkey,
+            &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+            &get_associated_token_address(&burn_vault, &vault.vrt_mint),
+            &vault_root.vault_admin,
+            &get_associated_token_address(&vault_root.vault_admin.pubkey(), &vault.supported_mint),
+            vault_update_state_trackers,
+        )
+        .await
+    }
+
+    pub async fn close_vault(
+        &mut self,
+        vault: &Pubkey,
+        vault_token_account: &Pubkey,
+        burn_vault_vrt_token_account: &Pubkey,
+        admin: &Keypair,
+        admin_token_account: &Pubkey,
+        vault_update_state_trackers: &[Pubkey],
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::close_vault(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                vault_token_account,
+                burn_vault_vrt_token_account,
+                &admin.pubkey(),
+                admin_token_account,
+                vault_update_state_trackers,
+            )],
+            Some(&admin.pubkey()),
+            &[admin],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::migrate_account(
+                &jito_vault_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn set_config_admin(
         &mut self,
         config: &Pubkey,
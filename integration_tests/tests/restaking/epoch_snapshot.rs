@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::{ConfiguredVault, TestBuilder},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a vault and NCN with two operators, delegates 60% and 40% of the vault to them,
+    /// then warps two epochs so every ticket is active and updates the vault.
+    /// Returns the current epoch.
+    async fn setup() -> (TestBuilder, ConfiguredVault, u64) {
+        let mut fixture = TestBuilder::new().await;
+        let mut configured_vault = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 2, &[])
+            .await
+            .unwrap();
+        let vault_root = &configured_vault.vault_root;
+        let operators: Vec<_> = configured_vault
+            .operator_roots
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+
+        let depositor = Keypair::new();
+        let vault_program_client = &mut configured_vault.vault_program_client;
+        vault_program_client
+            .configure_depositor(vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(vault_root, &operators[0], 60_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(vault_root, &operators[1], 40_000)
+            .await
+            .unwrap();
+
+        let config = configured_vault
+            .restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        configured_vault
+            .vault_program_client
+            .do_full_vault_update(&configured_vault.vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        (fixture, configured_vault, epoch)
+    }
+
+    #[tokio::test]
+    async fn test_epoch_snapshot_ok() {
+        let (_fixture, configured_vault, epoch) = setup().await;
+        let ConfiguredVault {
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = configured_vault;
+        let ncn = ncn_root.ncn_pubkey;
+        let vault = vault_root.vault_pubkey;
+
+        restaking_program_client
+            .initialize_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+        let epoch_snapshot = restaking_program_client
+            .get_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+        assert_eq!(epoch_snapshot.ncn, ncn);
+        assert_eq!(epoch_snapshot.epoch(), epoch);
+        assert_eq!(epoch_snapshot.operator_count(), 2);
+        assert_eq!(epoch_snapshot.vault_count(), 1);
+        assert!(!epoch_snapshot.is_complete());
+
+        for operator_root in operator_roots.iter() {
+            let operator = operator_root.operator_pubkey;
+            restaking_program_client
+                .initialize_operator_snapshot(&ncn, &operator, epoch)
+                .await
+                .unwrap();
+            let operator_snapshot = restaking_program_client
+                .get_operator_snapshot(&ncn, &operator, epoch)
+                .await
+                .unwrap();
+            assert!(operator_snapshot.is_active());
+            assert!(!operator_snapshot.is_complete());
+
+            restaking_program_client
+                .snapshot_vault_operator_delegation(&ncn, &operator, &vault, epoch)
+                .await
+                .unwrap();
+        }
+
+        let operator_snapshot = restaking_program_client
+            .get_operator_snapshot(&ncn, &operator_roots[0].operator_pubkey, epoch)
+            .await
+            .unwrap();
+        assert!(operator_snapshot.is_complete());
+        assert_eq!(operator_snapshot.vault_operator_stakes()[0].vault, vault);
+        assert_eq!(operator_snapshot.vault_operator_stakes()[0].stake(), 60_000);
+        let st_mint = operator_snapshot.vault_operator_stakes()[0].st_mint;
+        assert_eq!(operator_snapshot.stake_for_mint(&st_mint).unwrap(), 60_000);
+
+        let operator_snapshot = restaking_program_client
+            .get_operator_snapshot(&ncn, &operator_roots[1].operator_pubkey, epoch)
+            .await
+            .unwrap();
+        assert_eq!(operator_snapshot.vault_operator_stakes()[0].stake(), 40_000);
+
+        let epoch_snapshot = restaking_program_client
+            .get_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+        assert!(epoch_snapshot.is_complete());
+        assert_eq!(epoch_snapshot.active_operator_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_vault_operator_delegation_twice_fails() {
+        let (mut fixture, configured_vault, epoch) = setup().await;
+        let ConfiguredVault {
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = configured_vault;
+        let ncn = ncn_root.ncn_pubkey;
+        let operator = operator_roots[0].operator_pubkey;
+
+        restaking_program_client
+            .initialize_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+        restaking_program_client
+            .initialize_operator_snapshot(&ncn, &operator, epoch)
+            .await
+            .unwrap();
+        restaking_program_client
+            .snapshot_vault_operator_delegation(&ncn, &operator, &vault_root.vault_pubkey, epoch)
+            .await
+            .unwrap();
+
+        // get new blockhash
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let result = restaking_program_client
+            .snapshot_vault_operator_delegation(&ncn, &operator, &vault_root.vault_pubkey, epoch)
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::OperatorSnapshotVaultAlreadyRecorded as u32),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_operator_snapshot_operator_joined_after_snapshot_fails() {
+        let (_fixture, configured_vault, epoch) = setup().await;
+        let ConfiguredVault {
+            mut restaking_program_client,
+            ncn_root,
+            ..
+        } = configured_vault;
+        let ncn = ncn_root.ncn_pubkey;
+
+        restaking_program_client
+            .initialize_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .initialize_operator_snapshot(&ncn, &operator_root.operator_pubkey, epoch)
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::EpochSnapshotOperatorNotIncluded as u32),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_jailed_operator_excluded_from_snapshot() {
+        let (_fixture, configured_vault, epoch) = setup().await;
+        let ConfiguredVault {
+            mut restaking_program_client,
+            vault_root,
+            ncn_root,
+            operator_roots,
+            ..
+        } = configured_vault;
+        let ncn = ncn_root.ncn_pubkey;
+        let jailed_operator = operator_roots[0].operator_pubkey;
+        let operator = operator_roots[1].operator_pubkey;
+
+        restaking_program_client
+            .initialize_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+        restaking_program_client
+            .initialize_operator_snapshot(&ncn, &operator, epoch)
+            .await
+            .unwrap();
+
+        // jailed before its snapshot, the operator is inactive and complete on creation
+        restaking_program_client
+            .ncn_jail_operator(&ncn_root, &jailed_operator, 1, epoch + 1)
+            .await
+            .unwrap();
+        restaking_program_client
+            .initialize_operator_snapshot(&ncn, &jailed_operator, epoch)
+            .await
+            .unwrap();
+        let operator_snapshot = restaking_program_client
+            .get_operator_snapshot(&ncn, &jailed_operator, epoch)
+            .await
+            .unwrap();
+        assert!(!operator_snapshot.is_active());
+        assert!(operator_snapshot.is_complete());
+
+        // jailed after its snapshot was completed, the operator no longer counts any stake
+        let vault = vault_root.vault_pubkey;
+        restaking_program_client
+            .snapshot_vault_operator_delegation(&ncn, &operator, &vault, epoch)
+            .await
+            .unwrap();
+        let epoch_snapshot = restaking_program_client
+            .get_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+        assert!(epoch_snapshot.is_complete());
+        assert_eq!(epoch_snapshot.active_operator_count(), 1);
+
+        restaking_program_client
+            .ncn_jail_operator(&ncn_root, &operator, 1, epoch + 1)
+            .await
+            .unwrap();
+        restaking_program_client
+            .snapshot_vault_operator_delegation(&ncn, &operator, &vault, epoch)
+            .await
+            .unwrap();
+        let operator_snapshot = restaking_program_client
+            .get_operator_snapshot(&ncn, &operator, epoch)
+            .await
+            .unwrap();
+        assert!(!operator_snapshot.is_active());
+        assert!(operator_snapshot.is_complete());
+        assert!(operator_snapshot.vault_operator_stakes().is_empty());
+
+        let epoch_snapshot = restaking_program_client
+            .get_epoch_snapshot(&ncn, epoch)
+            .await
+            .unwrap();
+        assert!(epoch_snapshot.is_complete());
+        assert_eq!(epoch_snapshot.active_operator_count(), 0);
+    }
+}
@@ -64,7 +64,7 @@ mod tests {
         assert_eq!(ticket.ncn, ncn_root.ncn_pubkey);
         assert_eq!(ticket.vault, vault_root.vault_pubkey);
         assert_eq!(ticket.slasher, slasher.pubkey());
-        assert_eq!(ticket.max_slashable_per_epoch(), 100);
+        assert_eq!(ticket.stored_max_slashable_per_epoch(), 100);
         assert_eq!(ticket.index(), 0);
         let slot = fixture.get_current_slot().await.unwrap();
         let config = restaking_program_client
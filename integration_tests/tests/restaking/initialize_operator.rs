@@ -174,7 +174,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_initialize_operator_with_fee_ok() {
-        let fixture = TestBuilder::new().await;
+        let mut fixture = TestBuilder::new().await;
         let mut restaking_program_client = fixture.restaking_program_client();
 
         restaking_program_client
@@ -204,8 +204,15 @@ mod tests {
             .get_operator(&operator)
             .await
             .unwrap();
+        let config_account = restaking_program_client.get_config(&config).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
 
-        assert_eq!(operator_account.operator_fee_bps, fee_bps.into());
+        assert_eq!(
+            operator_account
+                .current_operator_fee_bps(slot, config_account.epoch_length())
+                .unwrap(),
+            fee_bps
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::InstructionError;
+
+    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+
+    #[tokio::test]
+    async fn test_migrate_account_at_current_layout_is_noop() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let ncn_before = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        let operator_before = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .migrate_account(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .migrate_account(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let ncn_after = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        let operator_after = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_before, ncn_after);
+        assert_eq!(operator_before, operator_after);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_newer_layout_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        fixture
+            .set_layout_version(&ncn_root.ncn_pubkey, 1)
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .migrate_account(&ncn_root.ncn_pubkey)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+    }
+}
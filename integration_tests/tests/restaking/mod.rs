@@ -1,3 +1,4 @@
+mod epoch_snapshot;
 mod initialize_config;
 mod initialize_ncn;
 mod initialize_ncn_operator_state;
@@ -5,14 +6,25 @@ mod initialize_ncn_vault_slasher_ticket;
 mod initialize_ncn_vault_ticket;
 mod initialize_operator;
 mod initialize_operator_vault_ticket;
+mod migrate_account;
 mod ncn_cooldown_operator;
 mod ncn_delegate_token_account;
+mod ncn_jail_operator;
+mod ncn_metadata;
 mod ncn_set_admin;
+mod ncn_set_max_slashable_per_epoch;
+mod ncn_set_operator_bond;
+mod ncn_set_program;
+mod ncn_set_transition_epochs;
 mod ncn_warmup_operator;
 mod operator_cooldown_ncn;
 mod operator_delegate_token_account;
+mod operator_metadata;
 mod operator_set_admin;
 mod operator_set_fee;
 mod operator_set_secondary_admin;
 mod operator_warmup_ncn;
+mod operator_withdraw_bond;
 mod set_config_admin;
+mod set_creation_policy;
+mod slash_operator_bond;
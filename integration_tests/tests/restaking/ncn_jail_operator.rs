@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+    };
+
+    /// Sets up an NCN and an operator that opted in to each other, warped until both are active.
+    /// Returns the current epoch.
+    async fn setup() -> (
+        TestBuilder,
+        RestakingProgramClient,
+        NcnRoot,
+        OperatorRoot,
+        u64,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        (
+            fixture,
+            restaking_program_client,
+            ncn_root,
+            operator_root,
+            epoch,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_and_unjail_operator_ok() {
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+        let operator = operator_root.operator_pubkey;
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .ncn_jail_operator(&ncn_root, &operator, 7, epoch + 3)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator)
+            .await
+            .unwrap();
+        assert!(ncn_operator_state
+            .is_jailed(slot, config.epoch_length())
+            .unwrap());
+        assert!(!ncn_operator_state
+            .is_active(slot, config.epoch_length())
+            .unwrap());
+        assert_eq!(ncn_operator_state.jailed_until_epoch(), epoch + 3);
+        assert_eq!(ncn_operator_state.jail_reason_code(), 7);
+        assert_eq!(ncn_operator_state.last_jail_slot(), slot);
+        assert_eq!(ncn_operator_state.jail_count(), 1);
+        let jail_history = ncn_operator_state.jail_history();
+        assert_eq!(jail_history.len(), 1);
+        assert_eq!(jail_history[0].reason_code(), 7);
+        assert_eq!(jail_history[0].jail_slot(), slot);
+        assert_eq!(jail_history[0].until_epoch(), epoch + 3);
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .ncn_unjail_operator(&ncn_root, &operator)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator)
+            .await
+            .unwrap();
+        assert!(ncn_operator_state
+            .is_active(slot, config.epoch_length())
+            .unwrap());
+        assert_eq!(ncn_operator_state.last_unjail_slot(), slot);
+        assert_eq!(ncn_operator_state.jail_reason_code(), 7);
+        assert_eq!(ncn_operator_state.jail_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_operator_release_epoch_passes() {
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+        let operator = operator_root.operator_pubkey;
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .ncn_jail_operator(&ncn_root, &operator, 1, epoch + 1)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator)
+            .await
+            .unwrap();
+        assert!(ncn_operator_state
+            .is_active(slot, config.epoch_length())
+            .unwrap());
+
+        let result = restaking_program_client
+            .ncn_unjail_operator(&ncn_root, &operator)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorNotJailed);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_operator_release_epoch_not_in_future_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+
+        let result = restaking_program_client
+            .ncn_jail_operator(&ncn_root, &operator_root.operator_pubkey, 1, epoch)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorJailEpochInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_operator_bad_admin_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+
+        let bad_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .ncn_jail_operator(&bad_ncn_root, &operator_root.operator_pubkey, 1, epoch + 1)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+    }
+}
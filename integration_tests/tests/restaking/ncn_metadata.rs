@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::metadata::MAX_NAME_LEN;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::instruction::InstructionError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::TestBuilder,
+        restaking_client::{NcnRoot, RestakingProgramClient},
+    };
+
+    async fn setup() -> (TestBuilder, RestakingProgramClient, NcnRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        (fixture, restaking_program_client, ncn_root)
+    }
+
+    #[tokio::test]
+    async fn test_initialize_and_update_ncn_metadata_ok() {
+        let (_fixture, mut restaking_program_client, ncn_root) = setup().await;
+
+        restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito NCN",
+                "https://jito.network",
+                "https://jito.network/logo.png",
+                "ncn@jito.network",
+            )
+            .await
+            .unwrap();
+
+        let ncn_metadata = restaking_program_client
+            .get_ncn_metadata(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_metadata.ncn, ncn_root.ncn_pubkey);
+        assert_eq!(ncn_metadata.metadata.update_count(), 1);
+        assert_eq!(ncn_metadata.metadata.name(), "Jito NCN");
+        assert_eq!(ncn_metadata.metadata.website(), "https://jito.network");
+        assert_eq!(
+            ncn_metadata.metadata.logo_uri(),
+            "https://jito.network/logo.png"
+        );
+        assert_eq!(ncn_metadata.metadata.contact(), "ncn@jito.network");
+
+        restaking_program_client
+            .update_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                Some("Jito NCN ⚡"),
+                None,
+                None,
+                Some(""),
+            )
+            .await
+            .unwrap();
+
+        let ncn_metadata = restaking_program_client
+            .get_ncn_metadata(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_metadata.metadata.update_count(), 2);
+        assert_eq!(ncn_metadata.metadata.name(), "Jito NCN ⚡");
+        assert_eq!(ncn_metadata.metadata.website(), "https://jito.network");
+        assert_eq!(ncn_metadata.metadata.contact(), "");
+    }
+
+    #[tokio::test]
+    async fn test_ncn_metadata_bad_metadata_admin_fails() {
+        let (mut fixture, mut restaking_program_client, ncn_root) = setup().await;
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = restaking_program_client
+            .initialize_ncn_metadata(&ncn_root.ncn_pubkey, &bad_admin, "NCN", "", "", "")
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::NcnMetadataAdminInvalid as u32),
+        );
+
+        restaking_program_client
+            .initialize_ncn_metadata(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, "NCN", "", "", "")
+            .await
+            .unwrap();
+        let result = restaking_program_client
+            .update_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &bad_admin,
+                Some("Hijacked"),
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::NcnMetadataAdminInvalid as u32),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_metadata_field_too_long_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                &"a".repeat(MAX_NAME_LEN + 1),
+                "",
+                "",
+                "",
+            )
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::MetadataFieldInvalid as u32),
+        );
+    }
+}
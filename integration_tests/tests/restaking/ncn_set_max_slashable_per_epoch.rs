@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+    };
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    const MAX_SLASHABLE_PER_EPOCH: u64 = 1_000;
+
+    async fn setup(fixture: &TestBuilder) -> (NcnRoot, Pubkey, Pubkey) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let slasher = Keypair::new().pubkey();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher,
+                MAX_SLASHABLE_PER_EPOCH,
+            )
+            .await
+            .unwrap();
+
+        (ncn_root, vault_root.vault_pubkey, slasher)
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_max_slashable_per_epoch_decrease_is_immediate() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, vault, slasher) = setup(&fixture).await;
+
+        restaking_program_client
+            .ncn_set_max_slashable_per_epoch(
+                &ncn_root.ncn_pubkey,
+                &vault,
+                &slasher,
+                &ncn_root.ncn_admin,
+                MAX_SLASHABLE_PER_EPOCH / 2,
+            )
+            .await
+            .unwrap();
+
+        let ncn_vault_slasher_ticket = restaking_program_client
+            .get_ncn_vault_slasher_ticket(&ncn_root.ncn_pubkey, &vault, &slasher)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket.stored_max_slashable_per_epoch(),
+            MAX_SLASHABLE_PER_EPOCH / 2
+        );
+        assert_eq!(ncn_vault_slasher_ticket.next_max_slashable_epoch(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_max_slashable_per_epoch_increase_is_delayed() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, vault, slasher) = setup(&fixture).await;
+        let epoch_length = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap()
+            .epoch_length();
+
+        restaking_program_client
+            .ncn_set_max_slashable_per_epoch(
+                &ncn_root.ncn_pubkey,
+                &vault,
+                &slasher,
+                &ncn_root.ncn_admin,
+                MAX_SLASHABLE_PER_EPOCH * 2,
+            )
+            .await
+            .unwrap();
+
+        let ncn_vault_slasher_ticket = restaking_program_client
+            .get_ncn_vault_slasher_ticket(&ncn_root.ncn_pubkey, &vault, &slasher)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket.next_max_slashable_per_epoch(),
+            MAX_SLASHABLE_PER_EPOCH * 2
+        );
+        assert_eq!(
+            ncn_vault_slasher_ticket.next_max_slashable_epoch(),
+            slot / epoch_length + 2
+        );
+        assert_eq!(
+            ncn_vault_slasher_ticket
+                .current_max_slashable_per_epoch(slot, epoch_length)
+                .unwrap(),
+            MAX_SLASHABLE_PER_EPOCH
+        );
+
+        fixture
+            .warp_slot_incremental(2 * epoch_length)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket
+                .current_max_slashable_per_epoch(slot, epoch_length)
+                .unwrap(),
+            MAX_SLASHABLE_PER_EPOCH * 2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_max_slashable_per_epoch_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, vault, slasher) = setup(&fixture).await;
+
+        let result = restaking_program_client
+            .ncn_set_max_slashable_per_epoch(
+                &ncn_root.ncn_pubkey,
+                &vault,
+                &slasher,
+                &Keypair::new(),
+                MAX_SLASHABLE_PER_EPOCH / 2,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnSlasherAdminInvalid);
+    }
+}
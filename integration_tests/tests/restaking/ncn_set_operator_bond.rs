@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot},
+    };
+
+    const MIN_OPERATOR_BOND: u64 = 10_000;
+    const MAX_BOND_SLASHABLE_PER_EPOCH: u64 = 1_000;
+
+    /// Sets up an NCN requiring an operator bond and an operator without any bond yet
+    async fn setup(fixture: &mut TestBuilder) -> (NcnRoot, OperatorRoot, Pubkey, Pubkey) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let bond_mint = Keypair::new();
+        fixture
+            .vault_program_client()
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_operator_bond(
+                &ncn_root.ncn_pubkey,
+                &bond_mint.pubkey(),
+                &ncn_root.ncn_admin,
+                MIN_OPERATOR_BOND,
+                MAX_BOND_SLASHABLE_PER_EPOCH,
+            )
+            .await
+            .unwrap();
+
+        let ncn_operator_state = NcnOperatorState::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+        )
+        .0;
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        (
+            ncn_root,
+            operator_root,
+            bond_mint.pubkey(),
+            ncn_operator_state,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_operator_bond_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, _operator_root, bond_mint, _) = setup(&mut fixture).await;
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.operator_bond_mint, bond_mint);
+        assert_eq!(ncn.min_operator_bond(), MIN_OPERATOR_BOND);
+        assert_eq!(
+            ncn.max_bond_slashable_per_epoch(),
+            MAX_BOND_SLASHABLE_PER_EPOCH
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_operator_bond_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, _operator_root, bond_mint, _) = setup(&mut fixture).await;
+
+        let result = restaking_program_client
+            .ncn_set_operator_bond(&ncn_root.ncn_pubkey, &bond_mint, &Keypair::new(), 0, 0)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_without_operator_bond_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, _bond_mint, _) = setup(&mut fixture).await;
+
+        let result = restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondInsufficient);
+
+        let result = restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondInsufficient);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_with_insufficient_operator_bond_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, bond_mint, ncn_operator_state) = setup(&mut fixture).await;
+
+        fixture
+            .mint_spl_to(
+                &bond_mint,
+                &ncn_operator_state,
+                MIN_OPERATOR_BOND - 1,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        let operator_bond = get_associated_token_address(&ncn_operator_state, &bond_mint);
+
+        let result = restaking_program_client
+            .ncn_warmup_operator(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &ncn_operator_state,
+                &ncn_root.ncn_admin,
+                Some(&operator_bond),
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondInsufficient);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_with_operator_bond_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, bond_mint, ncn_operator_state) = setup(&mut fixture).await;
+
+        fixture
+            .mint_spl_to(
+                &bond_mint,
+                &ncn_operator_state,
+                MIN_OPERATOR_BOND,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        let operator_bond = get_associated_token_address(&ncn_operator_state, &bond_mint);
+        let config = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        restaking_program_client
+            .ncn_warmup_operator(
+                &config,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &ncn_operator_state,
+                &ncn_root.ncn_admin,
+                Some(&operator_bond),
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .operator_warmup_ncn(
+                &config,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &ncn_operator_state,
+                &operator_root.operator_admin,
+                Some(&operator_bond),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_warmup_with_operator_bond_of_wrong_mint_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, _bond_mint, ncn_operator_state) = setup(&mut fixture).await;
+
+        let other_mint = Keypair::new();
+        fixture
+            .vault_program_client()
+            .create_token_mint(&other_mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &other_mint.pubkey(),
+                &ncn_operator_state,
+                MIN_OPERATOR_BOND,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .ncn_warmup_operator(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &ncn_operator_state,
+                &ncn_root.ncn_admin,
+                Some(&get_associated_token_address(
+                    &ncn_operator_state,
+                    &other_mint.pubkey(),
+                )),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::slot_toggle::SlotToggleState;
+    use jito_restaking_core::{config::Config, ncn::Ncn};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder, ncn_program, restaking_client::assert_restaking_error,
+    };
+
+    #[tokio::test]
+    async fn test_ncn_set_program_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        restaking_program_client
+            .ncn_set_program(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, &ncn_program::ID)
+            .await
+            .unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.ncn_program, ncn_program::ID);
+        assert_eq!(
+            ncn.ncn_program_signer,
+            Ncn::find_program_signer_address(&ncn_program::ID, &ncn_root.ncn_pubkey).0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_program_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let result = restaking_program_client
+            .ncn_set_program(&ncn_root.ncn_pubkey, &Keypair::new(), &ncn_program::ID)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnProgramAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_program_signer_warmup_operator() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let ncn_program_signer =
+            Ncn::find_program_signer_address(&ncn_program::ID, &ncn_root.ncn_pubkey).0;
+
+        // the PDA isn't accepted until the NCN is bound to the program
+        let result = restaking_program_client
+            .ncn_program_warmup_operator(
+                &ncn_root.ncn_pubkey,
+                &ncn_program_signer,
+                &operator_root.operator_pubkey,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+
+        restaking_program_client
+            .ncn_set_program(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, &ncn_program::ID)
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_program_warmup_operator(
+                &ncn_root.ncn_pubkey,
+                &ncn_program_signer,
+                &operator_root.operator_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, config.epoch_length())
+                .unwrap(),
+            SlotToggleState::WarmUp
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_program_unbind() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        restaking_program_client
+            .ncn_set_program(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, &ncn_program::ID)
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_program(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                &Pubkey::default(),
+            )
+            .await
+            .unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.ncn_program, Pubkey::default());
+        assert_eq!(ncn.ncn_program_signer, Pubkey::default());
+
+        let result = restaking_program_client
+            .ncn_program_warmup_operator(
+                &ncn_root.ncn_pubkey,
+                &Ncn::find_program_signer_address(&ncn_program::ID, &ncn_root.ncn_pubkey).0,
+                &operator_root.operator_pubkey,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+    }
+}
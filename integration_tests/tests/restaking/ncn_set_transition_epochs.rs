@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::slot_toggle::SlotToggleState;
+    use jito_restaking_core::{config::Config, MAX_TRANSITION_EPOCHS};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{fixture::TestBuilder, restaking_client::assert_restaking_error};
+
+    #[tokio::test]
+    async fn test_ncn_set_transition_epochs_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.warmup_epochs(), 1);
+        assert_eq!(ncn.cooldown_epochs(), 1);
+
+        restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, 2, 3)
+            .await
+            .unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.warmup_epochs(), 2);
+        assert_eq!(ncn.cooldown_epochs(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_transition_epochs_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let result = restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root.ncn_pubkey, &Keypair::new(), 2, 3)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_transition_epochs_out_of_range_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let result = restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, 0, 1)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTransitionEpochsInvalid);
+
+        let result = restaking_program_client
+            .ncn_set_transition_epochs(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                1,
+                MAX_TRANSITION_EPOCHS + 1,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTransitionEpochsInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_multi_epoch_warmup_and_cooldown() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let epoch_length = config.epoch_length();
+
+        restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, 2, 3)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        // a one-epoch warmup would be complete by now
+        fixture
+            .warp_slot_incremental(2 * epoch_length)
+            .await
+            .unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::WarmUp
+        );
+
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::Active
+        );
+
+        restaking_program_client
+            .do_ncn_cooldown_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(3 * epoch_length)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::Cooldown
+        );
+
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+}
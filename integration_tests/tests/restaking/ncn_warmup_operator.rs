@@ -82,6 +82,7 @@ mod tests {
                 )
                 .0,
                 &Keypair::new(),
+                None,
             )
             .await;
         assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::metadata::MAX_NAME_LEN;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::instruction::InstructionError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::TestBuilder,
+        restaking_client::{OperatorRoot, RestakingProgramClient},
+    };
+
+    async fn setup() -> (TestBuilder, RestakingProgramClient, OperatorRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        (fixture, restaking_program_client, operator_root)
+    }
+
+    #[tokio::test]
+    async fn test_initialize_and_update_operator_metadata_ok() {
+        let (_fixture, mut restaking_program_client, operator_root) = setup().await;
+
+        restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito Operator",
+                "https://jito.network",
+                "https://jito.network/logo.png",
+                "operator@jito.network",
+            )
+            .await
+            .unwrap();
+
+        let operator_metadata = restaking_program_client
+            .get_operator_metadata(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator_metadata.operator, operator_root.operator_pubkey);
+        assert_eq!(operator_metadata.metadata.update_count(), 1);
+        assert_eq!(operator_metadata.metadata.name(), "Jito Operator");
+        assert_eq!(operator_metadata.metadata.website(), "https://jito.network");
+        assert_eq!(
+            operator_metadata.metadata.logo_uri(),
+            "https://jito.network/logo.png"
+        );
+        assert_eq!(
+            operator_metadata.metadata.contact(),
+            "operator@jito.network"
+        );
+
+        restaking_program_client
+            .update_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                Some("Jito Operator ⚡"),
+                None,
+                None,
+                Some(""),
+            )
+            .await
+            .unwrap();
+
+        let operator_metadata = restaking_program_client
+            .get_operator_metadata(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator_metadata.metadata.update_count(), 2);
+        assert_eq!(operator_metadata.metadata.name(), "Jito Operator ⚡");
+        assert_eq!(operator_metadata.metadata.website(), "https://jito.network");
+        assert_eq!(operator_metadata.metadata.contact(), "");
+    }
+
+    #[tokio::test]
+    async fn test_operator_metadata_bad_metadata_admin_fails() {
+        let (mut fixture, mut restaking_program_client, operator_root) = setup().await;
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &bad_admin,
+                "Operator",
+                "",
+                "",
+                "",
+            )
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::OperatorMetadataAdminInvalid as u32),
+        );
+
+        restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Operator",
+                "",
+                "",
+                "",
+            )
+            .await
+            .unwrap();
+        let result = restaking_program_client
+            .update_operator_metadata(
+                &operator_root.operator_pubkey,
+                &bad_admin,
+                Some("Hijacked"),
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::OperatorMetadataAdminInvalid as u32),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_operator_metadata_field_too_long_fails() {
+        let (_fixture, mut restaking_program_client, operator_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                &"a".repeat(MAX_NAME_LEN + 1),
+                "",
+                "",
+                "",
+            )
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::MetadataFieldInvalid as u32),
+        );
+    }
+}
@@ -87,7 +87,11 @@ mod tests {
 
         assert_eq!(operator.ncn_admin, new_admin.pubkey());
         assert_eq!(operator.vault_admin, new_admin.pubkey());
-        assert_eq!(operator.voter, new_admin.pubkey());
         assert_eq!(operator.delegate_admin, new_admin.pubkey());
+
+        // the voter is handed over at the next epoch boundary
+        assert_eq!(operator.voter, operator_root.operator_admin.pubkey());
+        assert_eq!(operator.next_voter, new_admin.pubkey());
+        assert!(operator.next_voter_epoch() > 0);
     }
 }
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use jito_restaking_core::config::Config;
+    use jito_restaking_core::{config::Config, MAX_OPERATOR_FEE_BUMP_BPS};
     use jito_restaking_sdk::error::RestakingError;
     use solana_sdk::instruction::InstructionError;
 
@@ -10,7 +10,7 @@ mod tests {
         restaking_client::{OperatorRoot, RestakingProgramClient},
     };
 
-    async fn setup() -> (RestakingProgramClient, OperatorRoot) {
+    async fn setup() -> (TestBuilder, RestakingProgramClient, OperatorRoot) {
         let fixture = TestBuilder::new().await;
         let mut restaking_program_client = fixture.restaking_program_client();
 
@@ -25,24 +25,34 @@ mod tests {
             .await
             .unwrap();
 
-        (restaking_program_client, operator_root)
+        (fixture, restaking_program_client, operator_root)
     }
 
     #[tokio::test]
     async fn test_operator_set_fee_ok() {
         let initial_fee_bps = 0;
-        let (mut restaking_program_client, operator_root) = setup().await;
+        let (mut fixture, mut restaking_program_client, operator_root) = setup().await;
+
+        let restaking_config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        let config = restaking_program_client
+            .get_config(&restaking_config_pubkey)
+            .await
+            .unwrap();
 
         // Check initial fee
+        let slot = fixture.get_current_slot().await.unwrap();
         let operator = restaking_program_client
             .get_operator(&operator_root.operator_pubkey)
             .await
             .unwrap();
-        assert_eq!(operator.operator_fee_bps, initial_fee_bps.into());
-
-        let restaking_config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        assert_eq!(
+            operator
+                .current_operator_fee_bps(slot, config.epoch_length())
+                .unwrap(),
+            initial_fee_bps
+        );
 
-        let new_fee_bps = 2000;
+        let new_fee_bps = 200;
         restaking_program_client
             .operator_set_fee(
                 &restaking_config_pubkey,
@@ -53,16 +63,71 @@ mod tests {
             .await
             .unwrap();
 
+        // the new fee is pending until a full epoch has passed
+        let slot = fixture.get_current_slot().await.unwrap();
+        let updated_operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            updated_operator
+                .current_operator_fee_bps(slot, config.epoch_length())
+                .unwrap(),
+            initial_fee_bps
+        );
+        assert_eq!(updated_operator.next_operator_fee_bps(), new_fee_bps);
+        assert_eq!(
+            updated_operator.next_operator_fee_epoch(),
+            slot / config.epoch_length() + 2
+        );
+        assert_eq!(
+            updated_operator
+                .current_operator_fee_bps(slot + config.epoch_length(), config.epoch_length())
+                .unwrap(),
+            initial_fee_bps
+        );
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            updated_operator
+                .current_operator_fee_bps(slot, config.epoch_length())
+                .unwrap(),
+            new_fee_bps
+        );
+
+        // the next change is measured against the fee now in effect
+        restaking_program_client
+            .operator_set_fee(
+                &restaking_config_pubkey,
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                new_fee_bps + MAX_OPERATOR_FEE_BUMP_BPS,
+            )
+            .await
+            .unwrap();
         let updated_operator = restaking_program_client
             .get_operator(&operator_root.operator_pubkey)
             .await
             .unwrap();
-        assert_eq!(updated_operator.operator_fee_bps, new_fee_bps.into());
+        assert_eq!(
+            updated_operator
+                .current_operator_fee_bps(slot, config.epoch_length())
+                .unwrap(),
+            new_fee_bps
+        );
+        assert_eq!(
+            updated_operator.next_operator_fee_bps(),
+            new_fee_bps + MAX_OPERATOR_FEE_BUMP_BPS
+        );
     }
 
     #[tokio::test]
     async fn test_operator_set_fee_exceeds_max() {
-        let (mut restaking_program_client, operator_root) = setup().await;
+        let (_fixture, mut restaking_program_client, operator_root) = setup().await;
 
         let restaking_config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
 
@@ -81,4 +146,25 @@ mod tests {
             InstructionError::Custom(RestakingError::OperatorFeeCapExceeded as u32),
         );
     }
+
+    #[tokio::test]
+    async fn test_operator_set_fee_bump_too_large() {
+        let (_fixture, mut restaking_program_client, operator_root) = setup().await;
+
+        let restaking_config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let result = restaking_program_client
+            .operator_set_fee(
+                &restaking_config_pubkey,
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                MAX_OPERATOR_FEE_BUMP_BPS + 1,
+            )
+            .await;
+
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::OperatorFeeBumpTooLarge as u32),
+        );
+    }
 }
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use jito_restaking_core::config::Config;
     use jito_restaking_sdk::{error::RestakingError, instruction::OperatorAdminRole};
     use solana_sdk::{signature::Keypair, signer::Signer};
 
@@ -108,12 +109,15 @@ mod tests {
                 .await
                 .unwrap();
 
+            // the voter is rotated at the next epoch boundary
             let operator = restaking_program_client
                 .get_operator(&operator_root.operator_pubkey)
                 .await
                 .unwrap();
 
-            assert_eq!(operator.voter, new_admin.pubkey());
+            assert_eq!(operator.voter, operator_root.operator_admin.pubkey());
+            assert_eq!(operator.next_voter, new_admin.pubkey());
+            assert!(operator.next_voter_epoch() > 0);
         }
 
         {
@@ -137,4 +141,53 @@ mod tests {
             assert_eq!(operator.delegate_admin, new_admin.pubkey());
         }
     }
+
+    #[tokio::test]
+    async fn test_operator_set_voter_takes_effect_next_epoch() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        let epoch_length = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap()
+            .epoch_length();
+
+        let new_voter = Keypair::new();
+        restaking_program_client
+            .operator_set_secondary_admin(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                &new_voter,
+                OperatorAdminRole::VoterAdmin,
+            )
+            .await
+            .unwrap();
+
+        let operator = restaking_program_client
+            .get_operator(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(operator.next_voter_epoch(), slot / epoch_length + 1);
+        assert_eq!(
+            operator.current_voter(slot, epoch_length).unwrap(),
+            operator_root.operator_admin.pubkey()
+        );
+
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            operator.current_voter(slot, epoch_length).unwrap(),
+            new_voter.pubkey()
+        );
+    }
 }
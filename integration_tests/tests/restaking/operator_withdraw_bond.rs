@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot},
+    };
+
+    const MIN_OPERATOR_BOND: u64 = 10_000;
+    const EXCESS_OPERATOR_BOND: u64 = 500;
+
+    /// Sets up an operator warmed up with an NCN, bonding more than the NCN minimum
+    async fn setup(fixture: &mut TestBuilder) -> (NcnRoot, OperatorRoot, Pubkey, Pubkey, Pubkey) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let bond_mint = Keypair::new();
+        fixture
+            .vault_program_client()
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_operator_bond(
+                &ncn_root.ncn_pubkey,
+                &bond_mint.pubkey(),
+                &ncn_root.ncn_admin,
+                MIN_OPERATOR_BOND,
+                0,
+            )
+            .await
+            .unwrap();
+
+        let ncn_operator_state = NcnOperatorState::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+        )
+        .0;
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &ncn_operator_state,
+                MIN_OPERATOR_BOND + EXCESS_OPERATOR_BOND,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        let operator_bond = get_associated_token_address(&ncn_operator_state, &bond_mint.pubkey());
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .operator_warmup_ncn(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &ncn_operator_state,
+                &operator_root.operator_admin,
+                Some(&operator_bond),
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .create_ata(&bond_mint.pubkey(), &operator_root.operator_admin.pubkey())
+            .await
+            .unwrap();
+        let destination = get_associated_token_address(
+            &operator_root.operator_admin.pubkey(),
+            &bond_mint.pubkey(),
+        );
+
+        (
+            ncn_root,
+            operator_root,
+            bond_mint.pubkey(),
+            operator_bond,
+            destination,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_operator_withdraw_bond_excess_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, _bond_mint, operator_bond, destination) =
+            setup(&mut fixture).await;
+
+        restaking_program_client
+            .operator_withdraw_bond(
+                &ncn_root.ncn_pubkey,
+                &operator_root,
+                &operator_bond,
+                &destination,
+                EXCESS_OPERATOR_BOND,
+            )
+            .await
+            .unwrap();
+
+        let operator_bond = fixture.get_token_account(&operator_bond).await.unwrap();
+        assert_eq!(operator_bond.amount, MIN_OPERATOR_BOND);
+        let destination = fixture.get_token_account(&destination).await.unwrap();
+        assert_eq!(destination.amount, EXCESS_OPERATOR_BOND);
+    }
+
+    #[tokio::test]
+    async fn test_operator_withdraw_bond_below_min_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, _bond_mint, operator_bond, destination) =
+            setup(&mut fixture).await;
+
+        let result = restaking_program_client
+            .operator_withdraw_bond(
+                &ncn_root.ncn_pubkey,
+                &operator_root,
+                &operator_bond,
+                &destination,
+                EXCESS_OPERATOR_BOND + 1,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondInsufficient);
+    }
+
+    #[tokio::test]
+    async fn test_operator_withdraw_bond_wrong_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, _bond_mint, operator_bond, destination) =
+            setup(&mut fixture).await;
+
+        let wrong_operator_root = OperatorRoot {
+            operator_pubkey: operator_root.operator_pubkey,
+            operator_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .operator_withdraw_bond(
+                &ncn_root.ncn_pubkey,
+                &wrong_operator_root,
+                &operator_bond,
+                &destination,
+                EXCESS_OPERATOR_BOND,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_operator_withdraw_bond_after_cooldown_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let (ncn_root, operator_root, _bond_mint, operator_bond, destination) =
+            setup(&mut fixture).await;
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_cooldown_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        // the bond is still required while cooling down
+        let result = restaking_program_client
+            .operator_withdraw_bond(
+                &ncn_root.ncn_pubkey,
+                &operator_root,
+                &operator_bond,
+                &destination,
+                MIN_OPERATOR_BOND + EXCESS_OPERATOR_BOND,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondInsufficient);
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        restaking_program_client
+            .operator_withdraw_bond(
+                &ncn_root.ncn_pubkey,
+                &operator_root,
+                &operator_bond,
+                &destination,
+                MIN_OPERATOR_BOND + EXCESS_OPERATOR_BOND,
+            )
+            .await
+            .unwrap();
+
+        let operator_bond = fixture.get_token_account(&operator_bond).await.unwrap();
+        assert_eq!(operator_bond.amount, 0);
+    }
+}
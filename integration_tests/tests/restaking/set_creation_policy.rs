@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn::Ncn, operator::Operator};
+    use jito_restaking_sdk::{error::RestakingError, instruction::CreationPolicy};
+    use solana_program::{instruction::InstructionError, native_token::LAMPORTS_PER_SOL};
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        assert_ix_error, fixture::TestBuilder, restaking_client::RestakingProgramClient,
+    };
+
+    async fn setup() -> (TestBuilder, RestakingProgramClient, Keypair) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let config_admin = restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        (fixture, restaking_program_client, config_admin)
+    }
+
+    #[tokio::test]
+    async fn test_set_creation_policy_ok() {
+        let (_fixture, mut restaking_program_client, config_admin) = setup().await;
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        let creation_fee_treasury = Keypair::new().pubkey();
+        restaking_program_client
+            .set_creation_policy(
+                &config_admin,
+                &creation_fee_treasury,
+                CreationPolicy::Fee,
+                LAMPORTS_PER_SOL,
+            )
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(config.creation_policy().unwrap(), CreationPolicy::Fee);
+        assert_eq!(config.creation_fee_lamports(), LAMPORTS_PER_SOL);
+        assert_eq!(config.creation_fee_treasury, creation_fee_treasury);
+    }
+
+    #[tokio::test]
+    async fn test_set_creation_policy_bad_admin_fails() {
+        let (mut fixture, mut restaking_program_client, _config_admin) = setup().await;
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = restaking_program_client
+            .set_creation_policy(
+                &bad_admin,
+                &Keypair::new().pubkey(),
+                CreationPolicy::Allowlist,
+                0,
+            )
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::ConfigAdminInvalid as u32),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_allowlist_creation_policy() {
+        let (mut fixture, mut restaking_program_client, config_admin) = setup().await;
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+
+        restaking_program_client
+            .set_creation_policy(
+                &config_admin,
+                &Keypair::new().pubkey(),
+                CreationPolicy::Allowlist,
+                0,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client.do_initialize_ncn().await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::CreatorNotAllowlisted as u32),
+        );
+        let result = restaking_program_client.do_initialize_operator().await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::CreatorNotAllowlisted as u32),
+        );
+
+        let creator = Keypair::new();
+        fixture.transfer(&creator.pubkey(), 10.0).await.unwrap();
+        restaking_program_client
+            .initialize_creator_allowlist_entry(&config_admin, &creator.pubkey())
+            .await
+            .unwrap();
+
+        let ncn_base = Keypair::new();
+        restaking_program_client
+            .initialize_ncn(
+                &config_pubkey,
+                &Ncn::find_program_address(&jito_restaking_program::id(), &ncn_base.pubkey()).0,
+                &creator,
+                &ncn_base,
+            )
+            .await
+            .unwrap();
+        let operator_base = Keypair::new();
+        restaking_program_client
+            .initialize_operator(
+                &config_pubkey,
+                &Operator::find_program_address(
+                    &jito_restaking_program::id(),
+                    &operator_base.pubkey(),
+                )
+                .0,
+                &creator,
+                &operator_base,
+                0,
+            )
+            .await
+            .unwrap();
+
+        // once removed from the allowlist, the creator can't create another NCN
+        restaking_program_client
+            .close_creator_allowlist_entry(&config_admin, &creator.pubkey())
+            .await
+            .unwrap();
+        let ncn_base = Keypair::new();
+        let result = restaking_program_client
+            .initialize_ncn(
+                &config_pubkey,
+                &Ncn::find_program_address(&jito_restaking_program::id(), &ncn_base.pubkey()).0,
+                &creator,
+                &ncn_base,
+            )
+            .await;
+        assert_ix_error(
+            result,
+            InstructionError::Custom(RestakingError::CreatorNotAllowlisted as u32),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fee_creation_policy_pays_treasury() {
+        let (mut fixture, mut restaking_program_client, config_admin) = setup().await;
+
+        let creation_fee_treasury = Keypair::new().pubkey();
+        restaking_program_client
+            .set_creation_policy(
+                &config_admin,
+                &creation_fee_treasury,
+                CreationPolicy::Fee,
+                LAMPORTS_PER_SOL / 10,
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let creation_fee_treasury_account = fixture
+            .get_account(&creation_fee_treasury)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            creation_fee_treasury_account.lamports,
+            2 * LAMPORTS_PER_SOL / 10
+        );
+    }
+}
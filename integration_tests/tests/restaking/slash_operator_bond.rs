@@ -0,0 +1,234 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot},
+    };
+
+    const MIN_OPERATOR_BOND: u64 = 10_000;
+    const MAX_BOND_SLASHABLE_PER_EPOCH: u64 = 1_000;
+    /// The slasher ticket's cap, in the vault's supported token, doesn't apply to the bond
+    const VAULT_MAX_SLASHABLE_PER_EPOCH: u64 = 1;
+
+    struct BondedOperator {
+        ncn_root: NcnRoot,
+        operator_root: OperatorRoot,
+        vault: Pubkey,
+        slasher: Keypair,
+        operator_bond: Pubkey,
+        destination: Pubkey,
+    }
+
+    /// Sets up an operator bonding with an NCN and a slasher ticket of the NCN, left inactive
+    async fn setup(fixture: &mut TestBuilder) -> BondedOperator {
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let bond_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&bond_mint, &spl_token::id())
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_operator_bond(
+                &ncn_root.ncn_pubkey,
+                &bond_mint.pubkey(),
+                &ncn_root.ncn_admin,
+                MIN_OPERATOR_BOND,
+                MAX_BOND_SLASHABLE_PER_EPOCH,
+            )
+            .await
+            .unwrap();
+        let ncn_operator_state = NcnOperatorState::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+        )
+        .0;
+        fixture
+            .mint_spl_to(
+                &bond_mint.pubkey(),
+                &ncn_operator_state,
+                MIN_OPERATOR_BOND,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let slasher = Keypair::new();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher.pubkey(),
+                VAULT_MAX_SLASHABLE_PER_EPOCH,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .create_ata(&bond_mint.pubkey(), &slasher.pubkey())
+            .await
+            .unwrap();
+
+        BondedOperator {
+            ncn_root,
+            operator_root,
+            vault: vault_root.vault_pubkey,
+            operator_bond: get_associated_token_address(&ncn_operator_state, &bond_mint.pubkey()),
+            destination: get_associated_token_address(&slasher.pubkey(), &bond_mint.pubkey()),
+            slasher,
+        }
+    }
+
+    async fn activate_slasher(fixture: &mut TestBuilder, bonded_operator: &BondedOperator) {
+        let mut restaking_program_client = fixture.restaking_program_client();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_warmup_ncn_vault_slasher_ticket(
+                &bonded_operator.ncn_root,
+                &bonded_operator.vault,
+                &bonded_operator.slasher.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let bonded_operator = setup(&mut fixture).await;
+        activate_slasher(&mut fixture, &bonded_operator).await;
+
+        restaking_program_client
+            .slash_operator_bond(
+                &bonded_operator.ncn_root.ncn_pubkey,
+                &bonded_operator.operator_root.operator_pubkey,
+                &bonded_operator.vault,
+                &bonded_operator.slasher,
+                &bonded_operator.operator_bond,
+                &bonded_operator.destination,
+                MAX_BOND_SLASHABLE_PER_EPOCH,
+            )
+            .await
+            .unwrap();
+
+        let operator_bond = fixture
+            .get_token_account(&bonded_operator.operator_bond)
+            .await
+            .unwrap();
+        assert_eq!(
+            operator_bond.amount,
+            MIN_OPERATOR_BOND - MAX_BOND_SLASHABLE_PER_EPOCH
+        );
+        let destination = fixture
+            .get_token_account(&bonded_operator.destination)
+            .await
+            .unwrap();
+        assert_eq!(destination.amount, MAX_BOND_SLASHABLE_PER_EPOCH);
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(
+                &bonded_operator.ncn_root.ncn_pubkey,
+                &bonded_operator.operator_root.operator_pubkey,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state.bond_slashed_amount(),
+            MAX_BOND_SLASHABLE_PER_EPOCH
+        );
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_over_max_slashable_per_epoch_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let bonded_operator = setup(&mut fixture).await;
+        activate_slasher(&mut fixture, &bonded_operator).await;
+
+        restaking_program_client
+            .slash_operator_bond(
+                &bonded_operator.ncn_root.ncn_pubkey,
+                &bonded_operator.operator_root.operator_pubkey,
+                &bonded_operator.vault,
+                &bonded_operator.slasher,
+                &bonded_operator.operator_bond,
+                &bonded_operator.destination,
+                MAX_BOND_SLASHABLE_PER_EPOCH - 1,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .slash_operator_bond(
+                &bonded_operator.ncn_root.ncn_pubkey,
+                &bonded_operator.operator_root.operator_pubkey,
+                &bonded_operator.vault,
+                &bonded_operator.slasher,
+                &bonded_operator.operator_bond,
+                &bonded_operator.destination,
+                2,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondSlashCapExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_inactive_slasher_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let bonded_operator = setup(&mut fixture).await;
+
+        let result = restaking_program_client
+            .slash_operator_bond(
+                &bonded_operator.ncn_root.ncn_pubkey,
+                &bonded_operator.operator_root.operator_pubkey,
+                &bonded_operator.vault,
+                &bonded_operator.slasher,
+                &bonded_operator.operator_bond,
+                &bonded_operator.destination,
+                MAX_BOND_SLASHABLE_PER_EPOCH,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnVaultSlasherTicketInactive);
+    }
+}
@@ -195,4 +195,124 @@ mod tests {
 
         assert_vault_error(result, VaultError::VaultIsPaused);
     }
+
+    #[tokio::test]
+    async fn test_add_delegation_max_operator_share_exceeded_fails() {
+        const MINT_AMOUNT: u64 = 100_000;
+        const MIN_AMOUNT_OUT: u64 = 100_000;
+        let mut fixture = TestBuilder::new().await;
+
+        let deposit_fee_bps = 0;
+        let withdraw_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdraw_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MIN_AMOUNT_OUT)
+            .await
+            .unwrap();
+
+        // 50% of the deposited tokens
+        vault_program_client
+            .set_max_operator_share(&vault_root.vault_pubkey, &vault_root.vault_admin, 5_000)
+            .await
+            .unwrap();
+        let max_operator_delegation =
+            (MINT_AMOUNT + Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT) / 2;
+
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_roots[0].operator_pubkey,
+                max_operator_delegation,
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator_roots[0].operator_pubkey, 1)
+            .await;
+        assert_vault_error(result, VaultError::VaultMaxOperatorShareExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_add_delegation_operator_delegation_cap_exceeded_fails() {
+        const MINT_AMOUNT: u64 = 100_000;
+        const MIN_AMOUNT_OUT: u64 = 100_000;
+        let mut fixture = TestBuilder::new().await;
+
+        let deposit_fee_bps = 0;
+        let withdraw_fee_bps = 0;
+        let reward_fee_bps = 0;
+        let num_operators = 1;
+        let slasher_amounts = vec![];
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(
+                deposit_fee_bps,
+                withdraw_fee_bps,
+                reward_fee_bps,
+                num_operators,
+                &slasher_amounts,
+            )
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MIN_AMOUNT_OUT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_operator_delegation_cap(
+                &vault_root.vault_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &vault_root.vault_admin,
+                20_000,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator_roots[0].operator_pubkey, 20_000)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator_roots[0].operator_pubkey, 1)
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorDelegationCapExceeded);
+    }
 }
@@ -0,0 +1,269 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault::Vault, vault_withdrawal_claim_mint::VaultWithdrawalClaimMint,
+    };
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a vault without operators and a depositor who enqueued `MINT_AMOUNT` VRT for
+    /// withdrawal as claim tokens. Returns the epoch of the claim mint.
+    async fn setup() -> (
+        TestBuilder,
+        VaultProgramClient,
+        Config,
+        VaultRoot,
+        Keypair,
+        u64,
+    ) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let epoch = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        (
+            fixture,
+            vault_program_client,
+            config,
+            vault_root,
+            depositor,
+            epoch,
+        )
+    }
+
+    fn claim_mint(vault_root: &VaultRoot, epoch: u64) -> Pubkey {
+        VaultWithdrawalClaimMint::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            epoch,
+        )
+        .0
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_claim_ok() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor, epoch) =
+            setup().await;
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        // The vault has no operators, so the burn updates it in place
+        vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &depositor, epoch, MINT_AMOUNT, None)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault.vrt_supply(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 0);
+
+        let claim_mint_account = fixture
+            .get_token_mint(&claim_mint(&vault_root, epoch))
+            .await
+            .unwrap();
+        assert_eq!(claim_mint_account.supply, 0);
+
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_claim_transferred_ok() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor, epoch) =
+            setup().await;
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let vault_withdrawal_claim_mint = claim_mint(&vault_root, epoch);
+
+        // Sell the claim on the secondary market
+        let buyer = Keypair::new();
+        fixture.transfer(&buyer.pubkey(), 1.0).await.unwrap();
+        fixture
+            .create_ata(&vault_withdrawal_claim_mint, &buyer.pubkey())
+            .await
+            .unwrap();
+        fixture
+            .create_ata(&vault.supported_mint, &buyer.pubkey())
+            .await
+            .unwrap();
+        fixture
+            .transfer_token(
+                &spl_token::id(),
+                &depositor,
+                &buyer.pubkey(),
+                &vault_withdrawal_claim_mint,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        // The original staker no longer holds the claim
+        let result = vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &depositor, epoch, MINT_AMOUNT, None)
+            .await;
+        assert!(result.is_err());
+
+        vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &buyer, epoch, MINT_AMOUNT, None)
+            .await
+            .unwrap();
+
+        let buyer_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &buyer.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(buyer_token_account.amount, MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_claim_partial_ok() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor, epoch) =
+            setup().await;
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &depositor, epoch, MINT_AMOUNT / 4, None)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_ready_to_claim_amount(), MINT_AMOUNT * 3 / 4);
+
+        let escrow_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &claim_mint(&vault_root, epoch),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(escrow_token_account.amount, MINT_AMOUNT * 3 / 4);
+
+        vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &depositor, epoch, MINT_AMOUNT * 3 / 4, None)
+            .await
+            .unwrap();
+
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_claim_not_withdrawable_fails() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor, epoch) =
+            setup().await;
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &depositor, epoch, MINT_AMOUNT, None)
+            .await;
+        assert_vault_error(result, VaultError::VaultWithdrawalClaimNotWithdrawable);
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_claim_zero_fails() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor, epoch) =
+            setup().await;
+
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &depositor, epoch, 0, None)
+            .await;
+        assert_vault_error(result, VaultError::VaultBurnZero);
+    }
+
+    #[tokio::test]
+    async fn test_burn_withdrawal_claim_wrong_epoch_fails() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor, epoch) =
+            setup().await;
+
+        fixture
+            .warp_slot_incremental(3 * config.epoch_length())
+            .await
+            .unwrap();
+
+        // No claims were minted in the following epoch
+        let result = vault_program_client
+            .do_burn_withdrawal_claim(&vault_root, &depositor, epoch + 1, MINT_AMOUNT, None)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+    }
+}
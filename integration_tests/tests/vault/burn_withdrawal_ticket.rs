@@ -33,6 +33,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -117,6 +118,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -217,6 +219,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -367,6 +370,7 @@ mod tests {
             vault_config_admin,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -534,6 +538,7 @@ mod tests {
             vault_config_admin,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -701,6 +706,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -862,6 +868,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1023,6 +1030,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1179,6 +1187,7 @@ mod tests {
             vault_config_admin,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1378,6 +1387,7 @@ mod tests {
             vault_config_admin,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1510,6 +1520,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1626,6 +1637,7 @@ mod tests {
             vault_config_admin,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1843,4 +1855,73 @@ mod tests {
             .unwrap();
         assert_eq!(depositor_token_account.amount, 0);
     }
+
+    /// A vault without operators is updated in place, so the burn doesn't need a crank first
+    #[tokio::test]
+    async fn test_burn_withdrawal_ticket_vault_without_operators_ok() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_burn_withdrawal_ticket(
+                &vault_root,
+                &depositor,
+                &base,
+                &config.program_fee_wallet,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.last_full_state_update_slot(), slot);
+        assert_eq!(
+            vault.vrt_supply(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(vault.vrt_ready_to_claim_amount(), 0);
+
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, MINT_AMOUNT);
+    }
 }
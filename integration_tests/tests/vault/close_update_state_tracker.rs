@@ -2,9 +2,13 @@
 mod tests {
     use jito_vault_core::{config::Config, vault_update_state_tracker::VaultUpdateStateTracker};
     use jito_vault_sdk::error::VaultError;
-    use solana_sdk::signature::{Keypair, Signer};
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
 
     use crate::fixtures::{
+        assert_ix_error,
         fixture::{ConfiguredVault, TestBuilder},
         vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
     };
@@ -429,4 +433,77 @@ mod tests {
 
         assert_vault_error(result, VaultError::VaultIsPaused);
     }
+
+    #[tokio::test]
+    async fn test_close_update_state_tracker_outdated_layout() {
+        let mut fixture = TestBuilder::new().await;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 0, &[])
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_epoch = slot / config.epoch_length();
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_epoch,
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+        fixture
+            .set_layout_version(&vault_update_state_tracker, 0)
+            .await
+            .unwrap();
+
+        // A tracker written before the operator bitmap can't finish the current update
+        let result = vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                ncn_epoch,
+            )
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+                ncn_epoch,
+            )
+            .await
+            .unwrap();
+        assert!(fixture
+            .get_account(&vault_update_state_tracker)
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
@@ -337,4 +337,102 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[tokio::test]
+    async fn test_vault_ncn_ticket_follows_ncn_transition_epochs() {
+        let mut fixture = TestBuilder::new().await;
+
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(DEPOSIT_FEE_BPS, WITHDRAW_FEE_BPS, ZERO_REWARD_FEE_BPS)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root.ncn_pubkey, &ncn_root.ncn_admin, 2, 3)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let epoch_length = config.epoch_length();
+
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+        vault_program_client
+            .do_warmup_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        let vault_ncn_ticket = vault_program_client
+            .get_vault_ncn_ticket(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        // a one-epoch warmup would be complete by now
+        fixture
+            .warp_slot_incremental(2 * epoch_length)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            vault_ncn_ticket.state.state(slot, epoch_length).unwrap(),
+            SlotToggleState::WarmUp
+        );
+
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            vault_ncn_ticket.state.state(slot, epoch_length).unwrap(),
+            SlotToggleState::Active
+        );
+
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+        vault_program_client
+            .do_cooldown_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        let vault_ncn_ticket = vault_program_client
+            .get_vault_ncn_ticket(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(3 * epoch_length)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            vault_ncn_ticket.state.state(slot, epoch_length).unwrap(),
+            SlotToggleState::Cooldown
+        );
+
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            vault_ncn_ticket.state.state(slot, epoch_length).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
 }
@@ -81,7 +81,7 @@ mod tests {
             .unwrap();
         assert_eq!(vault_update_state_tracker.vault, vault_root.vault_pubkey);
         assert_eq!(vault_update_state_tracker.ncn_epoch(), ncn_epoch);
-        assert_eq!(vault_update_state_tracker.last_updated_index(), u64::MAX);
+        assert_eq!(vault_update_state_tracker.operators_updated(), 0);
         assert_eq!(
             vault_update_state_tracker.delegation_state,
             DelegationState::default()
@@ -98,7 +98,7 @@ mod tests {
             .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
             .await
             .unwrap();
-        assert_eq!(vault_update_state_tracker.last_updated_index(), 0);
+        assert_eq!(vault_update_state_tracker.operators_updated(), 1);
         assert_eq!(
             vault_update_state_tracker.delegation_state,
             DelegationState::default()
@@ -207,10 +207,7 @@ mod tests {
             .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
             .await
             .unwrap();
-        assert_eq!(
-            vault_update_state_tracker.last_updated_index(),
-            operator_index as u64
-        );
+        assert_eq!(vault_update_state_tracker.operators_updated(), 1);
         assert_eq!(
             vault_update_state_tracker.delegation_state,
             DelegationState::new(50000, 0, 0)
@@ -229,10 +226,7 @@ mod tests {
             .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
             .await
             .unwrap();
-        assert_eq!(
-            vault_update_state_tracker.last_updated_index(),
-            operator_index as u64
-        );
+        assert_eq!(vault_update_state_tracker.operators_updated(), 2);
         assert_eq!(
             vault_update_state_tracker.delegation_state,
             DelegationState::new(100000, 0, 0)
@@ -331,7 +325,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_crank_vault_update_state_tracker_skip_start_index_fails() {
+    async fn test_crank_vault_update_state_tracker_out_of_order_ok() {
         let mut fixture = TestBuilder::new().await;
 
         let deposit_fee_bps = 0;
@@ -402,17 +396,44 @@ mod tests {
             .await
             .unwrap();
 
-        let result = vault_program_client
+        // The operators don't need to be cranked starting at the epoch's start index
+        vault_program_client
             .do_crank_vault_update_state_tracker(
                 &vault_root.vault_pubkey,
                 &operator_roots[operator_index].operator_pubkey,
             )
-            .await;
-        assert_vault_error(result, VaultError::VaultUpdateIncorrectIndex);
+            .await
+            .unwrap();
+        vault_program_client
+            .do_crank_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &operator_roots[(operator_index + 1) % num_operators as usize].operator_pubkey,
+            )
+            .await
+            .unwrap();
+
+        let vault_update_state_tracker = vault_program_client
+            .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
+            .await
+            .unwrap();
+        assert_eq!(vault_update_state_tracker.operators_updated(), 2);
+        assert_eq!(
+            vault_update_state_tracker.delegation_state,
+            DelegationState::new(100000, 0, 0)
+        );
+
+        vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker_pubkey,
+                ncn_epoch,
+            )
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
-    async fn test_crank_vault_update_state_tracker_skip_index_fails() {
+    async fn test_crank_vault_update_state_tracker_skip_index_ok() {
         let mut fixture = TestBuilder::new().await;
 
         let deposit_fee_bps = 0;
@@ -490,15 +511,119 @@ mod tests {
             .await
             .unwrap();
 
+        let skipped_operator_index = (operator_index + 1) % num_operators as usize;
         let operator_index = (operator_index + 2) % num_operators as usize;
 
-        let result = vault_program_client
+        vault_program_client
             .do_crank_vault_update_state_tracker(
                 &vault_root.vault_pubkey,
                 &operator_roots[operator_index].operator_pubkey,
             )
+            .await
+            .unwrap();
+
+        // The tracker can't be closed until the skipped operator is cranked
+        let result = vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker_pubkey,
+                ncn_epoch,
+            )
             .await;
-        assert_vault_error(result, VaultError::VaultUpdateIncorrectIndex);
+        assert_vault_error(result, VaultError::VaultUpdateStateNotFinishedUpdating);
+
+        vault_program_client
+            .do_crank_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &operator_roots[skipped_operator_index].operator_pubkey,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker_pubkey,
+                ncn_epoch,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state, DelegationState::new(100000, 0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_reverse_order_many_operators_ok() {
+        let mut fixture = TestBuilder::new().await;
+
+        // More than eight operators so the bitmap spans multiple bytes
+        let num_operators = 10;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, num_operators, &[])
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_epoch = slot / config.epoch_length();
+        let vault_update_state_tracker_pubkey = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_epoch,
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker_pubkey,
+            )
+            .await
+            .unwrap();
+
+        for operator_root in operator_roots.iter().rev() {
+            vault_program_client
+                .do_crank_vault_update_state_tracker(
+                    &vault_root.vault_pubkey,
+                    &operator_root.operator_pubkey,
+                )
+                .await
+                .unwrap();
+        }
+
+        let vault_update_state_tracker = vault_program_client
+            .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_update_state_tracker.operators_updated(),
+            num_operators as u64
+        );
+
+        vault_program_client
+            .close_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker_pubkey,
+                ncn_epoch,
+            )
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
@@ -627,7 +752,7 @@ mod tests {
             .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
             .await
             .unwrap();
-        assert_eq!(vault_update_state_tracker.last_updated_index(), 0);
+        assert_eq!(vault_update_state_tracker.operators_updated(), 1);
         assert_eq!(
             vault_update_state_tracker.delegation_state,
             DelegationState::new(25000, 0, 0)
@@ -647,7 +772,7 @@ mod tests {
             .get_vault_update_state_tracker(&vault_root.vault_pubkey, ncn_epoch)
             .await
             .unwrap();
-        assert_eq!(vault_update_state_tracker.last_updated_index(), 1);
+        assert_eq!(vault_update_state_tracker.operators_updated(), 2);
         assert_eq!(
             vault_update_state_tracker.delegation_state,
             DelegationState::new(50000, 0, 0)
@@ -900,6 +1025,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1048,6 +1174,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -1176,6 +1303,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
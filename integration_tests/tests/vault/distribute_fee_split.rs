@@ -0,0 +1,343 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault_fee_split::VaultFeeSplit};
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+    };
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    /// Sets up a vault with the given fees, split between three recipients with VRT token accounts
+    async fn setup(
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        recipient_bps: [u16; 3],
+    ) -> (TestBuilder, VaultProgramClient, VaultRoot, Vec<Pubkey>) {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(deposit_fee_bps, withdrawal_fee_bps, reward_fee_bps)
+            .await
+            .unwrap();
+
+        let recipients: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut bps = [0; 8];
+        bps[..3].copy_from_slice(&recipient_bps);
+        vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &recipients,
+                bps,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        for recipient in recipients.iter() {
+            fixture
+                .create_ata(&vault.vrt_mint, recipient)
+                .await
+                .unwrap();
+        }
+
+        (fixture, vault_program_client, vault_root, recipients)
+    }
+
+    async fn fee_split_balance(
+        fixture: &mut TestBuilder,
+        vault_program_client: &mut VaultProgramClient,
+        vault_root: &VaultRoot,
+    ) -> u64 {
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .get_token_account(&get_associated_token_address(
+                &vault.fee_wallet,
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap()
+            .amount
+    }
+
+    async fn vrt_balance(
+        fixture: &mut TestBuilder,
+        vault_program_client: &mut VaultProgramClient,
+        vault_root: &VaultRoot,
+        owner: &Pubkey,
+    ) -> u64 {
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .get_token_account(&get_associated_token_address(owner, &vault.vrt_mint))
+            .await
+            .unwrap()
+            .amount
+    }
+
+    #[tokio::test]
+    async fn test_distribute_deposit_fees_ok() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let (mut fixture, mut vault_program_client, vault_root, recipients) =
+            setup(1_000, 0, 0, [5_000, 3_000, 2_000]).await;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        // The deposit fee accumulates in the fee split
+        let fees = fee_split_balance(&mut fixture, &mut vault_program_client, &vault_root).await;
+        assert_eq!(fees, MINT_AMOUNT / 10);
+
+        vault_program_client
+            .distribute_fee_split(&vault_root.vault_pubkey, &recipients)
+            .await
+            .unwrap();
+
+        for (recipient, expected) in recipients.iter().zip([5_000, 3_000, 2_000]) {
+            assert_eq!(
+                vrt_balance(
+                    &mut fixture,
+                    &mut vault_program_client,
+                    &vault_root,
+                    recipient
+                )
+                .await,
+                expected
+            );
+        }
+        assert_eq!(
+            fee_split_balance(&mut fixture, &mut vault_program_client, &vault_root).await,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_distribute_withdrawal_fees_ok() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let (mut fixture, mut vault_program_client, vault_root, recipients) =
+            setup(0, 1_000, 0, [5_000, 3_000, 2_000]).await;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let base = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap()
+            .base;
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_burn_withdrawal_ticket(
+                &vault_root,
+                &depositor,
+                &base,
+                &config.program_fee_wallet,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // The withdrawal fee accumulates in the fee split
+        let fees = fee_split_balance(&mut fixture, &mut vault_program_client, &vault_root).await;
+        assert!(fees > 0);
+
+        vault_program_client
+            .distribute_fee_split(&vault_root.vault_pubkey, &recipients)
+            .await
+            .unwrap();
+
+        let mut distributed = 0;
+        for (recipient, bps) in recipients.iter().zip([5_000, 3_000, 2_000]) {
+            let balance = vrt_balance(
+                &mut fixture,
+                &mut vault_program_client,
+                &vault_root,
+                recipient,
+            )
+            .await;
+            assert_eq!(balance, fees * bps / 10_000);
+            distributed += balance;
+        }
+        assert_eq!(
+            fee_split_balance(&mut fixture, &mut vault_program_client, &vault_root).await,
+            fees - distributed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_distribute_reward_fees_ok() {
+        let (mut fixture, mut vault_program_client, vault_root, recipients) =
+            setup(0, 0, 1_000, [5_000, 3_000, 2_000]).await;
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .mint_spl_to(&vault.supported_mint, &vault_root.vault_pubkey, 100_000)
+            .await
+            .unwrap();
+        vault_program_client
+            .update_vault_balance(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // The reward fee accumulates in the fee split
+        let fees = fee_split_balance(&mut fixture, &mut vault_program_client, &vault_root).await;
+        assert!(fees > 0);
+
+        vault_program_client
+            .distribute_fee_split(&vault_root.vault_pubkey, &recipients)
+            .await
+            .unwrap();
+
+        for (recipient, bps) in recipients.iter().zip([5_000, 3_000, 2_000]) {
+            assert_eq!(
+                vrt_balance(
+                    &mut fixture,
+                    &mut vault_program_client,
+                    &vault_root,
+                    recipient
+                )
+                .await,
+                fees * bps / 10_000
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_distribute_fee_split_remainder_stays_ok() {
+        const MINT_AMOUNT: u64 = 10_000;
+
+        let (mut fixture, mut vault_program_client, vault_root, recipients) =
+            setup(1_000, 0, 0, [3_333, 3_333, 3_334]).await;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+        assert_eq!(
+            fee_split_balance(&mut fixture, &mut vault_program_client, &vault_root).await,
+            1_000
+        );
+
+        vault_program_client
+            .distribute_fee_split(&vault_root.vault_pubkey, &recipients)
+            .await
+            .unwrap();
+
+        for recipient in recipients.iter() {
+            assert_eq!(
+                vrt_balance(
+                    &mut fixture,
+                    &mut vault_program_client,
+                    &vault_root,
+                    recipient
+                )
+                .await,
+                333
+            );
+        }
+        assert_eq!(
+            fee_split_balance(&mut fixture, &mut vault_program_client, &vault_root).await,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_distribute_fee_split_wrong_order_fails() {
+        let (_fixture, mut vault_program_client, vault_root, mut recipients) =
+            setup(1_000, 0, 0, [5_000, 3_000, 2_000]).await;
+
+        recipients.swap(0, 1);
+        let result = vault_program_client
+            .distribute_fee_split(&vault_root.vault_pubkey, &recipients)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+    }
+
+    #[tokio::test]
+    async fn test_distribute_fee_split_missing_recipient_fails() {
+        let (_fixture, mut vault_program_client, vault_root, recipients) =
+            setup(1_000, 0, 0, [5_000, 3_000, 2_000]).await;
+
+        let result = vault_program_client
+            .distribute_fee_split(&vault_root.vault_pubkey, &recipients[..2])
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeSplitInvalidRecipients);
+    }
+
+    #[tokio::test]
+    async fn test_distribute_fee_split_not_initialized_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .distribute_fee_split(&vault_root.vault_pubkey, &[Pubkey::new_unique()])
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+
+        // No fee split has been created
+        assert_ne!(
+            vault_program_client
+                .get_vault(&vault_root.vault_pubkey)
+                .await
+                .unwrap()
+                .fee_wallet,
+            VaultFeeSplit::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey
+            )
+            .0
+        );
+    }
+}
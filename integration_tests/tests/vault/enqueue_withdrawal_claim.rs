@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault_withdrawal_claim_mint::VaultWithdrawalClaimMint};
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::program_option::COption;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a vault without operators and a depositor holding `MINT_AMOUNT` VRT
+    async fn setup() -> (TestBuilder, VaultProgramClient, VaultRoot, Keypair) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        (fixture, vault_program_client, vault_root, depositor)
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_claim_ok() {
+        let (mut fixture, mut vault_program_client, vault_root, depositor) = setup().await;
+
+        let epoch = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_enqueued_for_cooldown_amount(), MINT_AMOUNT);
+
+        let vault_withdrawal_claim_mint = VaultWithdrawalClaimMint::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            epoch,
+        )
+        .0;
+        let claim_mint = fixture
+            .get_token_mint(&vault_withdrawal_claim_mint)
+            .await
+            .unwrap();
+        let vrt_mint = fixture.get_token_mint(&vault.vrt_mint).await.unwrap();
+        assert_eq!(claim_mint.supply, MINT_AMOUNT);
+        assert_eq!(claim_mint.decimals, vrt_mint.decimals);
+        assert_eq!(
+            claim_mint.mint_authority,
+            COption::Some(vault_root.vault_pubkey)
+        );
+
+        let depositor_claim_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault_withdrawal_claim_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_claim_token_account.amount, MINT_AMOUNT);
+
+        let escrow_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_withdrawal_claim_mint,
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(escrow_token_account.amount, MINT_AMOUNT);
+
+        let depositor_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_vrt_token_account.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_claim_same_epoch_shares_mint() {
+        let (mut fixture, mut vault_program_client, vault_root, depositor) = setup().await;
+
+        let first_epoch = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+        let second_epoch = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, MINT_AMOUNT / 4)
+            .await
+            .unwrap();
+        assert_eq!(first_epoch, second_epoch);
+
+        let vault_withdrawal_claim_mint = VaultWithdrawalClaimMint::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            first_epoch,
+        )
+        .0;
+        let claim_mint = fixture
+            .get_token_mint(&vault_withdrawal_claim_mint)
+            .await
+            .unwrap();
+        assert_eq!(claim_mint.supply, MINT_AMOUNT / 2 + MINT_AMOUNT / 4);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_claim_new_epoch_new_mint() {
+        let (mut fixture, mut vault_program_client, vault_root, depositor) = setup().await;
+
+        let first_epoch = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+
+        let second_epoch = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+        assert_eq!(second_epoch, first_epoch + 1);
+
+        for epoch in [first_epoch, second_epoch] {
+            let vault_withdrawal_claim_mint = VaultWithdrawalClaimMint::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                epoch,
+            )
+            .0;
+            let claim_mint = fixture
+                .get_token_mint(&vault_withdrawal_claim_mint)
+                .await
+                .unwrap();
+            assert_eq!(claim_mint.supply, MINT_AMOUNT / 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_claim_zero_fails() {
+        let (_fixture, mut vault_program_client, vault_root, depositor) = setup().await;
+
+        let result = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, 0)
+            .await;
+        assert_vault_error(result, VaultError::VaultEnqueueWithdrawalAmountZero);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_claim_holding_period_fails() {
+        let (_fixture, mut vault_program_client, vault_root, _depositor) = setup().await;
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 1)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultHoldingPeriodNotElapsed);
+    }
+}
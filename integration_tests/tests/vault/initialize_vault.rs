@@ -119,7 +119,7 @@ mod tests {
                 &Config::find_program_address(&jito_vault_program::id()).0,
                 &vault_pubkey,
                 &vrt_mint,
-                &st_mint,
+                &st_mint.pubkey(),
                 &admin_st_token_account,
                 &vault_st_token_account,
                 &burn_vault,
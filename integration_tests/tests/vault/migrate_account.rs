@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+    use solana_sdk::signature::Keypair;
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::TestBuilder,
+        vault_client::{VaultProgramClient, VaultRoot},
+    };
+
+    async fn setup() -> (TestBuilder, VaultProgramClient, Pubkey, Keypair) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            _config_admin,
+            VaultRoot {
+                vault_pubkey,
+                vault_admin,
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        (fixture, vault_program_client, vault_pubkey, vault_admin)
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_at_current_layout_is_noop() {
+        let (_fixture, mut vault_program_client, vault_pubkey, vault_admin) = setup().await;
+
+        let vault_before = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+
+        vault_program_client
+            .migrate_account(&vault_pubkey)
+            .await
+            .unwrap();
+
+        let vault_after = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert_eq!(vault_before, vault_after);
+
+        // The vault is still usable after the migration
+        vault_program_client
+            .set_is_paused(&vault_pubkey, &vault_admin, true)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_newer_layout_fails() {
+        let (mut fixture, mut vault_program_client, vault_pubkey, vault_admin) = setup().await;
+
+        fixture.set_layout_version(&vault_pubkey, 1).await.unwrap();
+
+        let result = vault_program_client.migrate_account(&vault_pubkey).await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+
+        // Accounts with an unknown layout version can't be deserialized
+        let result = vault_program_client
+            .set_is_paused(&vault_pubkey, &vault_admin, true)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_invalid_owner_fails() {
+        let (_fixture, mut vault_program_client, vault_pubkey, _vault_admin) = setup().await;
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        let vault_token_account =
+            get_associated_token_address(&vault_pubkey, &vault.supported_mint);
+
+        let result = vault_program_client
+            .migrate_account(&vault_token_account)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+    }
+}
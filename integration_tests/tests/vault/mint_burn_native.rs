@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault::Vault};
+    use solana_program::native_token::LAMPORTS_PER_SOL;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::VaultStakerWithdrawalTicketRoot};
+
+    const MINT_AMOUNT: u64 = LAMPORTS_PER_SOL;
+
+    #[tokio::test]
+    async fn test_mint_and_burn_native_sol() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        vault_program_client.do_initialize_config().await.unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let vault_root = vault_program_client
+            .do_initialize_native_vault(0, 0, 0, &config.program_fee_wallet)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        fixture.transfer(&depositor.pubkey(), 10.0).await.unwrap();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &depositor.pubkey())
+            .await
+            .unwrap();
+        let depositor_wsol_token_account =
+            get_associated_token_address(&depositor.pubkey(), &vault.supported_mint);
+
+        vault_program_client
+            .do_mint_to_native(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let depositor_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_vrt_token_account.amount, MINT_AMOUNT);
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault.tokens_deposited(),
+            MINT_AMOUNT + Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        // the temporary wSOL account is closed
+        assert!(fixture
+            .get_account(&depositor_wsol_token_account)
+            .await
+            .unwrap()
+            .is_none());
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let lamports_before = fixture
+            .get_account(&depositor.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports;
+        vault_program_client
+            .do_burn_withdrawal_ticket_native(
+                &vault_root,
+                &depositor,
+                &base,
+                &config.program_fee_wallet,
+            )
+            .await
+            .unwrap();
+
+        // the staker is paid out in lamports, less the transaction fee and plus the ticket's rent
+        let lamports_after = fixture
+            .get_account(&depositor.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports;
+        assert!(lamports_after >= lamports_before + MINT_AMOUNT - 5_000);
+        assert!(fixture
+            .get_account(&depositor_wsol_token_account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,406 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, vault::Vault};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    #[tokio::test]
+    async fn test_mint_to_updates_vault_without_operators() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        // No cranking is needed for a vault without operators
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.last_start_state_update_slot(), slot);
+        assert_eq!(vault.last_full_state_update_slot(), slot);
+        assert_eq!(
+            vault.tokens_deposited(),
+            2 * MINT_AMOUNT + Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_vault_with_operators_update_needed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultUpdateNeeded);
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_with_referrer_records_deposits() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(1_000, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        let referrer = Keypair::new().pubkey();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // Without a referral fee the referrer doesn't need a VRT token account
+        vault_program_client
+            .do_mint_to_with_referrer(&vault_root, &depositor, &referrer, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to_with_referrer(&vault_root, &depositor, &referrer, MINT_AMOUNT / 2, 0)
+            .await
+            .unwrap();
+
+        let vault_referral = vault_program_client
+            .get_vault_referral(&vault_root.vault_pubkey, &referrer)
+            .await
+            .unwrap();
+        assert_eq!(vault_referral.vault, vault_root.vault_pubkey);
+        assert_eq!(vault_referral.referrer, referrer);
+        assert_eq!(
+            vault_referral.referred_deposit_amount(),
+            MINT_AMOUNT + MINT_AMOUNT / 2
+        );
+        assert_eq!(vault_referral.referred_deposit_count(), 2);
+        assert_eq!(vault_referral.referral_fees_earned(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_with_referrer_shares_deposit_fee() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(1_000, 0, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_referral_fee(&vault_root.vault_pubkey, &vault_root.vault_admin, 2_500)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let depositor = Keypair::new();
+        let referrer = Keypair::new().pubkey();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &referrer)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_mint_to_with_referrer(&vault_root, &depositor, &referrer, MINT_AMOUNT, 0)
+            .await
+            .unwrap();
+
+        // 10% deposit fee, a quarter of which goes to the referrer
+        let referrer_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(&referrer, &vault.vrt_mint))
+            .await
+            .unwrap();
+        assert_eq!(referrer_vrt_token_account.amount, 2_500);
+        let vault_fee_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault.fee_wallet,
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(vault_fee_token_account.amount, 7_500);
+        let depositor_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_vrt_token_account.amount, 90_000);
+
+        let vault_referral = vault_program_client
+            .get_vault_referral(&vault_root.vault_pubkey, &referrer)
+            .await
+            .unwrap();
+        assert_eq!(vault_referral.referred_deposit_amount(), MINT_AMOUNT);
+        assert_eq!(vault_referral.referral_fees_earned(), 2_500);
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_self_referral_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to_with_referrer(
+                &vault_root,
+                &depositor,
+                &depositor.pubkey(),
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultReferrerInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_beneficiary() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 1)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let depositor = Keypair::new();
+        let beneficiary = Keypair::new().pubkey();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &beneficiary)
+            .await
+            .unwrap();
+
+        // The beneficiary doesn't sign
+        vault_program_client
+            .do_mint_to_beneficiary(
+                &vault_root,
+                &depositor,
+                &beneficiary,
+                None,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        let depositor_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.supported_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_token_account.amount, 0);
+        let depositor_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &depositor.pubkey(),
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(depositor_vrt_token_account.amount, 0);
+        let beneficiary_vrt_token_account = fixture
+            .get_token_account(&get_associated_token_address(&beneficiary, &vault.vrt_mint))
+            .await
+            .unwrap();
+        assert_eq!(beneficiary_vrt_token_account.amount, MINT_AMOUNT);
+
+        // The holding period applies to the beneficiary, who holds the VRT
+        let vault_deposit_receipt = vault_program_client
+            .get_vault_deposit_receipt(&vault_root.vault_pubkey, &beneficiary)
+            .await
+            .unwrap();
+        assert_eq!(vault_deposit_receipt.depositor, beneficiary);
+        assert_eq!(
+            vault_deposit_receipt.last_deposit_slot(),
+            fixture.get_current_slot().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_beneficiary_restarting_holding_period() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 1)
+            .await
+            .unwrap();
+
+        let beneficiary = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &beneficiary.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &beneficiary, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let deposit_slot = fixture.get_current_slot().await.unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        // Depositing on the beneficiary's behalf can't extend their existing holding period
+        let result = vault_program_client
+            .do_mint_to_beneficiary(
+                &vault_root,
+                &depositor,
+                &beneficiary.pubkey(),
+                None,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultBeneficiarySignatureRequired);
+        let vault_deposit_receipt = vault_program_client
+            .get_vault_deposit_receipt(&vault_root.vault_pubkey, &beneficiary.pubkey())
+            .await
+            .unwrap();
+        assert_eq!(vault_deposit_receipt.last_deposit_slot(), deposit_slot);
+
+        vault_program_client
+            .do_mint_to_beneficiary(
+                &vault_root,
+                &depositor,
+                &beneficiary.pubkey(),
+                Some(&beneficiary),
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+        let vault_deposit_receipt = vault_program_client
+            .get_vault_deposit_receipt(&vault_root.vault_pubkey, &beneficiary.pubkey())
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_deposit_receipt.last_deposit_slot(),
+            fixture.get_current_slot().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_vault_as_beneficiary_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .create_ata(&vault.vrt_mint, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_mint_to_beneficiary(
+                &vault_root,
+                &depositor,
+                &vault_root.vault_pubkey,
+                None,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await;
+        assert_vault_error(result, VaultError::InvalidDepositor);
+    }
+}
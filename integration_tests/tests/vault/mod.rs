@@ -1,4 +1,5 @@
 mod add_delegation;
+mod burn_withdrawal_claim;
 mod burn_withdrawal_ticket;
 mod close_update_state_tracker;
 mod close_vault_update_state_tracker;
@@ -7,20 +8,37 @@ mod cooldown_vault_ncn_ticket;
 mod crank_vault_update_state_tracker;
 mod create_token_metadata;
 mod delegate_token_account;
+mod distribute_fee_split;
 mod enqueue_withdrawal;
+mod enqueue_withdrawal_claim;
 mod initialize_config;
 mod initialize_vault;
 mod initialize_vault_ncn_slasher_ticket;
 mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
 mod initialize_vault_update_state_tracker;
+mod migrate_account;
+mod mint_burn_native;
+mod mint_to;
 mod reward_fee;
 mod set_admin;
 mod set_capacity;
 mod set_config_admin;
+mod set_creation_policy;
+mod set_fee_split;
 mod set_fees;
 mod set_is_paused;
+mod set_max_operator_share;
+mod set_min_holding_period;
+mod set_ncn_allocation_cap;
+mod set_operator_delegation_cap;
+mod set_program_fee_override;
 mod set_program_fee_wallet;
+mod set_referral_fee;
 mod set_secondary_admin;
 mod update_token_metadata;
 mod update_vault_balance;
+mod update_vault_full;
+mod update_vault_ncn_slasher_ticket;
+mod waive_holding_period;
+mod wind_down_vault;
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use jito_vault_sdk::{error::VaultError, instruction::CreationPolicy};
+    use solana_program::native_token::LAMPORTS_PER_SOL;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient},
+    };
+
+    async fn setup() -> (TestBuilder, VaultProgramClient, Config, Keypair) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let config_admin = vault_program_client.do_initialize_config().await.unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        (fixture, vault_program_client, config, config_admin)
+    }
+
+    #[tokio::test]
+    async fn test_set_creation_policy_ok() {
+        let (_fixture, mut vault_program_client, config, config_admin) = setup().await;
+        assert_eq!(config.creation_policy().unwrap(), CreationPolicy::Open);
+
+        let creation_fee_treasury = Keypair::new().pubkey();
+        vault_program_client
+            .set_creation_policy(
+                &config_admin,
+                &creation_fee_treasury,
+                CreationPolicy::Fee,
+                LAMPORTS_PER_SOL,
+            )
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        assert_eq!(config.creation_policy().unwrap(), CreationPolicy::Fee);
+        assert_eq!(config.creation_fee_lamports(), LAMPORTS_PER_SOL);
+        assert_eq!(config.creation_fee_treasury, creation_fee_treasury);
+    }
+
+    #[tokio::test]
+    async fn test_set_creation_policy_bad_admin_fails() {
+        let (mut fixture, mut vault_program_client, config, _config_admin) = setup().await;
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = vault_program_client
+            .set_creation_policy(
+                &bad_admin,
+                &config.creation_fee_treasury,
+                CreationPolicy::Allowlist,
+                0,
+            )
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+
+        let result = vault_program_client
+            .initialize_creator_allowlist_entry(&bad_admin, &Keypair::new().pubkey())
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_allowlist_creation_policy() {
+        let (_fixture, mut vault_program_client, config, config_admin) = setup().await;
+
+        vault_program_client
+            .set_creation_policy(
+                &config_admin,
+                &config.creation_fee_treasury,
+                CreationPolicy::Allowlist,
+                0,
+            )
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_initialize_vault(0, 0, 0, 9, &config.program_fee_wallet)
+            .await;
+        assert_vault_error(result, VaultError::VaultCreatorNotAllowlisted);
+
+        let vault_admin = Keypair::new();
+        vault_program_client
+            .initialize_creator_allowlist_entry(&config_admin, &vault_admin.pubkey())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_with_admin(
+                vault_admin.insecure_clone(),
+                0,
+                0,
+                0,
+                9,
+                &config.program_fee_wallet,
+            )
+            .await
+            .unwrap();
+
+        // once removed from the allowlist, the creator can't create another vault
+        vault_program_client
+            .close_creator_allowlist_entry(&config_admin, &vault_admin.pubkey())
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_initialize_vault_with_admin(vault_admin, 0, 0, 0, 9, &config.program_fee_wallet)
+            .await;
+        assert_vault_error(result, VaultError::VaultCreatorNotAllowlisted);
+    }
+
+    #[tokio::test]
+    async fn test_fee_creation_policy_pays_treasury() {
+        let (mut fixture, mut vault_program_client, config, config_admin) = setup().await;
+
+        let creation_fee_treasury = Keypair::new().pubkey();
+        vault_program_client
+            .set_creation_policy(
+                &config_admin,
+                &creation_fee_treasury,
+                CreationPolicy::Fee,
+                LAMPORTS_PER_SOL,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_initialize_vault(0, 0, 0, 9, &config.program_fee_wallet)
+            .await
+            .unwrap();
+
+        let creation_fee_treasury_account = fixture
+            .get_account(&creation_fee_treasury)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(creation_fee_treasury_account.lamports, LAMPORTS_PER_SOL);
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::vault_fee_split::VaultFeeSplit;
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    async fn setup() -> (TestBuilder, VaultProgramClient, VaultRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        (fixture, vault_program_client, vault_root)
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_split_ok() {
+        let (mut fixture, mut vault_program_client, vault_root) = setup().await;
+        let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &recipients,
+                [7_000, 3_000, 0, 0, 0, 0, 0, 0],
+            )
+            .await
+            .unwrap();
+
+        let fee_split = vault_program_client
+            .get_vault_fee_split(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(fee_split.vault, vault_root.vault_pubkey);
+        assert_eq!(fee_split.recipients().len(), 2);
+        assert_eq!(fee_split.recipients()[0].recipient, recipients[0]);
+        assert_eq!(fee_split.recipients()[0].bps(), 7_000);
+        assert_eq!(fee_split.recipients()[1].recipient, recipients[1]);
+        assert_eq!(fee_split.recipients()[1].bps(), 3_000);
+
+        // The fee split is the vault's fee wallet and its VRT token account is ready for fees
+        let vault_fee_split = VaultFeeSplit::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+        )
+        .0;
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.fee_wallet, vault_fee_split);
+        let vault_fee_split_token_account = fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_fee_split,
+                &vault.vrt_mint,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(vault_fee_split_token_account.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_split_replaces_recipients_ok() {
+        let (_fixture, mut vault_program_client, vault_root) = setup().await;
+
+        vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[Pubkey::new_unique(), Pubkey::new_unique()],
+                [5_000, 5_000, 0, 0, 0, 0, 0, 0],
+            )
+            .await
+            .unwrap();
+
+        let recipient = Pubkey::new_unique();
+        vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[recipient],
+                [10_000, 0, 0, 0, 0, 0, 0, 0],
+            )
+            .await
+            .unwrap();
+
+        let fee_split = vault_program_client
+            .get_vault_fee_split(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(fee_split.recipients().len(), 1);
+        assert_eq!(fee_split.recipients()[0].recipient, recipient);
+        assert_eq!(fee_split.recipients()[0].bps(), 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_split_non_admin_fails() {
+        let (mut fixture, mut vault_program_client, vault_root) = setup().await;
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &bad_admin,
+                &[Pubkey::new_unique()],
+                [10_000, 0, 0, 0, 0, 0, 0, 0],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_split_bps_not_max_fails() {
+        let (_fixture, mut vault_program_client, vault_root) = setup().await;
+
+        let result = vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[Pubkey::new_unique(), Pubkey::new_unique()],
+                [5_000, 4_000, 0, 0, 0, 0, 0, 0],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeSplitInvalidBps);
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_split_share_without_recipient_fails() {
+        let (_fixture, mut vault_program_client, vault_root) = setup().await;
+
+        let result = vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[Pubkey::new_unique()],
+                [9_000, 1_000, 0, 0, 0, 0, 0, 0],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeSplitInvalidRecipients);
+    }
+
+    #[tokio::test]
+    async fn test_set_fee_split_duplicate_recipient_fails() {
+        let (_fixture, mut vault_program_client, vault_root) = setup().await;
+
+        let recipient = Pubkey::new_unique();
+        let result = vault_program_client
+            .set_fee_split(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &[recipient, recipient],
+                [5_000, 5_000, 0, 0, 0, 0, 0, 0],
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeSplitInvalidRecipients);
+    }
+}
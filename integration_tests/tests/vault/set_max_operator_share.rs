@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    async fn setup() -> (VaultProgramClient, Pubkey, Keypair) {
+        let fixture = TestBuilder::new().await;
+
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (
+            _config_admin,
+            VaultRoot {
+                vault_pubkey,
+                vault_admin,
+            },
+        ) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        (vault_program_client, vault_pubkey, vault_admin)
+    }
+
+    #[tokio::test]
+    async fn test_set_max_operator_share_ok() {
+        let (mut vault_program_client, vault_pubkey, vault_admin) = setup().await;
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert_eq!(vault.max_operator_share_bps(), 0);
+
+        vault_program_client
+            .set_max_operator_share(&vault_pubkey, &vault_admin, 2_500)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client.get_vault(&vault_pubkey).await.unwrap();
+        assert_eq!(vault.max_operator_share_bps(), 2_500);
+    }
+
+    #[tokio::test]
+    async fn test_set_max_operator_share_with_bad_admin_fails() {
+        let (mut vault_program_client, vault_pubkey, _) = setup().await;
+
+        let bad_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&bad_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_max_operator_share(&vault_pubkey, &bad_admin, 2_500)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_max_operator_share_above_max_bps_fails() {
+        let (mut vault_program_client, vault_pubkey, vault_admin) = setup().await;
+
+        let response = vault_program_client
+            .set_max_operator_share(&vault_pubkey, &vault_admin, 10_001)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultMaxOperatorShareInvalid);
+    }
+}
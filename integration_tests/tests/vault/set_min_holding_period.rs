@@ -0,0 +1,354 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault::Vault, vault_deposit_receipt::VaultDepositReceipt,
+        MAX_HOLDING_EPOCHS,
+    };
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a vault without operators and a depositor funded with `2 * MINT_AMOUNT` tokens
+    async fn setup() -> (TestBuilder, VaultProgramClient, Config, VaultRoot, Keypair) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), 2 * MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        (fixture, vault_program_client, config, vault_root, depositor)
+    }
+
+    #[tokio::test]
+    async fn test_set_min_holding_period_ok() {
+        let (_fixture, mut vault_program_client, _config, vault_root, _depositor) = setup().await;
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 3)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.min_holding_epochs(), 3);
+        assert_eq!(vault.effective_min_holding_epochs(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_set_min_holding_period_bad_admin_fails() {
+        let (mut fixture, mut vault_program_client, _config, vault_root, _depositor) =
+            setup().await;
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &bad_admin, 3)
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_min_holding_period_above_max_fails() {
+        let (_fixture, mut vault_program_client, _config, vault_root, _depositor) = setup().await;
+
+        let result = vault_program_client
+            .set_min_holding_period(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                MAX_HOLDING_EPOCHS + 1,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultMinHoldingEpochsInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_mint_without_holding_period_skips_deposit_receipt() {
+        let (mut fixture, mut vault_program_client, _config, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault_deposit_receipt = VaultDepositReceipt::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &depositor.pubkey(),
+        )
+        .0;
+        assert!(fixture
+            .get_account(&vault_deposit_receipt)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_mint_without_deposit_receipt() {
+        let (mut fixture, mut vault_program_client, _config, vault_root, depositor) = setup().await;
+
+        // The deposit receipt is only required while the vault has a holding period
+        vault_program_client
+            .do_mint_to_without_deposit_receipt(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 1)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = vault_program_client
+            .do_mint_to_without_deposit_receipt(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultDepositReceiptMissing);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_of_transferred_vrt() {
+        let (mut fixture, mut vault_program_client, _config, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 1)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        // The holder has no deposit receipt, so the VRT isn't tracked in their wallet
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let holder = Keypair::new();
+        fixture.transfer(&holder.pubkey(), 10.0).await.unwrap();
+        fixture
+            .create_ata(&vault.vrt_mint, &holder.pubkey())
+            .await
+            .unwrap();
+        fixture
+            .transfer_token(
+                &spl_token::id(),
+                &depositor,
+                &holder.pubkey(),
+                &vault.vrt_mint,
+                MINT_AMOUNT,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &holder, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_enqueue_withdrawal_claim(&vault_root, &holder, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            fixture
+                .get_token_account(&get_associated_token_address(
+                    &holder.pubkey(),
+                    &vault.vrt_mint
+                ))
+                .await
+                .unwrap()
+                .amount,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_of_vrt_deposited_before_holding_period() {
+        let (_fixture, mut vault_program_client, _config, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 1)
+            .await
+            .unwrap();
+
+        // The depositor has no deposit receipt yet
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        // Depositing again only holds the VRT of the new deposit
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        let vault_deposit_receipt = vault_program_client
+            .get_vault_deposit_receipt(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap();
+        assert_eq!(vault_deposit_receipt.vrt_amount(), MINT_AMOUNT);
+
+        let result = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultHoldingPeriodNotElapsed);
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_withdrawal_before_holding_period_fails() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 2)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let vault_deposit_receipt = vault_program_client
+            .get_vault_deposit_receipt(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap();
+        assert_eq!(vault_deposit_receipt.vault, vault_root.vault_pubkey);
+        assert_eq!(vault_deposit_receipt.depositor, depositor.pubkey());
+        assert_eq!(vault_deposit_receipt.last_deposit_slot(), slot);
+        assert_eq!(vault_deposit_receipt.vrt_amount(), MINT_AMOUNT);
+
+        let result = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultHoldingPeriodNotElapsed);
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultHoldingPeriodNotElapsed);
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_new_deposit_restarts_holding_period() {
+        let (mut fixture, mut vault_program_client, config, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 1)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        // The vault has no operators, so the deposit updates it in place
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let vault_deposit_receipt = vault_program_client
+            .get_vault_deposit_receipt(&vault_root.vault_pubkey, &depositor.pubkey())
+            .await
+            .unwrap();
+        assert_eq!(vault_deposit_receipt.last_deposit_slot(), slot);
+        assert_eq!(vault_deposit_receipt.vrt_amount(), MINT_AMOUNT);
+
+        // Only the VRT of the first deposit, whose holding period elapsed, can be withdrawn
+        let result = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, 2 * MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultHoldingPeriodNotElapsed);
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_removing_holding_period_allows_withdrawal() {
+        let (_fixture, mut vault_program_client, _config, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 5)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.vrt_enqueued_for_cooldown_amount(), MINT_AMOUNT);
+        assert_eq!(
+            vault.vrt_supply(),
+            MINT_AMOUNT + Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    /// Sets up a vault with a [`jito_vault_core::vault_ncn_ticket::VaultNcnTicket`] for a new NCN
+    async fn setup() -> (TestBuilder, VaultProgramClient, VaultRoot, Pubkey) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        (
+            fixture,
+            vault_program_client,
+            vault_root,
+            ncn_root.ncn_pubkey,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_allocation_cap_ok() {
+        let (_fixture, mut vault_program_client, vault_root, ncn) = setup().await;
+
+        let vault_ncn_ticket = vault_program_client
+            .get_vault_ncn_ticket(&vault_root.vault_pubkey, &ncn)
+            .await
+            .unwrap();
+        assert_eq!(vault_ncn_ticket.max_allocation_bps(), 0);
+
+        vault_program_client
+            .set_ncn_allocation_cap(
+                &vault_root.vault_pubkey,
+                &ncn,
+                &vault_root.vault_admin,
+                4_000,
+            )
+            .await
+            .unwrap();
+
+        let vault_ncn_ticket = vault_program_client
+            .get_vault_ncn_ticket(&vault_root.vault_pubkey, &ncn)
+            .await
+            .unwrap();
+        assert_eq!(vault_ncn_ticket.max_allocation_bps(), 4_000);
+        assert_eq!(vault_ncn_ticket.allocate(100_000).unwrap(), 40_000);
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_allocation_cap_invalid_fails() {
+        let (_fixture, mut vault_program_client, vault_root, ncn) = setup().await;
+
+        let response = vault_program_client
+            .set_ncn_allocation_cap(
+                &vault_root.vault_pubkey,
+                &ncn,
+                &vault_root.vault_admin,
+                10_001,
+            )
+            .await;
+
+        assert_vault_error(response, VaultError::VaultNcnAllocationCapInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_ncn_allocation_cap_with_bad_admin_fails() {
+        let (_fixture, mut vault_program_client, vault_root, ncn) = setup().await;
+
+        let bad_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&bad_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_ncn_allocation_cap(&vault_root.vault_pubkey, &ncn, &bad_admin, 4_000)
+            .await;
+
+        assert_vault_error(response, VaultError::VaultNcnAdminInvalid);
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::assert_vault_error,
+    };
+
+    #[tokio::test]
+    async fn test_set_operator_delegation_cap_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_operator_delegation_cap(
+                &vault_root.vault_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &vault_root.vault_admin,
+                1_000,
+            )
+            .await
+            .unwrap();
+
+        let vault_operator_delegation = vault_program_client
+            .get_vault_operator_delegation(
+                &vault_root.vault_pubkey,
+                &operator_roots[0].operator_pubkey,
+            )
+            .await
+            .unwrap();
+        assert_eq!(vault_operator_delegation.max_delegation_amount(), 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_set_operator_delegation_cap_with_bad_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+
+        let bad_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&bad_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+
+        let response = vault_program_client
+            .set_operator_delegation_cap(
+                &vault_root.vault_pubkey,
+                &operator_roots[0].operator_pubkey,
+                &bad_admin,
+                1_000,
+            )
+            .await;
+
+        assert_vault_error(response, VaultError::VaultAdminInvalid);
+    }
+}
@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, MAX_BPS};
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const CONFIG_PROGRAM_FEE_BPS: u16 = 10;
+
+    /// Sets up a vault without operators and a config program fee of [`CONFIG_PROGRAM_FEE_BPS`]
+    async fn setup() -> (TestBuilder, VaultProgramClient, Config, Keypair, VaultRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        vault_program_client
+            .set_program_fee(&config_admin, CONFIG_PROGRAM_FEE_BPS)
+            .await
+            .unwrap();
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        (
+            fixture,
+            vault_program_client,
+            config,
+            config_admin,
+            vault_root,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_set_program_fee_override_applied_on_update() {
+        let (mut fixture, mut vault_program_client, config, config_admin, vault_root) =
+            setup().await;
+
+        vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &config_admin, Some(3))
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.program_fee_override_bps(), Some(3));
+        // the program fee only changes on the next vault update
+        assert_eq!(vault.program_fee_bps(), 0);
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.program_fee_bps(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_set_program_fee_override_zero() {
+        let (mut fixture, mut vault_program_client, config, config_admin, vault_root) =
+            setup().await;
+
+        vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &config_admin, Some(0))
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.program_fee_override_bps(), Some(0));
+        assert_eq!(vault.program_fee_bps(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_clear_program_fee_override_falls_back_to_config() {
+        let (mut fixture, mut vault_program_client, config, config_admin, vault_root) =
+            setup().await;
+
+        vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &config_admin, Some(3))
+            .await
+            .unwrap();
+
+        // the override can only be changed again after a full epoch
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &config_admin, None)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &[])
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.program_fee_override_bps(), None);
+        assert_eq!(vault.program_fee_bps(), CONFIG_PROGRAM_FEE_BPS);
+    }
+
+    #[tokio::test]
+    async fn test_set_program_fee_override_too_soon_fails() {
+        let (mut fixture, mut vault_program_client, config, config_admin, vault_root) =
+            setup().await;
+
+        vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &config_admin, Some(3))
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &config_admin, Some(4))
+            .await;
+        assert_vault_error(result, VaultError::VaultProgramFeeOverrideChangeTooSoon);
+    }
+
+    #[tokio::test]
+    async fn test_set_program_fee_override_bad_admin_fails() {
+        let (mut fixture, mut vault_program_client, _config, _config_admin, vault_root) =
+            setup().await;
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        // the vault admin can't override the program fee either
+        let result = vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &vault_root.vault_admin, Some(3))
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+
+        let result = vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &bad_admin, Some(3))
+            .await;
+        assert_vault_error(result, VaultError::ConfigAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_program_fee_override_above_max_fails() {
+        let (_fixture, mut vault_program_client, _config, config_admin, vault_root) = setup().await;
+
+        let result = vault_program_client
+            .set_program_fee_override(&vault_root.vault_pubkey, &config_admin, Some(MAX_BPS + 1))
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeCapExceeded);
+    }
+}
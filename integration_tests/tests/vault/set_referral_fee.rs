@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::MAX_BPS;
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{fixture::TestBuilder, vault_client::assert_vault_error};
+
+    #[tokio::test]
+    async fn test_set_referral_fee_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_referral_fee(&vault_root.vault_pubkey, &vault_root.vault_admin, 2_500)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.referral_fee_bps(), 2_500);
+    }
+
+    #[tokio::test]
+    async fn test_set_referral_fee_bad_admin_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = vault_program_client
+            .set_referral_fee(&vault_root.vault_pubkey, &bad_admin, 2_500)
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_set_referral_fee_above_max_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .set_referral_fee(
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                MAX_BPS + 1,
+            )
+            .await;
+        assert_vault_error(result, VaultError::VaultFeeCapExceeded);
+    }
+}
@@ -28,6 +28,7 @@ mod tests {
             vault_config_admin: _,
             vault_root,
             restaking_config_admin: _,
+            ncn_root: _,
             operator_roots,
         } = fixture
             .setup_vault_with_ncn_and_operators(
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{config::Config, delegation_state::DelegationState, vault::Vault};
+    use jito_vault_sdk::error::VaultError;
+    use solana_program::{instruction::InstructionError, pubkey::Pubkey};
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        assert_ix_error,
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a vault with `num_operators` operators, `MINT_AMOUNT` deposited and split evenly
+    /// across the first two operators, then warps to the next epoch so the vault needs an update
+    async fn setup(
+        num_operators: u16,
+    ) -> (
+        TestBuilder,
+        VaultProgramClient,
+        VaultRoot,
+        Vec<Pubkey>,
+        Keypair,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, num_operators, &[])
+            .await
+            .unwrap();
+        let operators: Vec<Pubkey> = operator_roots
+            .iter()
+            .map(|operator_root| operator_root.operator_pubkey)
+            .collect();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+        for operator in operators.iter().take(2) {
+            vault_program_client
+                .do_add_delegation(&vault_root, operator, MINT_AMOUNT / 2)
+                .await
+                .unwrap();
+        }
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        (
+            fixture,
+            vault_program_client,
+            vault_root,
+            operators,
+            depositor,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_full_ok() {
+        let (mut fixture, mut vault_program_client, vault_root, operators, _depositor) =
+            setup(3).await;
+
+        // The delegations can be passed in any order
+        let operators: Vec<Pubkey> = operators.into_iter().rev().collect();
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        let slot = fixture.get_current_slot().await.unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.last_start_state_update_slot(), slot);
+        assert_eq!(vault.last_full_state_update_slot(), slot);
+        assert_eq!(
+            vault.delegation_state,
+            DelegationState::new(MINT_AMOUNT, 0, 0)
+        );
+
+        for operator in operators.iter() {
+            let vault_operator_delegation = vault_program_client
+                .get_vault_operator_delegation(&vault_root.vault_pubkey, operator)
+                .await
+                .unwrap();
+            assert_eq!(vault_operator_delegation.last_update_slot(), slot);
+        }
+
+        // The vault is up-to-date, so a second update is rejected
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &operators)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsUpdated);
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_full_unstakes_for_withdrawals() {
+        let (mut fixture, mut vault_program_client, vault_root, operators, depositor) =
+            setup(2).await;
+
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT / 4)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.additional_assets_need_unstaking(), 0);
+        // The undelegated initialization tokens cover part of the withdrawal
+        assert_eq!(
+            vault.delegation_state.staked_amount(),
+            MINT_AMOUNT - (MINT_AMOUNT / 4 - Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT)
+        );
+        assert_eq!(
+            vault.delegation_state.total_security().unwrap(),
+            MINT_AMOUNT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_full_missing_delegation_fails() {
+        let (_fixture, mut vault_program_client, vault_root, operators, _depositor) =
+            setup(3).await;
+
+        let result = vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &operators[..2])
+            .await;
+        assert_ix_error(result, InstructionError::NotEnoughAccountKeys);
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_full_duplicate_delegation_fails() {
+        let (_fixture, mut vault_program_client, vault_root, operators, _depositor) =
+            setup(2).await;
+
+        let result = vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &[operators[0], operators[0]])
+            .await;
+        assert_vault_error(result, VaultError::VaultOperatorDelegationIsUpdated);
+    }
+
+    #[tokio::test]
+    async fn test_update_vault_full_too_many_operators_fails() {
+        let (_fixture, mut vault_program_client, vault_root, operators, _depositor) =
+            setup(Vault::MAX_FULL_UPDATE_OPERATORS as u16 + 1).await;
+
+        let result = vault_program_client
+            .do_update_vault_full(&vault_root.vault_pubkey, &operators[..1])
+            .await;
+        assert_vault_error(result, VaultError::VaultTooManyOperatorsForFullUpdate);
+
+        // The vault can still be updated with the VaultUpdateStateTracker
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &operators)
+            .await
+            .unwrap();
+    }
+}
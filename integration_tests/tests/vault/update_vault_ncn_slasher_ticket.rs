@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::config::Config;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::fixture::TestBuilder;
+
+    const MAX_SLASHABLE_PER_EPOCH: u64 = 1_000;
+
+    #[tokio::test]
+    async fn test_ncn_set_max_slashable_per_epoch_updates_vault_ncn_slasher_ticket() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_ticket(&vault_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let slasher = Keypair::new().pubkey();
+        restaking_program_client
+            .do_initialize_ncn_vault_slasher_ticket(
+                &ncn_root,
+                &vault_root.vault_pubkey,
+                &slasher,
+                MAX_SLASHABLE_PER_EPOCH,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_ncn_slasher_ticket(&vault_root, &ncn_root.ncn_pubkey, &slasher)
+            .await
+            .unwrap();
+
+        // a decrease reaches the vault in the same instruction
+        restaking_program_client
+            .ncn_set_max_slashable_per_epoch(
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+                &slasher,
+                &ncn_root.ncn_admin,
+                MAX_SLASHABLE_PER_EPOCH / 2,
+            )
+            .await
+            .unwrap();
+
+        let vault_ncn_slasher_ticket = vault_program_client
+            .get_vault_ncn_slasher_ticket(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey, &slasher)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_ncn_slasher_ticket.stored_max_slashable_per_epoch(),
+            MAX_SLASHABLE_PER_EPOCH / 2
+        );
+
+        // an increase is mirrored as pending and only applies once its epoch is reached
+        restaking_program_client
+            .ncn_set_max_slashable_per_epoch(
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+                &slasher,
+                &ncn_root.ncn_admin,
+                MAX_SLASHABLE_PER_EPOCH * 2,
+            )
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        vault_program_client
+            .update_vault_ncn_slasher_ticket(
+                &vault_root.vault_pubkey,
+                &ncn_root.ncn_pubkey,
+                &slasher,
+            )
+            .await
+            .unwrap();
+
+        let ncn_vault_slasher_ticket = restaking_program_client
+            .get_ncn_vault_slasher_ticket(&ncn_root.ncn_pubkey, &vault_root.vault_pubkey, &slasher)
+            .await
+            .unwrap();
+        let vault_ncn_slasher_ticket = vault_program_client
+            .get_vault_ncn_slasher_ticket(&vault_root.vault_pubkey, &ncn_root.ncn_pubkey, &slasher)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault_ncn_slasher_ticket.stored_max_slashable_per_epoch(),
+            ncn_vault_slasher_ticket.stored_max_slashable_per_epoch()
+        );
+        assert_eq!(
+            vault_ncn_slasher_ticket.next_max_slashable_per_epoch(),
+            MAX_SLASHABLE_PER_EPOCH * 2
+        );
+        assert_eq!(
+            vault_ncn_slasher_ticket.next_max_slashable_epoch(),
+            ncn_vault_slasher_ticket.next_max_slashable_epoch()
+        );
+
+        let epoch_length = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap()
+            .epoch_length();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            vault_ncn_slasher_ticket
+                .current_max_slashable_per_epoch(slot, epoch_length)
+                .unwrap(),
+            MAX_SLASHABLE_PER_EPOCH / 2
+        );
+        fixture
+            .warp_slot_incremental(2 * epoch_length)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert_eq!(
+            vault_ncn_slasher_ticket
+                .current_max_slashable_per_epoch(slot, epoch_length)
+                .unwrap(),
+            MAX_SLASHABLE_PER_EPOCH * 2
+        );
+    }
+}
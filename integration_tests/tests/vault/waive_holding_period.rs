@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_sdk::error::VaultError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        vault_client::{assert_vault_error, VaultProgramClient, VaultRoot},
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a vault without operators with a holding period of 10 epochs, and a depositor
+    /// holding `MINT_AMOUNT` VRT which is still locked up
+    async fn setup() -> (TestBuilder, VaultProgramClient, VaultRoot, Keypair) {
+        let fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .set_min_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin, 10)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        (fixture, vault_program_client, vault_root, depositor)
+    }
+
+    #[tokio::test]
+    async fn test_waive_holding_period_ok() {
+        let (_fixture, mut vault_program_client, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+        vault_program_client
+            .waive_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.is_holding_period_waived());
+        assert_eq!(vault.min_holding_epochs(), 10);
+        assert_eq!(vault.effective_min_holding_epochs(), 0);
+
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_winding_down_keeps_holding_period_until_waived() {
+        let (_fixture, mut vault_program_client, vault_root, depositor) = setup().await;
+
+        vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultHoldingPeriodNotElapsed);
+    }
+
+    #[tokio::test]
+    async fn test_waive_holding_period_not_winding_down_fails() {
+        let (_fixture, mut vault_program_client, vault_root, _depositor) = setup().await;
+
+        let result = vault_program_client
+            .waive_holding_period(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsNotWindingDown);
+    }
+
+    #[tokio::test]
+    async fn test_waive_holding_period_bad_admin_fails() {
+        let (mut fixture, mut vault_program_client, vault_root, _depositor) = setup().await;
+
+        vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        let bad_admin = Keypair::new();
+        fixture.transfer(&bad_admin.pubkey(), 1.0).await.unwrap();
+
+        let result = vault_program_client
+            .waive_holding_period(&vault_root.vault_pubkey, &bad_admin)
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+}
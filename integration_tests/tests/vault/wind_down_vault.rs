@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
+    };
+    use jito_vault_sdk::{error::VaultError, instruction::VaultAdminRole};
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::{
+            assert_vault_error, VaultProgramClient, VaultRoot, VaultStakerWithdrawalTicketRoot,
+        },
+    };
+
+    const MINT_AMOUNT: u64 = 100_000;
+
+    /// Sets up a vault with a single operator, a depositor holding `MINT_AMOUNT` VRT and all of the
+    /// deposited assets delegated to the operator
+    async fn setup() -> (TestBuilder, VaultProgramClient, VaultRoot, Pubkey, Keypair) {
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        (
+            fixture,
+            vault_program_client,
+            vault_root,
+            operator,
+            depositor,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_wind_down_vault_bad_admin_fails() {
+        let (_fixture, mut vault_program_client, vault_root, _operator, _depositor) = setup().await;
+
+        let bad_admin = Keypair::new();
+        vault_program_client
+            .airdrop(&bad_admin.pubkey(), 10.0)
+            .await
+            .unwrap();
+
+        let result = vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &bad_admin)
+            .await;
+        assert_vault_error(result, VaultError::VaultAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_wind_down_vault_twice_fails() {
+        let (mut fixture, mut vault_program_client, vault_root, _operator, _depositor) =
+            setup().await;
+
+        vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert!(vault.is_winding_down());
+
+        // Avoid resending the exact same transaction
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let result = vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsWindingDown);
+    }
+
+    #[tokio::test]
+    async fn test_wind_down_vault_rejects_deposits_and_delegation() {
+        let (_fixture, mut vault_program_client, vault_root, operator, depositor) = setup().await;
+
+        vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        let result = vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsWindingDown);
+
+        let result = vault_program_client
+            .do_add_delegation(&vault_root, &operator, 1)
+            .await;
+        assert_vault_error(result, VaultError::VaultIsWindingDown);
+    }
+
+    #[tokio::test]
+    async fn test_close_vault_not_winding_down_fails() {
+        let (_fixture, mut vault_program_client, vault_root, _operator, _depositor) = setup().await;
+
+        let result = vault_program_client.do_close_vault(&vault_root, &[]).await;
+        assert_vault_error(result, VaultError::VaultIsNotWindingDown);
+    }
+
+    #[tokio::test]
+    async fn test_close_vault_with_outstanding_vrt_fails() {
+        let (mut fixture, mut vault_program_client, vault_root, operator, _depositor) =
+            setup().await;
+
+        vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        let result = vault_program_client.do_close_vault(&vault_root, &[]).await;
+        assert_vault_error(result, VaultError::VaultHasOutstandingVrt);
+    }
+
+    #[tokio::test]
+    async fn test_wind_down_and_close_vault() {
+        let (mut fixture, mut vault_program_client, vault_root, operator, depositor) =
+            setup().await;
+
+        // The mint burn admin is no longer required once the vault is winding down
+        let mint_burn_admin = Keypair::new();
+        vault_program_client
+            .set_secondary_admin(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &vault_root.vault_admin,
+                &mint_burn_admin.pubkey(),
+                VaultAdminRole::MintBurnAdmin,
+            )
+            .await
+            .unwrap();
+
+        vault_program_client
+            .wind_down_vault(&vault_root.vault_pubkey, &vault_root.vault_admin)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+
+        // The update forces every delegation into cooldown without any delegation admin action
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(vault.delegation_state.staked_amount(), 0);
+        assert_eq!(vault.delegation_state.cooling_down_amount(), MINT_AMOUNT);
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_burn_withdrawal_ticket(
+                &vault_root,
+                &depositor,
+                &base,
+                &config.program_fee_wallet,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            vault.vrt_supply(),
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert_eq!(vault.delegation_state.total_security().unwrap(), 0);
+
+        let admin_token_account =
+            get_associated_token_address(&vault_root.vault_admin.pubkey(), &vault.supported_mint);
+        let admin_balance_before = fixture
+            .get_token_account(&admin_token_account)
+            .await
+            .unwrap()
+            .amount;
+
+        // Leave a tracker open from a previous epoch so the vault closes it
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            slot / config.epoch_length(),
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+
+        vault_program_client
+            .do_close_vault(&vault_root, &[vault_update_state_tracker])
+            .await
+            .unwrap();
+        assert!(fixture
+            .get_account(&vault_update_state_tracker)
+            .await
+            .unwrap()
+            .is_none());
+
+        // The tokens backing the locked VRT are returned to the admin
+        let admin_balance_after = fixture
+            .get_token_account(&admin_token_account)
+            .await
+            .unwrap()
+            .amount;
+        assert_eq!(
+            admin_balance_after - admin_balance_before,
+            Vault::DEFAULT_INITIALIZATION_TOKEN_AMOUNT
+        );
+        assert!(fixture
+            .get_token_account(&get_associated_token_address(
+                &vault_root.vault_pubkey,
+                &vault.supported_mint,
+            ))
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,301 @@
+//! Read-only checks of whether an operator is active for an NCN.
+//!
+//! An operator is active for an NCN with a vault once all six handshakes between the three of
+//! them are in place: the NCN and the operator opted in to each other through the
+//! [`NcnOperatorState`], the NCN and the vault through the [`NcnVaultTicket`] and
+//! [`VaultNcnTicket`], and the operator and the vault through the [`OperatorVaultTicket`] and
+//! [`VaultOperatorDelegation`]. The vault operator delegation has no toggle, so its existence is
+//! the vault's opt-in.
+
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    config::Config as VaultConfig, vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// Whether the operator is active for the NCN at `slot`, which requires both of them to have
+/// opted in to each other and the operator to not be jailed.
+///
+/// # Arguments
+/// * `restaking_program_id` - The restaking program
+/// * `accounts` - `[config, ncn, operator, ncn_operator_state]`, loaded read-only
+/// * `slot` - The slot to check the operator at
+pub fn check_operator_active(
+    restaking_program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slot: u64,
+) -> Result<bool, ProgramError> {
+    let [config, ncn, operator, ncn_operator_state] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(restaking_program_id, config, false)?;
+    Ncn::load(restaking_program_id, ncn, false)?;
+    Operator::load(restaking_program_id, operator, false)?;
+    NcnOperatorState::load(
+        restaking_program_id,
+        ncn_operator_state,
+        ncn,
+        operator,
+        false,
+    )?;
+
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+
+    ncn_operator_state.is_active(slot, config.epoch_length())
+}
+
+/// Whether the operator is active for the NCN with the vault at `slot`, see
+/// [`is_operator_vault_active`]. The vault program is read from the restaking config.
+///
+/// # Arguments
+/// * `restaking_program_id` - The restaking program
+/// * `accounts` - `[config, vault_config, ncn, operator, vault, ncn_operator_state,
+///   ncn_vault_ticket, operator_vault_ticket, vault_ncn_ticket, vault_operator_delegation]`,
+///   loaded read-only
+/// * `slot` - The slot to check the operator at
+pub fn check_operator_vault_active(
+    restaking_program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slot: u64,
+) -> Result<bool, ProgramError> {
+    let [config, vault_config, ncn, operator, vault, ncn_operator_state, ncn_vault_ticket, operator_vault_ticket, vault_ncn_ticket, vault_operator_delegation] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(restaking_program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let vault_program_id = &config.vault_program;
+
+    VaultConfig::load(vault_program_id, vault_config, false)?;
+    Ncn::load(restaking_program_id, ncn, false)?;
+    Operator::load(restaking_program_id, operator, false)?;
+    Vault::load(vault_program_id, vault, false)?;
+    NcnOperatorState::load(
+        restaking_program_id,
+        ncn_operator_state,
+        ncn,
+        operator,
+        false,
+    )?;
+    NcnVaultTicket::load(restaking_program_id, ncn_vault_ticket, ncn, vault, false)?;
+    OperatorVaultTicket::load(
+        restaking_program_id,
+        operator_vault_ticket,
+        operator,
+        vault,
+        false,
+    )?;
+    VaultNcnTicket::load(vault_program_id, vault_ncn_ticket, vault, ncn, false)?;
+    VaultOperatorDelegation::load(
+        vault_program_id,
+        vault_operator_delegation,
+        vault,
+        operator,
+        false,
+    )?;
+
+    let vault_config_data = vault_config.data.borrow();
+    let vault_config = VaultConfig::try_from_slice_unchecked(&vault_config_data)?;
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+    let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+    let ncn_vault_ticket = NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?;
+    let operator_vault_ticket_data = operator_vault_ticket.data.borrow();
+    let operator_vault_ticket =
+        OperatorVaultTicket::try_from_slice_unchecked(&operator_vault_ticket_data)?;
+    let vault_ncn_ticket_data = vault_ncn_ticket.data.borrow();
+    let vault_ncn_ticket = VaultNcnTicket::try_from_slice_unchecked(&vault_ncn_ticket_data)?;
+    let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+
+    is_operator_vault_active(
+        ncn_operator_state,
+        ncn_vault_ticket,
+        operator_vault_ticket,
+        vault_ncn_ticket,
+        vault_operator_delegation,
+        slot,
+        config.epoch_length(),
+        vault_config.epoch_length(),
+    )
+}
+
+/// Whether the operator is active for the NCN with the vault at `slot`: every ticket between
+/// the NCN, the operator and the vault is active and the operator is not jailed.
+///
+/// The tickets shall all refer to the same NCN, operator and vault.
+///
+/// # Arguments
+/// * `slot` - The slot to check the operator at
+/// * `epoch_length` - The epoch length of the restaking program
+/// * `vault_epoch_length` - The epoch length of the vault program
+#[allow(clippy::too_many_arguments)]
+pub fn is_operator_vault_active(
+    ncn_operator_state: &NcnOperatorState,
+    ncn_vault_ticket: &NcnVaultTicket,
+    operator_vault_ticket: &OperatorVaultTicket,
+    vault_ncn_ticket: &VaultNcnTicket,
+    vault_operator_delegation: &VaultOperatorDelegation,
+    slot: u64,
+    epoch_length: u64,
+    vault_epoch_length: u64,
+) -> Result<bool, ProgramError> {
+    let ncn = &ncn_operator_state.ncn;
+    let operator = &ncn_operator_state.operator;
+    let vault = &ncn_vault_ticket.vault;
+    if ncn_vault_ticket.ncn.ne(ncn)
+        || vault_ncn_ticket.ncn.ne(ncn)
+        || vault_ncn_ticket.vault.ne(vault)
+        || operator_vault_ticket.operator.ne(operator)
+        || operator_vault_ticket.vault.ne(vault)
+        || vault_operator_delegation.operator.ne(operator)
+        || vault_operator_delegation.vault.ne(vault)
+    {
+        msg!("Tickets don't refer to the same NCN, operator and vault");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(ncn_operator_state.is_active(slot, epoch_length)?
+        && ncn_vault_ticket.state.is_active(slot, epoch_length)?
+        && operator_vault_ticket.state.is_active(slot, epoch_length)?
+        && vault_ncn_ticket.state.is_active(slot, vault_epoch_length)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH_LENGTH: u64 = 100;
+
+    struct Tickets {
+        ncn_operator_state: NcnOperatorState,
+        ncn_vault_ticket: NcnVaultTicket,
+        operator_vault_ticket: OperatorVaultTicket,
+        vault_ncn_ticket: VaultNcnTicket,
+        vault_operator_delegation: VaultOperatorDelegation,
+    }
+
+    impl Tickets {
+        fn new(ncn: Pubkey, operator: Pubkey, vault: Pubkey) -> Self {
+            Self {
+                ncn_operator_state: NcnOperatorState::new(ncn, operator, 0, 0, 0),
+                ncn_vault_ticket: NcnVaultTicket::new(ncn, vault, 0, 0, 0),
+                operator_vault_ticket: OperatorVaultTicket::new(operator, vault, 0, 0, 0),
+                vault_ncn_ticket: VaultNcnTicket::new(vault, ncn, 0, 0, 0),
+                vault_operator_delegation: VaultOperatorDelegation::new(vault, operator, 0, 0, 0),
+            }
+        }
+
+        fn activate_all(&mut self, slot: u64) {
+            self.ncn_operator_state
+                .ncn_opt_in_state
+                .activate(slot, EPOCH_LENGTH)
+                .unwrap();
+            self.ncn_operator_state
+                .operator_opt_in_state
+                .activate(slot, EPOCH_LENGTH)
+                .unwrap();
+            self.ncn_vault_ticket
+                .state
+                .activate(slot, EPOCH_LENGTH)
+                .unwrap();
+            self.operator_vault_ticket
+                .state
+                .activate(slot, EPOCH_LENGTH)
+                .unwrap();
+            self.vault_ncn_ticket
+                .state
+                .activate(slot, EPOCH_LENGTH)
+                .unwrap();
+        }
+
+        fn is_active(&self, slot: u64) -> Result<bool, ProgramError> {
+            is_operator_vault_active(
+                &self.ncn_operator_state,
+                &self.ncn_vault_ticket,
+                &self.operator_vault_ticket,
+                &self.vault_ncn_ticket,
+                &self.vault_operator_delegation,
+                slot,
+                EPOCH_LENGTH,
+                EPOCH_LENGTH,
+            )
+        }
+    }
+
+    #[test]
+    fn test_operator_vault_active_after_warmup() {
+        let mut tickets = Tickets::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        assert!(!tickets.is_active(1).unwrap());
+
+        tickets.activate_all(1);
+        assert!(!tickets.is_active(EPOCH_LENGTH).unwrap());
+        assert!(tickets.is_active(2 * EPOCH_LENGTH).unwrap());
+    }
+
+    #[test]
+    fn test_operator_vault_inactive_with_one_ticket_missing() {
+        let mut tickets = Tickets::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        tickets.activate_all(1);
+        tickets
+            .vault_ncn_ticket
+            .state
+            .deactivate(2 * EPOCH_LENGTH, EPOCH_LENGTH)
+            .unwrap();
+
+        assert!(!tickets.is_active(4 * EPOCH_LENGTH).unwrap());
+    }
+
+    #[test]
+    fn test_operator_vault_inactive_while_jailed() {
+        let mut tickets = Tickets::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        tickets.activate_all(1);
+        tickets
+            .ncn_operator_state
+            .jail(1, 4, 2 * EPOCH_LENGTH, EPOCH_LENGTH)
+            .unwrap();
+
+        assert!(!tickets.is_active(3 * EPOCH_LENGTH).unwrap());
+        assert!(tickets.is_active(4 * EPOCH_LENGTH).unwrap());
+    }
+
+    #[test]
+    fn test_operator_vault_mismatched_tickets_fail() {
+        let mut tickets = Tickets::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        tickets.activate_all(1);
+        tickets.vault_operator_delegation.operator = Pubkey::new_unique();
+
+        assert_eq!(
+            tickets.is_active(2 * EPOCH_LENGTH),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
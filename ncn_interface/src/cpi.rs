@@ -0,0 +1,283 @@
+//! Typed CPI builders for the restaking instructions an NCN program signs for.
+//!
+//! Each builder holds the accounts of one instruction and implements [`RestakingCpi`], which
+//! invokes the restaking program directly, with arbitrary signer seeds, or as the signer PDA of
+//! the program the NCN is bound to through `NcnSetProgram`.
+
+use jito_restaking_core::ncn::Ncn;
+use jito_restaking_sdk::sdk;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    program::{invoke, invoke_signed},
+};
+
+/// A restaking instruction invoked through CPI
+pub trait RestakingCpi<'info> {
+    /// The instruction passed to the restaking program
+    fn instruction(&self) -> Instruction;
+
+    /// The accounts of the instruction, including the restaking program
+    fn account_infos(&self) -> Vec<AccountInfo<'info>>;
+
+    /// The NCN the instruction acts on
+    fn ncn(&self) -> &AccountInfo<'info>;
+
+    fn invoke(&self) -> ProgramResult {
+        invoke(&self.instruction(), &self.account_infos())
+    }
+
+    fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_signed(&self.instruction(), &self.account_infos(), signers_seeds)
+    }
+
+    /// Invokes the instruction signed by the NCN program signer, see
+    /// [`Ncn::find_program_signer_address`]. Shall be called by the program the NCN is bound to.
+    ///
+    /// # Arguments
+    /// * `bump` - The bump of the NCN program signer
+    fn invoke_as_ncn_program(&self, bump: u8) -> ProgramResult {
+        let mut seeds = Ncn::program_signer_seeds(self.ncn().key);
+        seeds.push(vec![bump]);
+        let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+        self.invoke_signed(&[&seed_slices])
+    }
+}
+
+/// [`jito_restaking_sdk::instruction::RestakingInstruction::NcnWarmupOperator`]
+pub struct NcnWarmupOperatorCpi<'a, 'info> {
+    pub restaking_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub ncn: &'a AccountInfo<'info>,
+    pub operator: &'a AccountInfo<'info>,
+    pub ncn_operator_state: &'a AccountInfo<'info>,
+    pub ncn_operator_admin: &'a AccountInfo<'info>,
+    /// Only required when the NCN requires an operator bond
+    pub operator_bond: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'info> RestakingCpi<'info> for NcnWarmupOperatorCpi<'_, 'info> {
+    fn instruction(&self) -> Instruction {
+        sdk::ncn_warmup_operator(
+            self.restaking_program.key,
+            self.config.key,
+            self.ncn.key,
+            self.operator.key,
+            self.ncn_operator_state.key,
+            self.ncn_operator_admin.key,
+            self.operator_bond.map(|operator_bond| operator_bond.key),
+        )
+    }
+
+    fn account_infos(&self) -> Vec<AccountInfo<'info>> {
+        let mut account_infos = vec![
+            self.restaking_program.clone(),
+            self.config.clone(),
+            self.ncn.clone(),
+            self.operator.clone(),
+            self.ncn_operator_state.clone(),
+            self.ncn_operator_admin.clone(),
+        ];
+        account_infos.extend(self.operator_bond.cloned());
+        account_infos
+    }
+
+    fn ncn(&self) -> &AccountInfo<'info> {
+        self.ncn
+    }
+}
+
+/// [`jito_restaking_sdk::instruction::RestakingInstruction::NcnCooldownOperator`]
+pub struct NcnCooldownOperatorCpi<'a, 'info> {
+    pub restaking_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub ncn: &'a AccountInfo<'info>,
+    pub operator: &'a AccountInfo<'info>,
+    pub ncn_operator_state: &'a AccountInfo<'info>,
+    pub ncn_operator_admin: &'a AccountInfo<'info>,
+}
+
+impl<'info> RestakingCpi<'info> for NcnCooldownOperatorCpi<'_, 'info> {
+    fn instruction(&self) -> Instruction {
+        sdk::ncn_cooldown_operator(
+            self.restaking_program.key,
+            self.config.key,
+            self.ncn.key,
+            self.operator.key,
+            self.ncn_operator_state.key,
+            self.ncn_operator_admin.key,
+        )
+    }
+
+    fn account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.restaking_program.clone(),
+            self.config.clone(),
+            self.ncn.clone(),
+            self.operator.clone(),
+            self.ncn_operator_state.clone(),
+            self.ncn_operator_admin.clone(),
+        ]
+    }
+
+    fn ncn(&self) -> &AccountInfo<'info> {
+        self.ncn
+    }
+}
+
+/// [`jito_restaking_sdk::instruction::RestakingInstruction::NcnJailOperator`]
+pub struct NcnJailOperatorCpi<'a, 'info> {
+    pub restaking_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub ncn: &'a AccountInfo<'info>,
+    pub operator: &'a AccountInfo<'info>,
+    pub ncn_operator_state: &'a AccountInfo<'info>,
+    pub ncn_operator_admin: &'a AccountInfo<'info>,
+    pub reason_code: u8,
+    pub until_epoch: u64,
+}
+
+impl<'info> RestakingCpi<'info> for NcnJailOperatorCpi<'_, 'info> {
+    fn instruction(&self) -> Instruction {
+        sdk::ncn_jail_operator(
+            self.restaking_program.key,
+            self.config.key,
+            self.ncn.key,
+            self.operator.key,
+            self.ncn_operator_state.key,
+            self.ncn_operator_admin.key,
+            self.reason_code,
+            self.until_epoch,
+        )
+    }
+
+    fn account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.restaking_program.clone(),
+            self.config.clone(),
+            self.ncn.clone(),
+            self.operator.clone(),
+            self.ncn_operator_state.clone(),
+            self.ncn_operator_admin.clone(),
+        ]
+    }
+
+    fn ncn(&self) -> &AccountInfo<'info> {
+        self.ncn
+    }
+}
+
+/// [`jito_restaking_sdk::instruction::RestakingInstruction::NcnUnjailOperator`]
+pub struct NcnUnjailOperatorCpi<'a, 'info> {
+    pub restaking_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub ncn: &'a AccountInfo<'info>,
+    pub operator: &'a AccountInfo<'info>,
+    pub ncn_operator_state: &'a AccountInfo<'info>,
+    pub ncn_operator_admin: &'a AccountInfo<'info>,
+}
+
+impl<'info> RestakingCpi<'info> for NcnUnjailOperatorCpi<'_, 'info> {
+    fn instruction(&self) -> Instruction {
+        sdk::ncn_unjail_operator(
+            self.restaking_program.key,
+            self.config.key,
+            self.ncn.key,
+            self.operator.key,
+            self.ncn_operator_state.key,
+            self.ncn_operator_admin.key,
+        )
+    }
+
+    fn account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.restaking_program.clone(),
+            self.config.clone(),
+            self.ncn.clone(),
+            self.operator.clone(),
+            self.ncn_operator_state.clone(),
+            self.ncn_operator_admin.clone(),
+        ]
+    }
+
+    fn ncn(&self) -> &AccountInfo<'info> {
+        self.ncn
+    }
+}
+
+/// [`jito_restaking_sdk::instruction::RestakingInstruction::WarmupNcnVaultTicket`]
+pub struct WarmupNcnVaultTicketCpi<'a, 'info> {
+    pub restaking_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub ncn: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub ncn_vault_ticket: &'a AccountInfo<'info>,
+    pub ncn_vault_admin: &'a AccountInfo<'info>,
+}
+
+impl<'info> RestakingCpi<'info> for WarmupNcnVaultTicketCpi<'_, 'info> {
+    fn instruction(&self) -> Instruction {
+        sdk::warmup_ncn_vault_ticket(
+            self.restaking_program.key,
+            self.config.key,
+            self.ncn.key,
+            self.vault.key,
+            self.ncn_vault_ticket.key,
+            self.ncn_vault_admin.key,
+        )
+    }
+
+    fn account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.restaking_program.clone(),
+            self.config.clone(),
+            self.ncn.clone(),
+            self.vault.clone(),
+            self.ncn_vault_ticket.clone(),
+            self.ncn_vault_admin.clone(),
+        ]
+    }
+
+    fn ncn(&self) -> &AccountInfo<'info> {
+        self.ncn
+    }
+}
+
+/// [`jito_restaking_sdk::instruction::RestakingInstruction::CooldownNcnVaultTicket`]
+pub struct CooldownNcnVaultTicketCpi<'a, 'info> {
+    pub restaking_program: &'a AccountInfo<'info>,
+    pub config: &'a AccountInfo<'info>,
+    pub ncn: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub ncn_vault_ticket: &'a AccountInfo<'info>,
+    pub ncn_vault_admin: &'a AccountInfo<'info>,
+}
+
+impl<'info> RestakingCpi<'info> for CooldownNcnVaultTicketCpi<'_, 'info> {
+    fn instruction(&self) -> Instruction {
+        sdk::cooldown_ncn_vault_ticket(
+            self.restaking_program.key,
+            self.config.key,
+            self.ncn.key,
+            self.vault.key,
+            self.ncn_vault_ticket.key,
+            self.ncn_vault_admin.key,
+        )
+    }
+
+    fn account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.restaking_program.clone(),
+            self.config.clone(),
+            self.ncn.clone(),
+            self.vault.clone(),
+            self.ncn_vault_ticket.clone(),
+            self.ncn_vault_admin.clone(),
+        ]
+    }
+
+    fn ncn(&self) -> &AccountInfo<'info> {
+        self.ncn
+    }
+}
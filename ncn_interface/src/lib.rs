@@ -0,0 +1,10 @@
+//! Interface for NCN programs built on top of Jito Restaking.
+//!
+//! NCN programs can check whether an operator is eligible without reimplementing the restaking
+//! and vault account loaders, and invoke the restaking program through typed CPI builders.
+//!
+//! The crate isn't `no_std`: it depends on `solana-program`, `jito-restaking-core` and
+//! `jito-vault-core`, which all require `std`, so NCN programs using it link `std` as well.
+
+pub mod activity;
+pub mod cpi;
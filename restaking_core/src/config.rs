@@ -2,14 +2,14 @@
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::CreationPolicy};
 use shank::ShankAccount;
 use solana_program::{
     account_info::AccountInfo, clock::DEFAULT_SLOTS_PER_EPOCH, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
-const RESERVED_SPACE_LEN: usize = 263;
+const RESERVED_SPACE_LEN: usize = 222;
 
 /// The global configuration account for the restaking program. Manages
 /// program-wide settings and state.
@@ -34,8 +34,17 @@ pub struct Config {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The account receiving the creation fee under [`CreationPolicy::Fee`]
+    pub creation_fee_treasury: Pubkey,
+
+    /// The lamports paid to create an NCN or operator under [`CreationPolicy::Fee`]
+    creation_fee_lamports: PodU64,
+
+    /// Who may create NCNs and operators, see [`CreationPolicy`]
+    creation_policy: u8,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 222],
 }
 
 impl Config {
@@ -47,6 +56,9 @@ impl Config {
             ncn_count: PodU64::from(0),
             operator_count: PodU64::from(0),
             bump,
+            creation_fee_treasury: Pubkey::default(),
+            creation_fee_lamports: PodU64::from(0),
+            creation_policy: CreationPolicy::Open as u8,
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -63,6 +75,31 @@ impl Config {
         self.operator_count.into()
     }
 
+    pub fn creation_policy(&self) -> Result<CreationPolicy, ProgramError> {
+        CreationPolicy::try_from(self.creation_policy)
+    }
+
+    pub fn creation_fee_lamports(&self) -> u64 {
+        self.creation_fee_lamports.into()
+    }
+
+    /// Sets who may create NCNs and operators
+    ///
+    /// # Arguments
+    /// * `creation_policy` - The new creation policy
+    /// * `creation_fee_lamports` - The creation fee, only charged under [`CreationPolicy::Fee`]
+    /// * `creation_fee_treasury` - The creation fee recipient, only used under [`CreationPolicy::Fee`]
+    pub fn set_creation_policy(
+        &mut self,
+        creation_policy: CreationPolicy,
+        creation_fee_lamports: u64,
+        creation_fee_treasury: Pubkey,
+    ) {
+        self.creation_policy = creation_policy as u8;
+        self.creation_fee_lamports = PodU64::from(creation_fee_lamports);
+        self.creation_fee_treasury = creation_fee_treasury;
+    }
+
     pub fn increment_ncn_count(&mut self) -> Result<(), RestakingError> {
         let ncn_count = self
             .ncn_count()
@@ -156,7 +193,22 @@ mod tests {
             std::mem::size_of::<PodU64>() + // operator_count
             std::mem::size_of::<PodU64>() + // epoch_length
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<Pubkey>() + // creation_fee_treasury
+            std::mem::size_of::<PodU64>() + // creation_fee_lamports
+            std::mem::size_of::<u8>() + // creation_policy
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(config_size, sum_of_fields);
     }
+
+    #[test]
+    fn test_set_creation_policy() {
+        let mut config = Config::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        assert_eq!(config.creation_policy(), Ok(CreationPolicy::Open));
+
+        let treasury = Pubkey::new_unique();
+        config.set_creation_policy(CreationPolicy::Allowlist, 0, treasury);
+        assert_eq!(config.creation_policy(), Ok(CreationPolicy::Allowlist));
+        assert_eq!(config.creation_fee_lamports(), 0);
+        assert_eq!(config.creation_fee_treasury, treasury);
+    }
 }
@@ -0,0 +1,56 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::creation_policy::CreatorAllowlistEntryAccount;
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`CreatorAllowlistEntry`] account
+///
+/// - allows its creator to initialize NCNs and operators while the config's creation policy is
+///   [`jito_jsm_core::creation_policy::CreationPolicy::Allowlist`].
+/// - is created and closed by the config admin.
+/// - shares its PDA and loading logic with the other program's entry through
+///   [`CreatorAllowlistEntryAccount`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct CreatorAllowlistEntry {
+    /// The allowlisted creator
+    pub creator: Pubkey,
+
+    /// The bump seed used to create the PDA
+    pub bump: u8,
+
+    // Shank only parses literal array lengths, so the assertion below keeps this in sync
+    reserved: [u8; 263],
+}
+
+const _: () = assert!(
+    size_of::<CreatorAllowlistEntry>()
+        == size_of::<Pubkey>() + size_of::<u8>() + RESERVED_SPACE_LEN
+);
+
+impl CreatorAllowlistEntryAccount for CreatorAllowlistEntry {
+    fn new(creator: Pubkey, bump: u8) -> Self {
+        Self {
+            creator,
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creator_allowlist_entry_no_padding() {
+        let creator_allowlist_entry_size = std::mem::size_of::<CreatorAllowlistEntry>();
+        let sum_of_fields = size_of::<Pubkey>() + // creator
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(creator_allowlist_entry_size, sum_of_fields);
+    }
+}
@@ -1,9 +1,11 @@
-use jito_bytemuck::Discriminator;
+use jito_bytemuck::{Discriminator, Migrate};
 
 use crate::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    config::Config, creator_allowlist_entry::CreatorAllowlistEntry, epoch_snapshot::EpochSnapshot,
+    ncn::Ncn, ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
     ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
-    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+    operator::Operator, operator_metadata::OperatorMetadata, operator_snapshot::OperatorSnapshot,
+    operator_vault_ticket::OperatorVaultTicket,
 };
 
 /// Discriminators for restaking accounts
@@ -17,6 +19,11 @@ pub enum RestakingDiscriminator {
     OperatorVaultTicket = 5,
     NcnVaultTicket = 6,
     NcnVaultSlasherTicket = 7,
+    CreatorAllowlistEntry = 8,
+    NcnMetadata = 9,
+    OperatorMetadata = 10,
+    EpochSnapshot = 11,
+    OperatorSnapshot = 12,
 }
 
 impl Discriminator for Config {
@@ -46,3 +53,39 @@ impl Discriminator for NcnVaultTicket {
 impl Discriminator for NcnVaultSlasherTicket {
     const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnVaultSlasherTicket as u8;
 }
+
+impl Discriminator for CreatorAllowlistEntry {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::CreatorAllowlistEntry as u8;
+}
+
+impl Discriminator for NcnMetadata {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnMetadata as u8;
+}
+
+impl Discriminator for OperatorMetadata {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::OperatorMetadata as u8;
+}
+
+impl Discriminator for EpochSnapshot {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::EpochSnapshot as u8;
+}
+
+impl Discriminator for OperatorSnapshot {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::OperatorSnapshot as u8;
+}
+
+// All accounts are still at their first layout version. When a layout grows, implement
+// `AccountDeserialize` by hand with a bumped `LAYOUT_VERSION` and override
+// `Migrate::layout_len` and `Migrate::migrate_layout` for the account.
+impl Migrate for Config {}
+impl Migrate for Ncn {}
+impl Migrate for Operator {}
+impl Migrate for NcnOperatorState {}
+impl Migrate for OperatorVaultTicket {}
+impl Migrate for NcnVaultTicket {}
+impl Migrate for NcnVaultSlasherTicket {}
+impl Migrate for CreatorAllowlistEntry {}
+impl Migrate for NcnMetadata {}
+impl Migrate for OperatorMetadata {}
+impl Migrate for EpochSnapshot {}
+impl Migrate for OperatorSnapshot {}
@@ -0,0 +1,302 @@
+//! The EpochSnapshot account records the stake snapshot of an NCN for one epoch
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_restaking_sdk::error::RestakingError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::operator_snapshot::MAX_SNAPSHOT_VAULTS;
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`EpochSnapshot`] account
+///
+/// - is created permissionlessly once per NCN and epoch, fixing the operators and vaults of the
+///   NCN that take part in the snapshot.
+/// - tracks how many [`crate::operator_snapshot::OperatorSnapshot`] accounts are complete. Once
+///   every operator is snapshotted, the snapshot is complete and NCN programs can consume it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct EpochSnapshot {
+    /// The NCN
+    pub ncn: Pubkey,
+
+    /// The epoch of the snapshot
+    epoch: PodU64,
+
+    /// The slot the snapshot was created
+    slot_created: PodU64,
+
+    /// The number of operators of the NCN when the snapshot was created
+    operator_count: PodU64,
+
+    /// The number of vaults of the NCN when the snapshot was created
+    vault_count: PodU64,
+
+    /// The number of operators whose snapshot is complete
+    operators_snapshotted: PodU64,
+
+    /// The number of operators active for the NCN during the epoch
+    active_operator_count: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl EpochSnapshot {
+    pub fn new(
+        ncn: Pubkey,
+        epoch: u64,
+        slot_created: u64,
+        operator_count: u64,
+        vault_count: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            ncn,
+            epoch: PodU64::from(epoch),
+            slot_created: PodU64::from(slot_created),
+            operator_count: PodU64::from(operator_count),
+            vault_count: PodU64::from(vault_count),
+            operators_snapshotted: PodU64::from(0),
+            active_operator_count: PodU64::from(0),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
+    pub fn operator_count(&self) -> u64 {
+        self.operator_count.into()
+    }
+
+    pub fn vault_count(&self) -> u64 {
+        self.vault_count.into()
+    }
+
+    pub fn operators_snapshotted(&self) -> u64 {
+        self.operators_snapshotted.into()
+    }
+
+    pub fn active_operator_count(&self) -> u64 {
+        self.active_operator_count.into()
+    }
+
+    /// Whether every operator of the NCN has been snapshotted
+    pub fn is_complete(&self) -> bool {
+        self.operators_snapshotted() == self.operator_count()
+    }
+
+    /// Checks the number of vaults of an NCN fits in an operator snapshot
+    ///
+    /// # Errors
+    /// * [`RestakingError::EpochSnapshotTooManyVaults`] - If the NCN has more than
+    ///   [`MAX_SNAPSHOT_VAULTS`] vaults
+    pub fn check_vault_count(vault_count: u64) -> Result<(), RestakingError> {
+        if vault_count > MAX_SNAPSHOT_VAULTS as u64 {
+            msg!(
+                "NCN has {} vaults, the snapshot supports at most {}",
+                vault_count,
+                MAX_SNAPSHOT_VAULTS
+            );
+            return Err(RestakingError::EpochSnapshotTooManyVaults);
+        }
+        Ok(())
+    }
+
+    /// Checks an operator, given by its [`crate::ncn_operator_state::NcnOperatorState`] index,
+    /// was part of the NCN when the snapshot was created
+    ///
+    /// # Errors
+    /// * [`RestakingError::EpochSnapshotOperatorNotIncluded`] - If the operator joined the NCN
+    ///   after the snapshot was created
+    pub fn check_operator_index(&self, index: u64) -> Result<(), RestakingError> {
+        if index >= self.operator_count() {
+            msg!("Operator index {} is not part of the snapshot", index);
+            return Err(RestakingError::EpochSnapshotOperatorNotIncluded);
+        }
+        Ok(())
+    }
+
+    /// Checks a vault, given by its [`crate::ncn_vault_ticket::NcnVaultTicket`] index, was part
+    /// of the NCN when the snapshot was created
+    ///
+    /// # Errors
+    /// * [`RestakingError::EpochSnapshotVaultNotIncluded`] - If the vault joined the NCN after
+    ///   the snapshot was created
+    pub fn check_vault_index(&self, index: u64) -> Result<(), RestakingError> {
+        if index >= self.vault_count() {
+            msg!("Vault index {} is not part of the snapshot", index);
+            return Err(RestakingError::EpochSnapshotVaultNotIncluded);
+        }
+        Ok(())
+    }
+
+    /// Records an operator whose snapshot is complete
+    ///
+    /// # Arguments
+    /// * `is_active` - Whether the operator was active for the NCN during the epoch
+    pub fn record_operator_snapshotted(&mut self, is_active: bool) -> Result<(), RestakingError> {
+        self.operators_snapshotted = PodU64::from(
+            self.operators_snapshotted()
+                .checked_add(1)
+                .ok_or(RestakingError::ArithmeticOverflow)?,
+        );
+        if is_active {
+            self.active_operator_count = PodU64::from(
+                self.active_operator_count()
+                    .checked_add(1)
+                    .ok_or(RestakingError::ArithmeticOverflow)?,
+            );
+        }
+        Ok(())
+    }
+
+    /// Records an operator whose snapshot was deactivated because it was jailed during the epoch
+    ///
+    /// # Arguments
+    /// * `was_complete` - Whether the operator snapshot was complete, and so already recorded as
+    ///   active, before it was deactivated
+    pub fn record_operator_deactivated(
+        &mut self,
+        was_complete: bool,
+    ) -> Result<(), RestakingError> {
+        if was_complete {
+            self.active_operator_count = PodU64::from(
+                self.active_operator_count()
+                    .checked_sub(1)
+                    .ok_or(RestakingError::ArithmeticUnderflow)?,
+            );
+            Ok(())
+        } else {
+            self.record_operator_snapshotted(false)
+        }
+    }
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `ncn` - The NCN
+    /// * `epoch` - The epoch of the snapshot
+    pub fn seeds(ncn: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"epoch_snapshot".to_vec(),
+            ncn.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `ncn` - The NCN
+    /// * `epoch` - The epoch of the snapshot
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`EpochSnapshot`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `epoch_snapshot` - The [`EpochSnapshot`] account
+    /// * `ncn` - The NCN account
+    /// * `epoch` - The epoch of the snapshot
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        epoch_snapshot: &AccountInfo,
+        ncn: &AccountInfo,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if epoch_snapshot.owner.ne(program_id) {
+            msg!("Epoch snapshot has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if epoch_snapshot.data_is_empty() {
+            msg!("Epoch snapshot data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !epoch_snapshot.is_writable {
+            msg!("Epoch snapshot is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if epoch_snapshot.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Epoch snapshot discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let expected_pubkey = Self::find_program_address(program_id, ncn.key, epoch).0;
+        if epoch_snapshot.key.ne(&expected_pubkey) {
+            msg!("Epoch snapshot is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_snapshot_no_padding() {
+        let epoch_snapshot_size = std::mem::size_of::<EpochSnapshot>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<PodU64>() + // epoch
+            size_of::<PodU64>() + // slot_created
+            size_of::<PodU64>() + // operator_count
+            size_of::<PodU64>() + // vault_count
+            size_of::<PodU64>() + // operators_snapshotted
+            size_of::<PodU64>() + // active_operator_count
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(epoch_snapshot_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_record_operator_snapshotted() {
+        let mut epoch_snapshot = EpochSnapshot::new(Pubkey::new_unique(), 1, 100, 2, 1, 0);
+        assert!(!epoch_snapshot.is_complete());
+        assert!(epoch_snapshot.check_operator_index(1).is_ok());
+        assert!(epoch_snapshot.check_operator_index(2).is_err());
+        assert!(epoch_snapshot.check_vault_index(1).is_err());
+
+        epoch_snapshot.record_operator_snapshotted(true).unwrap();
+        epoch_snapshot.record_operator_snapshotted(false).unwrap();
+        assert!(epoch_snapshot.is_complete());
+        assert_eq!(epoch_snapshot.active_operator_count(), 1);
+
+        // a complete snapshot jailed afterwards no longer counts as active
+        epoch_snapshot.record_operator_deactivated(true).unwrap();
+        assert!(epoch_snapshot.is_complete());
+        assert_eq!(epoch_snapshot.active_operator_count(), 0);
+    }
+}
@@ -1,11 +1,23 @@
 pub mod config;
+pub mod creator_allowlist_entry;
 pub mod discriminators;
+pub mod epoch_snapshot;
+pub mod metadata;
 pub mod ncn;
+pub mod ncn_metadata;
 pub mod ncn_operator_state;
 pub mod ncn_vault_slasher_ticket;
 pub mod ncn_vault_ticket;
 pub mod operator;
+pub mod operator_metadata;
+pub mod operator_snapshot;
 pub mod operator_vault_ticket;
 
 // Maximum allowed fee in basis points (100%)
 pub const MAX_FEE_BPS: u16 = 10_000;
+
+// Maximum increase of the operator fee in a single fee change, in basis points
+pub const MAX_OPERATOR_FEE_BUMP_BPS: u16 = 500;
+
+// Maximum warmup or cooldown an NCN can configure for its relationships, in epochs
+pub const MAX_TRANSITION_EPOCHS: u8 = 32;
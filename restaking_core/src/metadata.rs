@@ -0,0 +1,340 @@
+//! Bounded UTF-8 fields stored by the [`crate::ncn_metadata::NcnMetadata`] and
+//! [`crate::operator_metadata::OperatorMetadata`] accounts.
+//!
+//! Fields are stored in fixed-size byte arrays, zero padded on the right.
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize};
+use jito_restaking_sdk::error::RestakingError;
+use shank::ShankType;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// The maximum length of a name in bytes
+pub const MAX_NAME_LEN: usize = 64;
+
+/// The maximum length of a website URL in bytes
+pub const MAX_WEBSITE_LEN: usize = 128;
+
+/// The maximum length of a logo URI in bytes
+pub const MAX_LOGO_URI_LEN: usize = 128;
+
+/// The maximum length of a contact in bytes
+pub const MAX_CONTACT_LEN: usize = 64;
+
+/// The public profile shared by the [`crate::ncn_metadata::NcnMetadata`] and
+/// [`crate::operator_metadata::OperatorMetadata`] accounts
+///
+/// Shank only reads literal array lengths, so the fields spell out the `MAX_*_LEN` bounds.
+/// [`MetadataFields::update`] encodes each field against its bound, which fails to compile if
+/// the two ever disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct MetadataFields {
+    /// Incremented on every change to the metadata, starting at 1 on creation
+    update_count: PodU64,
+
+    /// The slot the metadata was last changed
+    last_update_slot: PodU64,
+
+    /// The name, zero padded UTF-8
+    name: [u8; 64],
+
+    /// The website, zero padded UTF-8
+    website: [u8; 128],
+
+    /// The URI of the logo, zero padded UTF-8
+    logo_uri: [u8; 128],
+
+    /// How to contact the owner, zero padded UTF-8
+    contact: [u8; 64],
+}
+
+impl Default for MetadataFields {
+    fn default() -> Self {
+        Self {
+            update_count: PodU64::from(0),
+            last_update_slot: PodU64::from(0),
+            name: [0; MAX_NAME_LEN],
+            website: [0; MAX_WEBSITE_LEN],
+            logo_uri: [0; MAX_LOGO_URI_LEN],
+            contact: [0; MAX_CONTACT_LEN],
+        }
+    }
+}
+
+impl MetadataFields {
+    pub fn update_count(&self) -> u64 {
+        self.update_count.into()
+    }
+
+    pub fn last_update_slot(&self) -> u64 {
+        self.last_update_slot.into()
+    }
+
+    pub fn name(&self) -> &str {
+        decode_metadata_field(&self.name)
+    }
+
+    pub fn website(&self) -> &str {
+        decode_metadata_field(&self.website)
+    }
+
+    pub fn logo_uri(&self) -> &str {
+        decode_metadata_field(&self.logo_uri)
+    }
+
+    pub fn contact(&self) -> &str {
+        decode_metadata_field(&self.contact)
+    }
+
+    /// Updates the given fields and increments the update count. Fields left as `None` are
+    /// unchanged.
+    ///
+    /// # Arguments
+    /// * `name` - The new name
+    /// * `website` - The new website
+    /// * `logo_uri` - The new logo URI
+    /// * `contact` - The new contact
+    /// * `slot` - The current slot
+    ///
+    /// # Errors
+    /// * [`RestakingError::MetadataFieldInvalid`] - If any field is too long or contains a NUL
+    ///   character. No field is updated in that case.
+    pub fn update(
+        &mut self,
+        name: Option<&str>,
+        website: Option<&str>,
+        logo_uri: Option<&str>,
+        contact: Option<&str>,
+        slot: u64,
+    ) -> Result<(), RestakingError> {
+        let name = name
+            .map(|name| encode_metadata_field::<MAX_NAME_LEN>("name", name))
+            .transpose()?;
+        let website = website
+            .map(|website| encode_metadata_field::<MAX_WEBSITE_LEN>("website", website))
+            .transpose()?;
+        let logo_uri = logo_uri
+            .map(|logo_uri| encode_metadata_field::<MAX_LOGO_URI_LEN>("logo URI", logo_uri))
+            .transpose()?;
+        let contact = contact
+            .map(|contact| encode_metadata_field::<MAX_CONTACT_LEN>("contact", contact))
+            .transpose()?;
+
+        if let Some(name) = name {
+            self.name = name;
+        }
+        if let Some(website) = website {
+            self.website = website;
+        }
+        if let Some(logo_uri) = logo_uri {
+            self.logo_uri = logo_uri;
+        }
+        if let Some(contact) = contact {
+            self.contact = contact;
+        }
+
+        self.update_count = PodU64::from(
+            self.update_count()
+                .checked_add(1)
+                .ok_or(RestakingError::ArithmeticOverflow)?,
+        );
+        self.last_update_slot = PodU64::from(slot);
+
+        Ok(())
+    }
+}
+
+/// An account storing the [`MetadataFields`] of an NCN or operator at a PDA derived from its
+/// owner
+pub trait MetadataAccount: AccountDeserialize {
+    /// The seed prefix of the PDA
+    const SEED: &'static [u8];
+
+    /// The name of the account, used for logging
+    const NAME: &'static str;
+
+    fn new(owner: Pubkey, bump: u8) -> Self;
+
+    fn metadata(&self) -> &MetadataFields;
+
+    fn metadata_mut(&mut self) -> &mut MetadataFields;
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `owner` - The NCN or operator
+    fn seeds(owner: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([Self::SEED.to_vec(), owner.to_bytes().to_vec()])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `owner` - The NCN or operator
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    fn find_program_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(owner);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the metadata account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `metadata` - The metadata account
+    /// * `owner` - The NCN or operator account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    fn load(
+        program_id: &Pubkey,
+        metadata: &AccountInfo,
+        owner: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if metadata.owner.ne(program_id) {
+            msg!("{} has an invalid owner", Self::NAME);
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if metadata.data_is_empty() {
+            msg!("{} data is empty", Self::NAME);
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !metadata.is_writable {
+            msg!("{} is not writable", Self::NAME);
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if metadata.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("{} discriminator is invalid", Self::NAME);
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let expected_pubkey = Self::find_program_address(program_id, owner.key).0;
+        if metadata.key.ne(&expected_pubkey) {
+            msg!("{} is not at the correct PDA", Self::NAME);
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+/// Encodes a metadata field into a zero padded byte array
+///
+/// # Arguments
+/// * `name` - The name of the field, used for logging
+/// * `value` - The value of the field
+///
+/// # Errors
+/// * [`RestakingError::MetadataFieldInvalid`] - If the value is longer than `N` bytes or contains
+///   a NUL character, which is reserved for padding
+pub fn encode_metadata_field<const N: usize>(
+    name: &str,
+    value: &str,
+) -> Result<[u8; N], RestakingError> {
+    if value.len() > N {
+        msg!("Metadata {} exceeds the maximum of {} bytes", name, N);
+        return Err(RestakingError::MetadataFieldInvalid);
+    }
+    if value.contains('\0') {
+        msg!("Metadata {} contains a NUL character", name);
+        return Err(RestakingError::MetadataFieldInvalid);
+    }
+
+    let mut field = [0; N];
+    field[..value.len()].copy_from_slice(value.as_bytes());
+    Ok(field)
+}
+
+/// Decodes a zero padded metadata field. Fields are only written by [`encode_metadata_field`], so
+/// invalid UTF-8 decodes to an empty string.
+pub fn decode_metadata_field(field: &[u8]) -> &str {
+    let len = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    core::str::from_utf8(&field[..len]).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_metadata_field() {
+        let field: [u8; MAX_NAME_LEN] = encode_metadata_field("name", "Jito ⚡").unwrap();
+        assert_eq!(decode_metadata_field(&field), "Jito ⚡");
+
+        let field: [u8; MAX_NAME_LEN] = encode_metadata_field("name", "").unwrap();
+        assert_eq!(decode_metadata_field(&field), "");
+
+        let full = "a".repeat(MAX_NAME_LEN);
+        let field: [u8; MAX_NAME_LEN] = encode_metadata_field("name", &full).unwrap();
+        assert_eq!(decode_metadata_field(&field), full);
+    }
+
+    #[test]
+    fn test_metadata_fields_no_padding() {
+        let metadata_fields_size = std::mem::size_of::<MetadataFields>();
+        let sum_of_fields = std::mem::size_of::<PodU64>() + // update_count
+            std::mem::size_of::<PodU64>() + // last_update_slot
+            MAX_NAME_LEN + // name
+            MAX_WEBSITE_LEN + // website
+            MAX_LOGO_URI_LEN + // logo_uri
+            MAX_CONTACT_LEN; // contact
+        assert_eq!(metadata_fields_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_update_metadata_fields() {
+        let mut metadata = MetadataFields::default();
+
+        metadata
+            .update(
+                Some("NCN"),
+                Some("https://ncn.xyz"),
+                Some("https://ncn.xyz/logo.png"),
+                Some("hello@ncn.xyz"),
+                10,
+            )
+            .unwrap();
+        assert_eq!(metadata.update_count(), 1);
+        assert_eq!(metadata.last_update_slot(), 10);
+        assert_eq!(metadata.name(), "NCN");
+        assert_eq!(metadata.website(), "https://ncn.xyz");
+        assert_eq!(metadata.logo_uri(), "https://ncn.xyz/logo.png");
+        assert_eq!(metadata.contact(), "hello@ncn.xyz");
+
+        // an invalid field leaves the metadata untouched
+        let too_long = "a".repeat(MAX_NAME_LEN + 1);
+        assert!(metadata
+            .update(Some("Renamed"), None, None, Some(&too_long), 20)
+            .is_err());
+        assert_eq!(metadata.update_count(), 1);
+        assert_eq!(metadata.name(), "NCN");
+
+        metadata
+            .update(Some("Renamed"), None, None, None, 20)
+            .unwrap();
+        assert_eq!(metadata.update_count(), 2);
+        assert_eq!(metadata.last_update_slot(), 20);
+        assert_eq!(metadata.name(), "Renamed");
+        assert_eq!(metadata.website(), "https://ncn.xyz");
+    }
+
+    #[test]
+    fn test_encode_metadata_field_invalid() {
+        assert!(matches!(
+            encode_metadata_field::<MAX_NAME_LEN>("name", &"a".repeat(MAX_NAME_LEN + 1)),
+            Err(RestakingError::MetadataFieldInvalid)
+        ));
+        assert!(matches!(
+            encode_metadata_field::<MAX_NAME_LEN>("name", "a\0b"),
+            Err(RestakingError::MetadataFieldInvalid)
+        ));
+    }
+}
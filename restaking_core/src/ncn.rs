@@ -2,16 +2,27 @@
 //!
 //! - is a program-owned account that represents a network of nodes that participate in consensus.
 //! - is used to manage the operators, vaults, and slashers that are associated with the network.
+//! - can be bound to an on-chain program whose signer PDA may act as the NCN's operator, vault
+//!   and slasher admins through CPI.
+//! - can require operators to bond a minimum amount of tokens before warming up with the NCN.
 
 use std::fmt::Debug;
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_jsm_core::{
+    loader::load_associated_token_account, slot_toggle::DEFAULT_TRANSITION_EPOCHS,
+};
 use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::state::Account;
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::MAX_TRANSITION_EPOCHS;
+
+const RESERVED_SPACE_LEN: usize = 149;
 
 /// The NCN manages the operators, vaults, and slashers associated with a network
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -59,8 +70,34 @@ pub struct Ncn {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The number of full epochs operators, vaults and slashers of the NCN take to warm up,
+    /// zero meaning [`DEFAULT_TRANSITION_EPOCHS`]
+    warmup_epochs: u8,
+
+    /// The number of full epochs operators, vaults and slashers of the NCN take to cool down,
+    /// zero meaning [`DEFAULT_TRANSITION_EPOCHS`]
+    cooldown_epochs: u8,
+
+    /// The on-chain program the NCN is bound to, the default pubkey if unbound
+    pub ncn_program: Pubkey,
+
+    /// The PDA of `ncn_program` that can sign for the operator, vault and slasher admins via CPI,
+    /// see [`Ncn::find_program_signer_address`]
+    pub ncn_program_signer: Pubkey,
+
+    /// The mint operators bond in, see [`Ncn::check_operator_bond`]
+    pub operator_bond_mint: Pubkey,
+
+    /// The minimum amount an operator shall have bonded to warm up with the NCN, zero meaning no
+    /// bond is required
+    min_operator_bond: PodU64,
+
+    /// The most a slasher of the NCN can slash from an operator's bond within an epoch, in units
+    /// of `operator_bond_mint`
+    max_bond_slashable_per_epoch: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 149],
 }
 
 impl Ncn {
@@ -81,6 +118,13 @@ impl Ncn {
             vault_count: PodU64::from(0),
             slasher_count: PodU64::from(0),
             bump,
+            warmup_epochs: DEFAULT_TRANSITION_EPOCHS,
+            cooldown_epochs: DEFAULT_TRANSITION_EPOCHS,
+            ncn_program: Pubkey::default(),
+            ncn_program_signer: Pubkey::default(),
+            operator_bond_mint: Pubkey::default(),
+            min_operator_bond: PodU64::from(0),
+            max_bond_slashable_per_epoch: PodU64::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -101,6 +145,137 @@ impl Ncn {
         self.slasher_count.into()
     }
 
+    pub const fn warmup_epochs(&self) -> u8 {
+        if self.warmup_epochs == 0 {
+            DEFAULT_TRANSITION_EPOCHS
+        } else {
+            self.warmup_epochs
+        }
+    }
+
+    pub const fn cooldown_epochs(&self) -> u8 {
+        if self.cooldown_epochs == 0 {
+            DEFAULT_TRANSITION_EPOCHS
+        } else {
+            self.cooldown_epochs
+        }
+    }
+
+    /// Sets the warmup and cooldown durations applied to the operator, vault and slasher
+    /// relationships of the NCN. Relationships already warming up or cooling down keep the
+    /// duration they started with.
+    ///
+    /// # Errors
+    /// * [`RestakingError::NcnTransitionEpochsInvalid`] - If a duration is zero or above
+    ///   [`MAX_TRANSITION_EPOCHS`]
+    pub fn set_transition_epochs(
+        &mut self,
+        warmup_epochs: u8,
+        cooldown_epochs: u8,
+    ) -> Result<(), RestakingError> {
+        for epochs in [warmup_epochs, cooldown_epochs] {
+            if epochs == 0 || epochs > MAX_TRANSITION_EPOCHS {
+                msg!(
+                    "Transition epochs shall be between 1 and {}, received {}",
+                    MAX_TRANSITION_EPOCHS,
+                    epochs
+                );
+                return Err(RestakingError::NcnTransitionEpochsInvalid);
+            }
+        }
+        self.warmup_epochs = warmup_epochs;
+        self.cooldown_epochs = cooldown_epochs;
+        Ok(())
+    }
+
+    /// Binds the NCN to an on-chain program, or unbinds it when `ncn_program` is the default
+    /// pubkey. While bound, the program's signer PDA is accepted wherever the operator, vault or
+    /// slasher admin signature is required.
+    ///
+    /// # Arguments
+    /// * `ncn` - The NCN account address, used to derive the program signer
+    /// * `ncn_program` - The program to bind the NCN to
+    pub fn set_ncn_program(&mut self, ncn: &Pubkey, ncn_program: &Pubkey) {
+        if ncn_program.eq(&Pubkey::default()) {
+            self.ncn_program = Pubkey::default();
+            self.ncn_program_signer = Pubkey::default();
+        } else {
+            self.ncn_program = *ncn_program;
+            self.ncn_program_signer = Self::find_program_signer_address(ncn_program, ncn).0;
+        }
+    }
+
+    /// Returns true if the NCN is bound to a program and `signer` is that program's signer PDA
+    pub fn is_ncn_program_signer(&self, signer: &Pubkey) -> bool {
+        self.ncn_program.ne(&Pubkey::default()) && self.ncn_program_signer.eq(signer)
+    }
+
+    pub fn min_operator_bond(&self) -> u64 {
+        self.min_operator_bond.into()
+    }
+
+    pub fn max_bond_slashable_per_epoch(&self) -> u64 {
+        self.max_bond_slashable_per_epoch.into()
+    }
+
+    /// Sets the bond operators shall hold to warm up with the NCN and how much of it can be
+    /// slashed per epoch. Operators already warming up or active are not affected by a new
+    /// minimum until they warm up again.
+    ///
+    /// # Arguments
+    /// * `operator_bond_mint` - The mint operators bond in
+    /// * `min_operator_bond` - The minimum bond, zero disabling the requirement
+    /// * `max_bond_slashable_per_epoch` - The most slashable from a bond per epoch, zero
+    ///   disabling bond slashing
+    pub fn set_operator_bond(
+        &mut self,
+        operator_bond_mint: Pubkey,
+        min_operator_bond: u64,
+        max_bond_slashable_per_epoch: u64,
+    ) {
+        self.operator_bond_mint = operator_bond_mint;
+        self.min_operator_bond = PodU64::from(min_operator_bond);
+        self.max_bond_slashable_per_epoch = PodU64::from(max_bond_slashable_per_epoch);
+    }
+
+    /// Checks the operator holds the minimum bond required by the NCN, if any. The bond is held in
+    /// the associated token account of the [`crate::ncn_operator_state::NcnOperatorState`], which
+    /// only the restaking program can transfer from.
+    ///
+    /// # Arguments
+    /// * `ncn_operator_state` - The NCN operator state address, owning the bond token account
+    /// * `operator_bond` - The bond token account, only required when the NCN requires a bond
+    ///
+    /// # Errors
+    /// * [`RestakingError::NcnOperatorBondInsufficient`] - If the bond token account is missing
+    ///   or holds less than the minimum bond
+    pub fn check_operator_bond(
+        &self,
+        ncn_operator_state: &Pubkey,
+        operator_bond: Option<&AccountInfo>,
+    ) -> Result<(), ProgramError> {
+        let min_operator_bond = self.min_operator_bond();
+        if min_operator_bond == 0 {
+            return Ok(());
+        }
+        let Some(operator_bond) = operator_bond else {
+            msg!("Operator bond token account is required by the NCN");
+            return Err(RestakingError::NcnOperatorBondInsufficient.into());
+        };
+        load_associated_token_account(operator_bond, ncn_operator_state, &self.operator_bond_mint)?;
+
+        let bond = Account::unpack(&operator_bond.data.borrow())?.amount;
+        if bond < min_operator_bond {
+            msg!(
+                "Operator bond {} is below the NCN minimum {}",
+                bond,
+                min_operator_bond
+            );
+            return Err(RestakingError::NcnOperatorBondInsufficient.into());
+        }
+        Ok(())
+    }
+
     pub fn increment_operator_count(&mut self) -> Result<(), RestakingError> {
         let mut operator_count: u64 = self.operator_count.into();
         operator_count = operator_count
@@ -152,6 +327,97 @@ impl Ncn {
         Ok(())
     }
 
+    /// Validates the operator_admin account and ensures it matches the expected operator_admin
+    /// or the signer PDA of the program the NCN is bound to.
+    ///
+    /// # Arguments
+    /// * `operator_admin` - A reference to the [`Pubkey`] representing the operator_admin Pubkey
+    ///   that is attempting to authorize the operation.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnOperatorAdminInvalid`] error in the following case:
+    /// * The `operator_admin` public key matches neither the operator_admin nor the NCN program signer.
+    pub fn check_operator_admin(&self, operator_admin: &Pubkey) -> Result<(), RestakingError> {
+        if self.operator_admin.ne(operator_admin) && !self.is_ncn_program_signer(operator_admin) {
+            msg!(
+                "Incorrect operator_admin provided, expected {}, received {}",
+                self.operator_admin,
+                operator_admin
+            );
+            return Err(RestakingError::NcnOperatorAdminInvalid);
+        }
+        Ok(())
+    }
+
+    /// Validates the vault_admin account and ensures it matches the expected vault_admin
+    /// or the signer PDA of the program the NCN is bound to.
+    ///
+    /// # Arguments
+    /// * `vault_admin` - A reference to the [`Pubkey`] representing the vault_admin Pubkey
+    ///   that is attempting to authorize the operation.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnVaultAdminInvalid`] error in the following case:
+    /// * The `vault_admin` public key matches neither the vault_admin nor the NCN program signer.
+    pub fn check_vault_admin(&self, vault_admin: &Pubkey) -> Result<(), RestakingError> {
+        if self.vault_admin.ne(vault_admin) && !self.is_ncn_program_signer(vault_admin) {
+            msg!(
+                "Incorrect vault_admin provided, expected {}, received {}",
+                self.vault_admin,
+                vault_admin
+            );
+            return Err(RestakingError::NcnVaultAdminInvalid);
+        }
+        Ok(())
+    }
+
+    /// Validates the slasher_admin account and ensures it matches the expected slasher_admin
+    /// or the signer PDA of the program the NCN is bound to.
+    ///
+    /// # Arguments
+    /// * `slasher_admin` - A reference to the [`Pubkey`] representing the slasher_admin Pubkey
+    ///   that is attempting to authorize the operation.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnSlasherAdminInvalid`] error in the following case:
+    /// * The `slasher_admin` public key matches neither the slasher_admin nor the NCN program signer.
+    pub fn check_slasher_admin(&self, slasher_admin: &Pubkey) -> Result<(), RestakingError> {
+        if self.slasher_admin.ne(slasher_admin) && !self.is_ncn_program_signer(slasher_admin) {
+            msg!(
+                "Incorrect slasher_admin provided, expected {}, received {}",
+                self.slasher_admin,
+                slasher_admin
+            );
+            return Err(RestakingError::NcnSlasherAdminInvalid);
+        }
+        Ok(())
+    }
+
+    /// Validates the ncn_program_admin account and ensures it matches the expected
+    /// ncn_program_admin.
+    ///
+    /// # Arguments
+    /// * `ncn_program_admin` - A reference to the [`Pubkey`] representing the ncn_program_admin
+    ///   Pubkey that is attempting to authorize the operation.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnProgramAdminInvalid`] error in the following case:
+    /// * The `ncn_program_admin` public key does not match the expected ncn_program_admin public key stored in `self`.
+    pub fn check_ncn_program_admin(
+        &self,
+        ncn_program_admin: &Pubkey,
+    ) -> Result<(), RestakingError> {
+        if self.ncn_program_admin.ne(ncn_program_admin) {
+            msg!(
+                "Incorrect ncn_program_admin provided, expected {}, received {}",
+                self.ncn_program_admin,
+                ncn_program_admin
+            );
+            return Err(RestakingError::NcnProgramAdminInvalid);
+        }
+        Ok(())
+    }
+
     /// Validates the delegate_admin account and ensures it matches the expected delegate_admin.
     ///
     /// # Arguments
@@ -176,6 +442,30 @@ impl Ncn {
         Ok(())
     }
 
+    /// Validates the metadata_admin account and ensures it matches the expected metadata_admin.
+    ///
+    /// # Arguments
+    /// * `metadata_admin` - A reference to the [`Pubkey`] representing the metadata_admin Pubkey
+    ///   that is attempting to authorize the operation.
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the metadata_admin account is valid.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnMetadataAdminInvalid`] error in the following case:
+    /// * The `metadata_admin` public key does not match the expected metadata_admin public key stored in `self`.
+    pub fn check_metadata_admin(&self, metadata_admin: &Pubkey) -> Result<(), RestakingError> {
+        if self.metadata_admin.ne(metadata_admin) {
+            msg!(
+                "Incorrect metadata_admin provided, expected {}, received {}",
+                self.metadata_admin,
+                metadata_admin
+            );
+            return Err(RestakingError::NcnMetadataAdminInvalid);
+        }
+        Ok(())
+    }
+
     /// Replace all secondary admins that were equal to the old admin to the new admin
     ///
     /// # Arguments
@@ -238,6 +528,30 @@ impl Ncn {
         (pda, bump, seeds)
     }
 
+    /// Returns the seeds for the signer PDA of the program an NCN is bound to
+    pub fn program_signer_seeds(ncn: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([b"ncn_program_signer".to_vec(), ncn.as_ref().to_vec()])
+    }
+
+    /// Find the signer PDA the NCN program signs with when invoking the restaking program on
+    /// behalf of the NCN
+    ///
+    /// # Arguments
+    /// * `ncn_program` - The program the NCN is bound to
+    /// * `ncn` - The NCN account
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program signer address
+    pub fn find_program_signer_address(
+        ncn_program: &Pubkey,
+        ncn: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::program_signer_seeds(ncn);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, ncn_program);
+        (pda, bump, seeds)
+    }
+
     /// Attempts to load the account as [`Ncn`], returning an error if it's not valid.
     ///
     /// # Arguments
@@ -283,9 +597,11 @@ impl Ncn {
 #[cfg(test)]
 mod tests {
     use jito_bytemuck::types::PodU64;
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::pubkey::Pubkey;
 
     use super::{Ncn, RESERVED_SPACE_LEN};
+    use crate::MAX_TRANSITION_EPOCHS;
 
     #[test]
     fn test_ncn_no_padding() {
@@ -304,10 +620,39 @@ mod tests {
             std::mem::size_of::<PodU64>() + // vault_count
             std::mem::size_of::<PodU64>() + // slasher_count
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<u8>() + // warmup_epochs
+            std::mem::size_of::<u8>() + // cooldown_epochs
+            std::mem::size_of::<Pubkey>() + // ncn_program
+            std::mem::size_of::<Pubkey>() + // ncn_program_signer
+            std::mem::size_of::<Pubkey>() + // operator_bond_mint
+            std::mem::size_of::<PodU64>() + // min_operator_bond
+            std::mem::size_of::<PodU64>() + // max_bond_slashable_per_epoch
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(ncn_size, sum_of_fields);
     }
 
+    #[test]
+    fn test_set_transition_epochs() {
+        let mut ncn = Ncn::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert_eq!(ncn.warmup_epochs(), 1);
+        assert_eq!(ncn.cooldown_epochs(), 1);
+
+        ncn.set_transition_epochs(2, 4).unwrap();
+        assert_eq!(ncn.warmup_epochs(), 2);
+        assert_eq!(ncn.cooldown_epochs(), 4);
+
+        assert!(matches!(
+            ncn.set_transition_epochs(0, 4),
+            Err(RestakingError::NcnTransitionEpochsInvalid)
+        ));
+        assert!(matches!(
+            ncn.set_transition_epochs(2, MAX_TRANSITION_EPOCHS + 1),
+            Err(RestakingError::NcnTransitionEpochsInvalid)
+        ));
+        assert_eq!(ncn.warmup_epochs(), 2);
+        assert_eq!(ncn.cooldown_epochs(), 4);
+    }
+
     #[test]
     fn test_update_secondary_admin_ok() {
         let old_admin = Pubkey::new_unique();
@@ -332,4 +677,55 @@ mod tests {
         assert_eq!(ncn.weight_table_admin, new_admin);
         assert_eq!(ncn.ncn_program_admin, new_admin);
     }
+
+    #[test]
+    fn test_ncn_program_signer_authorizes_admins() {
+        let ncn_pubkey = Pubkey::new_unique();
+        let ncn_program = Pubkey::new_unique();
+        let mut ncn = Ncn::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        let program_signer = Ncn::find_program_signer_address(&ncn_program, &ncn_pubkey).0;
+
+        assert!(matches!(
+            ncn.check_operator_admin(&program_signer),
+            Err(RestakingError::NcnOperatorAdminInvalid)
+        ));
+
+        ncn.set_ncn_program(&ncn_pubkey, &ncn_program);
+        assert_eq!(ncn.ncn_program, ncn_program);
+        ncn.check_operator_admin(&program_signer).unwrap();
+        ncn.check_vault_admin(&program_signer).unwrap();
+        ncn.check_slasher_admin(&program_signer).unwrap();
+        // the keypair admins keep working alongside the program
+        let operator_admin = ncn.operator_admin;
+        ncn.check_operator_admin(&operator_admin).unwrap();
+        assert!(matches!(
+            ncn.check_admin(&program_signer),
+            Err(RestakingError::NcnAdminInvalid)
+        ));
+
+        ncn.set_ncn_program(&ncn_pubkey, &Pubkey::default());
+        assert!(!ncn.is_ncn_program_signer(&program_signer));
+        assert!(matches!(
+            ncn.check_slasher_admin(&program_signer),
+            Err(RestakingError::NcnSlasherAdminInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_check_operator_bond_without_bond_account() {
+        let ncn_operator_state = Pubkey::new_unique();
+        let mut ncn = Ncn::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        ncn.check_operator_bond(&ncn_operator_state, None).unwrap();
+
+        ncn.set_operator_bond(Pubkey::new_unique(), 1_000, 100);
+        assert_eq!(ncn.min_operator_bond(), 1_000);
+        assert_eq!(ncn.max_bond_slashable_per_epoch(), 100);
+        assert_eq!(
+            ncn.check_operator_bond(&ncn_operator_state, None),
+            Err(RestakingError::NcnOperatorBondInsufficient.into())
+        );
+
+        ncn.set_operator_bond(ncn.operator_bond_mint, 0, 0);
+        ncn.check_operator_bond(&ncn_operator_state, None).unwrap();
+    }
 }
@@ -0,0 +1,69 @@
+//! The NcnMetadata account stores the public profile of an NCN
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::AccountDeserialize;
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::metadata::{MetadataAccount, MetadataFields};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`NcnMetadata`] account
+///
+/// - stores the name, website, logo and contact of an NCN.
+/// - is created and updated by the NCN metadata admin.
+/// - shares its fields, PDA and loading logic with the other metadata account through
+///   [`MetadataAccount`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct NcnMetadata {
+    /// The NCN
+    pub ncn: Pubkey,
+
+    /// The public profile of the NCN
+    pub metadata: MetadataFields,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl MetadataAccount for NcnMetadata {
+    const SEED: &'static [u8] = b"ncn_metadata";
+    const NAME: &'static str = "NCN metadata";
+
+    fn new(ncn: Pubkey, bump: u8) -> Self {
+        Self {
+            ncn,
+            metadata: MetadataFields::default(),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    fn metadata(&self) -> &MetadataFields {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut MetadataFields {
+        &mut self.metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ncn_metadata_no_padding() {
+        let ncn_metadata_size = std::mem::size_of::<NcnMetadata>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<MetadataFields>() + // metadata
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(ncn_metadata_size, sum_of_fields);
+    }
+}
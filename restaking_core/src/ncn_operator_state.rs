@@ -2,11 +2,43 @@ use std::fmt::Debug;
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
-use jito_jsm_core::slot_toggle::SlotToggle;
-use shank::ShankAccount;
+use jito_jsm_core::{get_epoch, slot_toggle::SlotToggle};
+use jito_restaking_sdk::error::RestakingError;
+use shank::{ShankAccount, ShankType};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+const RESERVED_SPACE_LEN: usize = 146;
+
+/// The number of jailings kept in the jail history of an [`NcnOperatorState`]
+pub const JAIL_HISTORY_LEN: usize = 4;
+
+/// A jailing of the operator by the NCN, see [`NcnOperatorState::jail_history`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct JailRecord {
+    /// The reason code given by the NCN, defined by the NCN
+    reason_code: u8,
+
+    /// The slot the operator was jailed
+    jail_slot: PodU64,
+
+    /// The release epoch the operator was jailed until
+    until_epoch: PodU64,
+}
+
+impl JailRecord {
+    pub const fn reason_code(&self) -> u8 {
+        self.reason_code
+    }
+
+    pub fn jail_slot(&self) -> u64 {
+        self.jail_slot.into()
+    }
+
+    pub fn until_epoch(&self) -> u64 {
+        self.until_epoch.into()
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
 #[repr(C)]
@@ -28,8 +60,34 @@ pub struct NcnOperatorState {
 
     pub bump: u8,
 
+    /// The operator is jailed by the NCN while the current epoch is below this epoch
+    jailed_until_epoch: PodU64,
+
+    /// The reason code given by the NCN for the latest jailing, defined by the NCN. Earlier
+    /// jailings are kept in `jail_history`.
+    jail_reason_code: u8,
+
+    /// The slot of the latest jailing
+    last_jail_slot: PodU64,
+
+    /// The slot of the latest unjailing
+    last_unjail_slot: PodU64,
+
+    /// The number of times the operator has been jailed by the NCN
+    jail_count: PodU64,
+
+    /// The epoch of the latest slashing of the operator bond
+    bond_slashed_epoch: PodU64,
+
+    /// The amount of the operator bond slashed during `bond_slashed_epoch`
+    bond_slashed_amount: PodU64,
+
+    /// The latest [`JAIL_HISTORY_LEN`] jailings as a ring, jailing number `n`, counting from
+    /// zero, being stored at `n % JAIL_HISTORY_LEN`
+    jail_history: [JailRecord; 4],
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 146],
 }
 
 impl NcnOperatorState {
@@ -41,6 +99,14 @@ impl NcnOperatorState {
             ncn_opt_in_state: SlotToggle::new(slot),
             operator_opt_in_state: SlotToggle::new(slot),
             bump,
+            jailed_until_epoch: PodU64::from(0),
+            jail_reason_code: 0,
+            last_jail_slot: PodU64::from(0),
+            last_unjail_slot: PodU64::from(0),
+            jail_count: PodU64::from(0),
+            bond_slashed_epoch: PodU64::from(0),
+            bond_slashed_amount: PodU64::from(0),
+            jail_history: [JailRecord::zeroed(); JAIL_HISTORY_LEN],
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -49,6 +115,183 @@ impl NcnOperatorState {
         self.index.into()
     }
 
+    pub fn jailed_until_epoch(&self) -> u64 {
+        self.jailed_until_epoch.into()
+    }
+
+    pub const fn jail_reason_code(&self) -> u8 {
+        self.jail_reason_code
+    }
+
+    pub fn last_jail_slot(&self) -> u64 {
+        self.last_jail_slot.into()
+    }
+
+    pub fn last_unjail_slot(&self) -> u64 {
+        self.last_unjail_slot.into()
+    }
+
+    pub fn jail_count(&self) -> u64 {
+        self.jail_count.into()
+    }
+
+    /// The position of jailing number `jailing`, counting from zero, in the jail history ring
+    const fn jail_history_index(jailing: u64) -> usize {
+        jailing.rem_euclid(JAIL_HISTORY_LEN as u64) as usize
+    }
+
+    /// The latest jailings of the operator, oldest first. Only the latest [`JAIL_HISTORY_LEN`]
+    /// jailings out of [`NcnOperatorState::jail_count`] are kept.
+    pub fn jail_history(&self) -> Vec<JailRecord> {
+        let jail_count = self.jail_count();
+        let first = jail_count.saturating_sub(JAIL_HISTORY_LEN as u64);
+        (first..jail_count)
+            .map(|jailing| self.jail_history[Self::jail_history_index(jailing)])
+            .collect()
+    }
+
+    /// Whether the operator is jailed by the NCN at the given slot
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn is_jailed(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
+        Ok(get_epoch(slot, epoch_length)? < self.jailed_until_epoch())
+    }
+
+    /// Whether the NCN and the operator have both opted in to each other and the operator is
+    /// not jailed. Only an active operator is eligible for the NCN's stake snapshots.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn is_active(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
+        Ok(self.ncn_opt_in_state.is_active(slot, epoch_length)?
+            && self.operator_opt_in_state.is_active(slot, epoch_length)?
+            && !self.is_jailed(slot, epoch_length)?)
+    }
+
+    /// Jails the operator, making it ineligible for the NCN immediately and until `until_epoch`.
+    /// Jailing an operator that is already jailed replaces the reason code and the release
+    /// epoch. Every jailing is counted and added to the jail history, overwriting the oldest
+    /// once the history is full.
+    ///
+    /// # Arguments
+    /// * `reason_code` - The reason code defined by the NCN
+    /// * `until_epoch` - The first epoch the operator is no longer jailed
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Errors
+    /// * [`RestakingError::NcnOperatorJailEpochInvalid`] - If `until_epoch` is not after the
+    ///   current epoch
+    pub fn jail(
+        &mut self,
+        reason_code: u8,
+        until_epoch: u64,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ProgramError> {
+        let epoch = get_epoch(slot, epoch_length)?;
+        if until_epoch <= epoch {
+            msg!(
+                "Jail release epoch {} shall be after the current epoch {}",
+                until_epoch,
+                epoch
+            );
+            return Err(RestakingError::NcnOperatorJailEpochInvalid.into());
+        }
+
+        let jail_count = self.jail_count();
+        self.jail_history[Self::jail_history_index(jail_count)] = JailRecord {
+            reason_code,
+            jail_slot: PodU64::from(slot),
+            until_epoch: PodU64::from(until_epoch),
+        };
+
+        self.jailed_until_epoch = PodU64::from(until_epoch);
+        self.jail_reason_code = reason_code;
+        self.last_jail_slot = PodU64::from(slot);
+        self.jail_count = PodU64::from(
+            jail_count
+                .checked_add(1)
+                .ok_or(RestakingError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Releases a jailed operator before its release epoch
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Errors
+    /// * [`RestakingError::NcnOperatorNotJailed`] - If the operator is not jailed
+    pub fn unjail(&mut self, slot: u64, epoch_length: u64) -> Result<(), ProgramError> {
+        if !self.is_jailed(slot, epoch_length)? {
+            msg!("Operator is not jailed");
+            return Err(RestakingError::NcnOperatorNotJailed.into());
+        }
+
+        self.jailed_until_epoch = PodU64::from(0);
+        self.last_unjail_slot = PodU64::from(slot);
+
+        Ok(())
+    }
+
+    pub fn bond_slashed_epoch(&self) -> u64 {
+        self.bond_slashed_epoch.into()
+    }
+
+    pub fn bond_slashed_amount(&self) -> u64 {
+        self.bond_slashed_amount.into()
+    }
+
+    /// Records a slashing of the operator bond. The bond slashed within an epoch, by any of the
+    /// NCN's slashers, is capped by the NCN's max bond slashable per epoch.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount slashed
+    /// * `max_slashable_per_epoch` - The NCN's max bond slashable per epoch
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Errors
+    /// * [`RestakingError::NcnOperatorBondSlashCapExceeded`] - If the bond slashed in the epoch
+    ///   would exceed `max_slashable_per_epoch`
+    pub fn record_bond_slash(
+        &mut self,
+        amount: u64,
+        max_slashable_per_epoch: u64,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ProgramError> {
+        let epoch = get_epoch(slot, epoch_length)?;
+        let slashed_amount = if self.bond_slashed_epoch() == epoch {
+            self.bond_slashed_amount()
+        } else {
+            0
+        };
+        let slashed_amount = slashed_amount
+            .checked_add(amount)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        if slashed_amount > max_slashable_per_epoch {
+            msg!(
+                "Operator bond slashed this epoch {} exceeds the max slashable {}",
+                slashed_amount,
+                max_slashable_per_epoch
+            );
+            return Err(RestakingError::NcnOperatorBondSlashCapExceeded.into());
+        }
+
+        self.bond_slashed_epoch = PodU64::from(epoch);
+        self.bond_slashed_amount = PodU64::from(slashed_amount);
+
+        Ok(())
+    }
+
     pub fn seeds(ncn: &Pubkey, operator: &Pubkey) -> Vec<Vec<u8>> {
         Vec::from_iter([
             b"ncn_operator_state".to_vec(),
@@ -126,6 +369,14 @@ mod tests {
             size_of::<SlotToggle>() + // ncn_opt_in_state
             size_of::<SlotToggle>() + // operator_opt_in_state
             size_of::<u8>() + // bump
+            size_of::<PodU64>() + // jailed_until_epoch
+            size_of::<u8>() + // jail_reason_code
+            size_of::<PodU64>() + // last_jail_slot
+            size_of::<PodU64>() + // last_unjail_slot
+            size_of::<PodU64>() + // jail_count
+            size_of::<PodU64>() + // bond_slashed_epoch
+            size_of::<PodU64>() + // bond_slashed_amount
+            size_of::<JailRecord>() * JAIL_HISTORY_LEN + // jail_history
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(ncn_operator_state_size, sum_of_fields);
     }
@@ -150,4 +401,91 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_ncn_operator_state_jail() {
+        let epoch_length = 100;
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 1);
+        assert!(!ncn_operator_state.is_jailed(150, epoch_length).unwrap());
+
+        assert_eq!(
+            ncn_operator_state.jail(3, 1, 150, epoch_length),
+            Err(RestakingError::NcnOperatorJailEpochInvalid.into())
+        );
+        assert_eq!(
+            ncn_operator_state.unjail(150, epoch_length),
+            Err(RestakingError::NcnOperatorNotJailed.into())
+        );
+
+        ncn_operator_state.jail(3, 3, 150, epoch_length).unwrap();
+        assert!(ncn_operator_state.is_jailed(150, epoch_length).unwrap());
+        assert!(ncn_operator_state.is_jailed(299, epoch_length).unwrap());
+        assert!(!ncn_operator_state.is_jailed(300, epoch_length).unwrap());
+        assert!(!ncn_operator_state.is_active(150, epoch_length).unwrap());
+        assert_eq!(ncn_operator_state.jail_reason_code(), 3);
+        assert_eq!(ncn_operator_state.last_jail_slot(), 150);
+        assert_eq!(ncn_operator_state.jail_count(), 1);
+
+        ncn_operator_state.unjail(250, epoch_length).unwrap();
+        assert!(!ncn_operator_state.is_jailed(250, epoch_length).unwrap());
+        assert_eq!(ncn_operator_state.last_unjail_slot(), 250);
+        assert_eq!(ncn_operator_state.jail_reason_code(), 3);
+        assert_eq!(ncn_operator_state.jail_count(), 1);
+    }
+
+    #[test]
+    fn test_ncn_operator_state_jail_history() {
+        let epoch_length = 100;
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 1);
+        assert!(ncn_operator_state.jail_history().is_empty());
+
+        for reason_code in 1..=JAIL_HISTORY_LEN as u8 + 2 {
+            let slot = u64::from(reason_code) * epoch_length;
+            ncn_operator_state
+                .jail(reason_code, u64::from(reason_code) + 1, slot, epoch_length)
+                .unwrap();
+        }
+
+        // the two oldest jailings were overwritten
+        let jail_history = ncn_operator_state.jail_history();
+        assert_eq!(jail_history.len(), JAIL_HISTORY_LEN);
+        for (jail_record, reason_code) in jail_history.iter().zip(3..) {
+            assert_eq!(jail_record.reason_code(), reason_code);
+            assert_eq!(
+                jail_record.jail_slot(),
+                u64::from(reason_code) * epoch_length
+            );
+            assert_eq!(jail_record.until_epoch(), u64::from(reason_code) + 1);
+        }
+        assert_eq!(ncn_operator_state.jail_count(), JAIL_HISTORY_LEN as u64 + 2);
+    }
+
+    #[test]
+    fn test_ncn_operator_state_record_bond_slash() {
+        let epoch_length = 100;
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 1);
+
+        ncn_operator_state
+            .record_bond_slash(60, 100, 150, epoch_length)
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state.record_bond_slash(41, 100, 199, epoch_length),
+            Err(RestakingError::NcnOperatorBondSlashCapExceeded.into())
+        );
+        ncn_operator_state
+            .record_bond_slash(40, 100, 199, epoch_length)
+            .unwrap();
+        assert_eq!(ncn_operator_state.bond_slashed_epoch(), 1);
+        assert_eq!(ncn_operator_state.bond_slashed_amount(), 100);
+
+        // the cap resets every epoch
+        ncn_operator_state
+            .record_bond_slash(100, 100, 200, epoch_length)
+            .unwrap();
+        assert_eq!(ncn_operator_state.bond_slashed_epoch(), 2);
+        assert_eq!(ncn_operator_state.bond_slashed_amount(), 100);
+    }
 }
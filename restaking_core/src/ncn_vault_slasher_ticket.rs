@@ -1,13 +1,17 @@
 //! The NcnVaultSlasherTicket tracks the opting-in of a slasher to a particular vault.
 //! The NcnVaultSlasherTicket can be activated and deactivated over time by the NCN slasher admin.
+//!
+//! The NCN slasher admin can also propose a new max slashable amount per epoch: decreases apply
+//! immediately while increases only apply after a full epoch has passed.
 
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+const RESERVED_SPACE_LEN: usize = 247;
 
 /// The [`NcnVaultSlasherTicket`] account
 ///
@@ -38,8 +42,15 @@ pub struct NcnVaultSlasherTicket {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The proposed max slashable funds per epoch per operator, applied from
+    /// `next_max_slashable_epoch`
+    next_max_slashable_per_epoch: PodU64,
+
+    /// The epoch the proposed max slashable amount takes effect, zero if no increase is pending
+    next_max_slashable_epoch: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 247],
 }
 
 impl NcnVaultSlasherTicket {
@@ -71,6 +82,8 @@ impl NcnVaultSlasherTicket {
             index: PodU64::from(index),
             state: SlotToggle::new(slot),
             bump,
+            next_max_slashable_per_epoch: PodU64::from(0),
+            next_max_slashable_epoch: PodU64::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -79,10 +92,80 @@ impl NcnVaultSlasherTicket {
         self.index.into()
     }
 
-    pub fn max_slashable_per_epoch(&self) -> u64 {
+    /// The stored max slashable per epoch, which a pending increase replaces once its epoch is
+    /// reached. Use [`Self::current_max_slashable_per_epoch`] for the value in effect.
+    pub fn stored_max_slashable_per_epoch(&self) -> u64 {
         self.max_slashable_per_epoch.into()
     }
 
+    pub fn next_max_slashable_per_epoch(&self) -> u64 {
+        self.next_max_slashable_per_epoch.into()
+    }
+
+    pub fn next_max_slashable_epoch(&self) -> u64 {
+        self.next_max_slashable_epoch.into()
+    }
+
+    /// Returns the max slashable amount per epoch in effect at the given slot, taking a pending
+    /// increase into account once its epoch has been reached.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn current_max_slashable_per_epoch(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<u64, RestakingError> {
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(RestakingError::DivisionByZero)?;
+        if self.next_max_slashable_epoch() != 0 && epoch >= self.next_max_slashable_epoch() {
+            Ok(self.next_max_slashable_per_epoch())
+        } else {
+            Ok(self.stored_max_slashable_per_epoch())
+        }
+    }
+
+    /// Proposes a new max slashable amount per epoch. A decrease, or keeping the current value,
+    /// takes effect immediately and cancels any pending increase. An increase takes effect once
+    /// a full epoch has passed, replacing any increase still pending.
+    ///
+    /// # Arguments
+    /// * `max_slashable_per_epoch` - The new max slashable funds per epoch per operator
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn set_next_max_slashable_per_epoch(
+        &mut self,
+        max_slashable_per_epoch: u64,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), RestakingError> {
+        // apply a pending increase that already took effect before proposing the next one
+        let current_max_slashable_per_epoch =
+            self.current_max_slashable_per_epoch(slot, epoch_length)?;
+        self.max_slashable_per_epoch = PodU64::from(current_max_slashable_per_epoch);
+
+        if max_slashable_per_epoch <= current_max_slashable_per_epoch {
+            self.max_slashable_per_epoch = PodU64::from(max_slashable_per_epoch);
+            self.next_max_slashable_per_epoch = PodU64::from(0);
+            self.next_max_slashable_epoch = PodU64::from(0);
+            return Ok(());
+        }
+
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(RestakingError::DivisionByZero)?;
+        let next_max_slashable_epoch = epoch
+            .checked_add(2)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+
+        self.next_max_slashable_per_epoch = PodU64::from(max_slashable_per_epoch);
+        self.next_max_slashable_epoch = PodU64::from(next_max_slashable_epoch);
+
+        Ok(())
+    }
+
     /// Returns the seeds for the PDA
     ///
     /// # Arguments
@@ -187,6 +270,8 @@ mod tests {
             size_of::<PodU64>() + // index
             size_of::<SlotToggle>() + // state
             size_of::<u8>() + // bump
+            size_of::<PodU64>() + // next_max_slashable_per_epoch
+            size_of::<PodU64>() + // next_max_slashable_epoch
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(ncn_vault_slasher_ticket_size, sum_of_fields);
     }
@@ -208,4 +293,84 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_set_next_max_slashable_per_epoch_increase_is_delayed() {
+        let epoch_length = 100;
+        let mut ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1_000,
+            0,
+            0,
+            0,
+        );
+
+        ncn_vault_slasher_ticket
+            .set_next_max_slashable_per_epoch(2_000, 150, epoch_length)
+            .unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket.next_max_slashable_per_epoch(),
+            2_000
+        );
+        assert_eq!(ncn_vault_slasher_ticket.next_max_slashable_epoch(), 3);
+        assert_eq!(
+            ncn_vault_slasher_ticket
+                .current_max_slashable_per_epoch(299, epoch_length)
+                .unwrap(),
+            1_000
+        );
+        assert_eq!(
+            ncn_vault_slasher_ticket
+                .current_max_slashable_per_epoch(300, epoch_length)
+                .unwrap(),
+            2_000
+        );
+    }
+
+    #[test]
+    fn test_set_next_max_slashable_per_epoch_decrease_is_immediate() {
+        let epoch_length = 100;
+        let mut ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1_000,
+            0,
+            0,
+            0,
+        );
+
+        ncn_vault_slasher_ticket
+            .set_next_max_slashable_per_epoch(2_000, 150, epoch_length)
+            .unwrap();
+        ncn_vault_slasher_ticket
+            .set_next_max_slashable_per_epoch(500, 160, epoch_length)
+            .unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket.stored_max_slashable_per_epoch(),
+            500
+        );
+        assert_eq!(ncn_vault_slasher_ticket.next_max_slashable_epoch(), 0);
+        assert_eq!(
+            ncn_vault_slasher_ticket
+                .current_max_slashable_per_epoch(1_000, epoch_length)
+                .unwrap(),
+            500
+        );
+
+        // a decrease is measured against the amount in effect, including an applied increase
+        ncn_vault_slasher_ticket
+            .set_next_max_slashable_per_epoch(2_000, 160, epoch_length)
+            .unwrap();
+        ncn_vault_slasher_ticket
+            .set_next_max_slashable_per_epoch(1_500, 300, epoch_length)
+            .unwrap();
+        assert_eq!(
+            ncn_vault_slasher_ticket.stored_max_slashable_per_epoch(),
+            1_500
+        );
+        assert_eq!(ncn_vault_slasher_ticket.next_max_slashable_epoch(), 0);
+    }
 }
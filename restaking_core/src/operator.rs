@@ -12,7 +12,9 @@ use jito_restaking_sdk::error::RestakingError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 261;
+use crate::{MAX_FEE_BPS, MAX_OPERATOR_FEE_BUMP_BPS};
+
+const RESERVED_SPACE_LEN: usize = 211;
 
 /// The Operator account stores global information for a particular operator
 /// including the admin, voter, and the number of NCN and vault accounts.
@@ -52,14 +54,28 @@ pub struct Operator {
     /// Helpful for indexing all available OperatorVaultTickets.
     vault_count: PodU64,
 
-    /// The operator fee in basis points
-    pub operator_fee_bps: PodU16,
+    /// The operator fee in basis points, superseded by `next_operator_fee_bps` from
+    /// `next_operator_fee_epoch`. Read it through [`Operator::current_operator_fee_bps`].
+    operator_fee_bps: PodU16,
 
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The pending operator fee in basis points, applied from `next_operator_fee_epoch`
+    next_operator_fee_bps: PodU16,
+
+    /// The epoch the pending operator fee takes effect, zero if no fee change is pending
+    next_operator_fee_epoch: PodU64,
+
+    /// The pending voter, replacing `voter` from `next_voter_epoch`. NCNs reading the voter
+    /// mid-epoch should use [`Operator::current_voter`] to pick between the two keys.
+    pub next_voter: Pubkey,
+
+    /// The epoch the pending voter takes effect, zero if no voter rotation is pending
+    next_voter_epoch: PodU64,
+
     /// Reserved space
-    reserved_space: [u8; 261],
+    reserved_space: [u8; 211],
 }
 
 impl Operator {
@@ -83,6 +99,10 @@ impl Operator {
             vault_count: PodU64::from(0),
             operator_fee_bps: PodU16::from(operator_fee_bps),
             bump,
+            next_operator_fee_bps: PodU16::from(0),
+            next_operator_fee_epoch: PodU64::from(0),
+            next_voter: Pubkey::default(),
+            next_voter_epoch: PodU64::from(0),
             reserved_space: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -99,6 +119,143 @@ impl Operator {
         self.vault_count.into()
     }
 
+    pub fn next_operator_fee_bps(&self) -> u16 {
+        self.next_operator_fee_bps.into()
+    }
+
+    pub fn next_operator_fee_epoch(&self) -> u64 {
+        self.next_operator_fee_epoch.into()
+    }
+
+    /// Returns the operator fee in effect at the given slot, taking a pending fee change
+    /// into account once its epoch has been reached.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn current_operator_fee_bps(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<u16, RestakingError> {
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(RestakingError::DivisionByZero)?;
+        if self.next_operator_fee_epoch() != 0 && epoch >= self.next_operator_fee_epoch() {
+            Ok(self.next_operator_fee_bps())
+        } else {
+            Ok(self.operator_fee_bps.into())
+        }
+    }
+
+    /// Schedules a new operator fee. The fee takes effect once a full epoch has passed, and may
+    /// increase by at most [`MAX_OPERATOR_FEE_BUMP_BPS`] over the fee currently in effect.
+    /// Any fee change still pending is replaced.
+    ///
+    /// # Arguments
+    /// * `new_fee_bps` - The new operator fee in basis points
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Errors
+    /// * [`RestakingError::OperatorFeeCapExceeded`] - If the fee exceeds [`MAX_FEE_BPS`]
+    /// * [`RestakingError::OperatorFeeBumpTooLarge`] - If the fee increases by more than
+    ///   [`MAX_OPERATOR_FEE_BUMP_BPS`]
+    pub fn set_next_operator_fee_bps(
+        &mut self,
+        new_fee_bps: u16,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), RestakingError> {
+        if new_fee_bps > MAX_FEE_BPS {
+            msg!("New fee exceeds maximum allowed fee");
+            return Err(RestakingError::OperatorFeeCapExceeded);
+        }
+
+        // apply a pending change that already took effect before scheduling the next one
+        let current_fee_bps = self.current_operator_fee_bps(slot, epoch_length)?;
+        self.operator_fee_bps = PodU16::from(current_fee_bps);
+
+        if new_fee_bps.saturating_sub(current_fee_bps) > MAX_OPERATOR_FEE_BUMP_BPS {
+            msg!(
+                "Fee increase from {} to {} exceeds the maximum of {} bps",
+                current_fee_bps,
+                new_fee_bps,
+                MAX_OPERATOR_FEE_BUMP_BPS
+            );
+            return Err(RestakingError::OperatorFeeBumpTooLarge);
+        }
+
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(RestakingError::DivisionByZero)?;
+        let next_operator_fee_epoch = epoch
+            .checked_add(2)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+
+        self.next_operator_fee_bps = PodU16::from(new_fee_bps);
+        self.next_operator_fee_epoch = PodU64::from(next_operator_fee_epoch);
+
+        Ok(())
+    }
+
+    pub fn next_voter_epoch(&self) -> u64 {
+        self.next_voter_epoch.into()
+    }
+
+    /// Returns the voter in effect at the given slot, taking a pending voter rotation into
+    /// account once its epoch has been reached.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn current_voter(&self, slot: u64, epoch_length: u64) -> Result<Pubkey, RestakingError> {
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(RestakingError::DivisionByZero)?;
+        if self.next_voter_epoch() != 0 && epoch >= self.next_voter_epoch() {
+            Ok(self.next_voter)
+        } else {
+            Ok(self.voter)
+        }
+    }
+
+    /// Schedules a new voter from the next epoch boundary so the voter never changes within an
+    /// epoch. Any voter rotation still pending is replaced, and setting the voter already in
+    /// effect cancels it.
+    ///
+    /// # Arguments
+    /// * `voter` - The new voter
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn set_next_voter(
+        &mut self,
+        voter: &Pubkey,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), RestakingError> {
+        // apply a pending rotation that already took effect before scheduling the next one
+        self.voter = self.current_voter(slot, epoch_length)?;
+
+        if self.voter.eq(voter) {
+            self.next_voter = Pubkey::default();
+            self.next_voter_epoch = PodU64::from(0);
+            return Ok(());
+        }
+
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(RestakingError::DivisionByZero)?;
+        let next_voter_epoch = epoch
+            .checked_add(1)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+
+        self.next_voter = *voter;
+        self.next_voter_epoch = PodU64::from(next_voter_epoch);
+
+        Ok(())
+    }
+
     pub fn increment_ncn_count(&mut self) -> Result<(), RestakingError> {
         let mut ncn_count: u64 = self.ncn_count.into();
         ncn_count = ncn_count
@@ -165,12 +322,46 @@ impl Operator {
         Ok(())
     }
 
-    /// Replace all secondary admins that were equal to the old admin to the new admin
+    /// Validates the metadata_admin account and ensures it matches the expected metadata_admin.
+    ///
+    /// # Arguments
+    /// * `metadata_admin` - A reference to the [`Pubkey`] representing the metadata_admin Pubkey
+    ///   that is attempting to authorize the operation.
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the metadata_admin account is valid.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::OperatorMetadataAdminInvalid`] error in the following case:
+    /// * The `metadata_admin` public key does not match the expected metadata_admin public key stored in `self`.
+    pub fn check_metadata_admin(&self, metadata_admin: &Pubkey) -> Result<(), RestakingError> {
+        if self.metadata_admin.ne(metadata_admin) {
+            msg!(
+                "Incorrect metadata_admin provided, expected {}, received {}",
+                self.metadata_admin,
+                metadata_admin
+            );
+            return Err(RestakingError::OperatorMetadataAdminInvalid);
+        }
+        Ok(())
+    }
+
+    /// Replace all secondary admins that were equal to the old admin to the new admin. If the old
+    /// admin is the upcoming voter, the new admin is scheduled through [`Operator::set_next_voter`]
+    /// so the voter still only changes at an epoch boundary.
     ///
     /// # Arguments
     /// * `old_admin` - The old admin Pubkey
     /// * `new_admin` - The new admin Pubkey
-    pub fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey) {
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn update_secondary_admin(
+        &mut self,
+        old_admin: &Pubkey,
+        new_admin: &Pubkey,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), RestakingError> {
         if self.ncn_admin.eq(old_admin) {
             self.ncn_admin = *new_admin;
             msg!("NCN admin set to {:?}", new_admin);
@@ -181,9 +372,21 @@ impl Operator {
             msg!("Vault admin set to {:?}", new_admin);
         }
 
-        if self.voter.eq(old_admin) {
-            self.voter = *new_admin;
-            msg!("Voter set to {:?}", new_admin);
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(RestakingError::DivisionByZero)?;
+        let upcoming_voter = if self.next_voter_epoch() > epoch {
+            self.next_voter
+        } else {
+            self.current_voter(slot, epoch_length)?
+        };
+        if upcoming_voter.eq(old_admin) {
+            self.set_next_voter(new_admin, slot, epoch_length)?;
+            msg!(
+                "Voter set to {:?} from epoch {}",
+                new_admin,
+                self.next_voter_epoch()
+            );
         }
 
         if self.delegate_admin.eq(old_admin) {
@@ -195,6 +398,8 @@ impl Operator {
             self.metadata_admin = *new_admin;
             msg!("Metadata admin set to {:?}", new_admin);
         }
+
+        Ok(())
     }
 
     /// Returns the seeds for the PDA
@@ -272,7 +477,10 @@ mod tests {
     use jito_bytemuck::types::{PodU16, PodU64};
     use solana_program::pubkey::Pubkey;
 
+    use jito_restaking_sdk::error::RestakingError;
+
     use super::{Operator, RESERVED_SPACE_LEN};
+    use crate::MAX_OPERATOR_FEE_BUMP_BPS;
 
     #[test]
     fn test_operator_no_padding() {
@@ -289,6 +497,10 @@ mod tests {
             std::mem::size_of::<PodU64>() + // vault_count
             std::mem::size_of::<PodU16>() + // operator_fee_bps
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<PodU16>() + // next_operator_fee_bps
+            std::mem::size_of::<PodU64>() + // next_operator_fee_epoch
+            std::mem::size_of::<Pubkey>() + // next_voter
+            std::mem::size_of::<PodU64>() + // next_voter_epoch
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(operator_size, sum_of_fields);
     }
@@ -304,12 +516,137 @@ mod tests {
         assert_eq!(operator.delegate_admin, old_admin);
 
         let new_admin = Pubkey::new_unique();
-        operator.update_secondary_admin(&old_admin, &new_admin);
+        operator
+            .update_secondary_admin(&old_admin, &new_admin, 150, 100)
+            .unwrap();
 
         assert_eq!(operator.ncn_admin, new_admin);
         assert_eq!(operator.vault_admin, new_admin);
-        assert_eq!(operator.voter, new_admin);
         assert_eq!(operator.delegate_admin, new_admin);
         assert_eq!(operator.metadata_admin, new_admin);
+
+        // the voter only rotates at the next epoch boundary
+        assert_eq!(operator.voter, old_admin);
+        assert_eq!(operator.next_voter, new_admin);
+        assert_eq!(operator.next_voter_epoch(), 2);
+        assert_eq!(operator.current_voter(199, 100).unwrap(), old_admin);
+        assert_eq!(operator.current_voter(200, 100).unwrap(), new_admin);
+    }
+
+    #[test]
+    fn test_update_secondary_admin_keeps_pending_voter() {
+        let old_admin = Pubkey::new_unique();
+        let mut operator = Operator::new(Pubkey::new_unique(), old_admin, 0, 0, 0);
+
+        let pending_voter = Pubkey::new_unique();
+        operator.set_next_voter(&pending_voter, 150, 100).unwrap();
+
+        let new_admin = Pubkey::new_unique();
+        operator
+            .update_secondary_admin(&old_admin, &new_admin, 160, 100)
+            .unwrap();
+
+        assert_eq!(operator.voter, old_admin);
+        assert_eq!(operator.next_voter, pending_voter);
+        assert_eq!(operator.next_voter_epoch(), 2);
+    }
+
+    #[test]
+    fn test_set_next_operator_fee_bps_takes_effect_after_full_epoch() {
+        let epoch_length = 100;
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 100, 0);
+
+        // requested in epoch 1, takes effect in epoch 3
+        operator
+            .set_next_operator_fee_bps(200, 150, epoch_length)
+            .unwrap();
+        assert_eq!(operator.next_operator_fee_bps(), 200);
+        assert_eq!(operator.next_operator_fee_epoch(), 3);
+        assert_eq!(
+            operator
+                .current_operator_fee_bps(299, epoch_length)
+                .unwrap(),
+            100
+        );
+        assert_eq!(
+            operator
+                .current_operator_fee_bps(300, epoch_length)
+                .unwrap(),
+            200
+        );
+
+        // the bump is measured against the fee in effect
+        operator
+            .set_next_operator_fee_bps(200 + MAX_OPERATOR_FEE_BUMP_BPS, 300, epoch_length)
+            .unwrap();
+        assert_eq!(
+            operator
+                .current_operator_fee_bps(300, epoch_length)
+                .unwrap(),
+            200
+        );
+    }
+
+    #[test]
+    fn test_set_next_operator_fee_bps_limits() {
+        let epoch_length = 100;
+        let mut operator = Operator::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 100, 0);
+
+        assert!(matches!(
+            operator.set_next_operator_fee_bps(10_001, 0, epoch_length),
+            Err(RestakingError::OperatorFeeCapExceeded)
+        ));
+        assert!(matches!(
+            operator.set_next_operator_fee_bps(101 + MAX_OPERATOR_FEE_BUMP_BPS, 0, epoch_length),
+            Err(RestakingError::OperatorFeeBumpTooLarge)
+        ));
+
+        // decreases are not limited
+        operator
+            .set_next_operator_fee_bps(0, 0, epoch_length)
+            .unwrap();
+        assert_eq!(operator.next_operator_fee_bps(), 0);
+    }
+
+    #[test]
+    fn test_set_next_voter_takes_effect_next_epoch() {
+        let epoch_length = 100;
+        let old_voter = Pubkey::new_unique();
+        let mut operator = Operator::new(Pubkey::new_unique(), old_voter, 0, 0, 0);
+
+        let new_voter = Pubkey::new_unique();
+        operator
+            .set_next_voter(&new_voter, 150, epoch_length)
+            .unwrap();
+        assert_eq!(operator.voter, old_voter);
+        assert_eq!(operator.next_voter, new_voter);
+        assert_eq!(operator.next_voter_epoch(), 2);
+        assert_eq!(
+            operator.current_voter(199, epoch_length).unwrap(),
+            old_voter
+        );
+        assert_eq!(
+            operator.current_voter(200, epoch_length).unwrap(),
+            new_voter
+        );
+
+        // the pending voter is applied before the next rotation is scheduled
+        let newest_voter = Pubkey::new_unique();
+        operator
+            .set_next_voter(&newest_voter, 250, epoch_length)
+            .unwrap();
+        assert_eq!(operator.voter, new_voter);
+        assert_eq!(operator.next_voter, newest_voter);
+        assert_eq!(operator.next_voter_epoch(), 3);
+
+        // setting the voter in effect cancels the pending rotation
+        operator
+            .set_next_voter(&new_voter, 260, epoch_length)
+            .unwrap();
+        assert_eq!(operator.next_voter_epoch(), 0);
+        assert_eq!(
+            operator.current_voter(1_000, epoch_length).unwrap(),
+            new_voter
+        );
     }
 }
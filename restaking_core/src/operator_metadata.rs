@@ -0,0 +1,69 @@
+//! The OperatorMetadata account stores the public profile of an operator
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::AccountDeserialize;
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::metadata::{MetadataAccount, MetadataFields};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`OperatorMetadata`] account
+///
+/// - stores the name, website, logo and contact of an operator.
+/// - is created and updated by the operator metadata admin.
+/// - shares its fields, PDA and loading logic with the other metadata account through
+///   [`MetadataAccount`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct OperatorMetadata {
+    /// The operator
+    pub operator: Pubkey,
+
+    /// The public profile of the operator
+    pub metadata: MetadataFields,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl MetadataAccount for OperatorMetadata {
+    const SEED: &'static [u8] = b"operator_metadata";
+    const NAME: &'static str = "Operator metadata";
+
+    fn new(operator: Pubkey, bump: u8) -> Self {
+        Self {
+            operator,
+            metadata: MetadataFields::default(),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    fn metadata(&self) -> &MetadataFields {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut MetadataFields {
+        &mut self.metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_metadata_no_padding() {
+        let operator_metadata_size = std::mem::size_of::<OperatorMetadata>();
+        let sum_of_fields = size_of::<Pubkey>() + // operator
+            size_of::<MetadataFields>() + // metadata
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(operator_metadata_size, sum_of_fields);
+    }
+}
@@ -0,0 +1,443 @@
+//! The OperatorSnapshot account records the stake delegated to an operator by each vault of an
+//! NCN for one epoch
+
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{
+    types::{PodBool, PodU64},
+    AccountDeserialize, Discriminator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use shank::{ShankAccount, ShankType};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// The maximum number of vaults of an NCN an operator snapshot can hold
+pub const MAX_SNAPSHOT_VAULTS: usize = 32;
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The stake a vault delegated to an operator, as counted by an NCN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct VaultOperatorStake {
+    /// The vault
+    pub vault: Pubkey,
+
+    /// The supported mint of the vault
+    pub st_mint: Pubkey,
+
+    /// The stake delegated to the operator, after the vault's allocation cap for the NCN
+    stake: PodU64,
+
+    /// Whether the vault has been snapshotted
+    is_snapshotted: PodBool,
+}
+
+impl Default for VaultOperatorStake {
+    fn default() -> Self {
+        Self {
+            vault: Pubkey::default(),
+            st_mint: Pubkey::default(),
+            stake: PodU64::from(0),
+            is_snapshotted: PodBool::from(false),
+        }
+    }
+}
+
+impl VaultOperatorStake {
+    pub fn stake(&self) -> u64 {
+        self.stake.into()
+    }
+
+    pub fn is_snapshotted(&self) -> bool {
+        self.is_snapshotted.into()
+    }
+}
+
+/// The [`OperatorSnapshot`] account
+///
+/// - is created permissionlessly for each operator of an NCN once the
+///   [`crate::epoch_snapshot::EpochSnapshot`] of the epoch exists.
+/// - holds one [`VaultOperatorStake`] per vault of the NCN, indexed by the vault's
+///   [`crate::ncn_vault_ticket::NcnVaultTicket`] index.
+/// - is complete once every vault has been snapshotted. An operator that is not active for the
+///   NCN is complete on creation and holds no stake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct OperatorSnapshot {
+    /// The NCN
+    pub ncn: Pubkey,
+
+    /// The operator
+    pub operator: Pubkey,
+
+    /// The epoch of the snapshot
+    epoch: PodU64,
+
+    /// The slot the snapshot was created
+    slot_created: PodU64,
+
+    /// Whether the operator was active for the NCN during the epoch
+    is_active: PodBool,
+
+    /// The number of vaults to snapshot
+    vault_count: PodU64,
+
+    /// The number of vaults snapshotted
+    vaults_snapshotted: PodU64,
+
+    /// The stake delegated to the operator by each vault of the NCN
+    vault_operator_stakes: [VaultOperatorStake; 32],
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    reserved: [u8; 263],
+}
+
+impl OperatorSnapshot {
+    pub fn new(
+        ncn: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+        slot_created: u64,
+        is_active: bool,
+        vault_count: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            ncn,
+            operator,
+            epoch: PodU64::from(epoch),
+            slot_created: PodU64::from(slot_created),
+            is_active: PodBool::from(is_active),
+            // an inactive operator holds no stake, so there is nothing to snapshot
+            vault_count: PodU64::from(if is_active { vault_count } else { 0 }),
+            vaults_snapshotted: PodU64::from(0),
+            vault_operator_stakes: [VaultOperatorStake::default(); MAX_SNAPSHOT_VAULTS],
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+
+    pub fn slot_created(&self) -> u64 {
+        self.slot_created.into()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active.into()
+    }
+
+    pub fn vault_count(&self) -> u64 {
+        self.vault_count.into()
+    }
+
+    pub fn vaults_snapshotted(&self) -> u64 {
+        self.vaults_snapshotted.into()
+    }
+
+    /// Whether every vault has been snapshotted
+    pub fn is_complete(&self) -> bool {
+        self.vaults_snapshotted() == self.vault_count()
+    }
+
+    /// The stakes of the vaults of the NCN, indexed by their NCN vault ticket index
+    pub fn vault_operator_stakes(&self) -> &[VaultOperatorStake] {
+        &self.vault_operator_stakes[..self.vault_count() as usize]
+    }
+
+    /// The total stake delegated to the operator by the vaults using the given mint
+    ///
+    /// # Arguments
+    /// * `st_mint` - The supported mint
+    pub fn stake_for_mint(&self, st_mint: &Pubkey) -> Result<u64, RestakingError> {
+        self.vault_operator_stakes()
+            .iter()
+            .filter(|vault_operator_stake| vault_operator_stake.st_mint.eq(st_mint))
+            .try_fold(0_u64, |total, vault_operator_stake| {
+                total
+                    .checked_add(vault_operator_stake.stake())
+                    .ok_or(RestakingError::ArithmeticOverflow)
+            })
+    }
+
+    /// Records the stake a vault delegated to the operator
+    ///
+    /// # Arguments
+    /// * `index` - The NCN vault ticket index of the vault
+    /// * `vault` - The vault
+    /// * `st_mint` - The supported mint of the vault
+    /// * `stake` - The stake delegated to the operator
+    ///
+    /// # Errors
+    /// * [`RestakingError::OperatorSnapshotInactive`] - If the operator is not active
+    /// * [`RestakingError::EpochSnapshotVaultNotIncluded`] - If the index is not part of the snapshot
+    /// * [`RestakingError::OperatorSnapshotVaultAlreadyRecorded`] - If the vault was already
+    ///   snapshotted
+    pub fn record_vault_operator_stake(
+        &mut self,
+        index: u64,
+        vault: Pubkey,
+        st_mint: Pubkey,
+        stake: u64,
+    ) -> Result<(), RestakingError> {
+        if !self.is_active() {
+            msg!("Operator is not active, there is no stake to snapshot");
+            return Err(RestakingError::OperatorSnapshotInactive);
+        }
+        if index >= self.vault_count() {
+            msg!("Vault index {} is not part of the snapshot", index);
+            return Err(RestakingError::EpochSnapshotVaultNotIncluded);
+        }
+
+        let vault_operator_stake = &mut self.vault_operator_stakes[index as usize];
+        if vault_operator_stake.is_snapshotted() {
+            msg!("Vault {} was already snapshotted", vault);
+            return Err(RestakingError::OperatorSnapshotVaultAlreadyRecorded);
+        }
+        *vault_operator_stake = VaultOperatorStake {
+            vault,
+            st_mint,
+            stake: PodU64::from(stake),
+            is_snapshotted: PodBool::from(true),
+        };
+
+        self.vaults_snapshotted = PodU64::from(
+            self.vaults_snapshotted()
+                .checked_add(1)
+                .ok_or(RestakingError::ArithmeticOverflow)?,
+        );
+
+        Ok(())
+    }
+
+    /// Deactivates the snapshot of an operator jailed during the epoch, dropping the stake of
+    /// every vault including those already snapshotted. The snapshot is complete afterwards.
+    ///
+    /// # Errors
+    /// * [`RestakingError::OperatorSnapshotInactive`] - If the operator is not active
+    pub fn deactivate(&mut self) -> Result<(), RestakingError> {
+        if !self.is_active() {
+            msg!("Operator is not active, there is no stake to drop");
+            return Err(RestakingError::OperatorSnapshotInactive);
+        }
+
+        self.is_active = PodBool::from(false);
+        self.vault_count = PodU64::from(0);
+        self.vaults_snapshotted = PodU64::from(0);
+        self.vault_operator_stakes = [VaultOperatorStake::default(); MAX_SNAPSHOT_VAULTS];
+
+        Ok(())
+    }
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `ncn` - The NCN
+    /// * `operator` - The operator
+    /// * `epoch` - The epoch of the snapshot
+    pub fn seeds(ncn: &Pubkey, operator: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"operator_snapshot".to_vec(),
+            ncn.to_bytes().to_vec(),
+            operator.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `ncn` - The NCN
+    /// * `operator` - The operator
+    /// * `epoch` - The epoch of the snapshot
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(ncn, operator, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`OperatorSnapshot`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `operator_snapshot` - The [`OperatorSnapshot`] account
+    /// * `ncn` - The NCN account
+    /// * `operator` - The operator account
+    /// * `epoch` - The epoch of the snapshot
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        operator_snapshot: &AccountInfo,
+        ncn: &AccountInfo,
+        operator: &AccountInfo,
+        epoch: u64,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if operator_snapshot.owner.ne(program_id) {
+            msg!("Operator snapshot has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if operator_snapshot.data_is_empty() {
+            msg!("Operator snapshot data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !operator_snapshot.is_writable {
+            msg!("Operator snapshot is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if operator_snapshot.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Operator snapshot discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let expected_pubkey =
+            Self::find_program_address(program_id, ncn.key, operator.key, epoch).0;
+        if operator_snapshot.key.ne(&expected_pubkey) {
+            msg!("Operator snapshot is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_operator_stake_no_padding() {
+        let vault_operator_stake_size = std::mem::size_of::<VaultOperatorStake>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // st_mint
+            size_of::<PodU64>() + // stake
+            size_of::<PodBool>(); // is_snapshotted
+        assert_eq!(vault_operator_stake_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_operator_snapshot_no_padding() {
+        let operator_snapshot_size = std::mem::size_of::<OperatorSnapshot>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() + // operator
+            size_of::<PodU64>() + // epoch
+            size_of::<PodU64>() + // slot_created
+            size_of::<PodBool>() + // is_active
+            size_of::<PodU64>() + // vault_count
+            size_of::<PodU64>() + // vaults_snapshotted
+            size_of::<VaultOperatorStake>() * MAX_SNAPSHOT_VAULTS + // vault_operator_stakes
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(operator_snapshot_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_record_vault_operator_stake() {
+        let st_mint = Pubkey::new_unique();
+        let mut operator_snapshot = OperatorSnapshot::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            100,
+            true,
+            2,
+            0,
+        );
+        assert!(!operator_snapshot.is_complete());
+
+        let vault = Pubkey::new_unique();
+        operator_snapshot
+            .record_vault_operator_stake(1, vault, st_mint, 1_000)
+            .unwrap();
+        assert!(matches!(
+            operator_snapshot.record_vault_operator_stake(1, vault, st_mint, 1_000),
+            Err(RestakingError::OperatorSnapshotVaultAlreadyRecorded)
+        ));
+        assert!(matches!(
+            operator_snapshot.record_vault_operator_stake(2, vault, st_mint, 1_000),
+            Err(RestakingError::EpochSnapshotVaultNotIncluded)
+        ));
+
+        operator_snapshot
+            .record_vault_operator_stake(0, Pubkey::new_unique(), st_mint, 500)
+            .unwrap();
+        assert!(operator_snapshot.is_complete());
+        assert_eq!(operator_snapshot.vault_operator_stakes().len(), 2);
+        assert_eq!(operator_snapshot.vault_operator_stakes()[1].vault, vault);
+        assert_eq!(operator_snapshot.stake_for_mint(&st_mint).unwrap(), 1_500);
+        assert_eq!(
+            operator_snapshot
+                .stake_for_mint(&Pubkey::new_unique())
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_deactivate_operator_snapshot() {
+        let st_mint = Pubkey::new_unique();
+        let mut operator_snapshot = OperatorSnapshot::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            100,
+            true,
+            2,
+            0,
+        );
+        operator_snapshot
+            .record_vault_operator_stake(0, Pubkey::new_unique(), st_mint, 1_000)
+            .unwrap();
+
+        operator_snapshot.deactivate().unwrap();
+        assert!(!operator_snapshot.is_active());
+        assert!(operator_snapshot.is_complete());
+        assert!(operator_snapshot.vault_operator_stakes().is_empty());
+        assert_eq!(operator_snapshot.stake_for_mint(&st_mint).unwrap(), 0);
+        assert!(matches!(
+            operator_snapshot.deactivate(),
+            Err(RestakingError::OperatorSnapshotInactive)
+        ));
+    }
+
+    #[test]
+    fn test_inactive_operator_snapshot_is_complete() {
+        let mut operator_snapshot = OperatorSnapshot::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1,
+            100,
+            false,
+            2,
+            0,
+        );
+        assert!(operator_snapshot.is_complete());
+        assert!(matches!(
+            operator_snapshot.record_vault_operator_stake(
+                0,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                1
+            ),
+            Err(RestakingError::OperatorSnapshotInactive)
+        ));
+    }
+}
@@ -37,10 +37,7 @@ pub fn process_cooldown_ncn_vault_slasher_ticket(
     // The NCN slasher admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
-        msg!("Invalid slasher admin for NCN");
-        return Err(RestakingError::NcnSlasherAdminInvalid.into());
-    }
+    ncn.check_slasher_admin(ncn_slasher_admin.key)?;
 
     // The NcnVaultSlasherTicket shall be active before it can be cooled down
     let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow_mut();
@@ -48,7 +45,11 @@ pub fn process_cooldown_ncn_vault_slasher_ticket(
         NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
     if !ncn_vault_slasher_ticket
         .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
+        .deactivate_with_cooldown_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.cooldown_epochs(),
+        )?
     {
         msg!("Slasher is not ready to be deactivated");
         return Err(RestakingError::NcnVaultSlasherTicketFailedCooldown.into());
@@ -24,10 +24,7 @@ pub fn process_cooldown_ncn_vault_ticket(
     // The NCN vault admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if ncn.vault_admin.ne(ncn_vault_admin.key) {
-        msg!("Invalid vault admin for NCN");
-        return Err(RestakingError::NcnVaultAdminInvalid.into());
-    }
+    ncn.check_vault_admin(ncn_vault_admin.key)?;
 
     // The NcnVaultTicket shall be active before it can be cooled down
     let config_data = config.data.borrow();
@@ -35,10 +32,11 @@ pub fn process_cooldown_ncn_vault_ticket(
     let mut ncn_vault_ticket_data = ncn_vault_ticket.data.borrow_mut();
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
-    if !ncn_vault_ticket
-        .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
-    {
+    if !ncn_vault_ticket.state.deactivate_with_cooldown_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.cooldown_epochs(),
+    )? {
         msg!("Vault is not ready to be deactivated");
         return Err(RestakingError::NcnVaultTicketFailedCooldown.into());
     }
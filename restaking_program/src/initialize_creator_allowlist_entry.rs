@@ -0,0 +1,46 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    creation_policy::initialize_creator_allowlist_entry,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{config::Config, creator_allowlist_entry::CreatorAllowlistEntry};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the initialize creator allowlist entry instruction:
+/// [`crate::RestakingInstruction::InitializeCreatorAllowlistEntry`]
+///
+/// Specification:
+/// - Only the config admin can allowlist a creator. The config admin must sign the transaction
+///   and pays for the account.
+/// - The [`CreatorAllowlistEntry`] shall be at the canonical PDA for the creator
+pub fn process_initialize_creator_allowlist_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, creator_allowlist_entry, creator, config_admin, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    load_system_account(creator_allowlist_entry, true)?;
+    load_signer(config_admin, true)?;
+    load_system_program(system_program)?;
+
+    if config_admin.key != &config.admin {
+        msg!("Config admin does not match");
+        return Err(RestakingError::ConfigAdminInvalid.into());
+    }
+
+    initialize_creator_allowlist_entry::<CreatorAllowlistEntry>(
+        program_id,
+        creator_allowlist_entry,
+        creator,
+        config_admin,
+        system_program,
+    )
+}
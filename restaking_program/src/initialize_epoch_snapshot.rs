@@ -0,0 +1,83 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    create_account, get_epoch,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{config::Config, epoch_snapshot::EpochSnapshot, ncn::Ncn};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Processes the initialize epoch snapshot instruction:
+/// [`crate::RestakingInstruction::InitializeEpochSnapshot`]
+///
+/// Specification:
+/// - Anyone can initialize the snapshot of an NCN for the current epoch
+/// - The [`EpochSnapshot`] shall be at the canonical PDA for the NCN and epoch
+/// - The operators and vaults of the NCN at the time of the snapshot take part in it
+pub fn process_initialize_epoch_snapshot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, ncn, epoch_snapshot, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    load_system_account(epoch_snapshot, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let slot = Clock::get()?.slot;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let epoch = get_epoch(slot, config.epoch_length())?;
+
+    let ncn_data = ncn.data.borrow();
+    let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    EpochSnapshot::check_vault_count(ncn_account.vault_count())?;
+
+    // The epoch snapshot shall be at the canonical PDA
+    let (epoch_snapshot_pubkey, epoch_snapshot_bump, mut epoch_snapshot_seeds) =
+        EpochSnapshot::find_program_address(program_id, ncn.key, epoch);
+    epoch_snapshot_seeds.push(vec![epoch_snapshot_bump]);
+    if epoch_snapshot.key.ne(&epoch_snapshot_pubkey) {
+        msg!("Epoch snapshot is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!(
+        "Initializing epoch snapshot for epoch {} at address {}",
+        epoch,
+        epoch_snapshot.key
+    );
+    create_account(
+        payer,
+        epoch_snapshot,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<EpochSnapshot>() as u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?,
+        &epoch_snapshot_seeds,
+    )?;
+
+    let mut epoch_snapshot_data = epoch_snapshot.try_borrow_mut_data()?;
+    EpochSnapshot::write_header(&mut epoch_snapshot_data);
+    let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
+    *epoch_snapshot = EpochSnapshot::new(
+        *ncn.key,
+        epoch,
+        slot,
+        ncn_account.operator_count(),
+        ncn_account.vault_count(),
+        epoch_snapshot_bump,
+    );
+
+    Ok(())
+}
@@ -1,11 +1,14 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    creation_policy::{self, CreationPolicyError},
     loader::{load_signer, load_system_account, load_system_program},
 };
-use jito_restaking_core::{config::Config, ncn::Ncn};
+use jito_restaking_core::{
+    config::Config, creator_allowlist_entry::CreatorAllowlistEntry, ncn::Ncn,
+};
 use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
@@ -14,8 +17,10 @@ use solana_program::{
 
 /// Initializes an NCN and associated accounts
 /// [`crate::RestakingInstruction::InitializeNcn`]
+///
+/// The admin shall satisfy the config's [`CreationPolicy`], see [`check_creation_policy`]
 pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, ncn, admin, base, system_program] = accounts else {
+    let [config, ncn, admin, base, system_program, optional_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Config::load(program_id, config, true)?;
@@ -48,8 +53,16 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     let mut config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
+    check_creation_policy(
+        program_id,
+        config,
+        admin,
+        system_program,
+        optional_accounts.first(),
+    )?;
+
     let mut ncn_data = ncn.try_borrow_mut_data()?;
-    ncn_data[0] = Ncn::DISCRIMINATOR;
+    Ncn::write_header(&mut ncn_data);
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     *ncn = Ncn::new(*base.key, *admin.key, config.ncn_count(), ncn_bump);
 
@@ -57,3 +70,30 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
 
     Ok(())
 }
+
+/// Checks that `creator` may create an NCN or operator under the config's [`CreationPolicy`], see
+/// [`creation_policy::check_creation_policy`]
+pub fn check_creation_policy<'a, 'info>(
+    program_id: &Pubkey,
+    config: &Config,
+    creator: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    creation_policy_account: Option<&'a AccountInfo<'info>>,
+) -> ProgramResult {
+    creation_policy::check_creation_policy::<CreatorAllowlistEntry>(
+        program_id,
+        config.creation_policy()?,
+        &config.creation_fee_treasury,
+        config.creation_fee_lamports(),
+        creator,
+        system_program,
+        creation_policy_account,
+    )
+    .map_err(|e| match e {
+        CreationPolicyError::CreatorNotAllowlisted => RestakingError::CreatorNotAllowlisted.into(),
+        CreationPolicyError::CreationFeeTreasuryInvalid => {
+            RestakingError::CreationFeeTreasuryInvalid.into()
+        }
+        CreationPolicyError::Program(e) => e,
+    })
+}
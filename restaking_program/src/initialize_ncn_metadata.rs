@@ -0,0 +1,48 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, ncn_metadata::NcnMetadata};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::metadata::initialize_metadata;
+
+/// Processes the initialize NCN metadata instruction:
+/// [`crate::RestakingInstruction::InitializeNcnMetadata`]
+///
+/// Specification:
+/// - Only the NCN metadata admin can initialize the metadata. The metadata admin must sign the
+///   transaction.
+/// - The [`NcnMetadata`] shall be at the canonical PDA for the NCN
+/// - Each field shall be valid UTF-8 within its length bound and contain no NUL character
+pub fn process_initialize_ncn_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+) -> ProgramResult {
+    let [ncn, ncn_metadata, metadata_admin, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Ncn::load(program_id, ncn, false)?;
+    load_signer(metadata_admin, false)?;
+
+    let ncn_data = ncn.data.borrow();
+    let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn_account.check_metadata_admin(metadata_admin.key)?;
+
+    initialize_metadata::<NcnMetadata>(
+        program_id,
+        ncn,
+        ncn_metadata,
+        payer,
+        system_program,
+        &name,
+        &website,
+        &logo_uri,
+        &contact,
+    )
+}
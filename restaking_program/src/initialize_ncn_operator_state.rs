@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -48,10 +48,7 @@ pub fn process_initialize_ncn_operator_state(
     // The NCN operator admin must be the signer for adding an operator to the NCN
     let mut ncn_data = ncn_info.data.borrow_mut();
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
-    if ncn.operator_admin.ne(ncn_operator_admin.key) {
-        msg!("Invalid operator admin for NCN");
-        return Err(RestakingError::NcnOperatorAdminInvalid.into());
-    }
+    ncn.check_operator_admin(ncn_operator_admin.key)?;
 
     msg!("Initializing NcnOperatorState at address {}", operator.key);
     create_account(
@@ -67,7 +64,7 @@ pub fn process_initialize_ncn_operator_state(
     )?;
 
     let mut ncn_operator_state_data = ncn_operator_state.try_borrow_mut_data()?;
-    ncn_operator_state_data[0] = NcnOperatorState::DISCRIMINATOR;
+    NcnOperatorState::write_header(&mut ncn_operator_state_data);
     let ncn_operator_state =
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     *ncn_operator_state = NcnOperatorState::new(
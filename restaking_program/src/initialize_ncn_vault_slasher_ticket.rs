@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -60,10 +60,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
 
     let mut ncn_data = ncn_info.data.borrow_mut();
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
-    if ncn.slasher_admin.ne(ncn_slasher_admin.key) {
-        msg!("Admin is not the slasher admin");
-        return Err(RestakingError::NcnSlasherAdminInvalid.into());
-    }
+    ncn.check_slasher_admin(ncn_slasher_admin.key)?;
 
     msg!(
         "Initializing NcnVaultSlasherTicket at address {}",
@@ -81,7 +78,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
         &ncn_vault_slasher_ticket_seeds,
     )?;
     let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.try_borrow_mut_data()?;
-    ncn_vault_slasher_ticket_data[0] = NcnVaultSlasherTicket::DISCRIMINATOR;
+    NcnVaultSlasherTicket::write_header(&mut ncn_vault_slasher_ticket_data);
     let ncn_vault_slasher_ticket =
         NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
     *ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
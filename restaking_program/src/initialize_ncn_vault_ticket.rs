@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -48,10 +48,7 @@ pub fn process_initialize_ncn_vault_ticket(
 
     let mut ncn_data = ncn_info.data.borrow_mut();
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
-    if ncn.vault_admin.ne(ncn_vault_admin.key) {
-        msg!("Invalid vault admin for NCN");
-        return Err(RestakingError::NcnVaultAdminInvalid.into());
-    }
+    ncn.check_vault_admin(ncn_vault_admin.key)?;
 
     msg!(
         "Initializing NcnVaultTicket at address {}",
@@ -70,7 +67,7 @@ pub fn process_initialize_ncn_vault_ticket(
     )?;
 
     let mut ncn_vault_ticket_data = ncn_vault_ticket.try_borrow_mut_data()?;
-    ncn_vault_ticket_data[0] = NcnVaultTicket::DISCRIMINATOR;
+    NcnVaultTicket::write_header(&mut ncn_vault_ticket_data);
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
     *ncn_vault_ticket = NcnVaultTicket::new(
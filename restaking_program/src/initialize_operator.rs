@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -12,14 +12,18 @@ use solana_program::{
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
 
+use crate::initialize_ncn::check_creation_policy;
+
 /// Initializes a node operator and associated accounts.
 /// [`crate::RestakingInstruction::InitializeOperator`]
+///
+/// The admin shall satisfy the config's creation policy, see [`check_creation_policy`]
 pub fn process_initialize_operator(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     operator_fee_bps: u16,
 ) -> ProgramResult {
-    let [config, operator, admin, base, system_program] = accounts else {
+    let [config, operator, admin, base, system_program, optional_accounts @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Config::load(program_id, config, true)?;
@@ -58,8 +62,16 @@ pub fn process_initialize_operator(
     let mut config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
+    check_creation_policy(
+        program_id,
+        config,
+        admin,
+        system_program,
+        optional_accounts.first(),
+    )?;
+
     let mut operator_data = operator.try_borrow_mut_data()?;
-    operator_data[0] = Operator::DISCRIMINATOR;
+    Operator::write_header(&mut operator_data);
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
 
     *operator = Operator::new(
@@ -0,0 +1,48 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{operator::Operator, operator_metadata::OperatorMetadata};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::metadata::initialize_metadata;
+
+/// Processes the initialize operator metadata instruction:
+/// [`crate::RestakingInstruction::InitializeOperatorMetadata`]
+///
+/// Specification:
+/// - Only the operator metadata admin can initialize the metadata. The metadata admin must sign the
+///   transaction.
+/// - The [`OperatorMetadata`] shall be at the canonical PDA for the operator
+/// - Each field shall be valid UTF-8 within its length bound and contain no NUL character
+pub fn process_initialize_operator_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+) -> ProgramResult {
+    let [operator, operator_metadata, metadata_admin, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Operator::load(program_id, operator, false)?;
+    load_signer(metadata_admin, false)?;
+
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator_account.check_metadata_admin(metadata_admin.key)?;
+
+    initialize_metadata::<OperatorMetadata>(
+        program_id,
+        operator,
+        operator_metadata,
+        payer,
+        system_program,
+        &name,
+        &website,
+        &logo_uri,
+        &contact,
+    )
+}
@@ -0,0 +1,106 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    create_account, get_epoch,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::{
+    config::Config, epoch_snapshot::EpochSnapshot, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    operator::Operator, operator_snapshot::OperatorSnapshot,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Processes the initialize operator snapshot instruction:
+/// [`crate::RestakingInstruction::InitializeOperatorSnapshot`]
+///
+/// Specification:
+/// - Anyone can initialize the snapshot of an operator once the [`EpochSnapshot`] of the current
+///   epoch exists
+/// - The operator shall have been part of the NCN when the [`EpochSnapshot`] was created
+/// - The operator is active when both the NCN and the operator opted in to each other and the
+///   operator is not jailed. An inactive operator holds no stake and its snapshot is complete on
+///   creation.
+pub fn process_initialize_operator_snapshot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, ncn, operator, ncn_operator_state, epoch_snapshot, operator_snapshot, payer, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, false)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, false)?;
+    load_system_account(operator_snapshot, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let slot = Clock::get()?.slot;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let epoch_length = config.epoch_length();
+    let epoch = get_epoch(slot, epoch_length)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn, epoch, true)?;
+
+    let mut epoch_snapshot_data = epoch_snapshot.data.borrow_mut();
+    let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+    epoch_snapshot.check_operator_index(ncn_operator_state.index())?;
+
+    // The operator snapshot shall be at the canonical PDA
+    let (operator_snapshot_pubkey, operator_snapshot_bump, mut operator_snapshot_seeds) =
+        OperatorSnapshot::find_program_address(program_id, ncn.key, operator.key, epoch);
+    operator_snapshot_seeds.push(vec![operator_snapshot_bump]);
+    if operator_snapshot.key.ne(&operator_snapshot_pubkey) {
+        msg!("Operator snapshot is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let is_active = ncn_operator_state.is_active(slot, epoch_length)?;
+
+    msg!(
+        "Initializing operator snapshot for epoch {} at address {}",
+        epoch,
+        operator_snapshot.key
+    );
+    create_account(
+        payer,
+        operator_snapshot,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<OperatorSnapshot>() as u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?,
+        &operator_snapshot_seeds,
+    )?;
+
+    let mut operator_snapshot_data = operator_snapshot.try_borrow_mut_data()?;
+    OperatorSnapshot::write_header(&mut operator_snapshot_data);
+    let operator_snapshot =
+        OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+    *operator_snapshot = OperatorSnapshot::new(
+        *ncn.key,
+        *operator.key,
+        epoch,
+        slot,
+        is_active,
+        epoch_snapshot.vault_count(),
+        operator_snapshot_bump,
+    );
+
+    // An inactive operator, or an NCN without vaults, leaves nothing to snapshot
+    if operator_snapshot.is_complete() {
+        epoch_snapshot.record_operator_snapshotted(is_active)?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -76,7 +76,7 @@ pub fn process_initialize_operator_vault_ticket(
     )?;
     let mut operator_vault_ticket_account_data =
         operator_vault_ticket_account.try_borrow_mut_data()?;
-    operator_vault_ticket_account_data[0] = OperatorVaultTicket::DISCRIMINATOR;
+    OperatorVaultTicket::write_header(&mut operator_vault_ticket_account_data);
     let operator_vault_ticket =
         OperatorVaultTicket::try_from_slice_unchecked_mut(&mut operator_vault_ticket_account_data)?;
     *operator_vault_ticket = OperatorVaultTicket::new(
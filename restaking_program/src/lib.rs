@@ -1,17 +1,31 @@
+mod close_creator_allowlist_entry;
 mod cooldown_ncn_vault_slasher_ticket;
 mod cooldown_ncn_vault_ticket;
 mod cooldown_operator_vault_ticket;
 mod initialize_config;
+mod initialize_creator_allowlist_entry;
+mod initialize_epoch_snapshot;
 mod initialize_ncn;
+mod initialize_ncn_metadata;
 mod initialize_ncn_operator_state;
 mod initialize_ncn_vault_slasher_ticket;
 mod initialize_ncn_vault_ticket;
 mod initialize_operator;
+mod initialize_operator_metadata;
+mod initialize_operator_snapshot;
 mod initialize_operator_vault_ticket;
+mod metadata;
+mod migrate_account;
 mod ncn_cooldown_operator;
 mod ncn_delegate_token_account;
+mod ncn_jail_operator;
 mod ncn_set_admin;
+mod ncn_set_max_slashable_per_epoch;
+mod ncn_set_operator_bond;
+mod ncn_set_program;
 mod ncn_set_secondary_admin;
+mod ncn_set_transition_epochs;
+mod ncn_unjail_operator;
 mod ncn_warmup_operator;
 mod operator_cooldown_ncn;
 mod operator_delegate_token_account;
@@ -19,7 +33,13 @@ mod operator_set_admin;
 mod operator_set_fee;
 mod operator_set_secondary_admin;
 mod operator_warmup_ncn;
+mod operator_withdraw_bond;
 mod set_config_admin;
+mod set_creation_policy;
+mod slash_operator_bond;
+mod snapshot_vault_operator_delegation;
+mod update_ncn_metadata;
+mod update_operator_metadata;
 mod warmup_ncn_vault_slasher_ticket;
 mod warmup_ncn_vault_ticket;
 mod warmup_operator_vault_ticket;
@@ -35,24 +55,42 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
+    close_creator_allowlist_entry::process_close_creator_allowlist_entry,
     cooldown_ncn_vault_slasher_ticket::process_cooldown_ncn_vault_slasher_ticket,
     cooldown_ncn_vault_ticket::process_cooldown_ncn_vault_ticket,
     cooldown_operator_vault_ticket::process_cooldown_operator_vault_ticket,
-    initialize_config::process_initialize_config, initialize_ncn::process_initialize_ncn,
+    initialize_config::process_initialize_config,
+    initialize_creator_allowlist_entry::process_initialize_creator_allowlist_entry,
+    initialize_epoch_snapshot::process_initialize_epoch_snapshot,
+    initialize_ncn::process_initialize_ncn,
+    initialize_ncn_metadata::process_initialize_ncn_metadata,
     initialize_ncn_operator_state::process_initialize_ncn_operator_state,
     initialize_ncn_vault_slasher_ticket::process_initialize_ncn_vault_slasher_ticket,
     initialize_ncn_vault_ticket::process_initialize_ncn_vault_ticket,
     initialize_operator::process_initialize_operator,
+    initialize_operator_metadata::process_initialize_operator_metadata,
+    initialize_operator_snapshot::process_initialize_operator_snapshot,
     initialize_operator_vault_ticket::process_initialize_operator_vault_ticket,
-    ncn_cooldown_operator::process_ncn_cooldown_operator,
+    migrate_account::process_migrate_account, ncn_cooldown_operator::process_ncn_cooldown_operator,
     ncn_delegate_token_account::process_ncn_delegate_token_account,
-    ncn_set_admin::process_ncn_set_admin, ncn_set_secondary_admin::process_ncn_set_secondary_admin,
+    ncn_jail_operator::process_ncn_jail_operator, ncn_set_admin::process_ncn_set_admin,
+    ncn_set_max_slashable_per_epoch::process_ncn_set_max_slashable_per_epoch,
+    ncn_set_operator_bond::process_ncn_set_operator_bond, ncn_set_program::process_ncn_set_program,
+    ncn_set_secondary_admin::process_ncn_set_secondary_admin,
+    ncn_set_transition_epochs::process_ncn_set_transition_epochs,
+    ncn_unjail_operator::process_ncn_unjail_operator,
     ncn_warmup_operator::process_ncn_warmup_operator,
     operator_cooldown_ncn::process_operator_cooldown_ncn,
     operator_delegate_token_account::process_operator_delegate_token_account,
     operator_set_admin::process_set_node_operator_admin,
     operator_set_secondary_admin::process_set_operator_secondary_admin,
-    operator_warmup_ncn::process_operator_warmup_ncn, set_config_admin::process_set_config_admin,
+    operator_warmup_ncn::process_operator_warmup_ncn,
+    operator_withdraw_bond::process_operator_withdraw_bond,
+    set_config_admin::process_set_config_admin, set_creation_policy::process_set_creation_policy,
+    slash_operator_bond::process_slash_operator_bond,
+    snapshot_vault_operator_delegation::process_snapshot_vault_operator_delegation,
+    update_ncn_metadata::process_update_ncn_metadata,
+    update_operator_metadata::process_update_operator_metadata,
     warmup_ncn_vault_slasher_ticket::process_warmup_ncn_vault_slasher_ticket,
     warmup_ncn_vault_ticket::process_warmup_ncn_vault_ticket,
     warmup_operator_vault_ticket::process_warmup_operator_vault_ticket,
@@ -193,5 +231,127 @@ pub fn process_instruction(
             msg!("Instruction: SetConfigAdmin");
             process_set_config_admin(program_id, accounts)
         }
+        RestakingInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        RestakingInstruction::SetCreationPolicy {
+            creation_policy,
+            creation_fee_lamports,
+        } => {
+            msg!("Instruction: SetCreationPolicy");
+            process_set_creation_policy(
+                program_id,
+                accounts,
+                creation_policy,
+                creation_fee_lamports,
+            )
+        }
+        RestakingInstruction::InitializeCreatorAllowlistEntry => {
+            msg!("Instruction: InitializeCreatorAllowlistEntry");
+            process_initialize_creator_allowlist_entry(program_id, accounts)
+        }
+        RestakingInstruction::CloseCreatorAllowlistEntry => {
+            msg!("Instruction: CloseCreatorAllowlistEntry");
+            process_close_creator_allowlist_entry(program_id, accounts)
+        }
+        RestakingInstruction::InitializeNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        } => {
+            msg!("Instruction: InitializeNcnMetadata");
+            process_initialize_ncn_metadata(program_id, accounts, name, website, logo_uri, contact)
+        }
+        RestakingInstruction::UpdateNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        } => {
+            msg!("Instruction: UpdateNcnMetadata");
+            process_update_ncn_metadata(program_id, accounts, name, website, logo_uri, contact)
+        }
+        RestakingInstruction::InitializeOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        } => {
+            msg!("Instruction: InitializeOperatorMetadata");
+            process_initialize_operator_metadata(
+                program_id, accounts, name, website, logo_uri, contact,
+            )
+        }
+        RestakingInstruction::UpdateOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        } => {
+            msg!("Instruction: UpdateOperatorMetadata");
+            process_update_operator_metadata(program_id, accounts, name, website, logo_uri, contact)
+        }
+        RestakingInstruction::InitializeEpochSnapshot => {
+            msg!("Instruction: InitializeEpochSnapshot");
+            process_initialize_epoch_snapshot(program_id, accounts)
+        }
+        RestakingInstruction::InitializeOperatorSnapshot => {
+            msg!("Instruction: InitializeOperatorSnapshot");
+            process_initialize_operator_snapshot(program_id, accounts)
+        }
+        RestakingInstruction::SnapshotVaultOperatorDelegation => {
+            msg!("Instruction: SnapshotVaultOperatorDelegation");
+            process_snapshot_vault_operator_delegation(program_id, accounts)
+        }
+        RestakingInstruction::NcnJailOperator {
+            reason_code,
+            until_epoch,
+        } => {
+            msg!("Instruction: NcnJailOperator");
+            process_ncn_jail_operator(program_id, accounts, reason_code, until_epoch)
+        }
+        RestakingInstruction::NcnUnjailOperator => {
+            msg!("Instruction: NcnUnjailOperator");
+            process_ncn_unjail_operator(program_id, accounts)
+        }
+        RestakingInstruction::NcnSetTransitionEpochs {
+            warmup_epochs,
+            cooldown_epochs,
+        } => {
+            msg!("Instruction: NcnSetTransitionEpochs");
+            process_ncn_set_transition_epochs(program_id, accounts, warmup_epochs, cooldown_epochs)
+        }
+        RestakingInstruction::NcnSetMaxSlashablePerEpoch {
+            max_slashable_per_epoch,
+        } => {
+            msg!("Instruction: NcnSetMaxSlashablePerEpoch");
+            process_ncn_set_max_slashable_per_epoch(program_id, accounts, max_slashable_per_epoch)
+        }
+        RestakingInstruction::NcnSetProgram { ncn_program } => {
+            msg!("Instruction: NcnSetProgram");
+            process_ncn_set_program(program_id, accounts, ncn_program)
+        }
+        RestakingInstruction::NcnSetOperatorBond {
+            min_operator_bond,
+            max_bond_slashable_per_epoch,
+        } => {
+            msg!("Instruction: NcnSetOperatorBond");
+            process_ncn_set_operator_bond(
+                program_id,
+                accounts,
+                min_operator_bond,
+                max_bond_slashable_per_epoch,
+            )
+        }
+        RestakingInstruction::OperatorWithdrawBond { amount } => {
+            msg!("Instruction: OperatorWithdrawBond");
+            process_operator_withdraw_bond(program_id, accounts, amount)
+        }
+        RestakingInstruction::SlashOperatorBond { amount } => {
+            msg!("Instruction: SlashOperatorBond");
+            process_slash_operator_bond(program_id, accounts, amount)
+        }
     }
 }
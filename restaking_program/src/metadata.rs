@@ -0,0 +1,106 @@
+//! Account creation and updates shared by the NCN and operator metadata instructions
+
+use std::mem::size_of;
+
+use jito_jsm_core::{
+    create_account,
+    loader::{load_signer, load_system_account, load_system_program},
+};
+use jito_restaking_core::metadata::MetadataAccount;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Creates the metadata account of `owner` at the canonical PDA and sets every field.
+/// The caller loads `owner` and checks its metadata admin.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_metadata<'a, 'info, T: MetadataAccount>(
+    program_id: &Pubkey,
+    owner: &'a AccountInfo<'info>,
+    metadata: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    name: &str,
+    website: &str,
+    logo_uri: &str,
+    contact: &str,
+) -> ProgramResult {
+    load_system_account(metadata, true)?;
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    // The metadata shall be at the canonical PDA
+    let (metadata_pubkey, metadata_bump, mut metadata_seeds) =
+        T::find_program_address(program_id, owner.key);
+    metadata_seeds.push(vec![metadata_bump]);
+    if metadata.key.ne(&metadata_pubkey) {
+        msg!("{} is not at the correct PDA", T::NAME);
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    msg!("Initializing {} at address {}", T::NAME, metadata.key);
+    create_account(
+        payer,
+        metadata,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<T>() as u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?,
+        &metadata_seeds,
+    )?;
+
+    let mut metadata_data = metadata.try_borrow_mut_data()?;
+    T::write_header(&mut metadata_data);
+    let metadata = T::try_from_slice_unchecked_mut(&mut metadata_data)?;
+    *metadata = T::new(*owner.key, metadata_bump);
+    metadata.metadata_mut().update(
+        Some(name),
+        Some(website),
+        Some(logo_uri),
+        Some(contact),
+        Clock::get()?.slot,
+    )?;
+
+    Ok(())
+}
+
+/// Updates the given fields of the metadata account of `owner`. The caller loads `owner` and
+/// checks its metadata admin.
+pub fn update_metadata<T: MetadataAccount>(
+    program_id: &Pubkey,
+    owner: &AccountInfo,
+    metadata: &AccountInfo,
+    name: Option<String>,
+    website: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+) -> ProgramResult {
+    T::load(program_id, metadata, owner, true)?;
+
+    if name.is_none() && website.is_none() && logo_uri.is_none() && contact.is_none() {
+        msg!("No metadata fields provided for update");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut metadata_data = metadata.try_borrow_mut_data()?;
+    let metadata = T::try_from_slice_unchecked_mut(&mut metadata_data)?;
+    metadata.metadata_mut().update(
+        name.as_deref(),
+        website.as_deref(),
+        logo_uri.as_deref(),
+        contact.as_deref(),
+        Clock::get()?.slot,
+    )?;
+
+    msg!(
+        "{} updated, update count {}",
+        T::NAME,
+        metadata.metadata().update_count()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{
+    loader::{load_signer, load_system_program},
+    migrate_account,
+};
+use jito_restaking_core::{
+    config::Config, creator_allowlist_entry::CreatorAllowlistEntry, epoch_snapshot::EpochSnapshot,
+    ncn::Ncn, ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_metadata::OperatorMetadata, operator_snapshot::OperatorSnapshot,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Processes the migrate account instruction: [`crate::RestakingInstruction::MigrateAccount`]
+///
+/// Specification:
+/// - The account must be owned by the restaking program
+/// - The account type is determined by its discriminator
+/// - The account is upgraded one layout version at a time until it reaches the current layout
+/// - The payer funds the rent for any additional space
+/// - Accounts already at the current layout version are left untouched
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if account.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let discriminator = account
+        .data
+        .borrow()
+        .first()
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let rent = Rent::get()?;
+
+    match discriminator {
+        Config::DISCRIMINATOR => migrate_account::<Config>(account, payer, &rent),
+        Ncn::DISCRIMINATOR => migrate_account::<Ncn>(account, payer, &rent),
+        Operator::DISCRIMINATOR => migrate_account::<Operator>(account, payer, &rent),
+        NcnOperatorState::DISCRIMINATOR => {
+            migrate_account::<NcnOperatorState>(account, payer, &rent)
+        }
+        OperatorVaultTicket::DISCRIMINATOR => {
+            migrate_account::<OperatorVaultTicket>(account, payer, &rent)
+        }
+        NcnVaultTicket::DISCRIMINATOR => migrate_account::<NcnVaultTicket>(account, payer, &rent),
+        NcnVaultSlasherTicket::DISCRIMINATOR => {
+            migrate_account::<NcnVaultSlasherTicket>(account, payer, &rent)
+        }
+        CreatorAllowlistEntry::DISCRIMINATOR => {
+            migrate_account::<CreatorAllowlistEntry>(account, payer, &rent)
+        }
+        NcnMetadata::DISCRIMINATOR => migrate_account::<NcnMetadata>(account, payer, &rent),
+        OperatorMetadata::DISCRIMINATOR => {
+            migrate_account::<OperatorMetadata>(account, payer, &rent)
+        }
+        EpochSnapshot::DISCRIMINATOR => migrate_account::<EpochSnapshot>(account, payer, &rent),
+        OperatorSnapshot::DISCRIMINATOR => {
+            migrate_account::<OperatorSnapshot>(account, payer, &rent)
+        }
+        _ => {
+            msg!("Account discriminator {} is unknown", discriminator);
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
@@ -29,10 +29,7 @@ pub fn process_ncn_cooldown_operator(
     // The NCN operator admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if !ncn.operator_admin.eq(ncn_operator_admin.key) {
-        msg!("Invalid operator admin for NCN");
-        return Err(RestakingError::NcnOperatorAdminInvalid.into());
-    }
+    ncn.check_operator_admin(ncn_operator_admin.key)?;
 
     // The NcnOperatorTicket shall be active before it can be cooled down
     let config_data = config.data.borrow();
@@ -42,7 +39,11 @@ pub fn process_ncn_cooldown_operator(
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     if !ncn_operator_ticket
         .ncn_opt_in_state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
+        .deactivate_with_cooldown_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.cooldown_epochs(),
+        )?
     {
         msg!("NCN is not ready to deactivate operator");
         return Err(RestakingError::NcnCooldownOperatorFailed.into());
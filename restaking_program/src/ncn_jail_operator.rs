@@ -0,0 +1,58 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// The NCN operator admin can jail a misbehaving operator. Unlike a cooldown, jailing takes
+/// effect immediately: the operator is excluded from the NCN's stake snapshots until
+/// `until_epoch` or until it is unjailed. The jailing is added to the jail history of the
+/// [`NcnOperatorState`], which keeps the latest ones.
+///
+/// [`crate::RestakingInstruction::NcnJailOperator`]
+pub fn process_ncn_jail_operator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reason_code: u8,
+    until_epoch: u64,
+) -> ProgramResult {
+    let [config, ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, false)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
+    load_signer(ncn_operator_admin, false)?;
+
+    // The NCN operator admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn.check_operator_admin(ncn_operator_admin.key)?;
+
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+    ncn_operator_state.jail(
+        reason_code,
+        until_epoch,
+        Clock::get()?.slot,
+        config.epoch_length(),
+    )?;
+
+    msg!(
+        "Operator {} jailed until epoch {} with reason code {}",
+        operator.key,
+        until_epoch,
+        reason_code
+    );
+
+    Ok(())
+}
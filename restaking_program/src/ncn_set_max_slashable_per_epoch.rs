@@ -0,0 +1,118 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+};
+use jito_vault_core::{vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket};
+use jito_vault_sdk::sdk::update_vault_ncn_slasher_ticket;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// The NCN slasher admin proposes a new max slashable amount per epoch for a slasher.
+///
+/// Specification:
+/// - A decrease takes effect immediately and cancels any pending increase.
+/// - An increase takes effect once a full epoch has passed, replacing any increase still pending.
+/// - The new values are mirrored onto the vault's VaultNcnSlasherTicket in the same instruction
+///   through `UpdateVaultNcnSlasherTicket`, so a decrease applies on both sides at once. A vault
+///   that has not created its slasher ticket yet copies the values when it does.
+///
+/// [`crate::RestakingInstruction::NcnSetMaxSlashablePerEpoch`]
+pub fn process_ncn_set_max_slashable_per_epoch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_slashable_per_epoch: u64,
+) -> ProgramResult {
+    let [config, ncn, vault, slasher, ncn_vault_slasher_ticket, ncn_slasher_admin, vault_config, vault_ncn_slasher_ticket, vault_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(&config.vault_program, vault, false)?;
+    NcnVaultSlasherTicket::load(
+        program_id,
+        ncn_vault_slasher_ticket,
+        ncn,
+        vault,
+        slasher,
+        true,
+    )?;
+    load_signer(ncn_slasher_admin, false)?;
+    if vault_program.key.ne(&config.vault_program) {
+        msg!("Vault program does not match the config");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if vault_ncn_slasher_ticket
+        .key
+        .ne(&VaultNcnSlasherTicket::find_program_address(
+            &config.vault_program,
+            vault.key,
+            ncn.key,
+            slasher.key,
+        )
+        .0)
+    {
+        msg!("Vault NCN slasher ticket is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The NCN slasher admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn_account.check_slasher_admin(ncn_slasher_admin.key)?;
+
+    {
+        let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow_mut();
+        let ncn_vault_slasher_ticket = NcnVaultSlasherTicket::try_from_slice_unchecked_mut(
+            &mut ncn_vault_slasher_ticket_data,
+        )?;
+        ncn_vault_slasher_ticket.set_next_max_slashable_per_epoch(
+            max_slashable_per_epoch,
+            Clock::get()?.slot,
+            config.epoch_length(),
+        )?;
+
+        if ncn_vault_slasher_ticket.next_max_slashable_epoch() == 0 {
+            msg!(
+                "Max slashable per epoch set to {}",
+                ncn_vault_slasher_ticket.stored_max_slashable_per_epoch()
+            );
+        } else {
+            msg!(
+                "Max slashable per epoch set to {} from epoch {}",
+                max_slashable_per_epoch,
+                ncn_vault_slasher_ticket.next_max_slashable_epoch()
+            );
+        }
+    }
+
+    if !vault_ncn_slasher_ticket.data_is_empty() {
+        invoke(
+            &update_vault_ncn_slasher_ticket(
+                &config.vault_program,
+                vault_config.key,
+                vault.key,
+                ncn.key,
+                slasher.key,
+                ncn_vault_slasher_ticket.key,
+                vault_ncn_slasher_ticket.key,
+            ),
+            &[
+                vault_config.clone(),
+                vault.clone(),
+                ncn.clone(),
+                slasher.clone(),
+                ncn_vault_slasher_ticket.clone(),
+                vault_ncn_slasher_ticket.clone(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
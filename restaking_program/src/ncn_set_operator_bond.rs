@@ -0,0 +1,46 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_signer, load_token_mint};
+use jito_restaking_core::ncn::Ncn;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN admin sets the mint and minimum amount operators shall bond to warm up with the NCN,
+/// and the most slashers can slash from a bond per epoch. The bond is checked when an operator or
+/// the NCN warms up their relationship.
+///
+/// [`crate::RestakingInstruction::NcnSetOperatorBond`]
+pub fn process_ncn_set_operator_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_operator_bond: u64,
+    max_bond_slashable_per_epoch: u64,
+) -> ProgramResult {
+    let [ncn, operator_bond_mint, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(program_id, ncn, true)?;
+    load_token_mint(operator_bond_mint)?;
+    load_signer(admin, false)?;
+
+    // The NCN admin shall be the signer of the transaction
+    let mut ncn_data = ncn.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    ncn.check_admin(admin.key)?;
+
+    ncn.set_operator_bond(
+        *operator_bond_mint.key,
+        min_operator_bond,
+        max_bond_slashable_per_epoch,
+    );
+    msg!(
+        "NCN operator bond set to {} of mint {}, slashable up to {} per epoch",
+        min_operator_bond,
+        operator_bond_mint.key,
+        max_bond_slashable_per_epoch
+    );
+
+    Ok(())
+}
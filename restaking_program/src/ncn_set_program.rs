@@ -0,0 +1,48 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN program admin binds the NCN to an on-chain program, or unbinds it by passing the
+/// default pubkey.
+///
+/// Specification:
+/// - While bound, the signer PDA of the program, see
+///   [`jito_restaking_core::ncn::Ncn::find_program_signer_address`], is accepted in place of the
+///   NCN operator, vault and slasher admins, so the program can manage the NCN through CPI.
+/// - The NCN admin and the other admins keep their authority.
+///
+/// [`crate::RestakingInstruction::NcnSetProgram`]
+pub fn process_ncn_set_program(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    ncn_program: Pubkey,
+) -> ProgramResult {
+    let [ncn_info, ncn_program_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(program_id, ncn_info, true)?;
+    load_signer(ncn_program_admin, false)?;
+
+    // The NCN program admin shall be the signer of the transaction
+    let mut ncn_data = ncn_info.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    ncn.check_ncn_program_admin(ncn_program_admin.key)?;
+
+    ncn.set_ncn_program(ncn_info.key, &ncn_program);
+    if ncn_program.eq(&Pubkey::default()) {
+        msg!("NCN unbound from its program");
+    } else {
+        msg!(
+            "NCN bound to program {} with signer {}",
+            ncn_program,
+            ncn.ncn_program_signer
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// The NCN admin sets how many full epochs the NCN's operator, vault and slasher relationships
+/// take to warm up and cool down. The durations apply to transitions started afterwards.
+///
+/// [`crate::RestakingInstruction::NcnSetTransitionEpochs`]
+pub fn process_ncn_set_transition_epochs(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    warmup_epochs: u8,
+    cooldown_epochs: u8,
+) -> ProgramResult {
+    let [ncn, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Ncn::load(program_id, ncn, true)?;
+    load_signer(admin, false)?;
+
+    // The NCN admin shall be the signer of the transaction
+    let mut ncn_data = ncn.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    ncn.check_admin(admin.key)?;
+
+    ncn.set_transition_epochs(warmup_epochs, cooldown_epochs)?;
+    msg!(
+        "NCN warmup set to {} epochs and cooldown to {} epochs",
+        warmup_epochs,
+        cooldown_epochs
+    );
+
+    Ok(())
+}
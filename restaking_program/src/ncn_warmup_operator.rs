@@ -10,8 +10,11 @@ use solana_program::{
 };
 
 /// [`crate::RestakingInstruction::NcnWarmupOperator`]
+///
+/// The operator shall hold the bond required by the NCN, if any
 pub fn process_ncn_warmup_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
+    let [config, ncn, operator, ncn_operator_state, ncn_operator_admin, rest @ ..] = accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Config::load(program_id, config, false)?;
@@ -23,10 +26,8 @@ pub fn process_ncn_warmup_operator(program_id: &Pubkey, accounts: &[AccountInfo]
     // The NCN operator admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if ncn.operator_admin.ne(ncn_operator_admin.key) {
-        msg!("Invalid operator admin for NCN");
-        return Err(RestakingError::NcnOperatorAdminInvalid.into());
-    }
+    ncn.check_operator_admin(ncn_operator_admin.key)?;
+    ncn.check_operator_bond(ncn_operator_state.key, rest.first())?;
 
     // The NcnOperatorTicket shall be inactive before it can warmed up
     let config_data = config.data.borrow_mut();
@@ -36,7 +37,11 @@ pub fn process_ncn_warmup_operator(program_id: &Pubkey, accounts: &[AccountInfo]
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     if !ncn_operator_state
         .ncn_opt_in_state
-        .activate(Clock::get()?.slot, config.epoch_length())?
+        .activate_with_warmup_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.warmup_epochs(),
+        )?
     {
         msg!("NCN is not ready to be warmup operator");
         return Err(RestakingError::NcnWarmupOperatorFailed.into());
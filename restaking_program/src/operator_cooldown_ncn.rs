@@ -24,6 +24,9 @@ pub fn process_operator_cooldown_ncn(
     NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
     load_signer(operator_ncn_admin, false)?;
 
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
     // The operator NCN admin shall be the signer of the transaction
     let operator_data = operator.data.borrow();
     let operator = Operator::try_from_slice_unchecked(&operator_data)?;
@@ -40,7 +43,11 @@ pub fn process_operator_cooldown_ncn(
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     if !ncn_operator_state
         .operator_opt_in_state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
+        .deactivate_with_cooldown_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.cooldown_epochs(),
+        )?
     {
         msg!("Operator is not ready to deactivate NCN");
         return Err(RestakingError::OperatorCooldownNcnFailed.into());
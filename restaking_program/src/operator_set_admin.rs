@@ -1,26 +1,32 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::operator::Operator;
+use jito_restaking_core::{config::Config, operator::Operator};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 /// The node operator admin can set a new admin for the node operator.
 /// This method is permissioned to the node operator admin and both the old and new admins must sign.
 ///
+/// Secondary admins held by the old admin move to the new admin. A voter held by the old admin
+/// is handed over at the next epoch boundary, like any other voter change.
+///
 /// [`crate::RestakingInstruction::OperatorSetAdmin`]
 pub fn process_set_node_operator_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [operator, old_admin, new_admin] = accounts else {
+    let [operator, old_admin, new_admin, config] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     Operator::load(program_id, operator, true)?;
     load_signer(old_admin, false)?;
     load_signer(new_admin, false)?;
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
 
     // The Operator admin shall be the signer of the transaction
     let mut operator_data = operator.data.borrow_mut();
@@ -29,7 +35,12 @@ pub fn process_set_node_operator_admin(
 
     operator.admin = *new_admin.key;
 
-    operator.update_secondary_admin(old_admin.key, new_admin.key);
+    operator.update_secondary_admin(
+        old_admin.key,
+        new_admin.key,
+        Clock::get()?.slot,
+        config.epoch_length(),
+    )?;
 
     Ok(())
 }
@@ -1,24 +1,33 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::{operator::Operator, MAX_FEE_BPS};
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_core::{config::Config, operator::Operator};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
 /// The node operator admin can set a new fee. This method is permissioned to the node operator admin.
 ///
+/// Specification:
+/// - The new fee shall not exceed 10_000 bps.
+/// - The new fee shall not exceed the fee currently in effect by more than
+///   [`jito_restaking_core::MAX_OPERATOR_FEE_BUMP_BPS`].
+/// - The new fee is stored as pending and takes effect once a full epoch has passed, replacing
+///   any fee change still pending.
+///
 /// [`crate::RestakingInstruction::OperatorSetFee`]
 pub fn process_operator_set_fee(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     new_fee_bps: u16,
 ) -> ProgramResult {
-    let [_config, operator_account, admin] = accounts else {
+    let [config, operator_account, admin] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
     Operator::load(program_id, operator_account, true)?;
     load_signer(admin, false)?;
 
@@ -26,15 +35,13 @@ pub fn process_operator_set_fee(
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
     operator.check_admin(admin.key)?;
 
-    // Check that the new fee is not greater than the maximum allowed
-    if new_fee_bps > MAX_FEE_BPS {
-        msg!("New fee exceeds maximum allowed fee");
-        return Err(RestakingError::OperatorFeeCapExceeded.into());
-    }
-
-    operator.operator_fee_bps = new_fee_bps.into();
+    operator.set_next_operator_fee_bps(new_fee_bps, Clock::get()?.slot, config.epoch_length())?;
 
-    msg!("Operator fee updated to {} basis points", new_fee_bps);
+    msg!(
+        "Operator fee set to {} basis points from epoch {}",
+        new_fee_bps,
+        operator.next_operator_fee_epoch()
+    );
 
     Ok(())
 }
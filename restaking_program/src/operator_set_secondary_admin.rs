@@ -1,26 +1,32 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::operator::Operator;
+use jito_restaking_core::{config::Config, operator::Operator};
 use jito_restaking_sdk::{error::RestakingError, instruction::OperatorAdminRole};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
-/// The node operator admin can set a new voter for the node operator.
+/// The node operator admin can set a new secondary admin or voter for the node operator.
 /// This method is permissioned to the node operator admin.
 ///
+/// A new voter does not replace the current one right away: it is recorded as the pending voter
+/// and takes effect at the next epoch boundary, so NCNs see a single voter per epoch.
+///
 /// [`crate::RestakingInstruction::OperatorSetSecondaryAdmin`]
 pub fn process_set_operator_secondary_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     role: OperatorAdminRole,
 ) -> ProgramResult {
-    let [operator, admin, new_admin] = accounts else {
+    let [operator, admin, new_admin, config] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Operator::load(program_id, operator, true)?;
     load_signer(admin, false)?;
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
 
     // The Operator admin shall be the signer of the transaction
     let mut operator_data = operator.data.borrow_mut();
@@ -38,7 +44,12 @@ pub fn process_set_operator_secondary_admin(
             operator.vault_admin = *new_admin.key;
         }
         OperatorAdminRole::VoterAdmin => {
-            operator.voter = *new_admin.key;
+            operator.set_next_voter(new_admin.key, Clock::get()?.slot, config.epoch_length())?;
+            msg!(
+                "Voter set to {} from epoch {}",
+                new_admin.key,
+                operator.next_voter_epoch()
+            );
         }
         OperatorAdminRole::DelegateAdmin => {
             operator.delegate_admin = *new_admin.key;
@@ -10,8 +10,11 @@ use solana_program::{
 };
 
 /// [`crate::RestakingInstruction::OperatorWarmupNcn`]
+///
+/// The operator shall hold the bond required by the NCN, if any
 pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, ncn, operator, ncn_operator_state, operator_ncn_admin] = accounts else {
+    let [config, ncn, operator, ncn_operator_state, operator_ncn_admin, rest @ ..] = accounts
+    else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
     Config::load(program_id, config, false)?;
@@ -20,6 +23,9 @@ pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]
     NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
     load_signer(operator_ncn_admin, false)?;
 
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
     // The operator NCN admin shall be the signer of the transaction
     let operator_data = operator.data.borrow();
     let operator = Operator::try_from_slice_unchecked(&operator_data)?;
@@ -27,6 +33,7 @@ pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]
         msg!("Invalid NCN admin for operator");
         return Err(RestakingError::OperatorNcnAdminInvalid.into());
     }
+    ncn.check_operator_bond(ncn_operator_state.key, rest.first())?;
 
     // The OperatorNcnTicket shall be inactive before it can warmed up
     let config_data = config.data.borrow();
@@ -36,7 +43,11 @@ pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     if !ncn_operator_state
         .operator_opt_in_state
-        .activate(Clock::get()?.slot, config.epoch_length())?
+        .activate_with_warmup_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.warmup_epochs(),
+        )?
     {
         msg!("Operator is not ready to warm up NCN");
         return Err(RestakingError::OperatorWarmupNcnFailed.into());
@@ -0,0 +1,114 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    loader::{load_signer, load_token_program},
+    slot_toggle::SlotToggleState,
+};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::{instruction::transfer, state::Account};
+
+/// Processes the operator withdraw bond instruction:
+/// [`crate::RestakingInstruction::OperatorWithdrawBond`]
+///
+/// Specification:
+/// - The operator admin shall be the signer of the transaction
+/// - The bond is any token account owned by the [`NcnOperatorState`], so a bond left in a mint
+///   the NCN no longer requires can always be withdrawn
+/// - Unless the operator has fully cooled down from the NCN, the bond left in the NCN's bond mint
+///   shall stay at or above the NCN minimum
+pub fn process_operator_withdraw_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let [config, ncn, operator, ncn_operator_state, operator_bond, destination, admin, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, false)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, false)?;
+    load_signer(admin, false)?;
+    load_token_program(token_program)?;
+
+    if operator_bond.owner.ne(&spl_token::id()) {
+        msg!("Operator bond account is not owned by the spl token program");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let bond = Account::unpack(&operator_bond.data.borrow())?;
+    if bond.owner.ne(ncn_operator_state.key) {
+        msg!("Operator bond account is not owned by the NCN operator state");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The operator admin shall be the signer of the transaction
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator_account.check_admin(admin.key)?;
+
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let ncn_data = ncn.data.borrow();
+    let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state_account =
+        NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+
+    let operator_opt_in_state = ncn_operator_state_account
+        .operator_opt_in_state
+        .state(Clock::get()?.slot, config.epoch_length())?;
+    if operator_opt_in_state != SlotToggleState::Inactive
+        && bond.mint.eq(&ncn_account.operator_bond_mint)
+    {
+        let bond_left = bond
+            .amount
+            .checked_sub(amount)
+            .ok_or(RestakingError::ArithmeticUnderflow)?;
+        if bond_left < ncn_account.min_operator_bond() {
+            msg!(
+                "Operator bond left {} would be below the NCN minimum {}",
+                bond_left,
+                ncn_account.min_operator_bond()
+            );
+            return Err(RestakingError::NcnOperatorBondInsufficient.into());
+        }
+    }
+
+    let mut ncn_operator_state_seeds = NcnOperatorState::seeds(ncn.key, operator.key);
+    ncn_operator_state_seeds.push(vec![ncn_operator_state_account.bump]);
+    let ncn_operator_state_seeds_slice = ncn_operator_state_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+
+    drop(ncn_operator_state_data);
+
+    invoke_signed(
+        &transfer(
+            token_program.key,
+            operator_bond.key,
+            destination.key,
+            ncn_operator_state.key,
+            &[],
+            amount,
+        )?,
+        &[
+            token_program.clone(),
+            operator_bond.clone(),
+            destination.clone(),
+            ncn_operator_state.clone(),
+        ],
+        &[&ncn_operator_state_seeds_slice],
+    )?;
+
+    Ok(())
+}
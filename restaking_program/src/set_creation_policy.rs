@@ -0,0 +1,47 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::config::Config;
+use jito_restaking_sdk::{error::RestakingError, instruction::CreationPolicy};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set creation policy instruction: [`crate::RestakingInstruction::SetCreationPolicy`]
+///
+/// Specification:
+/// - The creation policy can only be changed by the config admin. The config admin must sign the
+///   transaction.
+/// - The config shall store the new creation policy, creation fee and creation fee treasury. The
+///   fee and treasury are only used under [`CreationPolicy::Fee`].
+pub fn process_set_creation_policy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    creation_policy: CreationPolicy,
+    creation_fee_lamports: u64,
+) -> ProgramResult {
+    let [config, config_admin, creation_fee_treasury] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, true)?;
+    let mut config_data = config.data.borrow_mut();
+    let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
+    load_signer(config_admin, false)?;
+
+    if config_admin.key != &config.admin {
+        msg!("Config admin does not match");
+        return Err(RestakingError::ConfigAdminInvalid.into());
+    }
+
+    msg!(
+        "Setting NCN and operator creation policy to {:?}",
+        creation_policy
+    );
+    config.set_creation_policy(
+        creation_policy,
+        creation_fee_lamports,
+        *creation_fee_treasury.key,
+    );
+
+    Ok(())
+}
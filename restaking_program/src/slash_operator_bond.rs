@@ -0,0 +1,116 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_associated_token_account, load_signer, load_token_program};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, operator::Operator,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+use spl_token::instruction::transfer;
+
+/// Processes the slash operator bond instruction:
+/// [`crate::RestakingInstruction::SlashOperatorBond`]
+///
+/// Specification:
+/// - The slasher shall sign and hold an active [`NcnVaultSlasherTicket`] with the NCN for any of
+///   its vaults, which only authorizes the slasher
+/// - The bond is the associated token account of the [`NcnOperatorState`] in the NCN's bond mint
+/// - The bond slashed within an epoch, by any slasher, is capped by the NCN's max bond slashable
+///   per epoch, which is in units of the bond mint. The max slashable per epoch of the slasher
+///   ticket is in units of the vault's supported token and doesn't apply to the bond
+pub fn process_slash_operator_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let [config, ncn, operator, vault, slasher, ncn_vault_slasher_ticket, ncn_operator_state, operator_bond, destination, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, false)?;
+    load_signer(slasher, false)?;
+    NcnVaultSlasherTicket::load(
+        program_id,
+        ncn_vault_slasher_ticket,
+        ncn,
+        vault,
+        slasher,
+        false,
+    )?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
+    load_token_program(token_program)?;
+
+    let ncn_data = ncn.data.borrow();
+    let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    load_associated_token_account(
+        operator_bond,
+        ncn_operator_state.key,
+        &ncn_account.operator_bond_mint,
+    )?;
+
+    let slot = Clock::get()?.slot;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let epoch_length = config.epoch_length();
+
+    // The slasher shall be active for the NCN
+    let ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
+    if !ncn_vault_slasher_ticket
+        .state
+        .is_active(slot, epoch_length)?
+    {
+        msg!("Slasher is not active for the NCN");
+        return Err(RestakingError::NcnVaultSlasherTicketInactive.into());
+    }
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state_account =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+    ncn_operator_state_account.record_bond_slash(
+        amount,
+        ncn_account.max_bond_slashable_per_epoch(),
+        slot,
+        epoch_length,
+    )?;
+
+    let mut ncn_operator_state_seeds = NcnOperatorState::seeds(ncn.key, operator.key);
+    ncn_operator_state_seeds.push(vec![ncn_operator_state_account.bump]);
+    let ncn_operator_state_seeds_slice = ncn_operator_state_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+
+    drop(ncn_operator_state_data);
+
+    invoke_signed(
+        &transfer(
+            token_program.key,
+            operator_bond.key,
+            destination.key,
+            ncn_operator_state.key,
+            &[],
+            amount,
+        )?,
+        &[
+            token_program.clone(),
+            operator_bond.clone(),
+            destination.clone(),
+            ncn_operator_state.clone(),
+        ],
+        &[&ncn_operator_state_seeds_slice],
+    )?;
+    msg!(
+        "Slashed {} from the bond of operator {}",
+        amount,
+        operator.key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::get_epoch;
+use jito_restaking_core::{
+    config::Config, epoch_snapshot::EpochSnapshot, ncn::Ncn, ncn_operator_state::NcnOperatorState,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator, operator_snapshot::OperatorSnapshot,
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    config::Config as VaultConfig, vault::Vault, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Processes the snapshot vault operator delegation instruction:
+/// [`crate::RestakingInstruction::SnapshotVaultOperatorDelegation`]
+///
+/// Specification:
+/// - Anyone can snapshot the stake a vault delegated to an operator once the [`OperatorSnapshot`]
+///   of the current epoch exists
+/// - The vault shall be up-to-date and shall have been part of the NCN when the
+///   [`EpochSnapshot`] was created
+/// - The [`OperatorVaultTicket`], [`VaultNcnTicket`] and [`VaultOperatorDelegation`] may not
+///   exist yet, in which case they shall be passed at their canonical PDA and the stake is zero
+/// - The stake counts only when the NCN, operator and vault opted in to each other, and is capped
+///   by the vault's allocation to the NCN
+/// - An operator jailed after its snapshot was created no longer counts any stake: its whole
+///   [`OperatorSnapshot`] is zeroed and marked inactive, including vaults already snapshotted
+/// - Once every vault of an operator is snapshotted, the operator is recorded in the
+///   [`EpochSnapshot`]
+pub fn process_snapshot_vault_operator_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_config, ncn, operator, vault, ncn_operator_state, ncn_vault_ticket, operator_vault_ticket, vault_ncn_ticket, vault_operator_delegation, epoch_snapshot, operator_snapshot] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Ncn::load(program_id, ncn, false)?;
+    Operator::load(program_id, operator, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    VaultConfig::load(&config.vault_program, vault_config, false)?;
+    Vault::load(&config.vault_program, vault, false)?;
+    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, false)?;
+    NcnVaultTicket::load(program_id, ncn_vault_ticket, ncn, vault, false)?;
+
+    let slot = Clock::get()?.slot;
+    let epoch_length = config.epoch_length();
+    let epoch = get_epoch(slot, epoch_length)?;
+    EpochSnapshot::load(program_id, epoch_snapshot, ncn, epoch, true)?;
+    OperatorSnapshot::load(program_id, operator_snapshot, ncn, operator, epoch, true)?;
+
+    // The vault shall be up-to-date so the delegation reflects the current epoch
+    let vault_config_data = vault_config.data.borrow();
+    let vault_config = VaultConfig::try_from_slice_unchecked(&vault_config_data)?;
+    let vault_data = vault.data.borrow();
+    let vault_account = Vault::try_from_slice_unchecked(&vault_data)?;
+    vault_account.check_update_state_ok(slot, vault_config.epoch_length())?;
+
+    let mut epoch_snapshot_data = epoch_snapshot.data.borrow_mut();
+    let epoch_snapshot = EpochSnapshot::try_from_slice_unchecked_mut(&mut epoch_snapshot_data)?;
+    let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+    let ncn_vault_ticket = NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?;
+    epoch_snapshot.check_vault_index(ncn_vault_ticket.index())?;
+
+    let ncn_vault_ticket_active = ncn_vault_ticket.state.is_active(slot, epoch_length)?;
+
+    // An operator jailed after its snapshot was created no longer counts any stake
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+    if ncn_operator_state.is_jailed(slot, epoch_length)? {
+        let mut operator_snapshot_data = operator_snapshot.data.borrow_mut();
+        let operator_snapshot =
+            OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+        let was_complete = operator_snapshot.is_complete();
+        operator_snapshot.deactivate()?;
+        epoch_snapshot.record_operator_deactivated(was_complete)?;
+        msg!(
+            "Operator {} is jailed, its snapshot no longer counts any stake",
+            operator.key
+        );
+        return Ok(());
+    }
+
+    let operator_vault_ticket_active = if is_uninitialized_pda(
+        operator_vault_ticket,
+        &OperatorVaultTicket::find_program_address(program_id, operator.key, vault.key).0,
+    )? {
+        false
+    } else {
+        OperatorVaultTicket::load(program_id, operator_vault_ticket, operator, vault, false)?;
+        let operator_vault_ticket_data = operator_vault_ticket.data.borrow();
+        let operator_vault_ticket =
+            OperatorVaultTicket::try_from_slice_unchecked(&operator_vault_ticket_data)?;
+        operator_vault_ticket.state.is_active(slot, epoch_length)?
+    };
+
+    let stake = if is_uninitialized_pda(
+        vault_ncn_ticket,
+        &VaultNcnTicket::find_program_address(&config.vault_program, vault.key, ncn.key).0,
+    )? || is_uninitialized_pda(
+        vault_operator_delegation,
+        &VaultOperatorDelegation::find_program_address(
+            &config.vault_program,
+            vault.key,
+            operator.key,
+        )
+        .0,
+    )? {
+        0
+    } else {
+        VaultNcnTicket::load(&config.vault_program, vault_ncn_ticket, vault, ncn, false)?;
+        VaultOperatorDelegation::load(
+            &config.vault_program,
+            vault_operator_delegation,
+            vault,
+            operator,
+            false,
+        )?;
+        let vault_ncn_ticket_data = vault_ncn_ticket.data.borrow();
+        let vault_ncn_ticket = VaultNcnTicket::try_from_slice_unchecked(&vault_ncn_ticket_data)?;
+        let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
+        let vault_operator_delegation =
+            VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+
+        let vault_ncn_ticket_active = vault_ncn_ticket
+            .state
+            .is_active(slot, vault_config.epoch_length())?;
+        if ncn_vault_ticket_active && operator_vault_ticket_active && vault_ncn_ticket_active {
+            vault_ncn_ticket.allocated_stake(&vault_operator_delegation.delegation_state)?
+        } else {
+            0
+        }
+    };
+
+    let mut operator_snapshot_data = operator_snapshot.data.borrow_mut();
+    let operator_snapshot =
+        OperatorSnapshot::try_from_slice_unchecked_mut(&mut operator_snapshot_data)?;
+    operator_snapshot.record_vault_operator_stake(
+        ncn_vault_ticket.index(),
+        *vault.key,
+        vault_account.supported_mint,
+        stake,
+    )?;
+    msg!(
+        "Vault {} delegates {} to operator {}",
+        vault.key,
+        stake,
+        operator.key
+    );
+
+    if operator_snapshot.is_complete() {
+        epoch_snapshot.record_operator_snapshotted(true)?;
+    }
+
+    Ok(())
+}
+
+/// Whether an optional account does not exist yet. An account that does not exist shall be
+/// passed at its canonical PDA.
+fn is_uninitialized_pda(
+    account: &AccountInfo,
+    expected_pubkey: &Pubkey,
+) -> Result<bool, ProgramError> {
+    if !account.data_is_empty() {
+        return Ok(false);
+    }
+    if account.key.ne(expected_pubkey) {
+        msg!("Account {} is not at the correct PDA", account.key);
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(true)
+}
@@ -0,0 +1,47 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{ncn::Ncn, ncn_metadata::NcnMetadata};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::metadata::update_metadata;
+
+/// Processes the update NCN metadata instruction:
+/// [`crate::RestakingInstruction::UpdateNcnMetadata`]
+///
+/// Specification:
+/// - Only the NCN metadata admin can update the metadata. The metadata admin must sign the
+///   transaction.
+/// - The transaction shall fail if no fields are provided to update.
+/// - Each provided field shall be valid UTF-8 within its length bound and contain no NUL character
+/// - The metadata update count is incremented on every update.
+pub fn process_update_ncn_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: Option<String>,
+    website: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+) -> ProgramResult {
+    let [ncn, ncn_metadata, metadata_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Ncn::load(program_id, ncn, false)?;
+    load_signer(metadata_admin, false)?;
+
+    let ncn_data = ncn.data.borrow();
+    let ncn_account = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn_account.check_metadata_admin(metadata_admin.key)?;
+
+    update_metadata::<NcnMetadata>(
+        program_id,
+        ncn,
+        ncn_metadata,
+        name,
+        website,
+        logo_uri,
+        contact,
+    )
+}
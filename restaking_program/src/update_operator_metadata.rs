@@ -0,0 +1,47 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::{operator::Operator, operator_metadata::OperatorMetadata};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::metadata::update_metadata;
+
+/// Processes the update operator metadata instruction:
+/// [`crate::RestakingInstruction::UpdateOperatorMetadata`]
+///
+/// Specification:
+/// - Only the operator metadata admin can update the metadata. The metadata admin must sign the
+///   transaction.
+/// - The transaction shall fail if no fields are provided to update.
+/// - Each provided field shall be valid UTF-8 within its length bound and contain no NUL character
+/// - The metadata update count is incremented on every update.
+pub fn process_update_operator_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: Option<String>,
+    website: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+) -> ProgramResult {
+    let [operator, operator_metadata, metadata_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Operator::load(program_id, operator, false)?;
+    load_signer(metadata_admin, false)?;
+
+    let operator_data = operator.data.borrow();
+    let operator_account = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator_account.check_metadata_admin(metadata_admin.key)?;
+
+    update_metadata::<OperatorMetadata>(
+        program_id,
+        operator,
+        operator_metadata,
+        name,
+        website,
+        logo_uri,
+        contact,
+    )
+}
@@ -39,19 +39,17 @@ pub fn process_warmup_ncn_vault_slasher_ticket(
     // The NCN slasher admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if ncn.slasher_admin.ne(admin.key) {
-        msg!("Invalid slasher admin for NCN");
-        return Err(RestakingError::NcnSlasherAdminInvalid.into());
-    }
+    ncn.check_slasher_admin(admin.key)?;
 
     // The NcnVaultSlasherTicket shall be inactive before it can warmed up
     let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow_mut();
     let ncn_vault_slasher_ticket =
         NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
-    if !ncn_vault_slasher_ticket
-        .state
-        .activate(Clock::get()?.slot, config.epoch_length())?
-    {
+    if !ncn_vault_slasher_ticket.state.activate_with_warmup_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.warmup_epochs(),
+    )? {
         msg!("Slasher is not ready to be activated");
         return Err(RestakingError::NcnVaultSlasherTicketFailedWarmup.into());
     }
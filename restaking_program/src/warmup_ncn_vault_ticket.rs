@@ -28,19 +28,17 @@ pub fn process_warmup_ncn_vault_ticket(
     // The NCN vault admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if ncn.vault_admin.ne(ncn_vault_admin.key) {
-        msg!("Invalid vault admin for NCN");
-        return Err(RestakingError::NcnVaultAdminInvalid.into());
-    }
+    ncn.check_vault_admin(ncn_vault_admin.key)?;
 
     // The NcnVaultTicket shall be inactive before it can warmed up
     let mut ncn_vault_ticket_data = ncn_vault_ticket.data.borrow_mut();
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
-    if !ncn_vault_ticket
-        .state
-        .activate(Clock::get()?.slot, config.epoch_length())?
-    {
+    if !ncn_vault_ticket.state.activate_with_warmup_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.warmup_epochs(),
+    )? {
         msg!("Vault is not ready to be activated");
         return Err(RestakingError::NcnVaultTicketFailedWarmup.into());
     }
@@ -25,6 +25,8 @@ pub enum RestakingError {
     NcnVaultSlasherTicketFailedWarmup,
     #[error("NcnVaultTicketFailedWarmup")]
     NcnVaultTicketFailedWarmup,
+    #[error("NcnMetadataAdminInvalid")]
+    NcnMetadataAdminInvalid,
 
     #[error("OperatorNcnAdminInvalid")]
     OperatorNcnAdminInvalid = 2000,
@@ -57,6 +59,40 @@ pub enum RestakingError {
 
     #[error("ConfigAdminInvalid")]
     ConfigAdminInvalid,
+    #[error("CreatorNotAllowlisted")]
+    CreatorNotAllowlisted,
+    #[error("CreationFeeTreasuryInvalid")]
+    CreationFeeTreasuryInvalid,
+    #[error("OperatorFeeBumpTooLarge")]
+    OperatorFeeBumpTooLarge,
+    #[error("OperatorMetadataAdminInvalid")]
+    OperatorMetadataAdminInvalid,
+    #[error("MetadataFieldInvalid")]
+    MetadataFieldInvalid,
+    #[error("EpochSnapshotTooManyVaults")]
+    EpochSnapshotTooManyVaults,
+    #[error("EpochSnapshotOperatorNotIncluded")]
+    EpochSnapshotOperatorNotIncluded,
+    #[error("EpochSnapshotVaultNotIncluded")]
+    EpochSnapshotVaultNotIncluded,
+    #[error("OperatorSnapshotInactive")]
+    OperatorSnapshotInactive,
+    #[error("OperatorSnapshotVaultAlreadyRecorded")]
+    OperatorSnapshotVaultAlreadyRecorded,
+    #[error("NcnOperatorJailEpochInvalid")]
+    NcnOperatorJailEpochInvalid,
+    #[error("NcnOperatorNotJailed")]
+    NcnOperatorNotJailed,
+    #[error("NcnTransitionEpochsInvalid")]
+    NcnTransitionEpochsInvalid,
+    #[error("NcnProgramAdminInvalid")]
+    NcnProgramAdminInvalid,
+    #[error("NcnOperatorBondInsufficient")]
+    NcnOperatorBondInsufficient,
+    #[error("NcnVaultSlasherTicketInactive")]
+    NcnVaultSlasherTicketInactive,
+    #[error("NcnOperatorBondSlashCapExceeded")]
+    NcnOperatorBondSlashCapExceeded,
 
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 3000,
@@ -1,5 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+pub use jito_jsm_core::creation_policy::CreationPolicy;
 use shank::ShankInstruction;
+use solana_program::pubkey::Pubkey;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
 pub enum RestakingInstruction {
@@ -16,6 +18,13 @@ pub enum RestakingInstruction {
     #[account(2, writable, signer, name = "admin")]
     #[account(3, signer, name = "base")]
     #[account(4, name = "system_program")]
+    #[account(
+        5,
+        writable,
+        optional,
+        name = "creation_policy_account",
+        description = "The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy"
+    )]
     InitializeNcn,
 
     /// Initializes a operator
@@ -24,6 +33,13 @@ pub enum RestakingInstruction {
     #[account(2, writable, signer, name = "admin")]
     #[account(3, signer, name = "base")]
     #[account(4, name = "system_program")]
+    #[account(
+        5,
+        writable,
+        optional,
+        name = "creation_policy_account",
+        description = "The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy"
+    )]
     InitializeOperator { operator_fee_bps: u16 },
 
     /// The NCN adds support for a vault slasher
@@ -92,6 +108,12 @@ pub enum RestakingInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "ncn_operator_state")]
     #[account(4, signer, name = "admin")]
+    #[account(
+        5,
+        optional,
+        name = "operator_bond",
+        description = "The bond token account of the operator, required when the NCN requires a bond"
+    )]
     NcnWarmupOperator,
 
     #[account(0, name = "config")]
@@ -106,6 +128,12 @@ pub enum RestakingInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "ncn_operator_state")]
     #[account(4, signer, name = "admin")]
+    #[account(
+        5,
+        optional,
+        name = "operator_bond",
+        description = "The bond token account of the operator, required when the NCN requires a bond"
+    )]
     OperatorWarmupNcn,
 
     #[account(0, name = "config")]
@@ -162,15 +190,17 @@ pub enum RestakingInstruction {
     #[account(0, writable, name = "operator")]
     #[account(1, signer, name = "old_admin")]
     #[account(2, signer, name = "new_admin")]
+    #[account(3, name = "config")]
     OperatorSetAdmin,
 
-    /// Sets the voter for a node operator
+    /// Sets a secondary admin for a node operator. A new voter takes effect at the next epoch
     #[account(0, writable, name = "operator")]
     #[account(1, signer, name = "admin")]
     #[account(2, name = "new_admin")]
+    #[account(3, name = "config")]
     OperatorSetSecondaryAdmin(OperatorAdminRole),
 
-    /// Sets the fee for a node operator
+    /// Sets the fee for a node operator, taking effect once a full epoch has passed
     #[account(0, name = "config")]
     #[account(1, writable, name = "operator")]
     #[account(2, signer, name = "admin")]
@@ -197,6 +227,202 @@ pub enum RestakingInstruction {
     #[account(1, signer, name = "old_admin")]
     #[account(2, name = "new_admin")]
     SetConfigAdmin,
+
+    /// Migrates any restaking program account to its current layout version, reallocating it if needed
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+    /// Sets who may create NCNs and operators. The creation fee and treasury are only used by the
+    /// fee policy.
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "creation_fee_treasury")]
+    SetCreationPolicy {
+        creation_policy: CreationPolicy,
+        creation_fee_lamports: u64,
+    },
+
+    /// Adds a creator to the allowlist used by the allowlist creation policy
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "creator_allowlist_entry")]
+    #[account(2, name = "creator")]
+    #[account(3, writable, signer, name = "admin")]
+    #[account(4, name = "system_program")]
+    InitializeCreatorAllowlistEntry,
+
+    /// Removes a creator from the allowlist used by the allowlist creation policy
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "creator_allowlist_entry")]
+    #[account(2, name = "creator")]
+    #[account(3, writable, signer, name = "admin")]
+    CloseCreatorAllowlistEntry,
+
+    /// Initializes the metadata of an NCN
+    #[account(0, name = "ncn")]
+    #[account(1, writable, name = "ncn_metadata")]
+    #[account(2, signer, name = "metadata_admin")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    InitializeNcnMetadata {
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+    },
+
+    /// Updates the metadata of an NCN, fields left empty are unchanged
+    #[account(0, name = "ncn")]
+    #[account(1, writable, name = "ncn_metadata")]
+    #[account(2, signer, name = "metadata_admin")]
+    UpdateNcnMetadata {
+        name: Option<String>,
+        website: Option<String>,
+        logo_uri: Option<String>,
+        contact: Option<String>,
+    },
+
+    /// Initializes the metadata of a node operator
+    #[account(0, name = "operator")]
+    #[account(1, writable, name = "operator_metadata")]
+    #[account(2, signer, name = "metadata_admin")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    InitializeOperatorMetadata {
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+    },
+
+    /// Updates the metadata of a node operator, fields left empty are unchanged
+    #[account(0, name = "operator")]
+    #[account(1, writable, name = "operator_metadata")]
+    #[account(2, signer, name = "metadata_admin")]
+    UpdateOperatorMetadata {
+        name: Option<String>,
+        website: Option<String>,
+        logo_uri: Option<String>,
+        contact: Option<String>,
+    },
+
+    /// Initializes the stake snapshot of an NCN for the current epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "epoch_snapshot")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program")]
+    InitializeEpochSnapshot,
+
+    /// Initializes the stake snapshot of an operator of an NCN for the current epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "ncn_operator_state")]
+    #[account(4, writable, name = "epoch_snapshot")]
+    #[account(5, writable, name = "operator_snapshot")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    InitializeOperatorSnapshot,
+
+    /// Records the stake a vault delegated to an operator in the operator snapshot, or zeroes the
+    /// whole snapshot of an operator jailed during the epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "vault_config")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "operator")]
+    #[account(4, name = "vault")]
+    #[account(5, name = "ncn_operator_state")]
+    #[account(6, name = "ncn_vault_ticket")]
+    #[account(7, name = "operator_vault_ticket")]
+    #[account(8, name = "vault_ncn_ticket")]
+    #[account(9, name = "vault_operator_delegation")]
+    #[account(10, writable, name = "epoch_snapshot")]
+    #[account(11, writable, name = "operator_snapshot")]
+    SnapshotVaultOperatorDelegation,
+
+    /// The NCN jails an operator, making it immediately ineligible until the given epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "ncn_operator_admin")]
+    NcnJailOperator { reason_code: u8, until_epoch: u64 },
+
+    /// The NCN releases a jailed operator before its release epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "ncn_operator_admin")]
+    NcnUnjailOperator,
+
+    /// The NCN sets the warmup and cooldown durations of its operator, vault and slasher
+    /// relationships
+    #[account(0, writable, name = "ncn")]
+    #[account(1, signer, name = "admin")]
+    NcnSetTransitionEpochs {
+        warmup_epochs: u8,
+        cooldown_epochs: u8,
+    },
+
+    /// The NCN proposes a new max slashable amount per epoch for a slasher. Decreases apply
+    /// immediately, increases after a full epoch. The vault's slasher ticket is updated in the
+    /// same instruction.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "vault")]
+    #[account(3, name = "slasher")]
+    #[account(4, writable, name = "ncn_vault_slasher_ticket")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, name = "vault_config")]
+    #[account(7, writable, name = "vault_ncn_slasher_ticket")]
+    #[account(8, name = "vault_program")]
+    NcnSetMaxSlashablePerEpoch { max_slashable_per_epoch: u64 },
+
+    /// The NCN program admin binds the NCN to an on-chain program, whose signer PDA can then act
+    /// as the NCN operator, vault and slasher admins through CPI. The default pubkey unbinds the
+    /// NCN
+    #[account(0, writable, name = "ncn")]
+    #[account(1, signer, name = "ncn_program_admin")]
+    NcnSetProgram { ncn_program: Pubkey },
+
+    /// The NCN sets the mint and minimum amount operators shall bond to warm up with it, and the
+    /// most that can be slashed from a bond per epoch. A zero minimum disables the requirement
+    #[account(0, writable, name = "ncn")]
+    #[account(1, name = "operator_bond_mint")]
+    #[account(2, signer, name = "admin")]
+    NcnSetOperatorBond {
+        min_operator_bond: u64,
+        max_bond_slashable_per_epoch: u64,
+    },
+
+    /// The operator withdraws from its bond with an NCN, keeping at least the NCN minimum unless
+    /// the operator has fully cooled down from the NCN
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "ncn_operator_state")]
+    #[account(4, writable, name = "operator_bond")]
+    #[account(5, writable, name = "destination")]
+    #[account(6, signer, name = "admin")]
+    #[account(7, name = "token_program")]
+    OperatorWithdrawBond { amount: u64 },
+
+    /// A slasher of the NCN slashes the bond of an operator, capped by the NCN's max bond
+    /// slashable per epoch
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "vault")]
+    #[account(4, signer, name = "slasher")]
+    #[account(5, name = "ncn_vault_slasher_ticket")]
+    #[account(6, writable, name = "ncn_operator_state")]
+    #[account(7, writable, name = "operator_bond")]
+    #[account(8, writable, name = "destination")]
+    #[account(9, name = "token_program")]
+    SlashOperatorBond { amount: u64 },
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
@@ -5,7 +5,7 @@ use solana_program::{
     system_program,
 };
 
-use crate::instruction::{NcnAdminRole, OperatorAdminRole, RestakingInstruction};
+use crate::instruction::{CreationPolicy, NcnAdminRole, OperatorAdminRole, RestakingInstruction};
 
 pub fn initialize_config(
     program_id: &Pubkey,
@@ -32,14 +32,18 @@ pub fn initialize_ncn(
     ncn: &Pubkey,
     admin: &Pubkey,
     base: &Pubkey,
+    creation_policy_account: Option<&Pubkey>,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*config, false),
         AccountMeta::new(*ncn, false),
         AccountMeta::new(*admin, true),
         AccountMeta::new_readonly(*base, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
+    if let Some(creation_policy_account) = creation_policy_account {
+        accounts.push(AccountMeta::new(*creation_policy_account, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -256,15 +260,19 @@ pub fn initialize_operator(
     operator: &Pubkey,
     admin: &Pubkey,
     base: &Pubkey,
+    creation_policy_account: Option<&Pubkey>,
     operator_fee_bps: u16,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*config, false),
         AccountMeta::new(*operator, false),
         AccountMeta::new(*admin, true),
         AccountMeta::new_readonly(*base, true),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
+    if let Some(creation_policy_account) = creation_policy_account {
+        accounts.push(AccountMeta::new(*creation_policy_account, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -279,11 +287,13 @@ pub fn operator_set_admin(
     operator: &Pubkey,
     old_admin: &Pubkey,
     new_admin: &Pubkey,
+    config: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*operator, false),
         AccountMeta::new_readonly(*old_admin, true),
         AccountMeta::new_readonly(*new_admin, true),
+        AccountMeta::new_readonly(*config, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -297,12 +307,14 @@ pub fn operator_set_secondary_admin(
     operator: &Pubkey,
     admin: &Pubkey,
     new_admin: &Pubkey,
+    config: &Pubkey,
     operator_admin_role: OperatorAdminRole,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*operator, false),
         AccountMeta::new_readonly(*admin, true),
         AccountMeta::new_readonly(*new_admin, false),
+        AccountMeta::new_readonly(*config, false),
     ];
     Instruction {
         program_id: *program_id,
@@ -444,14 +456,18 @@ pub fn operator_warmup_ncn(
     operator: &Pubkey,
     ncn_operator_state: &Pubkey,
     admin: &Pubkey,
+    operator_bond: Option<&Pubkey>,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
         AccountMeta::new(*ncn_operator_state, false),
         AccountMeta::new_readonly(*admin, true),
     ];
+    if let Some(operator_bond) = operator_bond {
+        accounts.push(AccountMeta::new_readonly(*operator_bond, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -492,14 +508,18 @@ pub fn ncn_warmup_operator(
     operator: &Pubkey,
     ncn_operator_state: &Pubkey,
     admin: &Pubkey,
+    operator_bond: Option<&Pubkey>,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
         AccountMeta::new(*ncn_operator_state, false),
         AccountMeta::new_readonly(*admin, true),
     ];
+    if let Some(operator_bond) = operator_bond {
+        accounts.push(AccountMeta::new_readonly(*operator_bond, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -627,3 +647,531 @@ pub fn set_config_admin(
         data: RestakingInstruction::SetConfigAdmin.try_to_vec().unwrap(),
     }
 }
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_creation_policy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    creation_fee_treasury: &Pubkey,
+    creation_policy: CreationPolicy,
+    creation_fee_lamports: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*creation_fee_treasury, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SetCreationPolicy {
+            creation_policy,
+            creation_fee_lamports,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn initialize_creator_allowlist_entry(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    creator_allowlist_entry: &Pubkey,
+    creator: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*creator_allowlist_entry, false),
+        AccountMeta::new_readonly(*creator, false),
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeCreatorAllowlistEntry
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn close_creator_allowlist_entry(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    creator_allowlist_entry: &Pubkey,
+    creator: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*creator_allowlist_entry, false),
+        AccountMeta::new_readonly(*creator, false),
+        AccountMeta::new(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::CloseCreatorAllowlistEntry
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_ncn_metadata(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    ncn_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    payer: &Pubkey,
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_ncn_metadata(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    ncn_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    name: Option<String>,
+    website: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::UpdateNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_operator_metadata(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    operator_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    payer: &Pubkey,
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_operator_metadata(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    operator_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    name: Option<String>,
+    website: Option<String>,
+    logo_uri: Option<String>,
+    contact: Option<String>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::UpdateOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn initialize_epoch_snapshot(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    epoch_snapshot: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*epoch_snapshot, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeEpochSnapshot
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_operator_snapshot(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    epoch_snapshot: &Pubkey,
+    operator_snapshot: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*ncn_operator_state, false),
+        AccountMeta::new(*epoch_snapshot, false),
+        AccountMeta::new(*operator_snapshot, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeOperatorSnapshot
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn snapshot_vault_operator_delegation(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault_config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    ncn_vault_ticket: &Pubkey,
+    operator_vault_ticket: &Pubkey,
+    vault_ncn_ticket: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    epoch_snapshot: &Pubkey,
+    operator_snapshot: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault_config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*ncn_vault_ticket, false),
+        AccountMeta::new_readonly(*operator_vault_ticket, false),
+        AccountMeta::new_readonly(*vault_ncn_ticket, false),
+        AccountMeta::new_readonly(*vault_operator_delegation, false),
+        AccountMeta::new(*epoch_snapshot, false),
+        AccountMeta::new(*operator_snapshot, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SnapshotVaultOperatorDelegation
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_jail_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    ncn_operator_admin: &Pubkey,
+    reason_code: u8,
+    until_epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*ncn_operator_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnJailOperator {
+            reason_code,
+            until_epoch,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn ncn_unjail_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    ncn_operator_admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*ncn_operator_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnUnjailOperator
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_set_transition_epochs(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    admin: &Pubkey,
+    warmup_epochs: u8,
+    cooldown_epochs: u8,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetTransitionEpochs {
+            warmup_epochs,
+            cooldown_epochs,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_set_max_slashable_per_epoch(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    vault: &Pubkey,
+    slasher: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    admin: &Pubkey,
+    vault_config: &Pubkey,
+    vault_ncn_slasher_ticket: &Pubkey,
+    vault_program: &Pubkey,
+    max_slashable_per_epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*vault_config, false),
+        AccountMeta::new(*vault_ncn_slasher_ticket, false),
+        AccountMeta::new_readonly(*vault_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetMaxSlashablePerEpoch {
+            max_slashable_per_epoch,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn ncn_set_program(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    ncn_program_admin: &Pubkey,
+    ncn_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new_readonly(*ncn_program_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetProgram {
+            ncn_program: *ncn_program,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn ncn_set_operator_bond(
+    program_id: &Pubkey,
+    ncn: &Pubkey,
+    operator_bond_mint: &Pubkey,
+    admin: &Pubkey,
+    min_operator_bond: u64,
+    max_bond_slashable_per_epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new_readonly(*operator_bond_mint, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetOperatorBond {
+            min_operator_bond,
+            max_bond_slashable_per_epoch,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn operator_withdraw_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    operator_bond: &Pubkey,
+    destination: &Pubkey,
+    admin: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*ncn_operator_state, false),
+        AccountMeta::new(*operator_bond, false),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorWithdrawBond { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn slash_operator_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    vault: &Pubkey,
+    slasher: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    operator_bond: &Pubkey,
+    destination: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*slasher, true),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new(*operator_bond, false),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SlashOperatorBond { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
@@ -7,7 +7,7 @@ use jito_bytemuck::{
     types::{PodU16, PodU64},
     AccountDeserialize, Discriminator,
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::CreationPolicy};
 use shank::ShankAccount;
 use solana_program::{
     account_info::AccountInfo, epoch_schedule::DEFAULT_SLOTS_PER_EPOCH, msg,
@@ -16,7 +16,7 @@ use solana_program::{
 
 use crate::MAX_BPS;
 
-const RESERVED_SPACE_LEN: usize = 229;
+const RESERVED_SPACE_LEN: usize = 188;
 
 /// The vault configuration account for the vault program.
 /// Manages program-wide settings and state.
@@ -56,8 +56,17 @@ pub struct Config {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The account receiving the creation fee under [`CreationPolicy::Fee`]
+    pub creation_fee_treasury: Pubkey,
+
+    /// The lamports paid to create a vault under [`CreationPolicy::Fee`]
+    creation_fee_lamports: PodU64,
+
+    /// Who may create vaults, see [`CreationPolicy`]
+    creation_policy: u8,
+
     /// Reserved space
-    reserved: [u8; 229],
+    reserved: [u8; 188],
 }
 
 impl Config {
@@ -88,6 +97,9 @@ impl Config {
             program_fee_wallet,
             fee_admin: admin,
             bump,
+            creation_fee_treasury: Pubkey::default(),
+            creation_fee_lamports: PodU64::from(0),
+            creation_policy: CreationPolicy::Open as u8,
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -160,6 +172,31 @@ impl Config {
         Ok(())
     }
 
+    pub fn creation_policy(&self) -> Result<CreationPolicy, ProgramError> {
+        CreationPolicy::try_from(self.creation_policy)
+    }
+
+    pub fn creation_fee_lamports(&self) -> u64 {
+        self.creation_fee_lamports.into()
+    }
+
+    /// Sets who may create vaults
+    ///
+    /// # Arguments
+    /// * `creation_policy` - The new creation policy
+    /// * `creation_fee_lamports` - The creation fee, only charged under [`CreationPolicy::Fee`]
+    /// * `creation_fee_treasury` - The creation fee recipient, only used under [`CreationPolicy::Fee`]
+    pub fn set_creation_policy(
+        &mut self,
+        creation_policy: CreationPolicy,
+        creation_fee_lamports: u64,
+        creation_fee_treasury: Pubkey,
+    ) {
+        self.creation_policy = creation_policy as u8;
+        self.creation_fee_lamports = PodU64::from(creation_fee_lamports);
+        self.creation_fee_treasury = creation_fee_treasury;
+    }
+
     pub fn increment_num_vaults(&mut self) -> Result<(), VaultError> {
         let mut num_vaults: u64 = self.num_vaults.into();
         num_vaults = num_vaults.checked_add(1).ok_or(VaultError::VaultOverflow)?;
@@ -249,6 +286,9 @@ mod tests {
             std::mem::size_of::<Pubkey>() + // program_fee_wallet
             std::mem::size_of::<Pubkey>() + // fee_admin
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<Pubkey>() + // creation_fee_treasury
+            std::mem::size_of::<PodU64>() + // creation_fee_lamports
+            std::mem::size_of::<u8>() + // creation_policy
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(config_size, sum_of_fields);
     }
@@ -310,4 +350,22 @@ mod tests {
             Err(VaultError::VaultFeeCapExceeded)
         );
     }
+
+    #[test]
+    fn test_set_creation_policy() {
+        let mut config = Config::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            0,
+        );
+        assert_eq!(config.creation_policy(), Ok(CreationPolicy::Open));
+
+        let treasury = Pubkey::new_unique();
+        config.set_creation_policy(CreationPolicy::Fee, 1_000_000, treasury);
+        assert_eq!(config.creation_policy(), Ok(CreationPolicy::Fee));
+        assert_eq!(config.creation_fee_lamports(), 1_000_000);
+        assert_eq!(config.creation_fee_treasury, treasury);
+    }
 }
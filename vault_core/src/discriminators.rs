@@ -1,9 +1,11 @@
-use jito_bytemuck::Discriminator;
+use jito_bytemuck::{Discriminator, Migrate};
 
 use crate::{
-    config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    config::Config, creator_allowlist_entry::CreatorAllowlistEntry, vault::Vault,
+    vault_deposit_receipt::VaultDepositReceipt, vault_fee_split::VaultFeeSplit,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
     vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
-    vault_operator_delegation::VaultOperatorDelegation,
+    vault_operator_delegation::VaultOperatorDelegation, vault_referral::VaultReferral,
     vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
     vault_update_state_tracker::VaultUpdateStateTracker,
 };
@@ -20,6 +22,10 @@ pub enum VaultDiscriminator {
     VaultNcnSlasherOperatorTicket = 6,
     VaultStakerWithdrawalTicket = 7,
     VaultUpdateStateTracker = 8,
+    VaultDepositReceipt = 9,
+    VaultFeeSplit = 10,
+    CreatorAllowlistEntry = 11,
+    VaultReferral = 12,
 }
 
 impl Discriminator for Config {
@@ -53,3 +59,36 @@ impl Discriminator for VaultStakerWithdrawalTicket {
 impl Discriminator for VaultUpdateStateTracker {
     const DISCRIMINATOR: u8 = VaultDiscriminator::VaultUpdateStateTracker as u8;
 }
+
+impl Discriminator for VaultDepositReceipt {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultDepositReceipt as u8;
+}
+
+impl Discriminator for VaultFeeSplit {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultFeeSplit as u8;
+}
+
+impl Discriminator for CreatorAllowlistEntry {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::CreatorAllowlistEntry as u8;
+}
+
+impl Discriminator for VaultReferral {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultReferral as u8;
+}
+
+// When a layout grows, implement `AccountDeserialize` by hand with a bumped `LAYOUT_VERSION` and
+// override `Migrate::layout_len` and `Migrate::migrate_layout` for the account.
+// `VaultUpdateStateTracker` is at layout version 1 but keeps the default `Migrate`, rejecting
+// trackers written before the operator bitmap.
+impl Migrate for Config {}
+impl Migrate for Vault {}
+impl Migrate for VaultNcnTicket {}
+impl Migrate for VaultOperatorDelegation {}
+impl Migrate for VaultNcnSlasherTicket {}
+impl Migrate for VaultNcnSlasherOperatorTicket {}
+impl Migrate for VaultStakerWithdrawalTicket {}
+impl Migrate for VaultUpdateStateTracker {}
+impl Migrate for VaultDepositReceipt {}
+impl Migrate for VaultFeeSplit {}
+impl Migrate for CreatorAllowlistEntry {}
+impl Migrate for VaultReferral {}
@@ -1,14 +1,22 @@
 pub mod burn_vault;
 pub mod config;
+pub mod creator_allowlist_entry;
 pub mod delegation_state;
 pub mod discriminators;
 pub mod loader;
 pub mod vault;
+pub mod vault_deposit_receipt;
+pub mod vault_fee_split;
 pub mod vault_ncn_slasher_operator_ticket;
 pub mod vault_ncn_slasher_ticket;
 pub mod vault_ncn_ticket;
 pub mod vault_operator_delegation;
+pub mod vault_referral;
 pub mod vault_staker_withdrawal_ticket;
 pub mod vault_update_state_tracker;
+pub mod vault_withdrawal_claim_mint;
 
 pub const MAX_BPS: u16 = 10_000;
+
+// Maximum holding period a vault can require of its depositors, in epochs
+pub const MAX_HOLDING_EPOCHS: u64 = 32;
@@ -9,9 +9,9 @@ use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{config::Config, delegation_state::DelegationState, MAX_BPS};
+use crate::{config::Config, delegation_state::DelegationState, MAX_BPS, MAX_HOLDING_EPOCHS};
 
-const RESERVED_SPACE_LEN: usize = 251;
+const RESERVED_SPACE_LEN: usize = 226;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct BurnSummary {
@@ -153,14 +153,43 @@ pub struct Vault {
     /// last
     last_start_state_update_slot: PodU64,
 
+    /// Whether the vault is winding down and shall be decommissioned
+    is_winding_down: PodBool,
+
+    /// The maximum share of the deposited tokens, in basis points, that may be delegated to a
+    /// single operator. Zero means there is no limit.
+    max_operator_share_bps: PodU16,
+
+    /// The number of epochs a depositor shall hold their VRT after their latest deposit before
+    /// enqueueing a withdrawal. Zero means there is no holding period.
+    min_holding_epochs: PodU64,
+
+    /// Whether the vault admin has waived the holding period while winding down
+    is_holding_period_waived: PodBool,
+
+    /// Whether the config admin has set a program fee for this vault which takes precedence over
+    /// the config's program fee
+    has_program_fee_override: PodBool,
+
+    /// The program fee override in basis points, only used when `has_program_fee_override` is set
+    program_fee_override_bps: PodU16,
+
+    /// The slot of the last program fee override change
+    last_program_fee_override_change_slot: PodU64,
+
+    /// The share of the deposit fee, in basis points, minted to the referrer of a deposit
+    referral_fee_bps: PodU16,
+
     /// Reserved space
-    reserved: [u8; 251],
+    reserved: [u8; 226],
 }
 
 impl Vault {
     pub const MAX_REWARD_DELTA_BPS: u16 = 50; // 0.5%
     pub const MIN_WITHDRAWAL_SLIPPAGE_BPS: u16 = 50; // 0.5%
     pub const DEFAULT_INITIALIZATION_TOKEN_AMOUNT: u64 = 10_000;
+    /// The most operators a vault can have to be updated in a single transaction
+    pub const MAX_FULL_UPDATE_OPERATORS: u64 = 16;
 
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -226,6 +255,14 @@ impl Vault {
             delegation_state: DelegationState::default(),
             additional_assets_need_unstaking: PodU64::from(0),
             is_paused: PodBool::from_bool(false),
+            is_winding_down: PodBool::from_bool(false),
+            max_operator_share_bps: PodU16::from(0),
+            min_holding_epochs: PodU64::from(0),
+            is_holding_period_waived: PodBool::from_bool(false),
+            has_program_fee_override: PodBool::from_bool(false),
+            program_fee_override_bps: PodU16::from(0),
+            last_program_fee_override_change_slot: PodU64::from(0),
+            referral_fee_bps: PodU16::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         })
     }
@@ -354,6 +391,108 @@ impl Vault {
         Ok(())
     }
 
+    /// The program fee the config admin has negotiated for this vault, if any
+    pub fn program_fee_override_bps(&self) -> Option<u16> {
+        if self.has_program_fee_override.into() {
+            Some(self.program_fee_override_bps.into())
+        } else {
+            None
+        }
+    }
+
+    pub fn last_program_fee_override_change_slot(&self) -> u64 {
+        self.last_program_fee_override_change_slot.into()
+    }
+
+    /// Sets or clears the program fee override. The vault's program fee picks up the change on
+    /// the next vault update.
+    ///
+    /// # Arguments
+    /// * `program_fee_override_bps` - The new override, `None` to fall back to the config's fee
+    /// * `slot` - The current slot
+    pub fn set_program_fee_override_bps(
+        &mut self,
+        program_fee_override_bps: Option<u16>,
+        slot: u64,
+    ) -> Result<(), VaultError> {
+        if let Some(program_fee_override_bps) = program_fee_override_bps {
+            if program_fee_override_bps > MAX_BPS {
+                msg!(
+                    "Program fee override exceeds maximum allowed of {}",
+                    MAX_BPS
+                );
+                return Err(VaultError::VaultFeeCapExceeded);
+            }
+        }
+        self.has_program_fee_override = PodBool::from_bool(program_fee_override_bps.is_some());
+        self.program_fee_override_bps = PodU16::from(program_fee_override_bps.unwrap_or(0));
+        self.last_program_fee_override_change_slot = PodU64::from(slot);
+        Ok(())
+    }
+
+    /// The program fee this vault shall be charged, the override if set or else the config's fee
+    pub fn effective_program_fee_bps(&self, config_program_fee_bps: u16) -> u16 {
+        self.program_fee_override_bps()
+            .unwrap_or(config_program_fee_bps)
+    }
+
+    /// Program fee overrides can be changed at most once per epoch, and a **full** epoch must pass
+    /// before the override can be changed again. The first override can be set at any time.
+    pub fn check_can_modify_program_fee_override(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ProgramError> {
+        if self.last_program_fee_override_change_slot() == 0 {
+            return Ok(());
+        }
+
+        let current_epoch = get_epoch(slot, epoch_length)?;
+        let last_change_epoch =
+            get_epoch(self.last_program_fee_override_change_slot(), epoch_length)?;
+
+        if current_epoch
+            <= last_change_epoch
+                .checked_add(1)
+                .ok_or(VaultError::ArithmeticOverflow)?
+        {
+            msg!("Program fee override changes are only allowed once per epoch");
+            return Err(VaultError::VaultProgramFeeOverrideChangeTooSoon.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn referral_fee_bps(&self) -> u16 {
+        self.referral_fee_bps.into()
+    }
+
+    /// Sets the share of the deposit fee minted to the referrer of a deposit
+    ///
+    /// # Arguments
+    /// * `referral_fee_bps` - The share of the deposit fee in basis points
+    pub fn set_referral_fee_bps(&mut self, referral_fee_bps: u16) -> Result<(), VaultError> {
+        if referral_fee_bps > MAX_BPS {
+            msg!("Referral fee exceeds maximum allowed of {}", MAX_BPS);
+            return Err(VaultError::VaultFeeCapExceeded);
+        }
+        self.referral_fee_bps = PodU16::from(referral_fee_bps);
+        Ok(())
+    }
+
+    /// Calculate the referrer's share of the deposit fee, rounded down in favor of the fee wallet
+    ///
+    /// # Arguments
+    /// * `deposit_fee` - The amount of VRT collected as a deposit fee
+    pub fn calculate_referral_fee(&self, deposit_fee: u64) -> Result<u64, VaultError> {
+        let fee = (deposit_fee as u128)
+            .checked_mul(self.referral_fee_bps() as u128)
+            .and_then(|x| x.checked_div(MAX_BPS as u128))
+            .and_then(|x| x.try_into().ok())
+            .ok_or(VaultError::VaultOverflow)?;
+        Ok(fee)
+    }
+
     pub fn operator_count(&self) -> u64 {
         self.operator_count.into()
     }
@@ -463,6 +602,110 @@ impl Vault {
         self.is_paused = PodBool::from_bool(is_paused);
     }
 
+    pub fn is_winding_down(&self) -> bool {
+        self.is_winding_down.into()
+    }
+
+    pub fn set_is_winding_down(&mut self, is_winding_down: bool) {
+        self.is_winding_down = PodBool::from_bool(is_winding_down);
+    }
+
+    pub fn max_operator_share_bps(&self) -> u16 {
+        self.max_operator_share_bps.into()
+    }
+
+    /// Sets the maximum share of the deposited tokens that may be delegated to a single operator
+    ///
+    /// # Arguments
+    /// * `max_operator_share_bps` - The share in basis points, zero to remove the limit
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultMaxOperatorShareInvalid`] - If the share exceeds [`MAX_BPS`]
+    pub fn set_max_operator_share_bps(
+        &mut self,
+        max_operator_share_bps: u16,
+    ) -> Result<(), VaultError> {
+        if max_operator_share_bps > MAX_BPS {
+            msg!("Max operator share exceeds maximum allowed of {}", MAX_BPS);
+            return Err(VaultError::VaultMaxOperatorShareInvalid);
+        }
+        self.max_operator_share_bps = PodU16::from(max_operator_share_bps);
+        Ok(())
+    }
+
+    /// Checks an operator's delegation stays within the vault's max operator share
+    ///
+    /// # Arguments
+    /// * `operator_security` - The total security delegated to the operator, including the new delegation
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultMaxOperatorShareExceeded`] - If the operator would hold more than the max share
+    pub fn check_max_operator_share(&self, operator_security: u64) -> Result<(), VaultError> {
+        let max_operator_share_bps = self.max_operator_share_bps();
+        if max_operator_share_bps == 0 {
+            return Ok(());
+        }
+
+        let max_operator_security = (self.tokens_deposited() as u128)
+            .checked_mul(max_operator_share_bps as u128)
+            .and_then(|x| x.checked_div(MAX_BPS as u128))
+            .ok_or(VaultError::VaultOverflow)?;
+
+        if operator_security as u128 > max_operator_security {
+            msg!(
+                "Operator security of {} exceeds the max operator share of {} ({} bps)",
+                operator_security,
+                max_operator_security,
+                max_operator_share_bps
+            );
+            return Err(VaultError::VaultMaxOperatorShareExceeded);
+        }
+
+        Ok(())
+    }
+
+    pub fn min_holding_epochs(&self) -> u64 {
+        self.min_holding_epochs.into()
+    }
+
+    /// Sets the number of epochs depositors shall hold their VRT before withdrawing
+    ///
+    /// # Arguments
+    /// * `min_holding_epochs` - The holding period in epochs, zero to remove it
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultMinHoldingEpochsInvalid`] - If the holding period exceeds
+    ///   [`MAX_HOLDING_EPOCHS`]
+    pub fn set_min_holding_epochs(&mut self, min_holding_epochs: u64) -> Result<(), VaultError> {
+        if min_holding_epochs > MAX_HOLDING_EPOCHS {
+            msg!(
+                "Min holding epochs exceeds maximum allowed of {}",
+                MAX_HOLDING_EPOCHS
+            );
+            return Err(VaultError::VaultMinHoldingEpochsInvalid);
+        }
+        self.min_holding_epochs = PodU64::from(min_holding_epochs);
+        Ok(())
+    }
+
+    pub fn is_holding_period_waived(&self) -> bool {
+        self.is_holding_period_waived.into()
+    }
+
+    pub fn set_is_holding_period_waived(&mut self, is_holding_period_waived: bool) {
+        self.is_holding_period_waived = PodBool::from_bool(is_holding_period_waived);
+    }
+
+    /// The holding period depositors are currently subject to, in epochs. Zero when the vault
+    /// has no holding period or the vault admin has waived it.
+    pub fn effective_min_holding_epochs(&self) -> u64 {
+        if self.is_holding_period_waived() {
+            0
+        } else {
+            self.min_holding_epochs()
+        }
+    }
+
     // Only to be used in initialize_vault
     pub fn initialize_vault_override_deposit_fee_bps(
         &mut self,
@@ -501,6 +744,55 @@ impl Vault {
         Ok(())
     }
 
+    /// Checks whether the vault is winding down. Instructions that grow the vault's footprint,
+    /// such as deposits or new delegations, shall call this.
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultIsWindingDown`] - If the vault is winding down.
+    pub fn check_is_winding_down(&self) -> Result<(), VaultError> {
+        if self.is_winding_down() {
+            msg!("Vault is winding down.");
+            return Err(VaultError::VaultIsWindingDown);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the vault can be closed.
+    ///
+    /// A vault can only be closed once it is winding down, every delegation has been
+    /// returned to the vault and the only VRT left in circulation is the amount locked
+    /// in the burn vault at initialization.
+    ///
+    /// # Arguments
+    /// * `locked_vrt_amount` - The VRT held by the vault's burn vault
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultIsNotWindingDown`] - If the vault is not winding down.
+    /// * [`VaultError::VaultHasOutstandingVrt`] - If there are VRT left to be withdrawn.
+    /// * [`VaultError::VaultHasOutstandingDelegation`] - If assets are still delegated.
+    pub fn check_can_close(&self, locked_vrt_amount: u64) -> Result<(), VaultError> {
+        if !self.is_winding_down() {
+            msg!("Vault is not winding down");
+            return Err(VaultError::VaultIsNotWindingDown);
+        }
+
+        if self.vrt_supply() > locked_vrt_amount {
+            msg!(
+                "Vault has {} VRT outstanding",
+                self.vrt_supply().saturating_sub(locked_vrt_amount)
+            );
+            return Err(VaultError::VaultHasOutstandingVrt);
+        }
+
+        if self.delegation_state.total_security()? > 0 {
+            msg!("Vault still has assets delegated to operators");
+            return Err(VaultError::VaultHasOutstandingDelegation);
+        }
+
+        Ok(())
+    }
+
     pub fn check_vrt_mint(&self, vrt_mint: &Pubkey) -> Result<(), ProgramError> {
         if self.vrt_mint.ne(vrt_mint) {
             msg!("Vault VRT mint does not match the provided VRT mint");
@@ -1194,6 +1486,13 @@ impl Vault {
         let additional_assets_need_undelegating =
             amount_requested_for_withdrawals.saturating_sub(available_for_withdrawal);
 
+        // A vault that is winding down shall unstake everything that is still staked, regardless
+        // of how much has been requested for withdrawal
+        if self.is_winding_down() {
+            return Ok(additional_assets_need_undelegating
+                .max(delegation_state_after_update.staked_amount()));
+        }
+
         Ok(additional_assets_need_undelegating)
     }
 
@@ -1319,7 +1618,7 @@ mod tests {
     use crate::{
         delegation_state::DelegationState,
         vault::{BurnSummary, MintSummary, Vault, RESERVED_SPACE_LEN},
-        MAX_BPS,
+        MAX_BPS, MAX_HOLDING_EPOCHS,
     };
 
     fn make_test_vault(
@@ -1389,6 +1688,14 @@ mod tests {
             std::mem::size_of::<PodU16>() + // program_fee_bps
             std::mem::size_of::<PodBool>() + // is_paused
             std::mem::size_of::<PodU64>() + // last_start_state_update_slot
+            std::mem::size_of::<PodBool>() + // is_winding_down
+            std::mem::size_of::<PodU16>() + // max_operator_share_bps
+            std::mem::size_of::<PodU64>() + // min_holding_epochs
+            std::mem::size_of::<PodBool>() + // is_holding_period_waived
+            std::mem::size_of::<PodBool>() + // has_program_fee_override
+            std::mem::size_of::<PodU16>() + // program_fee_override_bps
+            std::mem::size_of::<PodU64>() + // last_program_fee_override_change_slot
+            std::mem::size_of::<PodU16>() + // referral_fee_bps
             1 + // bump
             RESERVED_SPACE_LEN; // reserved
 
@@ -1896,6 +2203,55 @@ mod tests {
         vault.delegate(1000).unwrap();
     }
 
+    #[test]
+    fn test_set_max_operator_share_bps_too_large_fails() {
+        let mut vault = make_test_vault(0, 0, 0, 1000, 1000, DelegationState::default());
+        assert_eq!(
+            vault.set_max_operator_share_bps(MAX_BPS + 1),
+            Err(VaultError::VaultMaxOperatorShareInvalid)
+        );
+    }
+
+    #[test]
+    fn test_check_max_operator_share() {
+        let mut vault = make_test_vault(0, 0, 0, 1000, 1000, DelegationState::default());
+
+        // no limit by default
+        vault.check_max_operator_share(1000).unwrap();
+
+        vault.set_max_operator_share_bps(2500).unwrap();
+        vault.check_max_operator_share(250).unwrap();
+        assert_eq!(
+            vault.check_max_operator_share(251),
+            Err(VaultError::VaultMaxOperatorShareExceeded)
+        );
+    }
+
+    #[test]
+    fn test_effective_min_holding_epochs() {
+        let mut vault = make_test_vault(0, 0, 0, 1000, 1000, DelegationState::default());
+        assert_eq!(vault.effective_min_holding_epochs(), 0);
+
+        vault.set_min_holding_epochs(3).unwrap();
+        assert_eq!(vault.effective_min_holding_epochs(), 3);
+
+        vault.set_is_holding_period_waived(true);
+        assert_eq!(vault.effective_min_holding_epochs(), 0);
+        assert_eq!(vault.min_holding_epochs(), 3);
+    }
+
+    #[test]
+    fn test_set_min_holding_epochs_above_max_fails() {
+        let mut vault = make_test_vault(0, 0, 0, 1000, 1000, DelegationState::default());
+
+        vault.set_min_holding_epochs(MAX_HOLDING_EPOCHS).unwrap();
+        assert_eq!(
+            vault.set_min_holding_epochs(MAX_HOLDING_EPOCHS + 1),
+            Err(VaultError::VaultMinHoldingEpochsInvalid)
+        );
+        assert_eq!(vault.min_holding_epochs(), MAX_HOLDING_EPOCHS);
+    }
+
     #[test]
     fn test_delegate_more_than_available_fails() {
         let mut vault = make_test_vault(0, 0, 0, 1000, 1000, DelegationState::default());
@@ -2671,11 +3027,11 @@ mod tests {
         )
         .unwrap();
         // Verify reserved space is initialized to zeros
-        assert_eq!(vault.reserved, [0u8; 251]);
+        assert_eq!(vault.reserved, [0u8; 226]);
 
         // Get the size of the reserved field
         let reserved_size = std::mem::size_of_val(&vault.reserved);
-        assert_eq!(reserved_size, 251);
+        assert_eq!(reserved_size, 226);
 
         // Verify the reserved field maintains alignment
         assert_eq!(std::mem::align_of_val(&vault.reserved), 1);
@@ -2702,11 +3058,11 @@ mod tests {
         let serialized = bytemuck::bytes_of(&vault);
 
         // Calculate the expected position of reserved field
-        let reserved_offset = serialized.len() - 251;
+        let reserved_offset = serialized.len() - 226;
 
         // Verify the reserved space in serialized form
         let reserved_slice = &serialized[reserved_offset..];
-        assert_eq!(reserved_slice, &[0u8; 251]);
+        assert_eq!(reserved_slice, &[0u8; 226]);
     }
 
     #[test]
@@ -2748,6 +3104,106 @@ mod tests {
         assert_eq!(vault.program_fee_bps(), MAX_BPS);
     }
 
+    #[test]
+    fn test_program_fee_override() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            100,
+            0,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.program_fee_override_bps(), None);
+        assert_eq!(vault.effective_program_fee_bps(100), 100);
+
+        // A zero override is a valid negotiated rate
+        vault.set_program_fee_override_bps(Some(0), 10).unwrap();
+        assert_eq!(vault.program_fee_override_bps(), Some(0));
+        assert_eq!(vault.effective_program_fee_bps(100), 0);
+        assert_eq!(vault.last_program_fee_override_change_slot(), 10);
+
+        vault.set_program_fee_override_bps(None, 20).unwrap();
+        assert_eq!(vault.program_fee_override_bps(), None);
+        assert_eq!(vault.effective_program_fee_bps(100), 100);
+
+        assert_eq!(
+            vault.set_program_fee_override_bps(Some(MAX_BPS + 1), 30),
+            Err(VaultError::VaultFeeCapExceeded)
+        );
+        assert_eq!(vault.last_program_fee_override_change_slot(), 20);
+    }
+
+    #[test]
+    fn test_calculate_referral_fee() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            100,
+            0,
+            0,
+            0,
+            0,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.calculate_referral_fee(1_000).unwrap(), 0);
+
+        vault.set_referral_fee_bps(2_500).unwrap();
+        assert_eq!(vault.calculate_referral_fee(1_000).unwrap(), 250);
+        // rounds down in favor of the fee wallet
+        assert_eq!(vault.calculate_referral_fee(3).unwrap(), 0);
+
+        vault.set_referral_fee_bps(MAX_BPS).unwrap();
+        assert_eq!(vault.calculate_referral_fee(1_000).unwrap(), 1_000);
+
+        assert_eq!(
+            vault.set_referral_fee_bps(MAX_BPS + 1),
+            Err(VaultError::VaultFeeCapExceeded)
+        );
+        assert_eq!(vault.referral_fee_bps(), MAX_BPS);
+    }
+
+    #[test]
+    fn test_check_can_modify_program_fee_override() {
+        let mut vault = Vault::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        )
+        .unwrap();
+
+        // The first override is not timelocked
+        vault.check_can_modify_program_fee_override(5, 100).unwrap();
+
+        // changed in epoch 1
+        vault.set_program_fee_override_bps(Some(50), 150).unwrap();
+        assert_eq!(
+            vault.check_can_modify_program_fee_override(250, 100),
+            Err(VaultError::VaultProgramFeeOverrideChangeTooSoon.into())
+        );
+        vault
+            .check_can_modify_program_fee_override(300, 100)
+            .unwrap();
+    }
+
     #[test]
     fn test_set_withdrawal_fee_bps() {
         let mut vault = Vault::new(
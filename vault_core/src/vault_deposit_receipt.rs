@@ -0,0 +1,257 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_jsm_core::get_epoch;
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+const RESERVED_SPACE_LEN: usize = 255;
+
+/// The [`VaultDepositReceipt`] account
+///
+/// - tracks the most recent deposit credited to a depositor, the holder of the minted VRT, and
+///   the VRT credited since the depositor's holding period last elapsed.
+/// - is created by `MintTo` when the vault has a minimum holding period, and is used by
+///   `EnqueueWithdrawal` to keep that VRT in the depositor's account until the period elapses.
+///   VRT the depositor held before their receipt was created isn't tracked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultDepositReceipt {
+    /// The vault deposited into
+    pub vault: Pubkey,
+
+    /// The depositor
+    pub depositor: Pubkey,
+
+    /// The slot of the depositor's most recent deposit
+    last_deposit_slot: PodU64,
+
+    /// The VRT credited by the deposits made since the holding period last elapsed
+    vrt_amount: PodU64,
+
+    /// The bump seed used to create the PDA
+    pub bump: u8,
+
+    reserved: [u8; 255],
+}
+
+impl VaultDepositReceipt {
+    pub fn new(
+        vault: Pubkey,
+        depositor: Pubkey,
+        last_deposit_slot: u64,
+        vrt_amount: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            vault,
+            depositor,
+            last_deposit_slot: PodU64::from(last_deposit_slot),
+            vrt_amount: PodU64::from(vrt_amount),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn last_deposit_slot(&self) -> u64 {
+        self.last_deposit_slot.into()
+    }
+
+    pub fn vrt_amount(&self) -> u64 {
+        self.vrt_amount.into()
+    }
+
+    /// Records a deposit, restarting the holding period. The VRT credited before the previous
+    /// holding period elapsed stays tracked along with the new deposit.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The epoch length
+    /// * `min_holding_epochs` - The vault's minimum holding period in epochs
+    /// * `vrt_amount` - The VRT credited by the deposit
+    pub fn record_deposit(
+        &mut self,
+        slot: u64,
+        epoch_length: u64,
+        min_holding_epochs: u64,
+        vrt_amount: u64,
+    ) -> Result<(), ProgramError> {
+        let vrt_amount =
+            if self.is_holding_period_elapsed(slot, epoch_length, min_holding_epochs)? {
+                vrt_amount
+            } else {
+                self.vrt_amount()
+                    .checked_add(vrt_amount)
+                    .ok_or(VaultError::ArithmeticOverflow)?
+            };
+        self.vrt_amount = PodU64::from(vrt_amount);
+        self.last_deposit_slot = PodU64::from(slot);
+        Ok(())
+    }
+
+    /// The VRT the depositor shall keep until the holding period elapses
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The epoch length
+    /// * `min_holding_epochs` - The vault's minimum holding period in epochs
+    pub fn held_vrt_amount(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+        min_holding_epochs: u64,
+    ) -> Result<u64, ProgramError> {
+        if self.is_holding_period_elapsed(slot, epoch_length, min_holding_epochs)? {
+            Ok(0)
+        } else {
+            Ok(self.vrt_amount())
+        }
+    }
+
+    /// Whether at least `min_holding_epochs` full epochs have started since the last deposit
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The epoch length
+    /// * `min_holding_epochs` - The vault's minimum holding period in epochs
+    pub fn is_holding_period_elapsed(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+        min_holding_epochs: u64,
+    ) -> Result<bool, ProgramError> {
+        let current_epoch = get_epoch(slot, epoch_length)?;
+        let deposit_epoch = get_epoch(self.last_deposit_slot(), epoch_length)?;
+
+        let unlock_epoch = deposit_epoch
+            .checked_add(min_holding_epochs)
+            .ok_or(VaultError::ArithmeticOverflow)?;
+
+        Ok(current_epoch >= unlock_epoch)
+    }
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    /// * `depositor` - The depositor
+    pub fn seeds(vault: &Pubkey, depositor: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_deposit_receipt".to_vec(),
+            vault.to_bytes().to_vec(),
+            depositor.to_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    /// * `depositor` - The depositor
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        depositor: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, depositor);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultDepositReceipt`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_deposit_receipt` - The [`VaultDepositReceipt`] account
+    /// * `vault` - The vault account
+    /// * `depositor` - The depositor
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_deposit_receipt: &AccountInfo,
+        vault: &AccountInfo,
+        depositor: &Pubkey,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_deposit_receipt.owner.ne(program_id) {
+            msg!("Vault deposit receipt has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_deposit_receipt.data_is_empty() {
+            msg!("Vault deposit receipt data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !vault_deposit_receipt.is_writable {
+            msg!("Vault deposit receipt is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if vault_deposit_receipt.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault deposit receipt discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key, depositor).0;
+        if vault_deposit_receipt.key.ne(&expected_pubkey) {
+            msg!("Vault deposit receipt is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_deposit_receipt_no_padding() {
+        let vault_deposit_receipt_size = std::mem::size_of::<VaultDepositReceipt>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // depositor
+            size_of::<PodU64>() + // last_deposit_slot
+            size_of::<PodU64>() + // vrt_amount
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_deposit_receipt_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_is_holding_period_elapsed() {
+        let receipt =
+            VaultDepositReceipt::new(Pubkey::new_unique(), Pubkey::new_unique(), 150, 100, 0);
+
+        // deposited in epoch 1
+        assert!(receipt.is_holding_period_elapsed(150, 100, 0).unwrap());
+        assert!(!receipt.is_holding_period_elapsed(199, 100, 1).unwrap());
+        assert!(receipt.is_holding_period_elapsed(200, 100, 1).unwrap());
+        assert!(!receipt.is_holding_period_elapsed(399, 100, 3).unwrap());
+        assert!(receipt.is_holding_period_elapsed(400, 100, 3).unwrap());
+    }
+
+    #[test]
+    fn test_record_deposit() {
+        let mut receipt =
+            VaultDepositReceipt::new(Pubkey::new_unique(), Pubkey::new_unique(), 150, 100, 0);
+
+        // deposits within the holding period add up
+        receipt.record_deposit(199, 100, 1, 50).unwrap();
+        assert_eq!(receipt.last_deposit_slot(), 199);
+        assert_eq!(receipt.vrt_amount(), 150);
+        assert_eq!(receipt.held_vrt_amount(199, 100, 1).unwrap(), 150);
+        assert_eq!(receipt.held_vrt_amount(200, 100, 1).unwrap(), 0);
+
+        // once it elapsed, only the new deposit is held
+        receipt.record_deposit(300, 100, 1, 20).unwrap();
+        assert_eq!(receipt.vrt_amount(), 20);
+        assert_eq!(receipt.held_vrt_amount(300, 100, 1).unwrap(), 20);
+    }
+}
@@ -0,0 +1,316 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU16, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::{ShankAccount, ShankType};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::MAX_BPS;
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The maximum number of recipients a fee split can pay out to
+pub const MAX_FEE_SPLIT_RECIPIENTS: usize = 8;
+
+/// A fee recipient and its share of the fees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankType)]
+#[repr(C)]
+pub struct FeeSplitRecipient {
+    /// The recipient, whose VRT associated token account receives the fees
+    pub recipient: Pubkey,
+
+    /// The share of the fees paid to the recipient in basis points
+    bps: PodU16,
+}
+
+impl Default for FeeSplitRecipient {
+    fn default() -> Self {
+        Self {
+            recipient: Pubkey::default(),
+            bps: PodU16::from(0),
+        }
+    }
+}
+
+impl FeeSplitRecipient {
+    pub fn bps(&self) -> u16 {
+        self.bps.into()
+    }
+}
+
+/// The [`VaultFeeSplit`] account
+///
+/// - is set as the vault's fee wallet, so the VRT fees taken on mint, burn and reward accrual
+///   accumulate in its associated token account.
+/// - lists the recipients the accumulated VRT is distributed to, weighted in basis points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultFeeSplit {
+    /// The vault whose fees are split
+    pub vault: Pubkey,
+
+    /// The fee recipients, only the first `recipients_len` entries are in use
+    recipients: [FeeSplitRecipient; 8],
+
+    /// The number of recipients in use
+    recipients_len: u8,
+
+    /// The bump seed used to create the PDA
+    pub bump: u8,
+
+    reserved: [u8; 263],
+}
+
+impl VaultFeeSplit {
+    pub fn new(vault: Pubkey, bump: u8) -> Self {
+        Self {
+            vault,
+            recipients: [FeeSplitRecipient::default(); MAX_FEE_SPLIT_RECIPIENTS],
+            recipients_len: 0,
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    /// The recipients in use
+    pub fn recipients(&self) -> &[FeeSplitRecipient] {
+        &self.recipients[..self.recipients_len as usize]
+    }
+
+    /// Replaces the recipients of the fee split
+    ///
+    /// # Arguments
+    /// * `recipients` - The fee recipients, between one and [`MAX_FEE_SPLIT_RECIPIENTS`] unique keys
+    /// * `recipient_bps` - The share of each recipient, non-zero and summing to [`MAX_BPS`]
+    pub fn set_recipients(
+        &mut self,
+        recipients: &[Pubkey],
+        recipient_bps: &[u16],
+    ) -> Result<(), VaultError> {
+        if recipients.is_empty()
+            || recipients.len() > MAX_FEE_SPLIT_RECIPIENTS
+            || recipients.len() != recipient_bps.len()
+        {
+            msg!(
+                "Fee split must have between 1 and {} recipients, each with a share",
+                MAX_FEE_SPLIT_RECIPIENTS
+            );
+            return Err(VaultError::VaultFeeSplitInvalidRecipients);
+        }
+        for (i, recipient) in recipients.iter().enumerate() {
+            if recipients[..i].contains(recipient) {
+                msg!("Fee split recipient {} is duplicated", recipient);
+                return Err(VaultError::VaultFeeSplitInvalidRecipients);
+            }
+        }
+
+        let mut total_bps: u16 = 0;
+        for bps in recipient_bps {
+            if *bps == 0 {
+                msg!("Fee split recipient share must be greater than zero");
+                return Err(VaultError::VaultFeeSplitInvalidBps);
+            }
+            total_bps = total_bps
+                .checked_add(*bps)
+                .ok_or(VaultError::VaultFeeSplitInvalidBps)?;
+        }
+        if total_bps != MAX_BPS {
+            msg!("Fee split shares must add up to {} bps", MAX_BPS);
+            return Err(VaultError::VaultFeeSplitInvalidBps);
+        }
+
+        self.recipients = [FeeSplitRecipient::default(); MAX_FEE_SPLIT_RECIPIENTS];
+        for ((entry, recipient), bps) in self
+            .recipients
+            .iter_mut()
+            .zip(recipients)
+            .zip(recipient_bps)
+        {
+            *entry = FeeSplitRecipient {
+                recipient: *recipient,
+                bps: PodU16::from(*bps),
+            };
+        }
+        self.recipients_len = recipients.len() as u8;
+
+        Ok(())
+    }
+
+    /// Splits `amount` between the recipients, rounding each share down. The remainder stays in
+    /// the escrow and is paid out in a later distribution.
+    ///
+    /// # Returns
+    /// * `Vec<u64>` - The amount owed to each recipient, in order
+    pub fn calculate_distribution(&self, amount: u64) -> Result<Vec<u64>, VaultError> {
+        self.recipients()
+            .iter()
+            .map(|recipient| {
+                let share = (amount as u128)
+                    .checked_mul(recipient.bps() as u128)
+                    .and_then(|x| x.checked_div(MAX_BPS as u128))
+                    .ok_or(VaultError::ArithmeticOverflow)?;
+                u64::try_from(share).map_err(|_| VaultError::ArithmeticOverflow)
+            })
+            .collect()
+    }
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    pub fn seeds(vault: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([b"vault_fee_split".to_vec(), vault.to_bytes().to_vec()])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    pub fn find_program_address(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultFeeSplit`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_fee_split` - The [`VaultFeeSplit`] account
+    /// * `vault` - The vault account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_fee_split: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_fee_split.owner.ne(program_id) {
+            msg!("Vault fee split has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_fee_split.data_is_empty() {
+            msg!("Vault fee split data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !vault_fee_split.is_writable {
+            msg!("Vault fee split is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if vault_fee_split.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault fee split discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key).0;
+        if vault_fee_split.key.ne(&expected_pubkey) {
+            msg!("Vault fee split is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_fee_split_no_padding() {
+        let vault_fee_split_size = std::mem::size_of::<VaultFeeSplit>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<[FeeSplitRecipient; MAX_FEE_SPLIT_RECIPIENTS]>() + // recipients
+            size_of::<u8>() + // recipients_len
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_fee_split_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_set_recipients() {
+        let mut fee_split = VaultFeeSplit::new(Pubkey::new_unique(), 0);
+        let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        fee_split
+            .set_recipients(&recipients, &[7_000, 3_000])
+            .unwrap();
+        assert_eq!(fee_split.recipients().len(), 2);
+        assert_eq!(fee_split.recipients()[0].recipient, recipients[0]);
+        assert_eq!(fee_split.recipients()[0].bps(), 7_000);
+        assert_eq!(fee_split.recipients()[1].recipient, recipients[1]);
+        assert_eq!(fee_split.recipients()[1].bps(), 3_000);
+
+        // shrinking clears the unused entries
+        fee_split
+            .set_recipients(&recipients[..1], &[10_000])
+            .unwrap();
+        assert_eq!(fee_split.recipients().len(), 1);
+        assert_eq!(fee_split.recipients[1], FeeSplitRecipient::default());
+    }
+
+    #[test]
+    fn test_set_recipients_invalid() {
+        let mut fee_split = VaultFeeSplit::new(Pubkey::new_unique(), 0);
+        let recipient = Pubkey::new_unique();
+
+        assert_eq!(
+            fee_split.set_recipients(&[], &[]),
+            Err(VaultError::VaultFeeSplitInvalidRecipients)
+        );
+        assert_eq!(
+            fee_split.set_recipients(&[recipient, recipient], &[5_000, 5_000]),
+            Err(VaultError::VaultFeeSplitInvalidRecipients)
+        );
+        assert_eq!(
+            fee_split.set_recipients(&[recipient], &[5_000, 5_000]),
+            Err(VaultError::VaultFeeSplitInvalidRecipients)
+        );
+        let too_many: Vec<Pubkey> = (0..=MAX_FEE_SPLIT_RECIPIENTS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        assert_eq!(
+            fee_split.set_recipients(&too_many, &[1_000; MAX_FEE_SPLIT_RECIPIENTS + 1]),
+            Err(VaultError::VaultFeeSplitInvalidRecipients)
+        );
+        assert_eq!(
+            fee_split.set_recipients(&[recipient, Pubkey::new_unique()], &[10_000, 0]),
+            Err(VaultError::VaultFeeSplitInvalidBps)
+        );
+        assert_eq!(
+            fee_split.set_recipients(&[recipient], &[9_999]),
+            Err(VaultError::VaultFeeSplitInvalidBps)
+        );
+    }
+
+    #[test]
+    fn test_calculate_distribution_rounds_down() {
+        let mut fee_split = VaultFeeSplit::new(Pubkey::new_unique(), 0);
+        fee_split
+            .set_recipients(
+                &[
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                ],
+                &[5_000, 3_333, 1_667],
+            )
+            .unwrap();
+
+        assert_eq!(
+            fee_split.calculate_distribution(1_000).unwrap(),
+            vec![500, 333, 166]
+        );
+        assert_eq!(
+            fee_split.calculate_distribution(u64::MAX).unwrap()[0],
+            u64::MAX / 2
+        );
+    }
+}
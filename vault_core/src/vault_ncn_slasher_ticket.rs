@@ -1,12 +1,15 @@
 //! The [`VaultNcnSlasherTicket`] account tracks a vault's support for a node consensus network
 //! slasher. It can be enabled and disabled over time by the vault slasher admin.
+//!
+//! Its max slashable amount per epoch mirrors the NCN vault slasher ticket in the restaking program.
 use bytemuck::{Pod, Zeroable};
 use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+const RESERVED_SPACE_LEN: usize = 247;
 
 /// The [`VaultNcnSlasherTicket`] account tracks a vault's support for a node consensus network
 /// slasher. It can be enabled and disabled over time by the vault slasher admin.
@@ -34,8 +37,15 @@ pub struct VaultNcnSlasherTicket {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The pending maximum slashable per epoch per operator, applied from
+    /// `next_max_slashable_epoch`
+    next_max_slashable_per_epoch: PodU64,
+
+    /// The epoch the pending maximum slashable takes effect, zero if no increase is pending
+    next_max_slashable_epoch: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 247],
 }
 
 impl VaultNcnSlasherTicket {
@@ -56,6 +66,8 @@ impl VaultNcnSlasherTicket {
             index: PodU64::from(index),
             state: SlotToggle::new(slot),
             bump,
+            next_max_slashable_per_epoch: PodU64::from(0),
+            next_max_slashable_epoch: PodU64::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -64,10 +76,58 @@ impl VaultNcnSlasherTicket {
         self.index.into()
     }
 
-    pub fn max_slashable_per_epoch(&self) -> u64 {
+    /// The stored max slashable per epoch, which a pending increase replaces once its epoch is
+    /// reached. Use [`Self::current_max_slashable_per_epoch`] for the value in effect.
+    pub fn stored_max_slashable_per_epoch(&self) -> u64 {
         self.max_slashable_per_epoch.into()
     }
 
+    pub fn next_max_slashable_per_epoch(&self) -> u64 {
+        self.next_max_slashable_per_epoch.into()
+    }
+
+    pub fn next_max_slashable_epoch(&self) -> u64 {
+        self.next_max_slashable_epoch.into()
+    }
+
+    /// Returns the maximum slashable per epoch in effect at the given slot
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn current_max_slashable_per_epoch(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<u64, VaultError> {
+        let epoch = slot
+            .checked_div(epoch_length)
+            .ok_or(VaultError::DivisionByZero)?;
+        if self.next_max_slashable_epoch() != 0 && epoch >= self.next_max_slashable_epoch() {
+            Ok(self.next_max_slashable_per_epoch())
+        } else {
+            Ok(self.stored_max_slashable_per_epoch())
+        }
+    }
+
+    /// Copies the maximum slashable per epoch, along with any pending increase, from the NCN's
+    /// slasher ticket so both tickets resolve to the same value at every slot.
+    ///
+    /// # Arguments
+    /// * `max_slashable_per_epoch` - The maximum slashable per epoch per operator
+    /// * `next_max_slashable_per_epoch` - The pending maximum slashable per epoch per operator
+    /// * `next_max_slashable_epoch` - The epoch the pending value takes effect, zero if none
+    pub fn sync_max_slashable_per_epoch(
+        &mut self,
+        max_slashable_per_epoch: u64,
+        next_max_slashable_per_epoch: u64,
+        next_max_slashable_epoch: u64,
+    ) {
+        self.max_slashable_per_epoch = PodU64::from(max_slashable_per_epoch);
+        self.next_max_slashable_per_epoch = PodU64::from(next_max_slashable_per_epoch);
+        self.next_max_slashable_epoch = PodU64::from(next_max_slashable_epoch);
+    }
+
     /// Returns the seeds for the PDA
     /// # Arguments
     /// * `vault` - The vault
@@ -169,6 +229,8 @@ mod tests {
             size_of::<PodU64>() + // index
             size_of::<SlotToggle>() + // state
             size_of::<u8>() + // bump
+            size_of::<PodU64>() + // next_max_slashable_per_epoch
+            size_of::<PodU64>() + // next_max_slashable_epoch
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(vault_ncn_slasher_ticket_size, sum_of_fields);
     }
@@ -190,4 +252,32 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_current_max_slashable_per_epoch_applies_pending_increase() {
+        let epoch_length = 100;
+        let mut vault_ncn_slasher_ticket = VaultNcnSlasherTicket::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1_000,
+            0,
+            0,
+            0,
+        );
+        vault_ncn_slasher_ticket.sync_max_slashable_per_epoch(1_000, 2_000, 3);
+
+        assert_eq!(
+            vault_ncn_slasher_ticket
+                .current_max_slashable_per_epoch(299, epoch_length)
+                .unwrap(),
+            1_000
+        );
+        assert_eq!(
+            vault_ncn_slasher_ticket
+                .current_max_slashable_per_epoch(300, epoch_length)
+                .unwrap(),
+            2_000
+        );
+    }
 }
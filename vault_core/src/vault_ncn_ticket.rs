@@ -1,12 +1,18 @@
 //! The [`VaultNcnTicket`] account tracks a vault supporting a node consensus network. It can be
 //! enabled and disabled over time by the vault NCN admin.
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{
+    types::{PodU16, PodU64},
+    AccountDeserialize, Discriminator,
+};
 use jito_jsm_core::slot_toggle::SlotToggle;
+use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-const RESERVED_SPACE_LEN: usize = 263;
+use crate::{delegation_state::DelegationState, MAX_BPS};
+
+const RESERVED_SPACE_LEN: usize = 261;
 
 /// The [`VaultNcnTicket`] account tracks a vault supporting a node consensus network. It can be
 /// enabled and disabled over time by the vault NCN admin.
@@ -28,8 +34,12 @@ pub struct VaultNcnTicket {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The share of each operator's delegated security the NCN may count and slash, in basis
+    /// points. Zero means there is no cap.
+    max_allocation_bps: PodU16,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 261],
 }
 
 impl VaultNcnTicket {
@@ -40,6 +50,7 @@ impl VaultNcnTicket {
             index: PodU64::from(index),
             state: SlotToggle::new(slot),
             bump,
+            max_allocation_bps: PodU16::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -48,6 +59,54 @@ impl VaultNcnTicket {
         self.index.into()
     }
 
+    pub fn max_allocation_bps(&self) -> u16 {
+        self.max_allocation_bps.into()
+    }
+
+    /// Sets the share of each operator's delegated security allocated to the NCN
+    ///
+    /// # Arguments
+    /// * `max_allocation_bps` - The share in basis points, zero to remove the cap
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultNcnAllocationCapInvalid`] - If the share exceeds [`MAX_BPS`]
+    pub fn set_max_allocation_bps(&mut self, max_allocation_bps: u16) -> Result<(), VaultError> {
+        if max_allocation_bps > MAX_BPS {
+            msg!("Max allocation exceeds maximum allowed of {}", MAX_BPS);
+            return Err(VaultError::VaultNcnAllocationCapInvalid);
+        }
+        self.max_allocation_bps = PodU16::from(max_allocation_bps);
+        Ok(())
+    }
+
+    /// Applies the allocation cap to an amount of operator security
+    ///
+    /// # Arguments
+    /// * `amount` - The operator security before the cap is applied
+    ///
+    /// # Returns
+    /// * `u64` - The portion of `amount` allocated to the NCN
+    pub fn allocate(&self, amount: u64) -> Result<u64, VaultError> {
+        let max_allocation_bps = self.max_allocation_bps();
+        if max_allocation_bps == 0 {
+            return Ok(amount);
+        }
+
+        let allocated = (amount as u128)
+            .checked_mul(max_allocation_bps as u128)
+            .and_then(|x| x.checked_div(MAX_BPS as u128))
+            .ok_or(VaultError::VaultOverflow)?;
+        u64::try_from(allocated).map_err(|_| VaultError::VaultOverflow)
+    }
+
+    /// The staked amount of an operator delegation the NCN may count towards its stake weight
+    ///
+    /// # Arguments
+    /// * `delegation_state` - The delegation state of a [`crate::vault_operator_delegation::VaultOperatorDelegation`]
+    pub fn allocated_stake(&self, delegation_state: &DelegationState) -> Result<u64, VaultError> {
+        self.allocate(delegation_state.staked_amount())
+    }
+
     /// The seeds for the PDA
     ///
     /// # Arguments
@@ -140,6 +199,7 @@ mod tests {
             std::mem::size_of::<PodU64>() + // index
             std::mem::size_of::<SlotToggle>() + // state
             std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<PodU16>() + // max_allocation_bps
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(vault_ncn_ticket_size, sum_of_fields);
     }
@@ -154,4 +214,32 @@ mod tests {
             SlotToggleState::Inactive
         );
     }
+
+    #[test]
+    fn test_vault_ncn_ticket_allocation() {
+        let mut vault_ncn_ticket =
+            VaultNcnTicket::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+
+        let mut delegation_state = DelegationState::default();
+        delegation_state.delegate(1_000).unwrap();
+        delegation_state.cooldown(200).unwrap();
+
+        // no cap by default
+        assert_eq!(
+            vault_ncn_ticket.allocated_stake(&delegation_state).unwrap(),
+            800
+        );
+
+        vault_ncn_ticket.set_max_allocation_bps(2_500).unwrap();
+        assert_eq!(
+            vault_ncn_ticket.allocated_stake(&delegation_state).unwrap(),
+            200
+        );
+
+        assert_eq!(
+            vault_ncn_ticket.set_max_allocation_bps(MAX_BPS + 1),
+            Err(VaultError::VaultNcnAllocationCapInvalid)
+        );
+        assert_eq!(vault_ncn_ticket.max_allocation_bps(), 2_500);
+    }
 }
@@ -12,7 +12,7 @@ use solana_program::{
 
 use crate::delegation_state::DelegationState;
 
-const RESERVED_SPACE_LEN: usize = 263;
+const RESERVED_SPACE_LEN: usize = 255;
 
 /// The [`VaultOperatorDelegation`] account tracks a vault's delegation to an operator
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
@@ -35,8 +35,11 @@ pub struct VaultOperatorDelegation {
     /// The bump seed for the PDA
     pub bump: u8,
 
+    /// The maximum amount of security that may be delegated to the operator. Zero means there is no cap.
+    max_delegation_amount: PodU64,
+
     /// Reserved space
-    reserved: [u8; 263],
+    reserved: [u8; 255],
 }
 
 impl VaultOperatorDelegation {
@@ -48,6 +51,7 @@ impl VaultOperatorDelegation {
             delegation_state: DelegationState::default(),
             index: PodU64::from(index),
             bump,
+            max_delegation_amount: PodU64::from(0),
             reserved: [0; RESERVED_SPACE_LEN],
         }
     }
@@ -60,6 +64,37 @@ impl VaultOperatorDelegation {
         self.index.into()
     }
 
+    pub fn max_delegation_amount(&self) -> u64 {
+        self.max_delegation_amount.into()
+    }
+
+    pub fn set_max_delegation_amount(&mut self, max_delegation_amount: u64) {
+        self.max_delegation_amount = PodU64::from(max_delegation_amount);
+    }
+
+    /// Checks the security delegated to the operator stays within the delegation cap
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultOperatorDelegationCapExceeded`] - If the delegation exceeds the cap
+    pub fn check_max_delegation_amount(&self) -> Result<(), VaultError> {
+        let max_delegation_amount = self.max_delegation_amount();
+        if max_delegation_amount == 0 {
+            return Ok(());
+        }
+
+        let total_security = self.delegation_state.total_security()?;
+        if total_security > max_delegation_amount {
+            msg!(
+                "Operator delegation of {} exceeds the cap of {}",
+                total_security,
+                max_delegation_amount
+            );
+            return Err(VaultError::VaultOperatorDelegationCapExceeded);
+        }
+
+        Ok(())
+    }
+
     pub fn check_is_already_updated(
         &self,
         slot: u64,
@@ -178,6 +213,36 @@ impl VaultOperatorDelegation {
         }
         Ok(())
     }
+
+    /// Loads the account without the operator account, using the operator stored in the account
+    /// to check the PDA. Used when every delegation of a vault is passed as remaining accounts.
+    pub fn load_with_stored_operator(
+        program_id: &Pubkey,
+        vault_operator_delegation: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_operator_delegation.owner.ne(program_id) {
+            msg!("Vault operator ticket account has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_operator_delegation.data_is_empty() {
+            msg!("Vault operator ticket account data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !vault_operator_delegation.is_writable {
+            msg!("Vault operator ticket account is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let data = vault_operator_delegation.data.borrow();
+        let operator = Self::try_from_slice_unchecked(&data)?.operator;
+        let expected_pubkey = Self::find_program_address(program_id, vault.key, &operator).0;
+        if vault_operator_delegation.key.ne(&expected_pubkey) {
+            msg!("Vault operator ticket account is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -193,10 +258,37 @@ mod tests {
             size_of::<PodU64>() + // last_update_slot
             size_of::<PodU64>() + // index
             size_of::<u8>() + // bump
+            size_of::<PodU64>() + // max_delegation_amount
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(vault_operator_delegation_size, sum_of_fields);
     }
 
+    #[test]
+    fn test_vault_operator_delegation_max_delegation_amount() {
+        let mut vault_operator_delegation =
+            VaultOperatorDelegation::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+
+        vault_operator_delegation
+            .delegation_state
+            .delegate(100)
+            .unwrap();
+        // no cap by default
+        vault_operator_delegation
+            .check_max_delegation_amount()
+            .unwrap();
+
+        vault_operator_delegation.set_max_delegation_amount(100);
+        vault_operator_delegation
+            .check_max_delegation_amount()
+            .unwrap();
+
+        vault_operator_delegation.set_max_delegation_amount(99);
+        assert_eq!(
+            vault_operator_delegation.check_max_delegation_amount(),
+            Err(VaultError::VaultOperatorDelegationCapExceeded)
+        );
+    }
+
     #[test]
     fn test_vault_operator_delegation_update_single_epoch_ok() {
         let mut vault_operator_delegation =
@@ -0,0 +1,197 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_vault_sdk::error::VaultError;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+const RESERVED_SPACE_LEN: usize = 247;
+
+/// The [`VaultReferral`] account
+///
+/// - attributes deposits into a vault to a referrer.
+/// - is created by `MintTo` on the first deposit referred by the referrer, and accumulates the
+///   referred deposit volume and the VRT the referrer earned from deposit fees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+#[repr(C)]
+pub struct VaultReferral {
+    /// The vault deposited into
+    pub vault: Pubkey,
+
+    /// The referrer
+    pub referrer: Pubkey,
+
+    /// The total amount of supported tokens deposited through this referrer
+    referred_deposit_amount: PodU64,
+
+    /// The number of deposits made through this referrer
+    referred_deposit_count: PodU64,
+
+    /// The total amount of VRT the referrer received as their share of deposit fees
+    referral_fees_earned: PodU64,
+
+    /// The bump seed used to create the PDA
+    pub bump: u8,
+
+    reserved: [u8; 247],
+}
+
+impl VaultReferral {
+    pub fn new(vault: Pubkey, referrer: Pubkey, bump: u8) -> Self {
+        Self {
+            vault,
+            referrer,
+            referred_deposit_amount: PodU64::from(0),
+            referred_deposit_count: PodU64::from(0),
+            referral_fees_earned: PodU64::from(0),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn referred_deposit_amount(&self) -> u64 {
+        self.referred_deposit_amount.into()
+    }
+
+    pub fn referred_deposit_count(&self) -> u64 {
+        self.referred_deposit_count.into()
+    }
+
+    pub fn referral_fees_earned(&self) -> u64 {
+        self.referral_fees_earned.into()
+    }
+
+    /// Records a deposit made through the referrer
+    ///
+    /// # Arguments
+    /// * `amount_in` - The amount of supported tokens deposited
+    /// * `referral_fee` - The amount of VRT minted to the referrer
+    pub fn record_deposit(&mut self, amount_in: u64, referral_fee: u64) -> Result<(), VaultError> {
+        self.referred_deposit_amount = PodU64::from(
+            self.referred_deposit_amount()
+                .checked_add(amount_in)
+                .ok_or(VaultError::ArithmeticOverflow)?,
+        );
+        self.referred_deposit_count = PodU64::from(
+            self.referred_deposit_count()
+                .checked_add(1)
+                .ok_or(VaultError::ArithmeticOverflow)?,
+        );
+        self.referral_fees_earned = PodU64::from(
+            self.referral_fees_earned()
+                .checked_add(referral_fee)
+                .ok_or(VaultError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
+
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    /// * `referrer` - The referrer
+    pub fn seeds(vault: &Pubkey, referrer: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_referral".to_vec(),
+            vault.to_bytes().to_vec(),
+            referrer.to_bytes().to_vec(),
+        ])
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    /// * `referrer` - The referrer
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        referrer: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, referrer);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultReferral`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_referral` - The [`VaultReferral`] account
+    /// * `vault` - The vault account
+    /// * `referrer` - The referrer account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_referral: &AccountInfo,
+        vault: &AccountInfo,
+        referrer: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if vault_referral.owner.ne(program_id) {
+            msg!("Vault referral has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if vault_referral.data_is_empty() {
+            msg!("Vault referral data is empty");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if expect_writable && !vault_referral.is_writable {
+            msg!("Vault referral is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if vault_referral.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            msg!("Vault referral discriminator is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let expected_pubkey = Self::find_program_address(program_id, vault.key, referrer.key).0;
+        if vault_referral.key.ne(&expected_pubkey) {
+            msg!("Vault referral is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_referral_no_padding() {
+        let vault_referral_size = std::mem::size_of::<VaultReferral>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // referrer
+            size_of::<PodU64>() + // referred_deposit_amount
+            size_of::<PodU64>() + // referred_deposit_count
+            size_of::<PodU64>() + // referral_fees_earned
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_referral_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_record_deposit() {
+        let mut vault_referral = VaultReferral::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+
+        vault_referral.record_deposit(100, 0).unwrap();
+        vault_referral.record_deposit(50, 2).unwrap();
+        assert_eq!(vault_referral.referred_deposit_amount(), 150);
+        assert_eq!(vault_referral.referred_deposit_count(), 2);
+        assert_eq!(vault_referral.referral_fees_earned(), 2);
+
+        assert_eq!(
+            vault_referral.record_deposit(u64::MAX, 0),
+            Err(VaultError::ArithmeticOverflow)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator, ACCOUNT_HEADER_LEN};
 use jito_vault_sdk::error::VaultError;
 use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
@@ -8,7 +8,11 @@ use crate::delegation_state::DelegationState;
 
 const RESERVED_SPACE_LEN: usize = 263;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
+/// The [`VaultUpdateStateTracker`] account tracks the operators updated during a vault update.
+///
+/// The account data is followed by a bitmap with one bit per operator delegation index, so
+/// operators can be cranked in any order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
 #[repr(C)]
 pub struct VaultUpdateStateTracker {
     /// The vault associated with this update ticket
@@ -17,8 +21,8 @@ pub struct VaultUpdateStateTracker {
     /// The NCN epoch for which the delegations are to be updated
     ncn_epoch: PodU64,
 
-    /// The last updated index of the vault
-    last_updated_index: PodU64,
+    /// The number of operators updated, equal to the number of bits set in the bitmap
+    operators_updated: PodU64,
 
     /// The total amount delegated across all the operators in the vault
     pub delegation_state: DelegationState,
@@ -28,12 +32,19 @@ pub struct VaultUpdateStateTracker {
     reserved: [u8; 263],
 }
 
+/// Layout version 0 stored the index of the last updated operator where `operators_updated` is
+/// now and had no bitmap. Such trackers can't be migrated, see
+/// [`VaultUpdateStateTracker::is_outdated_layout`].
+impl AccountDeserialize for VaultUpdateStateTracker {
+    const LAYOUT_VERSION: u8 = 1;
+}
+
 impl VaultUpdateStateTracker {
     pub fn new(vault: Pubkey, ncn_epoch: u64, withdrawal_allocation_method: u8) -> Self {
         Self {
             vault,
             ncn_epoch: PodU64::from(ncn_epoch),
-            last_updated_index: PodU64::from(u64::MAX),
+            operators_updated: PodU64::from(0),
             delegation_state: DelegationState::default(),
             withdrawal_allocation_method,
             reserved: [0; RESERVED_SPACE_LEN],
@@ -44,64 +55,110 @@ impl VaultUpdateStateTracker {
         self.ncn_epoch.into()
     }
 
-    pub fn last_updated_index(&self) -> u64 {
-        self.last_updated_index.into()
+    pub fn operators_updated(&self) -> u64 {
+        self.operators_updated.into()
+    }
+
+    /// The length of the operator bitmap for a given number of operators
+    pub fn bitmap_len(num_operators: u64) -> Result<usize, VaultError> {
+        usize::try_from(num_operators.div_ceil(8)).map_err(|_| VaultError::ArithmeticOverflow)
+    }
+
+    /// The size of the account, including the header and the operator bitmap
+    pub fn account_size(num_operators: u64) -> Result<usize, VaultError> {
+        ACCOUNT_HEADER_LEN
+            .checked_add(std::mem::size_of::<Self>())
+            .and_then(|size| size.checked_add(Self::bitmap_len(num_operators).ok()?))
+            .ok_or(VaultError::ArithmeticOverflow)
+    }
+
+    /// Whether the account data was written with a layout older than the operator bitmap.
+    /// Outdated trackers can't be finished, they may only be closed once their epoch has passed.
+    pub fn is_outdated_layout(data: &[u8]) -> Result<bool, ProgramError> {
+        Ok(Self::layout_version(data)? < Self::LAYOUT_VERSION)
+    }
+
+    /// Deserializes the account data into the struct and the operator bitmap that follows it
+    pub fn try_from_slice_with_bitmap(data: &[u8]) -> Result<(&Self, &[u8]), ProgramError> {
+        let split_index = ACCOUNT_HEADER_LEN
+            .checked_add(std::mem::size_of::<Self>())
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        if data.len() < split_index {
+            msg!("Vault update state tracker data is too short");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (data, bitmap) = data.split_at(split_index);
+        Ok((Self::try_from_slice_unchecked(data)?, bitmap))
+    }
+
+    /// Deserializes the account data into the mutable struct and the operator bitmap that follows it
+    pub fn try_from_slice_with_bitmap_mut(
+        data: &mut [u8],
+    ) -> Result<(&mut Self, &mut [u8]), ProgramError> {
+        let split_index = ACCOUNT_HEADER_LEN
+            .checked_add(std::mem::size_of::<Self>())
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        if data.len() < split_index {
+            msg!("Vault update state tracker data is too short");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (data, bitmap) = data.split_at_mut(split_index);
+        Ok((Self::try_from_slice_unchecked_mut(data)?, bitmap))
+    }
+
+    /// Returns the byte offset and bit mask of an operator index in the bitmap
+    fn bitmap_position(bitmap: &[u8], index: u64) -> Result<(usize, u8), VaultError> {
+        let byte = index
+            .checked_div(8)
+            .and_then(|byte| usize::try_from(byte).ok())
+            .ok_or(VaultError::ArithmeticOverflow)?;
+        if byte >= bitmap.len() {
+            msg!(
+                "VaultUpdateStateTracker bitmap does not cover index {}",
+                index
+            );
+            return Err(VaultError::VaultUpdateIncorrectIndex);
+        }
+        let bit = index.checked_rem(8).ok_or(VaultError::DivisionByZero)?;
+        Ok((byte, 1 << bit))
+    }
+
+    /// Returns whether the operator at `index` has been updated
+    pub fn is_operator_updated(bitmap: &[u8], index: u64) -> Result<bool, VaultError> {
+        let (byte, mask) = Self::bitmap_position(bitmap, index)?;
+        Ok(bitmap[byte] & mask != 0)
     }
 
-    /// Checks and updates the index of the vault update state tracker
-    /// Index starts at different values depending on the NCN epoch to prevent
-    /// any single operator from getting starved
+    /// Checks the operator at `index` has not been updated yet and marks it as updated.
+    /// Operators can be updated in any order.
     pub fn check_and_update_index(
         &mut self,
+        bitmap: &mut [u8],
         index: u64,
         num_operators: u64,
     ) -> Result<(), VaultError> {
-        if self.last_updated_index() == u64::MAX {
-            let start_index = self
-                .ncn_epoch()
-                .checked_rem(num_operators)
-                .ok_or(VaultError::DivisionByZero)?;
-            if index != start_index {
-                msg!("VaultUpdateStateTracker incorrect index");
-                return Err(VaultError::VaultUpdateIncorrectIndex);
-            }
-        } else {
-            let next_index = self
-                .last_updated_index()
-                .checked_add(1)
-                .and_then(|i| i.checked_rem(num_operators))
-                .ok_or(VaultError::ArithmeticOverflow)?;
-
-            if index != next_index {
-                msg!("VaultUpdateStateTracker incorrect index");
-                return Err(VaultError::VaultUpdateIncorrectIndex);
-            }
+        if index >= num_operators {
+            msg!("VaultUpdateStateTracker incorrect index");
+            return Err(VaultError::VaultUpdateIncorrectIndex);
         }
-        self.last_updated_index = PodU64::from(index);
-        Ok(())
-    }
 
-    pub fn all_operators_updated(&self, num_operators: u64) -> Result<bool, VaultError> {
-        if self.last_updated_index() == u64::MAX {
-            return Ok(false);
+        let (byte, mask) = Self::bitmap_position(bitmap, index)?;
+        if bitmap[byte] & mask != 0 {
+            msg!("VaultUpdateStateTracker index {} already updated", index);
+            return Err(VaultError::VaultUpdateIncorrectIndex);
         }
+        bitmap[byte] |= mask;
 
-        let start_index = self
-            .ncn_epoch()
-            .checked_rem(num_operators)
-            .ok_or(VaultError::DivisionByZero)?;
-
-        if start_index == 0 {
-            return Ok(self.last_updated_index()
-                == num_operators
-                    .checked_sub(1)
-                    .ok_or(VaultError::ArithmeticUnderflow)?);
-        }
+        self.operators_updated = PodU64::from(
+            self.operators_updated()
+                .checked_add(1)
+                .ok_or(VaultError::ArithmeticOverflow)?,
+        );
+        Ok(())
+    }
 
-        Ok(self.last_updated_index()
-            == start_index
-                .checked_sub(1)
-                .ok_or(VaultError::ArithmeticUnderflow)?)
+    pub fn all_operators_updated(&self, num_operators: u64) -> bool {
+        self.operators_updated() == num_operators
     }
 
     /// Returns the seeds for the PDA
@@ -163,9 +220,9 @@ impl VaultUpdateStateTracker {
 
 #[cfg(test)]
 mod tests {
-    use jito_bytemuck::types::PodU64;
+    use jito_bytemuck::{types::PodU64, AccountDeserialize, Migrate, LAYOUT_VERSION_INDEX};
     use jito_vault_sdk::error::VaultError;
-    use solana_program::pubkey::Pubkey;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
     use crate::{
         delegation_state::DelegationState,
@@ -177,82 +234,90 @@ mod tests {
         let vault_update_state_tracker_size = std::mem::size_of::<VaultUpdateStateTracker>();
         let sum_of_fields = size_of::<Pubkey>() + // vault
             size_of::<PodU64>() + // ncn_epoch
-            size_of::<PodU64>() + // last_updated_index
+            size_of::<PodU64>() + // operators_updated
             size_of::<DelegationState>() + // delegation_state
             size_of::<u8>() + // withdrawal_allocation_method
             RESERVED_SPACE_LEN; // reserved
         assert_eq!(vault_update_state_tracker_size, sum_of_fields);
     }
 
+    #[test]
+    fn test_bitmap_len() {
+        assert_eq!(VaultUpdateStateTracker::bitmap_len(0).unwrap(), 0);
+        assert_eq!(VaultUpdateStateTracker::bitmap_len(1).unwrap(), 1);
+        assert_eq!(VaultUpdateStateTracker::bitmap_len(8).unwrap(), 1);
+        assert_eq!(VaultUpdateStateTracker::bitmap_len(9).unwrap(), 2);
+    }
+
     #[test]
     fn test_update_index_zero_ok() {
         let mut vault_update_state_tracker =
             VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0);
+        let mut bitmap = vec![0; VaultUpdateStateTracker::bitmap_len(1).unwrap()];
 
-        assert!(vault_update_state_tracker
-            .check_and_update_index(0, 1)
-            .is_ok());
+        vault_update_state_tracker
+            .check_and_update_index(&mut bitmap, 0, 1)
+            .unwrap();
+        assert!(VaultUpdateStateTracker::is_operator_updated(&bitmap, 0).unwrap());
+        assert_eq!(vault_update_state_tracker.operators_updated(), 1);
     }
 
     #[test]
-    fn test_update_index_skip_zero_fails() {
+    fn test_update_index_out_of_order_ok() {
+        let n = 10;
         let mut vault_update_state_tracker =
-            VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0);
-        assert_eq!(
-            vault_update_state_tracker.check_and_update_index(1, 2),
-            Err(VaultError::VaultUpdateIncorrectIndex)
-        );
+            VaultUpdateStateTracker::new(Pubkey::new_unique(), 6, 0);
+        let mut bitmap = vec![0; VaultUpdateStateTracker::bitmap_len(n).unwrap()];
+
+        for index in [9, 0, 4, 8, 1, 7, 3, 2, 6] {
+            vault_update_state_tracker
+                .check_and_update_index(&mut bitmap, index, n)
+                .unwrap();
+            assert!(!vault_update_state_tracker.all_operators_updated(n));
+        }
+        assert!(!VaultUpdateStateTracker::is_operator_updated(&bitmap, 5).unwrap());
+
+        vault_update_state_tracker
+            .check_and_update_index(&mut bitmap, 5, n)
+            .unwrap();
+        assert!(vault_update_state_tracker.all_operators_updated(n));
+        assert_eq!(bitmap, vec![0xff, 0x03]);
     }
 
     #[test]
-    fn test_update_index_skip_index_fails() {
+    fn test_update_index_twice_fails() {
+        let n = 4;
         let mut vault_update_state_tracker =
             VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0);
-        let n = 4;
-        vault_update_state_tracker
-            .check_and_update_index(0, n)
-            .unwrap();
+        let mut bitmap = vec![0; VaultUpdateStateTracker::bitmap_len(n).unwrap()];
+
         vault_update_state_tracker
-            .check_and_update_index(1, n)
+            .check_and_update_index(&mut bitmap, 2, n)
             .unwrap();
         assert_eq!(
-            vault_update_state_tracker.check_and_update_index(3, n),
+            vault_update_state_tracker.check_and_update_index(&mut bitmap, 2, n),
             Err(VaultError::VaultUpdateIncorrectIndex)
         );
+        assert_eq!(vault_update_state_tracker.operators_updated(), 1);
     }
 
     #[test]
-    fn test_update_index_wraparound() {
+    fn test_update_index_out_of_range_fails() {
         let n = 4;
-        // Epoch 6, offset is 6 % 4 = 2
         let mut vault_update_state_tracker =
-            VaultUpdateStateTracker::new(Pubkey::new_unique(), 6, 0);
+            VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0);
+        let mut bitmap = vec![0; VaultUpdateStateTracker::bitmap_len(n).unwrap()];
 
         assert_eq!(
-            vault_update_state_tracker.check_and_update_index(0, n),
+            vault_update_state_tracker.check_and_update_index(&mut bitmap, n, n),
             Err(VaultError::VaultUpdateIncorrectIndex)
         );
 
-        vault_update_state_tracker
-            .check_and_update_index(2, n)
-            .unwrap();
-        assert_eq!(vault_update_state_tracker.last_updated_index(), 2);
-
-        vault_update_state_tracker
-            .check_and_update_index(3, n)
-            .unwrap();
-
-        vault_update_state_tracker
-            .check_and_update_index(0, n)
-            .unwrap();
-        assert_eq!(vault_update_state_tracker.last_updated_index(), 0);
-
-        vault_update_state_tracker
-            .check_and_update_index(1, n)
-            .unwrap();
-        assert_eq!(vault_update_state_tracker.last_updated_index(), 1);
-
-        assert!(vault_update_state_tracker.all_operators_updated(n).unwrap());
+        // The bitmap must cover the index
+        assert_eq!(
+            vault_update_state_tracker.check_and_update_index(&mut [], 0, n),
+            Err(VaultError::VaultUpdateIncorrectIndex)
+        );
     }
 
     #[test]
@@ -261,42 +326,52 @@ mod tests {
 
         // Cranking not started
         let mut tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0);
-        assert_eq!(tracker.all_operators_updated(n).unwrap(), false);
+        assert!(!tracker.all_operators_updated(n));
 
         // Middle of cranking
-        tracker.last_updated_index = PodU64::from(1);
-        assert_eq!(tracker.all_operators_updated(n).unwrap(), false);
-
-        // All operators updated, start_index = 0
-        tracker.last_updated_index = PodU64::from(3);
-        assert_eq!(tracker.all_operators_updated(n).unwrap(), true);
-
-        // start_index = operators - 1
-        let mut tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), n - 1, 0);
-        tracker.last_updated_index = PodU64::from(n - 2);
-        assert_eq!(tracker.all_operators_updated(n).unwrap(), true);
-
-        // start_index = operators, last_updated = start_index - 1
-        let mut tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), n, 0);
-        tracker.last_updated_index = PodU64::from(0);
-        assert_eq!(tracker.all_operators_updated(n).unwrap(), false);
-
-        // All operators updated, start_index != 0
-        let mut tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), 1, 0);
-        tracker.last_updated_index = PodU64::from(0);
-        assert_eq!(tracker.all_operators_updated(n).unwrap(), true);
-
-        // Single operator
-        let mut tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), 2, 0);
-        tracker.last_updated_index = PodU64::from(0);
-        assert_eq!(tracker.all_operators_updated(1).unwrap(), true);
-
-        // Error - division by zero
-        let mut tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0);
-        tracker.last_updated_index = PodU64::from(0);
+        tracker.operators_updated = PodU64::from(2);
+        assert!(!tracker.all_operators_updated(n));
+
+        // All operators updated
+        tracker.operators_updated = PodU64::from(n);
+        assert!(tracker.all_operators_updated(n));
+
+        // No operators
+        let tracker = VaultUpdateStateTracker::new(Pubkey::new_unique(), 0, 0);
+        assert!(tracker.all_operators_updated(0));
+    }
+
+    #[test]
+    fn test_try_from_slice_with_bitmap() {
+        let n = 12;
+        let mut data = vec![0; VaultUpdateStateTracker::account_size(n).unwrap()];
+        VaultUpdateStateTracker::write_header(&mut data);
+
+        let (tracker, bitmap) =
+            VaultUpdateStateTracker::try_from_slice_with_bitmap_mut(&mut data).unwrap();
+        assert_eq!(bitmap.len(), 2);
+        tracker.check_and_update_index(bitmap, 11, n).unwrap();
+
+        let (tracker, bitmap) = VaultUpdateStateTracker::try_from_slice_with_bitmap(&data).unwrap();
+        assert_eq!(tracker.operators_updated(), 1);
+        assert!(VaultUpdateStateTracker::is_operator_updated(bitmap, 11).unwrap());
+    }
+
+    #[test]
+    fn test_outdated_layout_rejected() {
+        let mut data = vec![0; VaultUpdateStateTracker::account_size(8).unwrap()];
+        VaultUpdateStateTracker::write_header(&mut data);
+        assert!(!VaultUpdateStateTracker::is_outdated_layout(&data).unwrap());
+
+        data[LAYOUT_VERSION_INDEX] = 0;
+        assert!(VaultUpdateStateTracker::is_outdated_layout(&data).unwrap());
+        assert_eq!(
+            VaultUpdateStateTracker::try_from_slice_with_bitmap(&data).map(|_| ()),
+            Err(ProgramError::InvalidAccountData)
+        );
         assert_eq!(
-            tracker.all_operators_updated(0),
-            Err(VaultError::DivisionByZero)
+            VaultUpdateStateTracker::migrate_layout(&mut data, 0),
+            Err(ProgramError::InvalidAccountData)
         );
     }
 }
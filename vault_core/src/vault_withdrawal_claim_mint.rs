@@ -0,0 +1,137 @@
+use jito_jsm_core::get_epoch;
+use jito_vault_sdk::error::VaultError;
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// PDA of the SPL mint whose tokens represent the VRT enqueued for withdrawal from a vault during
+/// a single epoch.
+///
+/// The vault is the mint authority. The enqueued VRT is held in the associated token account owned
+/// by the mint itself until the claim tokens are burned.
+pub struct VaultWithdrawalClaimMint {}
+
+impl VaultWithdrawalClaimMint {
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    /// * `epoch` - The epoch the withdrawals were enqueued in
+    pub fn seeds(vault: &Pubkey, epoch: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"vault_withdrawal_claim_mint".to_vec(),
+            vault.to_bytes().to_vec(),
+            epoch.to_le_bytes().to_vec(),
+        ]
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    /// * `epoch` - The epoch the withdrawals were enqueued in
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, epoch);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Claims follow the same rule as withdrawal tickets: they can be burned once a full epoch has
+    /// passed since the epoch they were minted in
+    pub fn is_withdrawable(epoch: u64, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
+        let current_epoch = get_epoch(slot, epoch_length)?;
+        let withdrawable_epoch = epoch.checked_add(1).ok_or(VaultError::ArithmeticOverflow)?;
+        Ok(current_epoch > withdrawable_epoch)
+    }
+
+    pub fn load(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+        account: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), ProgramError> {
+        if account.owner.ne(&spl_token::id()) {
+            msg!("Vault withdrawal claim mint has an invalid owner");
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if expect_writable && !account.is_writable {
+            msg!("Vault withdrawal claim mint is not writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if account
+            .key
+            .ne(&Self::find_program_address(program_id, vault, epoch).0)
+        {
+            msg!("Vault withdrawal claim mint is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::rent::Rent;
+
+    use super::*;
+
+    #[test]
+    fn test_claim_mints_differ_per_epoch() {
+        let program_id = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+
+        let (epoch_1, _, _) =
+            VaultWithdrawalClaimMint::find_program_address(&program_id, &vault, 1);
+        let (epoch_2, _, _) =
+            VaultWithdrawalClaimMint::find_program_address(&program_id, &vault, 2);
+
+        assert_ne!(epoch_1, epoch_2);
+    }
+
+    #[test]
+    fn test_is_withdrawable() {
+        // minted in epoch 1
+        assert!(!VaultWithdrawalClaimMint::is_withdrawable(1, 150, 100).unwrap());
+        assert!(!VaultWithdrawalClaimMint::is_withdrawable(1, 299, 100).unwrap());
+        assert!(VaultWithdrawalClaimMint::is_withdrawable(1, 300, 100).unwrap());
+    }
+
+    #[test]
+    fn test_load_invalid_pda() {
+        let program_id = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let (pda, _, _) = VaultWithdrawalClaimMint::find_program_address(&program_id, &vault, 1);
+
+        let mut lamports = 1000000;
+        let mut data = vec![];
+        let owner = spl_token::id();
+        let account = AccountInfo::new(
+            &pda,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            Rent::default().minimum_balance(0),
+        );
+
+        VaultWithdrawalClaimMint::load(&program_id, &vault, 1, &account, true).unwrap();
+        assert_eq!(
+            VaultWithdrawalClaimMint::load(&program_id, &vault, 2, &account, true).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+}
@@ -19,6 +19,8 @@ use solana_program::{
 /// - The amount delegated to the operator must be accurately reported in the VaultOperatorDelegation account.
 /// - The vault's delegation state must be updated accordingly to ensure it's accurately tracking state across the entire operator delegation set.
 /// - The amount delegated must be greater than zero.
+/// - The operator's total delegation shall not exceed the vault's max operator share of the deposited tokens,
+///   nor the cap set on the VaultOperatorDelegation account.
 pub fn process_add_delegation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -58,6 +60,7 @@ pub fn process_add_delegation(
     vault.check_update_state_ok(clock.slot, config.epoch_length())?;
 
     vault.check_is_paused()?;
+    vault.check_is_winding_down()?;
 
     vault.delegate(amount)?;
 
@@ -65,5 +68,12 @@ pub fn process_add_delegation(
         .delegation_state
         .delegate(amount)?;
 
+    vault.check_max_operator_share(
+        vault_operator_delegation
+            .delegation_state
+            .total_security()?,
+    )?;
+    vault_operator_delegation.check_max_delegation_amount()?;
+
     Ok(())
 }
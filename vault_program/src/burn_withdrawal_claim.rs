@@ -0,0 +1,234 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{
+    load_associated_token_account, load_signer, load_token_mint, load_token_program,
+};
+use jito_vault_core::{
+    config::Config,
+    vault::{BurnSummary, Vault},
+    vault_withdrawal_claim_mint::VaultWithdrawalClaimMint,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::instruction::{burn, transfer};
+
+use crate::update_vault_full::update_vault_without_operators;
+
+/// Processes the burn withdrawal claim instruction: [`crate::VaultInstruction::BurnWithdrawalClaim`]
+///
+/// The tokenized counterpart of [`crate::VaultInstruction::BurnWithdrawalTicket`]. Any holder of
+/// claim tokens may redeem them for the vault's supported token.
+///
+/// Specification:
+/// - If the vault has a mint burn admin, it shall be present and be a signer of the transaction,
+///   unless the vault is winding down
+/// - A vault without operators is updated in place, otherwise the vault must be up-to-date
+/// - The amount to burn must be greater than zero
+/// - The claim mint shall be at the canonical PDA for the given epoch, and a full epoch shall have
+///   passed since that epoch
+/// - The claimant's claim tokens shall be burned
+/// - The vault and program fees shall be paid out of the escrowed VRT, and the rest burned
+/// - The claimant shall receive the pro-rata amount of the vault's supported token
+pub fn process_burn_withdrawal_claim(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    epoch: u64,
+    amount: u64,
+) -> ProgramResult {
+    let [config, vault_info, vault_token_account, vrt_mint, vault_withdrawal_claim_mint, vault_withdrawal_claim_mint_vrt_token_account, claimant, claimant_claim_token_account, claimant_token_account, vault_fee_token_account, program_fee_token_account, token_program, optional_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
+    load_token_mint(vrt_mint)?;
+
+    VaultWithdrawalClaimMint::load(
+        program_id,
+        vault_info.key,
+        epoch,
+        vault_withdrawal_claim_mint,
+        true,
+    )?;
+    load_token_mint(vault_withdrawal_claim_mint)?;
+    load_associated_token_account(
+        vault_withdrawal_claim_mint_vrt_token_account,
+        vault_withdrawal_claim_mint.key,
+        &vault.vrt_mint,
+    )?;
+
+    // claimant
+    load_signer(claimant, false)?;
+    load_associated_token_account(
+        claimant_claim_token_account,
+        claimant.key,
+        vault_withdrawal_claim_mint.key,
+    )?;
+    load_associated_token_account(claimant_token_account, claimant.key, &vault.supported_mint)?;
+
+    load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, &vault.vrt_mint)?;
+    load_associated_token_account(
+        program_fee_token_account,
+        &config.program_fee_wallet,
+        &vault.vrt_mint,
+    )?;
+    // Only the original spl token program is allowed
+    load_token_program(token_program)?;
+
+    // Stakers shall be able to exit a vault that is winding down without the mint burn admin
+    if !vault.is_winding_down() {
+        vault.check_mint_burn_admin(optional_accounts.first())?;
+    }
+    vault.check_vrt_mint(vrt_mint.key)?;
+    let slot = Clock::get()?.slot;
+    update_vault_without_operators(config, vault, slot)?;
+    vault.check_update_state_ok(slot, config.epoch_length())?;
+    vault.check_is_paused()?;
+
+    if amount == 0 {
+        msg!("Claim amount must be greater than zero");
+        return Err(VaultError::VaultBurnZero.into());
+    }
+
+    if !VaultWithdrawalClaimMint::is_withdrawable(epoch, slot, config.epoch_length())? {
+        msg!("Vault withdrawal claim is not withdrawable");
+        return Err(VaultError::VaultWithdrawalClaimNotWithdrawable.into());
+    }
+
+    let is_claimant_program_fee_wallet = config.program_fee_wallet.eq(claimant.key);
+    let is_claimant_vault_fee_wallet = vault.fee_wallet.eq(claimant.key);
+
+    let BurnSummary {
+        vault_fee_amount,
+        program_fee_amount,
+        burn_amount,
+        out_amount,
+    } = vault.burn_with_fee(
+        is_claimant_program_fee_wallet,
+        is_claimant_vault_fee_wallet,
+        amount,
+    )?;
+
+    vault.decrement_vrt_ready_to_claim_amount(amount)?;
+
+    // burn the claimant's claim tokens
+    invoke(
+        &burn(
+            &spl_token::id(),
+            claimant_claim_token_account.key,
+            vault_withdrawal_claim_mint.key,
+            claimant.key,
+            &[],
+            amount,
+        )?,
+        &[
+            claimant_claim_token_account.clone(),
+            vault_withdrawal_claim_mint.clone(),
+            claimant.clone(),
+        ],
+    )?;
+
+    let (_, vault_withdrawal_claim_mint_bump, mut vault_withdrawal_claim_mint_seeds) =
+        VaultWithdrawalClaimMint::find_program_address(program_id, vault_info.key, epoch);
+    vault_withdrawal_claim_mint_seeds.push(vec![vault_withdrawal_claim_mint_bump]);
+    let seed_slices: Vec<&[u8]> = vault_withdrawal_claim_mint_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+
+    // transfer fee to fee wallet
+    invoke_signed(
+        &transfer(
+            &spl_token::id(),
+            vault_withdrawal_claim_mint_vrt_token_account.key,
+            vault_fee_token_account.key,
+            vault_withdrawal_claim_mint.key,
+            &[],
+            vault_fee_amount,
+        )?,
+        &[
+            vault_withdrawal_claim_mint_vrt_token_account.clone(),
+            vault_fee_token_account.clone(),
+            vault_withdrawal_claim_mint.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+    // Transfer program fee to program fee wallet
+    invoke_signed(
+        &transfer(
+            &spl_token::id(),
+            vault_withdrawal_claim_mint_vrt_token_account.key,
+            program_fee_token_account.key,
+            vault_withdrawal_claim_mint.key,
+            &[],
+            program_fee_amount,
+        )?,
+        &[
+            vault_withdrawal_claim_mint_vrt_token_account.clone(),
+            program_fee_token_account.clone(),
+            vault_withdrawal_claim_mint.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    // burn the VRT tokens
+    invoke_signed(
+        &burn(
+            &spl_token::id(),
+            vault_withdrawal_claim_mint_vrt_token_account.key,
+            vrt_mint.key,
+            vault_withdrawal_claim_mint.key,
+            &[],
+            burn_amount,
+        )?,
+        &[
+            vault_withdrawal_claim_mint_vrt_token_account.clone(),
+            vrt_mint.clone(),
+            vault_withdrawal_claim_mint.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    // transfer the assets to the claimant
+    let vault_signer_seeds = vault.signing_seeds();
+    let seed_slices: Vec<&[u8]> = vault_signer_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+
+    drop(vault_data); // avoid double borrow
+
+    invoke_signed(
+        &transfer(
+            &spl_token::id(),
+            vault_token_account.key,
+            claimant_token_account.key,
+            vault_info.key,
+            &[],
+            out_amount,
+        )?,
+        &[
+            vault_token_account.clone(),
+            claimant_token_account.clone(),
+            vault_info.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    Ok(())
+}
@@ -19,10 +19,17 @@ use solana_program::{
 use spl_token::instruction::{burn, close_account, transfer};
 use spl_token_2022::state::Account;
 
+use crate::update_vault_full::update_vault_without_operators;
+
 /// Burns the withdrawal ticket, transferring the assets to the staker and closing the withdrawal ticket.
 ///
 /// One should call the [`crate::VaultInstruction::CrankVaultUpdateStateTracker`] instruction before running this instruction
 /// to ensure that any rewards that were accrued are accounted for.
+///
+/// The staker does not need to sign, so once a vault is winding down and the mint burn admin is no
+/// longer required, matured tickets can be redeemed by anyone on the staker's behalf.
+///
+/// A vault without operators is updated in place, so no cranking is needed before the burn.
 pub fn process_burn_withdrawal_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -76,8 +83,12 @@ pub fn process_burn_withdrawal_ticket(
 
     load_system_program(system_program)?;
 
-    vault.check_mint_burn_admin(optional_accounts.first())?;
+    // Stakers shall be able to exit a vault that is winding down without the mint burn admin
+    if !vault.is_winding_down() {
+        vault.check_mint_burn_admin(optional_accounts.first())?;
+    }
     vault.check_vrt_mint(vrt_mint.key)?;
+    update_vault_without_operators(config, vault, Clock::get()?.slot)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
 
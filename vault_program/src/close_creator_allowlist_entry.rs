@@ -0,0 +1,42 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    close_program_account, creation_policy::CreatorAllowlistEntryAccount, loader::load_signer,
+};
+use jito_vault_core::{config::Config, creator_allowlist_entry::CreatorAllowlistEntry};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the close creator allowlist entry instruction:
+/// [`crate::VaultInstruction::CloseCreatorAllowlistEntry`]
+///
+/// Specification:
+/// - Only the config admin can remove a creator from the allowlist. The config admin must sign
+///   the transaction and receives the account's rent.
+/// - Vaults already created by the creator are not affected
+pub fn process_close_creator_allowlist_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, creator_allowlist_entry, creator, config_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    CreatorAllowlistEntry::load(program_id, creator_allowlist_entry, creator.key, true)?;
+    load_signer(config_admin, true)?;
+
+    if config_admin.key != &config.admin {
+        msg!("Config admin does not match");
+        return Err(VaultError::ConfigAdminInvalid.into());
+    }
+
+    msg!(
+        "Closing creator allowlist entry at address {}",
+        creator_allowlist_entry.key
+    );
+    close_program_account(program_id, creator_allowlist_entry, config_admin)
+}
@@ -1,7 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{close_program_account, get_epoch, loader::load_signer};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
+    config::Config, delegation_state::DelegationState, vault::Vault,
+    vault_update_state_tracker::VaultUpdateStateTracker,
 };
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
@@ -10,7 +11,8 @@ use solana_program::{
 };
 
 /// Close the VaultUpdateStateTracker
-/// Can close previous epochs to get rent back, but it shall not update the current epoch
+/// Can close previous epochs to get rent back, but it shall not update the current epoch.
+/// Trackers with an outdated layout can only be closed once their epoch has passed.
 pub fn process_close_vault_update_state_tracker(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -35,16 +37,28 @@ pub fn process_close_vault_update_state_tracker(
         ncn_epoch,
         true,
     )?;
-    let vault_update_state_tracker_data = vault_update_state_tracker_info.data.borrow();
-    let vault_update_state_tracker =
-        VaultUpdateStateTracker::try_from_slice_unchecked(&vault_update_state_tracker_data)?;
     load_signer(payer, true)?;
 
     vault.check_is_paused()?;
 
     let epoch_length = config.epoch_length();
     let current_ncn_epoch = get_epoch(slot, epoch_length)?;
-    let last_updated_epoch = get_epoch(vault.last_full_state_update_slot(), epoch_length)?;
+
+    // Trackers written before the operator bitmap can't be finished, only closed for the rent
+    if VaultUpdateStateTracker::is_outdated_layout(&vault_update_state_tracker_info.data.borrow())?
+    {
+        if ncn_epoch == current_ncn_epoch {
+            msg!("VaultUpdateStateTracker has an outdated layout and can't finish the update");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        msg!("Closing outdated VaultUpdateStateTracker");
+        close_program_account(program_id, vault_update_state_tracker_info, payer)?;
+        return Ok(());
+    }
+
+    let vault_update_state_tracker_data = vault_update_state_tracker_info.data.borrow();
+    let vault_update_state_tracker =
+        VaultUpdateStateTracker::try_from_slice_with_bitmap(&vault_update_state_tracker_data)?.0;
 
     // The VaultUpdateStateTracker shall be up-to-date before closing
     if ncn_epoch != current_ncn_epoch {
@@ -55,33 +69,19 @@ pub fn process_close_vault_update_state_tracker(
         );
     } else {
         // The VaultUpdateStateTracker shall have updated every operator ticket before closing
-        if vault.operator_count() > 0
-            && !vault_update_state_tracker.all_operators_updated(vault.operator_count())?
-        {
+        if !vault_update_state_tracker.all_operators_updated(vault.operator_count()) {
             msg!("VaultUpdateStateTracker is not fully updated");
             return Err(VaultError::VaultUpdateStateNotFinishedUpdating.into());
         }
 
-        if vault.additional_assets_need_unstaking() > 0 {
-            msg!("This should not happen: additional assets need unstaking cannot be non-zero at the end of an update");
-            return Err(VaultError::NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate.into());
-        }
+        finish_vault_update(
+            vault,
+            vault_update_state_tracker.delegation_state,
+            slot,
+            epoch_length,
+        )?;
 
         msg!("Finished updating VaultUpdateStateTracker");
-
-        vault.delegation_state = vault_update_state_tracker.delegation_state;
-        vault.set_last_full_state_update_slot(slot);
-
-        // shift the VRT amounts down by one, accumulating in vrt_ready_to_claim_amount
-        // at max, two epochs are needed to run through the cycle
-        let epoch_diff = current_ncn_epoch
-            .checked_sub(last_updated_epoch)
-            .ok_or(VaultError::VaultUnderflow)?;
-        for _ in 0..epoch_diff.min(2) {
-            vault.increment_vrt_ready_to_claim_amount(vault.vrt_cooling_down_amount())?;
-            vault.set_vrt_cooling_down_amount(vault.vrt_enqueued_for_cooldown_amount());
-            vault.set_vrt_enqueued_for_cooldown_amount(0);
-        }
     }
 
     msg!("Closing VaultUpdateStateTracker");
@@ -90,3 +90,42 @@ pub fn process_close_vault_update_state_tracker(
 
     Ok(())
 }
+
+/// Finishes a vault update cycle, shared by the tracker based update and
+/// [`crate::VaultInstruction::UpdateVaultFull`]
+///
+/// # Arguments
+/// * `vault` - The vault being updated
+/// * `delegation_state` - The delegation state accumulated across every operator
+/// * `slot` - The current slot
+/// * `epoch_length` - The epoch length
+pub fn finish_vault_update(
+    vault: &mut Vault,
+    delegation_state: DelegationState,
+    slot: u64,
+    epoch_length: u64,
+) -> ProgramResult {
+    let current_ncn_epoch = get_epoch(slot, epoch_length)?;
+    let last_updated_epoch = get_epoch(vault.last_full_state_update_slot(), epoch_length)?;
+
+    if vault.additional_assets_need_unstaking() > 0 {
+        msg!("This should not happen: additional assets need unstaking cannot be non-zero at the end of an update");
+        return Err(VaultError::NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate.into());
+    }
+
+    vault.delegation_state = delegation_state;
+    vault.set_last_full_state_update_slot(slot);
+
+    // shift the VRT amounts down by one, accumulating in vrt_ready_to_claim_amount
+    // at max, two epochs are needed to run through the cycle
+    let epoch_diff = current_ncn_epoch
+        .checked_sub(last_updated_epoch)
+        .ok_or(VaultError::VaultUnderflow)?;
+    for _ in 0..epoch_diff.min(2) {
+        vault.increment_vrt_ready_to_claim_amount(vault.vrt_cooling_down_amount())?;
+        vault.set_vrt_cooling_down_amount(vault.vrt_enqueued_for_cooldown_amount());
+        vault.set_vrt_enqueued_for_cooldown_amount(0);
+    }
+
+    Ok(())
+}
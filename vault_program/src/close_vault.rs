@@ -0,0 +1,131 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    close_program_account,
+    loader::{load_associated_token_account, load_signer, load_token_program},
+};
+use jito_vault_core::{
+    burn_vault::BurnVault, config::Config, vault::Vault,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token::{
+    instruction::{close_account, transfer},
+    state::Account,
+};
+
+/// Processes the close vault instruction: [`crate::VaultInstruction::CloseVault`]
+///
+/// Specification:
+/// - The vault admin must sign
+/// - The vault must be winding down and up-to-date
+/// - No assets may be delegated and the only VRT left shall be the VRT locked in the burn vault
+/// - Any supported tokens left in the vault token account are sent to the admin's token account
+/// - The vault token account and the vault are closed, returning the rent to the admin
+/// - Any [`VaultUpdateStateTracker`] accounts passed in as remaining accounts are closed as well
+pub fn process_close_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault_info, vault_token_account, burn_vault_vrt_token_account, admin, admin_token_account, token_program, vault_update_state_trackers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
+    let burn_vault = BurnVault::find_program_address(program_id, &vault.base).0;
+    load_associated_token_account(burn_vault_vrt_token_account, &burn_vault, &vault.vrt_mint)?;
+    load_signer(admin, true)?;
+    load_associated_token_account(admin_token_account, admin.key, &vault.supported_mint)?;
+    // Only the original spl token program is allowed
+    load_token_program(token_program)?;
+
+    vault.check_admin(admin.key)?;
+    vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
+
+    let locked_vrt_amount = Account::unpack(&burn_vault_vrt_token_account.data.borrow())?.amount;
+    vault.check_can_close(locked_vrt_amount)?;
+
+    let vault_signer_seeds = vault.signing_seeds();
+    let seed_slices: Vec<&[u8]> = vault_signer_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+    drop(vault_data);
+
+    // Whatever remains in the vault token account is no longer backing any VRT
+    let remaining_amount = Account::unpack(&vault_token_account.data.borrow())?.amount;
+    if remaining_amount > 0 {
+        msg!(
+            "Transferring {} remaining tokens to the admin",
+            remaining_amount
+        );
+        invoke_signed(
+            &transfer(
+                &spl_token::id(),
+                vault_token_account.key,
+                admin_token_account.key,
+                vault_info.key,
+                &[],
+                remaining_amount,
+            )?,
+            &[
+                vault_token_account.clone(),
+                admin_token_account.clone(),
+                vault_info.clone(),
+            ],
+            &[&seed_slices],
+        )?;
+    }
+
+    invoke_signed(
+        &close_account(
+            &spl_token::id(),
+            vault_token_account.key,
+            admin.key,
+            vault_info.key,
+            &[],
+        )?,
+        &[
+            vault_token_account.clone(),
+            admin.clone(),
+            vault_info.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    // The trackers are closed after the token CPIs so the admin's lamports don't change before them
+    for vault_update_state_tracker in vault_update_state_trackers {
+        let ncn_epoch = {
+            if vault_update_state_tracker.owner.ne(program_id) {
+                msg!("Vault update state tracker has an invalid owner");
+                return Err(ProgramError::InvalidAccountOwner);
+            }
+            let vault_update_state_tracker_data = vault_update_state_tracker.data.borrow();
+            VaultUpdateStateTracker::try_from_slice_with_bitmap(&vault_update_state_tracker_data)?
+                .0
+                .ncn_epoch()
+        };
+        VaultUpdateStateTracker::load(
+            program_id,
+            vault_update_state_tracker,
+            vault_info,
+            ncn_epoch,
+            true,
+        )?;
+        msg!("Closing VaultUpdateStateTracker for epoch {}", ncn_epoch);
+        close_program_account(program_id, vault_update_state_tracker, admin)?;
+    }
+
+    msg!("Closing vault {}", vault_info.key);
+    close_program_account(program_id, vault_info, admin)?;
+
+    Ok(())
+}
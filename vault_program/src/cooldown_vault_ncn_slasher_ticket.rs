@@ -41,14 +41,24 @@ pub fn process_cooldown_vault_ncn_slasher_ticket(
         VaultNcnSlasherTicket::try_from_slice_unchecked_mut(&mut vault_ncn_slasher_ticket_data)?;
     load_signer(vault_slasher_admin, false)?;
 
-    vault.check_slasher_admin(vault_slasher_admin.key)?;
+    // Once the vault is winding down, anyone may cooldown its slasher tickets
+    if !vault.is_winding_down() {
+        vault.check_slasher_admin(vault_slasher_admin.key)?;
+    }
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
 
     // The vault slasher ticket must be active in order to cooldown the slasher
+    // The ticket transitions over the NCN's cooldown period
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
     if !vault_ncn_slasher_ticket
         .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
+        .deactivate_with_cooldown_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.cooldown_epochs(),
+        )?
     {
         msg!("Slasher is not ready to be deactivated");
         return Err(VaultError::VaultNcnSlasherTicketFailedCooldown.into());
@@ -13,6 +13,7 @@ use solana_program::{
 /// # Behavior:
 /// * The vault admin shall have the ability to remove support for a previously supported vault
 ///   at any time, independent of whether the NCN still supports the vault or not.
+/// * If the vault is winding down, any signer may remove support for the NCN.
 ///
 /// Instruction: [`crate::VaultInstruction::CooldownVaultNcnTicket`]
 pub fn process_cooldown_vault_ncn_ticket(
@@ -36,15 +37,22 @@ pub fn process_cooldown_vault_ncn_ticket(
         VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
     load_signer(vault_ncn_admin, false)?;
 
-    vault.check_ncn_admin(vault_ncn_admin.key)?;
+    // Once the vault is winding down, anyone may cooldown its NCN tickets
+    if !vault.is_winding_down() {
+        vault.check_ncn_admin(vault_ncn_admin.key)?;
+    }
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
 
     // The VaultNcnTicket must be active in order to cooldown the NCN
-    if !vault_ncn_ticket
-        .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
-    {
+    // The ticket transitions over the NCN's cooldown period
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if !vault_ncn_ticket.state.deactivate_with_cooldown_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.cooldown_epochs(),
+    )? {
         msg!("NCN is not ready to be deactivated");
         return Err(VaultError::VaultNcnTicketFailedCooldown.into());
     }
@@ -43,8 +43,47 @@ pub fn process_crank_vault_update_state_tracker(
     let vault_operator_delegation =
         VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
 
+    let ncn_epoch = get_epoch(slot, config.epoch_length())?;
+
+    VaultUpdateStateTracker::load(
+        program_id,
+        vault_update_state_tracker,
+        vault_info,
+        ncn_epoch,
+        true,
+    )?;
+    let mut vault_update_state_tracker_data = vault_update_state_tracker.data.borrow_mut();
+    let (vault_update_state_tracker, operator_bitmap) =
+        VaultUpdateStateTracker::try_from_slice_with_bitmap_mut(
+            &mut vault_update_state_tracker_data,
+        )?;
+
+    vault.check_is_paused()?;
+
+    update_vault_operator_delegation(
+        config,
+        vault,
+        vault_operator_delegation,
+        vault_update_state_tracker,
+        operator_bitmap,
+        slot,
+    )
+}
+
+/// Updates a single [`VaultOperatorDelegation`] as part of a vault update cycle, shared by the
+/// tracker based update and [`crate::VaultInstruction::UpdateVaultFull`]
+///
+/// Any `additional_assets_need_unstaking` are force cooled down from the operator according to the
+/// tracker's withdrawal allocation method before the delegation state is accumulated.
+pub fn update_vault_operator_delegation(
+    config: &Config,
+    vault: &mut Vault,
+    vault_operator_delegation: &mut VaultOperatorDelegation,
+    vault_update_state_tracker: &mut VaultUpdateStateTracker,
+    operator_bitmap: &mut [u8],
+    slot: u64,
+) -> ProgramResult {
     let epoch_length = config.epoch_length();
-    let ncn_epoch = get_epoch(slot, epoch_length)?;
 
     let last_full_state_update_slot = vault.last_full_state_update_slot();
     let last_full_state_update_epoch = get_epoch(last_full_state_update_slot, epoch_length)?;
@@ -60,23 +99,13 @@ pub fn process_crank_vault_update_state_tracker(
     // the assets from the operator.
     let has_been_partially_updated = last_full_state_update_epoch < operator_last_updated_epoch;
 
-    VaultUpdateStateTracker::load(
-        program_id,
-        vault_update_state_tracker,
-        vault_info,
-        ncn_epoch,
-        true,
-    )?;
-    let mut vault_update_state_tracker_data = vault_update_state_tracker.data.borrow_mut();
-    let vault_update_state_tracker = VaultUpdateStateTracker::try_from_slice_unchecked_mut(
-        &mut vault_update_state_tracker_data,
-    )?;
-
-    vault.check_is_paused()?;
-
     vault_operator_delegation.check_is_already_updated(slot, config.epoch_length())?;
-    vault_update_state_tracker
-        .check_and_update_index(vault_operator_delegation.index(), vault.operator_count())?;
+    // Operators may be cranked in any order, each exactly once
+    vault_update_state_tracker.check_and_update_index(
+        operator_bitmap,
+        vault_operator_delegation.index(),
+        vault.operator_count(),
+    )?;
 
     match WithdrawalAllocationMethod::try_from(
         vault_update_state_tracker.withdrawal_allocation_method,
@@ -0,0 +1,98 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::{load_associated_token_account, load_token_mint, load_token_program};
+use jito_vault_core::{config::Config, vault::Vault, vault_fee_split::VaultFeeSplit};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
+    program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+};
+use spl_token::{instruction::transfer, state::Account};
+
+/// Processes the distribute fee split instruction: [`crate::VaultInstruction::DistributeFeeSplit`]
+///
+/// Specification:
+/// - Anyone may distribute the fees
+/// - The VRT token account of every recipient shall be passed as a remaining account, in the fee
+///   split's order
+/// - Each recipient receives its share of the fee split's VRT balance, rounded down. The remainder
+///   stays in the fee split's token account for the next distribution
+pub fn process_distribute_fee_split(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, vrt_mint, vault_fee_split, vault_fee_split_token_account, token_program, recipient_token_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    load_token_mint(vrt_mint)?;
+    VaultFeeSplit::load(program_id, vault_fee_split, vault_info, false)?;
+    let vault_fee_split_data = vault_fee_split.data.borrow();
+    let fee_split = VaultFeeSplit::try_from_slice_unchecked(&vault_fee_split_data)?;
+    load_associated_token_account(
+        vault_fee_split_token_account,
+        vault_fee_split.key,
+        &vault.vrt_mint,
+    )?;
+    load_token_program(token_program)?;
+
+    vault.check_vrt_mint(vrt_mint.key)?;
+
+    if recipient_token_accounts.len() != fee_split.recipients().len() {
+        msg!(
+            "Expected {} recipient token accounts, received {}",
+            fee_split.recipients().len(),
+            recipient_token_accounts.len()
+        );
+        return Err(VaultError::VaultFeeSplitInvalidRecipients.into());
+    }
+    for (recipient_token_account, recipient) in
+        recipient_token_accounts.iter().zip(fee_split.recipients())
+    {
+        load_associated_token_account(
+            recipient_token_account,
+            &recipient.recipient,
+            &vault.vrt_mint,
+        )?;
+    }
+
+    let balance = Account::unpack(&vault_fee_split_token_account.data.borrow())?.amount;
+    let amounts = fee_split.calculate_distribution(balance)?;
+
+    let (_, vault_fee_split_bump, mut vault_fee_split_seeds) =
+        VaultFeeSplit::find_program_address(program_id, vault_info.key);
+    vault_fee_split_seeds.push(vec![vault_fee_split_bump]);
+    let seed_slices: Vec<&[u8]> = vault_fee_split_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+
+    for (recipient_token_account, amount) in recipient_token_accounts.iter().zip(amounts) {
+        if amount == 0 {
+            continue;
+        }
+        invoke_signed(
+            &transfer(
+                &spl_token::id(),
+                vault_fee_split_token_account.key,
+                recipient_token_account.key,
+                vault_fee_split.key,
+                &[],
+                amount,
+            )?,
+            &[
+                vault_fee_split_token_account.clone(),
+                recipient_token_account.clone(),
+                vault_fee_split.clone(),
+            ],
+            &[&seed_slices],
+        )?;
+    }
+
+    Ok(())
+}
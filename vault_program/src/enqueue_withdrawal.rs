@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{
@@ -9,22 +9,28 @@ use jito_jsm_core::{
     },
 };
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    config::Config, vault::Vault, vault_deposit_receipt::VaultDepositReceipt,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, program::invoke,
-    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    program_error::ProgramError, program_pack::Pack, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
-use spl_token::instruction::transfer;
+use spl_token::{instruction::transfer, state::Account};
 
 /// Enqueues a withdraw into the VaultStakerWithdrawalTicket account, transferring the amount from the
 /// staker's VRT token account to the VaultStakerWithdrawalTicket VRT token account.
 ///
 /// Specification:
-/// - If the vault has a mint burn admin, it shall be present and be a signer of the transaction
+/// - If the vault has a mint burn admin, it shall be present and be a signer of the transaction,
+///   unless the vault is winding down
 /// - The vault shall be up to date
 /// - The amount to withdraw must be greater than zero
+/// - If the vault has a holding period that hasn't been waived, the staker's VaultDepositReceipt
+///   shall be passed after the burn signer, even if it doesn't exist. The VRT it tracks shall stay
+///   in the staker's VRT token account until the holding period has elapsed since the staker's
+///   latest deposit. A missing optional account is passed as the program ID.
 /// - The VaultStakerWithdrawalTicket account shall be at the canonical PDA
 /// - The vault shall accurately track the amount of VRT that has been enqueued for cooldown
 /// - The staker's VRT tokens shall be transferred to the VaultStakerWithdrawalTicket associated token account
@@ -33,10 +39,8 @@ pub fn process_enqueue_withdrawal(
     accounts: &[AccountInfo],
     vrt_amount: u64,
 ) -> ProgramResult {
-    let (required_accounts, optional_accounts) = accounts.split_at(9);
-
-    let [config, vault_info, vault_staker_withdrawal_ticket, vault_staker_withdrawal_ticket_token_account, staker, staker_vrt_token_account, base, token_program, system_program] =
-        required_accounts
+    let [config, vault_info, vault_staker_withdrawal_ticket, vault_staker_withdrawal_ticket_token_account, staker, staker_vrt_token_account, base, token_program, system_program, optional_accounts @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -60,7 +64,14 @@ pub fn process_enqueue_withdrawal(
     load_signer(base, false)?;
     load_system_program(system_program)?;
 
-    vault.check_mint_burn_admin(optional_accounts.first())?;
+    // Stakers shall be able to exit a vault that is winding down without the mint burn admin
+    if !vault.is_winding_down() {
+        vault.check_mint_burn_admin(optional_accounts.first())?;
+    }
+    // A missing optional account is passed as the program ID
+    let vault_deposit_receipt = optional_accounts
+        .get(1)
+        .filter(|vault_deposit_receipt| vault_deposit_receipt.key.ne(program_id));
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
 
@@ -69,6 +80,17 @@ pub fn process_enqueue_withdrawal(
         return Err(VaultError::VaultEnqueueWithdrawalAmountZero.into());
     }
 
+    check_holding_period(
+        program_id,
+        config,
+        vault_info,
+        vault,
+        staker,
+        staker_vrt_token_account,
+        vault_deposit_receipt,
+        vrt_amount,
+    )?;
+
     // The VaultStakerWithdrawalTicket shall be at the canonical PDA
     let (
         vault_staker_withdrawal_ticket_pubkey,
@@ -101,7 +123,7 @@ pub fn process_enqueue_withdrawal(
         &vault_staker_withdrawal_ticket_seeds,
     )?;
     let mut vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow_mut();
-    vault_staker_withdrawal_ticket_data[0] = VaultStakerWithdrawalTicket::DISCRIMINATOR;
+    VaultStakerWithdrawalTicket::write_header(&mut vault_staker_withdrawal_ticket_data);
     let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
         &mut vault_staker_withdrawal_ticket_data,
     )?;
@@ -136,3 +158,66 @@ pub fn process_enqueue_withdrawal(
 
     Ok(())
 }
+
+/// Checks the staker keeps the VRT their VaultDepositReceipt tracks until the vault's holding
+/// period, if any, has elapsed since their latest deposit. The receipt shall be passed while the
+/// holding period applies so it can't be skipped, but a staker without one, such as a depositor
+/// from before the holding period or a holder of transferred VRT, holds no tracked VRT.
+#[allow(clippy::too_many_arguments)]
+pub fn check_holding_period(
+    program_id: &Pubkey,
+    config: &Config,
+    vault_info: &AccountInfo,
+    vault: &Vault,
+    staker: &AccountInfo,
+    staker_vrt_token_account: &AccountInfo,
+    vault_deposit_receipt: Option<&AccountInfo>,
+    vrt_amount: u64,
+) -> ProgramResult {
+    let min_holding_epochs = vault.effective_min_holding_epochs();
+    if min_holding_epochs == 0 {
+        return Ok(());
+    }
+
+    let Some(vault_deposit_receipt) = vault_deposit_receipt else {
+        msg!("The staker's deposit receipt is required while the vault has a holding period");
+        return Err(VaultError::VaultDepositReceiptMissing.into());
+    };
+    if vault_deposit_receipt.data_is_empty() {
+        let vault_deposit_receipt_pubkey =
+            VaultDepositReceipt::find_program_address(program_id, vault_info.key, staker.key).0;
+        if vault_deposit_receipt.key.ne(&vault_deposit_receipt_pubkey) {
+            msg!("Vault deposit receipt is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        return Ok(());
+    }
+
+    VaultDepositReceipt::load(
+        program_id,
+        vault_deposit_receipt,
+        vault_info,
+        staker.key,
+        false,
+    )?;
+    let vault_deposit_receipt_data = vault_deposit_receipt.data.borrow();
+    let vault_deposit_receipt =
+        VaultDepositReceipt::try_from_slice_unchecked(&vault_deposit_receipt_data)?;
+    let held_vrt_amount = vault_deposit_receipt.held_vrt_amount(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        min_holding_epochs,
+    )?;
+
+    let vrt_balance = Account::unpack(&staker_vrt_token_account.data.borrow())?.amount;
+    if vrt_balance.saturating_sub(vrt_amount) < held_vrt_amount {
+        msg!(
+            "{} VRT deposited within the last {} epochs shall be held until the holding period elapses",
+            held_vrt_amount,
+            min_holding_epochs
+        );
+        return Err(VaultError::VaultHoldingPeriodNotElapsed.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,239 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    create_account,
+    loader::{
+        load_associated_token_account, load_associated_token_account_program, load_signer,
+        load_system_program, load_token_mint, load_token_program,
+    },
+};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_withdrawal_claim_mint::VaultWithdrawalClaimMint,
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use spl_token::{
+    instruction::{initialize_mint2, mint_to, transfer},
+    state::Mint,
+};
+
+use crate::enqueue_withdrawal::check_holding_period;
+
+/// Processes the enqueue withdrawal claim instruction: [`crate::VaultInstruction::EnqueueWithdrawalClaim`]
+///
+/// The tokenized counterpart of [`crate::VaultInstruction::EnqueueWithdrawal`]. Instead of a
+/// staker-owned withdrawal ticket, the staker receives claim tokens which can be transferred freely
+/// and burned by any holder through [`crate::VaultInstruction::BurnWithdrawalClaim`].
+///
+/// Specification:
+/// - If the vault has a mint burn admin, it shall be present and be a signer of the transaction,
+///   unless the vault is winding down
+/// - The vault shall be up to date
+/// - The amount to withdraw must be greater than zero
+/// - The VRT tracked by the staker's VaultDepositReceipt shall stay in the staker's VRT token
+///   account until the vault's holding period, if any, has elapsed since the staker's latest
+///   deposit. The receipt shall be passed after the burn signer while the holding period applies.
+/// - The claim mint for the current epoch shall be at the canonical PDA, and is created on the
+///   first withdrawal of the epoch with the vault as mint authority and the VRT mint's decimals
+/// - The staker's VRT shall be transferred to the claim mint's VRT associated token account
+/// - The staker shall receive one claim token per VRT enqueued
+/// - The vault shall accurately track the amount of VRT that has been enqueued for cooldown
+pub fn process_enqueue_withdrawal_claim(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    vrt_amount: u64,
+) -> ProgramResult {
+    let [config, vault_info, vrt_mint, vault_withdrawal_claim_mint, vault_withdrawal_claim_mint_vrt_token_account, staker, staker_vrt_token_account, staker_claim_token_account, token_program, system_program, associated_token_program, optional_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_token_mint(vrt_mint)?;
+    load_signer(staker, true)?;
+    load_associated_token_account(staker_vrt_token_account, staker.key, &vault.vrt_mint)?;
+    load_token_program(token_program)?;
+    load_system_program(system_program)?;
+    load_associated_token_account_program(associated_token_program)?;
+
+    // Stakers shall be able to exit a vault that is winding down without the mint burn admin
+    if !vault.is_winding_down() {
+        vault.check_mint_burn_admin(optional_accounts.first())?;
+    }
+    // A missing optional account is passed as the program ID
+    let vault_deposit_receipt = optional_accounts
+        .get(1)
+        .filter(|vault_deposit_receipt| vault_deposit_receipt.key.ne(program_id));
+    vault.check_vrt_mint(vrt_mint.key)?;
+    let slot = Clock::get()?.slot;
+    vault.check_update_state_ok(slot, config.epoch_length())?;
+    vault.check_is_paused()?;
+
+    if vrt_amount == 0 {
+        msg!("VRT amount must be greater than zero");
+        return Err(VaultError::VaultEnqueueWithdrawalAmountZero.into());
+    }
+
+    check_holding_period(
+        program_id,
+        config,
+        vault_info,
+        vault,
+        staker,
+        staker_vrt_token_account,
+        vault_deposit_receipt,
+        vrt_amount,
+    )?;
+
+    // The claim mint shall be at the canonical PDA for the current epoch
+    let epoch = config.get_epoch_from_slot(slot)?;
+    let (
+        vault_withdrawal_claim_mint_pubkey,
+        vault_withdrawal_claim_mint_bump,
+        mut vault_withdrawal_claim_mint_seeds,
+    ) = VaultWithdrawalClaimMint::find_program_address(program_id, vault_info.key, epoch);
+    vault_withdrawal_claim_mint_seeds.push(vec![vault_withdrawal_claim_mint_bump]);
+    if vault_withdrawal_claim_mint
+        .key
+        .ne(&vault_withdrawal_claim_mint_pubkey)
+    {
+        msg!("Vault withdrawal claim mint is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if vault_withdrawal_claim_mint.data_is_empty() {
+        msg!(
+            "Initializing vault withdrawal claim mint at address {}",
+            vault_withdrawal_claim_mint.key
+        );
+        create_account(
+            staker,
+            vault_withdrawal_claim_mint,
+            system_program,
+            &spl_token::id(),
+            &Rent::get()?,
+            Mint::get_packed_len() as u64,
+            &vault_withdrawal_claim_mint_seeds,
+        )?;
+
+        let decimals = Mint::unpack(&vrt_mint.data.borrow())?.decimals;
+        invoke(
+            &initialize_mint2(
+                &spl_token::id(),
+                vault_withdrawal_claim_mint.key,
+                vault_info.key,
+                None,
+                decimals,
+            )?,
+            &[vault_withdrawal_claim_mint.clone()],
+        )?;
+    } else {
+        VaultWithdrawalClaimMint::load(
+            program_id,
+            vault_info.key,
+            epoch,
+            vault_withdrawal_claim_mint,
+            true,
+        )?;
+        load_token_mint(vault_withdrawal_claim_mint)?;
+    }
+
+    // Create the VRT escrow and the staker's claim token account if they don't exist yet
+    invoke(
+        &create_associated_token_account_idempotent(
+            staker.key,
+            vault_withdrawal_claim_mint.key,
+            vrt_mint.key,
+            &spl_token::id(),
+        ),
+        &[
+            staker.clone(),
+            vault_withdrawal_claim_mint_vrt_token_account.clone(),
+            vault_withdrawal_claim_mint.clone(),
+            vrt_mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )?;
+    invoke(
+        &create_associated_token_account_idempotent(
+            staker.key,
+            staker.key,
+            vault_withdrawal_claim_mint.key,
+            &spl_token::id(),
+        ),
+        &[
+            staker.clone(),
+            staker_claim_token_account.clone(),
+            staker.clone(),
+            vault_withdrawal_claim_mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )?;
+
+    vault.increment_vrt_enqueued_for_cooldown_amount(vrt_amount)?;
+
+    // Move the staker's VRT into the escrow owned by the claim mint
+    invoke(
+        &transfer(
+            &spl_token::id(),
+            staker_vrt_token_account.key,
+            vault_withdrawal_claim_mint_vrt_token_account.key,
+            staker.key,
+            &[],
+            vrt_amount,
+        )?,
+        &[
+            staker_vrt_token_account.clone(),
+            vault_withdrawal_claim_mint_vrt_token_account.clone(),
+            staker.clone(),
+        ],
+    )?;
+
+    let vault_signing_seeds = vault.signing_seeds();
+    let seed_slices: Vec<&[u8]> = vault_signing_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect();
+
+    drop(vault_data); // no double borrow
+
+    invoke_signed(
+        &mint_to(
+            &spl_token::id(),
+            vault_withdrawal_claim_mint.key,
+            staker_claim_token_account.key,
+            vault_info.key,
+            &[],
+            vrt_amount,
+        )?,
+        &[
+            vault_withdrawal_claim_mint.clone(),
+            staker_claim_token_account.clone(),
+            vault_info.clone(),
+        ],
+        &[&seed_slices],
+    )?;
+
+    Ok(())
+}
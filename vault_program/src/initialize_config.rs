@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -53,7 +53,7 @@ pub fn process_initialize_config(
     )?;
 
     let mut config_data = config.try_borrow_mut_data()?;
-    config_data[0] = Config::DISCRIMINATOR;
+    Config::write_header(&mut config_data);
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
     *config = Config::new(
         *admin.key,
@@ -1,14 +1,18 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
+    creation_policy::{self, CreationPolicyError},
     loader::{
         load_associated_token_account_program, load_signer, load_system_account,
         load_system_program, load_token_account, load_token_mint, load_token_program,
     },
 };
-use jito_vault_core::{burn_vault::BurnVault, config::Config, vault::Vault, MAX_BPS};
+use jito_vault_core::{
+    burn_vault::BurnVault, config::Config, creator_allowlist_entry::CreatorAllowlistEntry,
+    vault::Vault, MAX_BPS,
+};
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
     account_info::AccountInfo,
@@ -30,6 +34,9 @@ use spl_token::{
 };
 
 /// Processes the create instruction: [`crate::VaultInstruction::InitializeVault`]
+///
+/// Specification:
+/// - The admin shall satisfy the config's [`CreationPolicy`], see [`check_creation_policy`]
 pub fn process_initialize_vault(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -39,7 +46,7 @@ pub fn process_initialize_vault(
     decimals: u8,
     initialize_token_amount: u64,
 ) -> ProgramResult {
-    let [config, vault, vrt_mint, st_mint, admin_st_token_account, vault_st_token_account, burn_vault, burn_vault_vrt_token_account, admin, base, system_program, token_program, associated_token_program] =
+    let [config, vault, vrt_mint, st_mint, admin_st_token_account, vault_st_token_account, burn_vault, burn_vault_vrt_token_account, admin, base, system_program, token_program, associated_token_program, optional_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -72,6 +79,14 @@ pub fn process_initialize_vault(
     load_token_program(token_program)?;
     load_associated_token_account_program(associated_token_program)?;
 
+    check_creation_policy(
+        program_id,
+        config,
+        admin,
+        system_program,
+        optional_accounts.first(),
+    )?;
+
     if initialize_token_amount == 0 {
         msg!("Initialize token amount must be greater than zero");
         return Err(VaultError::VaultInitialAmountFailed.into());
@@ -147,7 +162,7 @@ pub fn process_initialize_vault(
         )?;
 
         let mut vault_data = vault.try_borrow_mut_data()?;
-        vault_data[0] = Vault::DISCRIMINATOR;
+        Vault::write_header(&mut vault_data);
         let vault_account = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
 
         *vault_account = Vault::new(
@@ -250,3 +265,30 @@ pub fn process_initialize_vault(
 
     Ok(())
 }
+
+/// Checks that `creator` may create a vault under the config's [`CreationPolicy`], see
+/// [`creation_policy::check_creation_policy`]
+pub fn check_creation_policy<'a, 'info>(
+    program_id: &Pubkey,
+    config: &Config,
+    creator: &'a AccountInfo<'info>,
+    system_program: &'a AccountInfo<'info>,
+    creation_policy_account: Option<&'a AccountInfo<'info>>,
+) -> ProgramResult {
+    creation_policy::check_creation_policy::<CreatorAllowlistEntry>(
+        program_id,
+        config.creation_policy()?,
+        &config.creation_fee_treasury,
+        config.creation_fee_lamports(),
+        creator,
+        system_program,
+        creation_policy_account,
+    )
+    .map_err(|e| match e {
+        CreationPolicyError::CreatorNotAllowlisted => VaultError::VaultCreatorNotAllowlisted.into(),
+        CreationPolicyError::CreationFeeTreasuryInvalid => {
+            VaultError::VaultCreationFeeTreasuryInvalid.into()
+        }
+        CreationPolicyError::Program(e) => e,
+    })
+}
@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -47,6 +47,7 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
     load_system_program(system_program)?;
 
     vault.check_is_paused()?;
+    vault.check_is_winding_down()?;
 
     // The VaultNcnSlasherTicket shall be at the canonical PDA
     let (
@@ -91,18 +92,23 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
         NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
 
     let mut vault_ncn_slasher_ticket_data = vault_ncn_slasher_ticket.try_borrow_mut_data()?;
-    vault_ncn_slasher_ticket_data[0] = VaultNcnSlasherTicket::DISCRIMINATOR;
+    VaultNcnSlasherTicket::write_header(&mut vault_ncn_slasher_ticket_data);
     let vault_ncn_slasher_ticket =
         VaultNcnSlasherTicket::try_from_slice_unchecked_mut(&mut vault_ncn_slasher_ticket_data)?;
     *vault_ncn_slasher_ticket = VaultNcnSlasherTicket::new(
         *vault_info.key,
         *ncn.key,
         *slasher.key,
-        ncn_vault_slasher_ticket.max_slashable_per_epoch(),
+        ncn_vault_slasher_ticket.stored_max_slashable_per_epoch(),
         vault.slasher_count(),
         vault_ncn_slasher_ticket_bump,
         slot,
     );
+    vault_ncn_slasher_ticket.sync_max_slashable_per_epoch(
+        ncn_vault_slasher_ticket.stored_max_slashable_per_epoch(),
+        ncn_vault_slasher_ticket.next_max_slashable_per_epoch(),
+        ncn_vault_slasher_ticket.next_max_slashable_epoch(),
+    );
 
     vault.increment_slasher_count()?;
 
@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -64,6 +64,7 @@ pub fn process_initialize_vault_ncn_ticket(
     vault.check_ncn_admin(vault_ncn_admin.key)?;
     vault.check_update_state_ok(slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_winding_down()?;
 
     // The NcnVaultTicket shall be active
     msg!(
@@ -82,7 +83,7 @@ pub fn process_initialize_vault_ncn_ticket(
         &vault_ncn_ticket_seeds,
     )?;
     let mut vault_ncn_ticket_data = vault_ncn_ticket.try_borrow_mut_data()?;
-    vault_ncn_ticket_data[0] = VaultNcnTicket::DISCRIMINATOR;
+    VaultNcnTicket::write_header(&mut vault_ncn_ticket_data);
     let vault_ncn_ticket =
         VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
     *vault_ncn_ticket = VaultNcnTicket::new(
@@ -1,6 +1,6 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account,
     loader::{load_signer, load_system_account, load_system_program},
@@ -62,6 +62,7 @@ pub fn process_initialize_vault_operator_delegation(
     vault.check_operator_admin(vault_operator_admin.key)?;
     vault.check_update_state_ok(slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_winding_down()?;
 
     msg!(
         "Initializing VaultOperatorDelegation at address {}",
@@ -80,7 +81,7 @@ pub fn process_initialize_vault_operator_delegation(
     )?;
 
     let mut vault_operator_delegation_data = vault_operator_delegation.try_borrow_mut_data()?;
-    vault_operator_delegation_data[0] = VaultOperatorDelegation::DISCRIMINATOR;
+    VaultOperatorDelegation::write_header(&mut vault_operator_delegation_data);
     let vault_operator_delegation =
         VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
     *vault_operator_delegation = VaultOperatorDelegation::new(
@@ -1,9 +1,10 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
     create_account, get_epoch,
     loader::{load_signer, load_system_account, load_system_program},
+    realloc,
 };
 use jito_vault_core::{
     config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
@@ -16,7 +17,8 @@ use solana_program::{
 
 /// Instruction: [`crate::VaultInstruction::InitializeVaultUpdateDelegationsTicket`]
 /// Initializes a new [`VaultUpdateStateTracker`] account, which is used to track the delegations
-/// that are to be updated at the epoch boundary.
+/// that are to be updated at the epoch boundary. The account is reallocated to hold a bitmap with
+/// one bit per operator so the operators can be cranked in any order.
 pub fn process_initialize_vault_update_state_tracker(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -75,6 +77,35 @@ pub fn process_initialize_vault_update_state_tracker(
         &vault_update_state_tracker_seeds,
     )?;
 
+    start_vault_update(config, vault, slot)?;
+
+    realloc(
+        vault_update_state_tracker,
+        VaultUpdateStateTracker::account_size(vault.operator_count())?,
+        payer,
+        &Rent::get()?,
+    )?;
+
+    let mut vault_update_state_tracker_data = vault_update_state_tracker.try_borrow_mut_data()?;
+    VaultUpdateStateTracker::write_header(&mut vault_update_state_tracker_data);
+    let (vault_update_state_tracker, _) = VaultUpdateStateTracker::try_from_slice_with_bitmap_mut(
+        &mut vault_update_state_tracker_data,
+    )?;
+    *vault_update_state_tracker = VaultUpdateStateTracker::new(
+        *vault_info.key,
+        ncn_epoch,
+        withdrawal_allocation_method as u8,
+    );
+
+    Ok(())
+}
+
+/// Starts a vault update cycle for the current epoch, shared by the tracker based update and
+/// [`crate::VaultInstruction::UpdateVaultFull`]
+///
+/// Carries over the `additional_assets_need_unstaking` of an unfinished update, otherwise applies
+/// the pending fees and calculates the assets that need to be unstaked this epoch.
+pub fn start_vault_update(config: &Config, vault: &mut Vault, slot: u64) -> ProgramResult {
     let needs_to_recover_from_partial_or_late_update = {
         let epoch_length = config.epoch_length();
 
@@ -101,7 +132,7 @@ pub fn process_initialize_vault_update_state_tracker(
         // Fees can only be updated here so `additional_assets_need_unstaking` will be static
         // Otherwise there may be a mismatch between withdrawn assets and outstanding claim tickets
         vault.set_withdrawal_fee_bps(vault.next_withdrawal_fee_bps())?;
-        vault.set_program_fee_bps(config.program_fee_bps())?;
+        vault.set_program_fee_bps(vault.effective_program_fee_bps(config.program_fee_bps()))?;
 
         // If the vault is not in the middle of unstaking, calculate the additional assets needed
         // to unstake
@@ -113,16 +144,5 @@ pub fn process_initialize_vault_update_state_tracker(
 
     vault.set_last_start_state_update_slot(slot);
 
-    let mut vault_update_state_tracker_data = vault_update_state_tracker.try_borrow_mut_data()?;
-    vault_update_state_tracker_data[0] = VaultUpdateStateTracker::DISCRIMINATOR;
-    let vault_update_state_tracker = VaultUpdateStateTracker::try_from_slice_unchecked_mut(
-        &mut vault_update_state_tracker_data,
-    )?;
-    *vault_update_state_tracker = VaultUpdateStateTracker::new(
-        *vault_info.key,
-        ncn_epoch,
-        withdrawal_allocation_method as u8,
-    );
-
     Ok(())
 }
@@ -1,15 +1,21 @@
 mod add_delegation;
+mod burn_withdrawal_claim;
 mod burn_withdrawal_ticket;
 mod change_withdrawal_ticket_owner;
+mod close_creator_allowlist_entry;
 mod close_update_state_tracker;
+mod close_vault;
 mod cooldown_delegation;
 mod cooldown_vault_ncn_slasher_ticket;
 mod cooldown_vault_ncn_ticket;
 mod crank_vault_update_state_tracker;
 mod create_token_metadata;
 mod delegate_token_account;
+mod distribute_fee_split;
 mod enqueue_withdrawal;
+mod enqueue_withdrawal_claim;
 mod initialize_config;
+mod initialize_creator_allowlist_entry;
 mod initialize_vault;
 mod initialize_vault_ncn_slasher_operator_ticket;
 mod initialize_vault_ncn_slasher_ticket;
@@ -17,19 +23,32 @@ mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
 mod initialize_vault_update_state_tracker;
 mod initialize_vault_with_mint;
+mod migrate_account;
 mod mint_to;
 mod set_admin;
 mod set_capacity;
 mod set_config_admin;
+mod set_creation_policy;
+mod set_fee_split;
 mod set_fees;
 mod set_is_paused;
+mod set_max_operator_share;
+mod set_min_holding_period;
+mod set_ncn_allocation_cap;
+mod set_operator_delegation_cap;
 mod set_program_fee;
+mod set_program_fee_override;
 mod set_program_fee_wallet;
+mod set_referral_fee;
 mod set_secondary_admin;
 mod update_token_metadata;
 mod update_vault_balance;
+mod update_vault_full;
+mod update_vault_ncn_slasher_ticket;
+mod waive_holding_period;
 mod warmup_vault_ncn_slasher_ticket;
 mod warmup_vault_ncn_ticket;
+mod wind_down_vault;
 
 use borsh::BorshDeserialize;
 use jito_vault_sdk::instruction::VaultInstruction;
@@ -42,31 +61,48 @@ use solana_program::{
 use solana_security_txt::security_txt;
 
 use crate::{
-    add_delegation::process_add_delegation, burn_withdrawal_ticket::process_burn_withdrawal_ticket,
+    add_delegation::process_add_delegation, burn_withdrawal_claim::process_burn_withdrawal_claim,
+    burn_withdrawal_ticket::process_burn_withdrawal_ticket,
     change_withdrawal_ticket_owner::process_change_withdrawal_ticket_owner,
+    close_creator_allowlist_entry::process_close_creator_allowlist_entry,
     close_update_state_tracker::process_close_vault_update_state_tracker,
-    cooldown_delegation::process_cooldown_delegation,
+    close_vault::process_close_vault, cooldown_delegation::process_cooldown_delegation,
     cooldown_vault_ncn_slasher_ticket::process_cooldown_vault_ncn_slasher_ticket,
     cooldown_vault_ncn_ticket::process_cooldown_vault_ncn_ticket,
     crank_vault_update_state_tracker::process_crank_vault_update_state_tracker,
     create_token_metadata::process_create_token_metadata,
     delegate_token_account::process_delegate_token_account,
-    enqueue_withdrawal::process_enqueue_withdrawal, initialize_config::process_initialize_config,
+    distribute_fee_split::process_distribute_fee_split,
+    enqueue_withdrawal::process_enqueue_withdrawal,
+    enqueue_withdrawal_claim::process_enqueue_withdrawal_claim,
+    initialize_config::process_initialize_config,
+    initialize_creator_allowlist_entry::process_initialize_creator_allowlist_entry,
     initialize_vault::process_initialize_vault,
     initialize_vault_ncn_slasher_operator_ticket::process_initialize_vault_ncn_slasher_operator_ticket,
     initialize_vault_ncn_slasher_ticket::process_initialize_vault_ncn_slasher_ticket,
     initialize_vault_ncn_ticket::process_initialize_vault_ncn_ticket,
     initialize_vault_operator_delegation::process_initialize_vault_operator_delegation,
     initialize_vault_update_state_tracker::process_initialize_vault_update_state_tracker,
-    initialize_vault_with_mint::process_initialize_vault_with_mint, mint_to::process_mint,
-    set_admin::process_set_admin, set_capacity::process_set_deposit_capacity,
-    set_config_admin::process_set_config_admin, set_fees::process_set_fees,
-    set_is_paused::process_set_is_paused, set_program_fee_wallet::process_set_program_fee_wallet,
-    set_secondary_admin::process_set_secondary_admin,
+    initialize_vault_with_mint::process_initialize_vault_with_mint,
+    migrate_account::process_migrate_account, mint_to::process_mint, set_admin::process_set_admin,
+    set_capacity::process_set_deposit_capacity, set_config_admin::process_set_config_admin,
+    set_creation_policy::process_set_creation_policy, set_fee_split::process_set_fee_split,
+    set_fees::process_set_fees, set_is_paused::process_set_is_paused,
+    set_max_operator_share::process_set_max_operator_share,
+    set_min_holding_period::process_set_min_holding_period,
+    set_ncn_allocation_cap::process_set_ncn_allocation_cap,
+    set_operator_delegation_cap::process_set_operator_delegation_cap,
+    set_program_fee_override::process_set_program_fee_override,
+    set_program_fee_wallet::process_set_program_fee_wallet,
+    set_referral_fee::process_set_referral_fee, set_secondary_admin::process_set_secondary_admin,
     update_token_metadata::process_update_token_metadata,
     update_vault_balance::process_update_vault_balance,
+    update_vault_full::process_update_vault_full,
+    update_vault_ncn_slasher_ticket::process_update_vault_ncn_slasher_ticket,
+    waive_holding_period::process_waive_holding_period,
     warmup_vault_ncn_slasher_ticket::process_warmup_vault_ncn_slasher_ticket,
     warmup_vault_ncn_ticket::process_warmup_vault_ncn_ticket,
+    wind_down_vault::process_wind_down_vault,
 };
 
 declare_id!(env!("VAULT_PROGRAM_ID"));
@@ -180,14 +216,85 @@ pub fn process_instruction(
             msg!("Instruction: SetProgramFee");
             process_set_program_fee(program_id, accounts, new_fee_bps)
         }
+        VaultInstruction::SetProgramFeeOverride { program_fee_bps } => {
+            msg!("Instruction: SetProgramFeeOverride");
+            process_set_program_fee_override(program_id, accounts, program_fee_bps)
+        }
         VaultInstruction::SetProgramFeeWallet => {
             msg!("Instruction: SetProgramFeeWallet");
             process_set_program_fee_wallet(program_id, accounts)
         }
+        VaultInstruction::SetCreationPolicy {
+            creation_policy,
+            creation_fee_lamports,
+        } => {
+            msg!("Instruction: SetCreationPolicy");
+            process_set_creation_policy(
+                program_id,
+                accounts,
+                creation_policy,
+                creation_fee_lamports,
+            )
+        }
+        VaultInstruction::InitializeCreatorAllowlistEntry => {
+            msg!("Instruction: InitializeCreatorAllowlistEntry");
+            process_initialize_creator_allowlist_entry(program_id, accounts)
+        }
+        VaultInstruction::CloseCreatorAllowlistEntry => {
+            msg!("Instruction: CloseCreatorAllowlistEntry");
+            process_close_creator_allowlist_entry(program_id, accounts)
+        }
+        VaultInstruction::SetReferralFee { referral_fee_bps } => {
+            msg!("Instruction: SetReferralFee");
+            process_set_referral_fee(program_id, accounts, referral_fee_bps)
+        }
         VaultInstruction::SetIsPaused { is_paused } => {
             msg!("Instruction: SetIsPaused");
             process_set_is_paused(program_id, accounts, is_paused)
         }
+        VaultInstruction::SetMaxOperatorShare {
+            max_operator_share_bps,
+        } => {
+            msg!("Instruction: SetMaxOperatorShare");
+            process_set_max_operator_share(program_id, accounts, max_operator_share_bps)
+        }
+        VaultInstruction::SetOperatorDelegationCap {
+            max_delegation_amount,
+        } => {
+            msg!("Instruction: SetOperatorDelegationCap");
+            process_set_operator_delegation_cap(program_id, accounts, max_delegation_amount)
+        }
+        VaultInstruction::SetNcnAllocationCap { max_allocation_bps } => {
+            msg!("Instruction: SetNcnAllocationCap");
+            process_set_ncn_allocation_cap(program_id, accounts, max_allocation_bps)
+        }
+        VaultInstruction::SetMinHoldingPeriod { min_holding_epochs } => {
+            msg!("Instruction: SetMinHoldingPeriod");
+            process_set_min_holding_period(program_id, accounts, min_holding_epochs)
+        }
+        VaultInstruction::SetFeeSplit { recipient_bps } => {
+            msg!("Instruction: SetFeeSplit");
+            process_set_fee_split(program_id, accounts, recipient_bps)
+        }
+        VaultInstruction::DistributeFeeSplit => {
+            msg!("Instruction: DistributeFeeSplit");
+            process_distribute_fee_split(program_id, accounts)
+        }
+        // ------------------------------------------
+        // Vault decommissioning
+        // ------------------------------------------
+        VaultInstruction::WindDownVault => {
+            msg!("Instruction: WindDownVault");
+            process_wind_down_vault(program_id, accounts)
+        }
+        VaultInstruction::CloseVault => {
+            msg!("Instruction: CloseVault");
+            process_close_vault(program_id, accounts)
+        }
+        VaultInstruction::WaiveHoldingPeriod => {
+            msg!("Instruction: WaiveHoldingPeriod");
+            process_waive_holding_period(program_id, accounts)
+        }
         // ------------------------------------------
         // Vault minting and burning
         // ------------------------------------------
@@ -210,6 +317,14 @@ pub fn process_instruction(
             msg!("Instruction: BurnWithdrawalTicket");
             process_burn_withdrawal_ticket(program_id, accounts)
         }
+        VaultInstruction::EnqueueWithdrawalClaim { amount } => {
+            msg!("Instruction: EnqueueWithdrawalClaim");
+            process_enqueue_withdrawal_claim(program_id, accounts, amount)
+        }
+        VaultInstruction::BurnWithdrawalClaim { epoch, amount } => {
+            msg!("Instruction: BurnWithdrawalClaim");
+            process_burn_withdrawal_claim(program_id, accounts, epoch, amount)
+        }
         // ------------------------------------------
         // Vault-NCN operations
         // ------------------------------------------
@@ -232,6 +347,10 @@ pub fn process_instruction(
             msg!("Instruction: CooldownVaultNcnSlasherTicket");
             process_cooldown_vault_ncn_slasher_ticket(program_id, accounts)
         }
+        VaultInstruction::UpdateVaultNcnSlasherTicket => {
+            msg!("Instruction: UpdateVaultNcnSlasherTicket");
+            process_update_vault_ncn_slasher_ticket(program_id, accounts)
+        }
         // ------------------------------------------
         // Vault delegation
         // ------------------------------------------
@@ -265,6 +384,12 @@ pub fn process_instruction(
             msg!("Instruction: CloseVaultUpdateStateTracker");
             process_close_vault_update_state_tracker(program_id, accounts, ncn_epoch)
         }
+        VaultInstruction::UpdateVaultFull {
+            withdrawal_allocation_method,
+        } => {
+            msg!("Instruction: UpdateVaultFull");
+            process_update_vault_full(program_id, accounts, withdrawal_allocation_method)
+        }
         // ------------------------------------------
         // VRT metadata
         // ------------------------------------------
@@ -280,5 +405,12 @@ pub fn process_instruction(
             msg!("Instruction: SetConfigAdmin");
             process_set_config_admin(program_id, accounts)
         }
+        // ------------------------------------------
+        // Account migration
+        // ------------------------------------------
+        VaultInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
     }
 }
@@ -0,0 +1,84 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{
+    loader::{load_signer, load_system_program},
+    migrate_account,
+};
+use jito_vault_core::{
+    config::Config, creator_allowlist_entry::CreatorAllowlistEntry, vault::Vault,
+    vault_deposit_receipt::VaultDepositReceipt, vault_fee_split::VaultFeeSplit,
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation, vault_referral::VaultReferral,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Processes the migrate account instruction: [`crate::VaultInstruction::MigrateAccount`]
+///
+/// Specification:
+/// - The account must be owned by the vault program
+/// - The account type is determined by its discriminator
+/// - The account is upgraded one layout version at a time until it reaches the current layout
+/// - The payer funds the rent for any additional space
+/// - Accounts already at the current layout version are left untouched
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [account, payer, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if account.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    load_signer(payer, true)?;
+    load_system_program(system_program)?;
+
+    let discriminator = account
+        .data
+        .borrow()
+        .first()
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let rent = Rent::get()?;
+
+    match discriminator {
+        Config::DISCRIMINATOR => migrate_account::<Config>(account, payer, &rent),
+        Vault::DISCRIMINATOR => migrate_account::<Vault>(account, payer, &rent),
+        VaultNcnTicket::DISCRIMINATOR => migrate_account::<VaultNcnTicket>(account, payer, &rent),
+        VaultOperatorDelegation::DISCRIMINATOR => {
+            migrate_account::<VaultOperatorDelegation>(account, payer, &rent)
+        }
+        VaultNcnSlasherTicket::DISCRIMINATOR => {
+            migrate_account::<VaultNcnSlasherTicket>(account, payer, &rent)
+        }
+        VaultNcnSlasherOperatorTicket::DISCRIMINATOR => {
+            migrate_account::<VaultNcnSlasherOperatorTicket>(account, payer, &rent)
+        }
+        VaultStakerWithdrawalTicket::DISCRIMINATOR => {
+            migrate_account::<VaultStakerWithdrawalTicket>(account, payer, &rent)
+        }
+        VaultUpdateStateTracker::DISCRIMINATOR => {
+            migrate_account::<VaultUpdateStateTracker>(account, payer, &rent)
+        }
+        VaultDepositReceipt::DISCRIMINATOR => {
+            migrate_account::<VaultDepositReceipt>(account, payer, &rent)
+        }
+        VaultFeeSplit::DISCRIMINATOR => migrate_account::<VaultFeeSplit>(account, payer, &rent),
+        CreatorAllowlistEntry::DISCRIMINATOR => {
+            migrate_account::<CreatorAllowlistEntry>(account, payer, &rent)
+        }
+        VaultReferral::DISCRIMINATOR => migrate_account::<VaultReferral>(account, payer, &rent),
+        _ => {
+            msg!("Account discriminator {} is unknown", discriminator);
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
@@ -1,10 +1,18 @@
+use std::mem::size_of;
+
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{
-    load_associated_token_account, load_signer, load_token_mint, load_token_program,
+use jito_jsm_core::{
+    create_account,
+    loader::{
+        load_associated_token_account, load_signer, load_system_account, load_system_program,
+        load_token_mint, load_token_program,
+    },
 };
 use jito_vault_core::{
     config::Config,
     vault::{MintSummary, Vault},
+    vault_deposit_receipt::VaultDepositReceipt,
+    vault_referral::VaultReferral,
 };
 use jito_vault_sdk::error::VaultError;
 use solana_program::{
@@ -14,10 +22,17 @@ use solana_program::{
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
+    rent::Rent,
     sysvar::Sysvar,
 };
-use spl_token::instruction::{mint_to, transfer};
+use spl_token::{
+    instruction::{mint_to, transfer},
+    state::Account,
+};
+
+use crate::update_vault_full::update_vault_without_operators;
 
 /// Processes the mint instruction: [`crate::VaultInstruction::MintTo`]
 ///
@@ -26,24 +41,35 @@ use spl_token::instruction::{mint_to, transfer};
 ///
 /// Specification:
 /// - If the vault has a mint burn admin, it must match be present and be a signer
-/// - The vault must be up-to-date
+/// - A vault without operators is updated in place, otherwise the vault must be up-to-date
+/// - The vault must not be winding down
 /// - The vault VRT mint must be correct
 /// - The amount to mint must be greater than zero
 /// - The post-mint tokens deposited shall be less than or equal to the vault capacity
 /// - The vault fee wallet must get the fee amount
 /// - The transaction shall fail if the amount out is less than the minimum amount out
 /// - The user's assets shall be deposited into the vault supported mint ATA
-/// - The vault shall mint the pro-rata amount to the user and the fee wallet
+/// - The VRT may be minted to any beneficiary: the VRT token account shall be the beneficiary's
+///   VRT ATA, and the beneficiary shall not be the vault
+/// - The vault shall mint the pro-rata amount to the beneficiary and the fee wallet
+/// - If the vault has a minimum holding period, the beneficiary's VaultDepositReceipt shall be
+///   passed and is created if needed, at the canonical PDA along with the system program.
+/// - An existing receipt records the deposit slot and the VRT minted to the beneficiary when the
+///   beneficiary deposits or signs. While the vault has a holding period, other depositors can't
+///   restart the beneficiary's holding period without the beneficiary's signature.
+/// - A missing optional account is passed as the program ID
+/// - If a referrer is given, it shall be neither the depositor nor the beneficiary. The deposit is recorded in the
+///   referrer's VaultReferral, which is created at the canonical PDA on their first referred
+///   deposit, and the vault's referral share of the deposit fee is minted to the referrer's VRT
+///   token account instead of the fee wallet
 pub fn process_mint(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     amount_in: u64,
     min_amount_out: u64,
 ) -> ProgramResult {
-    let (required_accounts, optional_accounts) = accounts.split_at(9);
-
-    let [config, vault_info, vrt_mint, depositor, depositor_token_account, vault_token_account, depositor_vrt_token_account, vault_fee_token_account, token_program] =
-        required_accounts
+    let [config, vault_info, vrt_mint, depositor, depositor_token_account, vault_token_account, depositor_vrt_token_account, vault_fee_token_account, token_program, optional_accounts @ ..] =
+        accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -63,21 +89,50 @@ pub fn process_mint(
         &vault.supported_mint,
     )?;
     load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
-    load_associated_token_account(depositor_vrt_token_account, depositor.key, vrt_mint.key)?;
+    // The VRT is minted to the owner of the VRT token account, which need not be the depositor
+    let beneficiary = Account::unpack(&depositor_vrt_token_account.data.borrow())?.owner;
+    load_associated_token_account(depositor_vrt_token_account, &beneficiary, vrt_mint.key)?;
     load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, vrt_mint.key)?;
 
     // Only the original spl token program is allowed
     load_token_program(token_program)?;
 
-    vault.check_mint_burn_admin(optional_accounts.first())?;
+    // A missing optional account is passed as the program ID
+    let optional_account = |index: usize| {
+        optional_accounts
+            .get(index)
+            .filter(|account| account.key.ne(program_id))
+    };
+    let mint_signer = optional_account(0);
+    let vault_deposit_receipt = optional_account(1);
+    let system_program = optional_account(2);
+    let beneficiary_signer = optional_account(6);
+    let referral = match optional_accounts.get(3..) {
+        None | Some([]) => None,
+        Some([_, referrer, ..]) if referrer.key.eq(program_id) => None,
+        Some([vault_referral, referrer, referrer_vrt_token_account, ..]) => {
+            Some((vault_referral, referrer, referrer_vrt_token_account))
+        }
+        Some(_) => return Err(ProgramError::NotEnoughAccountKeys),
+    };
+    // The system program is only needed to create the deposit receipt or the referral account
+    let load_optional_system_program = || -> Result<&AccountInfo, ProgramError> {
+        let system_program = system_program.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        load_system_program(system_program)?;
+        Ok(system_program)
+    };
+
+    vault.check_mint_burn_admin(mint_signer)?;
     vault.check_vrt_mint(vrt_mint.key)?;
+    update_vault_without_operators(config, vault, Clock::get()?.slot)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_winding_down()?;
 
     // Currently, this is not possible, since the there are currently no instructions that allow the
     // vault to deposit tokens into the vault token account. This check is for future proofing.
-    if depositor.key.eq(vault_info.key) {
-        msg!("Depositor cannot be the vault");
+    if depositor.key.eq(vault_info.key) || beneficiary.eq(vault_info.key) {
+        msg!("Depositor and beneficiary cannot be the vault");
         return Err(VaultError::InvalidDepositor.into());
     }
 
@@ -94,11 +149,148 @@ pub fn process_mint(
         vrt_to_fee_wallet,
     } = vault.mint_with_fee(amount_in, min_amount_out)?;
 
+    let vrt_to_referrer = if referral.is_some() {
+        vault.calculate_referral_fee(vrt_to_fee_wallet)?
+    } else {
+        0
+    };
+    let vrt_to_fee_wallet = vrt_to_fee_wallet
+        .checked_sub(vrt_to_referrer)
+        .ok_or(VaultError::VaultUnderflow)?;
+
     if vrt_to_depositor == 0 {
         msg!("Some VRT must be minted to the depositor. If you wish to donate to the vault, please send ST directly to the vault token account");
         return Err(VaultError::VrtOutCannotBeZero.into());
     }
 
+    // Track the beneficiary's latest deposits so EnqueueWithdrawal can enforce the holding period
+    let slot = Clock::get()?.slot;
+    match vault_deposit_receipt {
+        Some(vault_deposit_receipt) if !vault_deposit_receipt.data_is_empty() => {
+            VaultDepositReceipt::load(
+                program_id,
+                vault_deposit_receipt,
+                vault_info,
+                &beneficiary,
+                true,
+            )?;
+            // Otherwise anyone could lock the beneficiary out by depositing on their behalf
+            let is_beneficiary_deposit = depositor.key.eq(&beneficiary)
+                || beneficiary_signer.is_some_and(|beneficiary_signer| {
+                    beneficiary_signer.key.eq(&beneficiary) && beneficiary_signer.is_signer
+                });
+            if is_beneficiary_deposit {
+                let mut vault_deposit_receipt_data = vault_deposit_receipt.data.borrow_mut();
+                let vault_deposit_receipt = VaultDepositReceipt::try_from_slice_unchecked_mut(
+                    &mut vault_deposit_receipt_data,
+                )?;
+                vault_deposit_receipt.record_deposit(
+                    slot,
+                    config.epoch_length(),
+                    vault.min_holding_epochs(),
+                    vrt_to_depositor,
+                )?;
+            } else if vault.min_holding_epochs() > 0 {
+                msg!("The beneficiary shall sign deposits that restart their holding period");
+                return Err(VaultError::VaultBeneficiarySignatureRequired.into());
+            }
+        }
+        Some(vault_deposit_receipt) if vault.min_holding_epochs() > 0 => {
+            let (
+                vault_deposit_receipt_pubkey,
+                vault_deposit_receipt_bump,
+                mut vault_deposit_receipt_seeds,
+            ) = VaultDepositReceipt::find_program_address(program_id, vault_info.key, &beneficiary);
+            vault_deposit_receipt_seeds.push(vec![vault_deposit_receipt_bump]);
+            if vault_deposit_receipt.key.ne(&vault_deposit_receipt_pubkey) {
+                msg!("Vault deposit receipt is not at the correct PDA");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            load_system_account(vault_deposit_receipt, true)?;
+
+            msg!(
+                "Initializing vault deposit receipt at address {}",
+                vault_deposit_receipt.key
+            );
+            create_account(
+                depositor,
+                vault_deposit_receipt,
+                load_optional_system_program()?,
+                program_id,
+                &Rent::get()?,
+                8_u64
+                    .checked_add(size_of::<VaultDepositReceipt>() as u64)
+                    .ok_or(VaultError::ArithmeticOverflow)?,
+                &vault_deposit_receipt_seeds,
+            )?;
+            let mut vault_deposit_receipt_data = vault_deposit_receipt.data.borrow_mut();
+            VaultDepositReceipt::write_header(&mut vault_deposit_receipt_data);
+            let vault_deposit_receipt =
+                VaultDepositReceipt::try_from_slice_unchecked_mut(&mut vault_deposit_receipt_data)?;
+            *vault_deposit_receipt = VaultDepositReceipt::new(
+                *vault_info.key,
+                beneficiary,
+                slot,
+                vrt_to_depositor,
+                vault_deposit_receipt_bump,
+            );
+        }
+        None if vault.min_holding_epochs() > 0 => {
+            msg!("The beneficiary's deposit receipt is required while the vault has a holding period");
+            return Err(VaultError::VaultDepositReceiptMissing.into());
+        }
+        _ => {}
+    }
+
+    if let Some((vault_referral, referrer, referrer_vrt_token_account)) = referral {
+        if referrer.key.eq(depositor.key) || referrer.key.eq(&beneficiary) {
+            msg!("Depositor and beneficiary cannot refer their own deposit");
+            return Err(VaultError::VaultReferrerInvalid.into());
+        }
+        if vrt_to_referrer > 0 {
+            load_associated_token_account(referrer_vrt_token_account, referrer.key, vrt_mint.key)?;
+        }
+
+        if vault_referral.data_is_empty() {
+            let (vault_referral_pubkey, vault_referral_bump, mut vault_referral_seeds) =
+                VaultReferral::find_program_address(program_id, vault_info.key, referrer.key);
+            vault_referral_seeds.push(vec![vault_referral_bump]);
+            if vault_referral.key.ne(&vault_referral_pubkey) {
+                msg!("Vault referral is not at the correct PDA");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            load_system_account(vault_referral, true)?;
+
+            msg!(
+                "Initializing vault referral at address {}",
+                vault_referral.key
+            );
+            create_account(
+                depositor,
+                vault_referral,
+                load_optional_system_program()?,
+                program_id,
+                &Rent::get()?,
+                8_u64
+                    .checked_add(size_of::<VaultReferral>() as u64)
+                    .ok_or(VaultError::ArithmeticOverflow)?,
+                &vault_referral_seeds,
+            )?;
+            let mut vault_referral_data = vault_referral.data.borrow_mut();
+            VaultReferral::write_header(&mut vault_referral_data);
+            let vault_referral_account =
+                VaultReferral::try_from_slice_unchecked_mut(&mut vault_referral_data)?;
+            *vault_referral_account =
+                VaultReferral::new(*vault_info.key, *referrer.key, vault_referral_bump);
+        } else {
+            VaultReferral::load(program_id, vault_referral, vault_info, referrer, true)?;
+        }
+
+        let mut vault_referral_data = vault_referral.data.borrow_mut();
+        let vault_referral = VaultReferral::try_from_slice_unchecked_mut(&mut vault_referral_data)?;
+        vault_referral.record_deposit(amount_in, vrt_to_referrer)?;
+    }
+
     // transfer tokens from depositor to vault
     {
         invoke(
@@ -158,6 +350,27 @@ pub fn process_mint(
             ],
             &[&seed_slices],
         )?;
+
+        if let Some((_, _, referrer_vrt_token_account)) = referral {
+            if vrt_to_referrer > 0 {
+                invoke_signed(
+                    &mint_to(
+                        &spl_token::id(),
+                        vrt_mint.key,
+                        referrer_vrt_token_account.key,
+                        vault_info.key,
+                        &[],
+                        vrt_to_referrer,
+                    )?,
+                    &[
+                        vrt_mint.clone(),
+                        referrer_vrt_token_account.clone(),
+                        vault_info.clone(),
+                    ],
+                    &[&seed_slices],
+                )?;
+            }
+        }
     }
 
     Ok(())
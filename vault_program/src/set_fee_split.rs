@@ -0,0 +1,133 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    create_account,
+    loader::{
+        load_associated_token_account_program, load_signer, load_system_account,
+        load_system_program, load_token_mint, load_token_program,
+    },
+};
+use jito_vault_core::{
+    config::Config,
+    vault::Vault,
+    vault_fee_split::{VaultFeeSplit, MAX_FEE_SPLIT_RECIPIENTS},
+};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+/// Processes the set fee split instruction: [`crate::VaultInstruction::SetFeeSplit`]
+///
+/// Specification:
+/// - The vault admin must sign
+/// - The [`VaultFeeSplit`] shall be at the canonical PDA, and is created on first use
+/// - The recipients are the remaining accounts and replace any previous recipients. There shall be
+///   one share per recipient, each greater than zero, adding up to 10,000 bps. The shares past the
+///   last recipient shall be zero
+/// - The fee split's VRT token account is created if it doesn't exist yet
+/// - The vault's fee wallet is set to the fee split, so all vault fees accumulate in its VRT token
+///   account until distributed
+pub fn process_set_fee_split(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    recipient_bps: [u16; 8],
+) -> ProgramResult {
+    let [config, vault_info, vrt_mint, vault_fee_split, vault_fee_split_token_account, admin, token_program, system_program, associated_token_program, recipients @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_token_mint(vrt_mint)?;
+    load_signer(admin, true)?;
+    load_token_program(token_program)?;
+    load_system_program(system_program)?;
+    load_associated_token_account_program(associated_token_program)?;
+
+    vault.check_admin(admin.key)?;
+    vault.check_vrt_mint(vrt_mint.key)?;
+
+    if vault_fee_split.data_is_empty() {
+        let (vault_fee_split_pubkey, vault_fee_split_bump, mut vault_fee_split_seeds) =
+            VaultFeeSplit::find_program_address(program_id, vault_info.key);
+        vault_fee_split_seeds.push(vec![vault_fee_split_bump]);
+        if vault_fee_split.key.ne(&vault_fee_split_pubkey) {
+            msg!("Vault fee split is not at the correct PDA");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        load_system_account(vault_fee_split, true)?;
+
+        msg!(
+            "Initializing vault fee split at address {}",
+            vault_fee_split.key
+        );
+        create_account(
+            admin,
+            vault_fee_split,
+            system_program,
+            program_id,
+            &Rent::get()?,
+            8_u64
+                .checked_add(size_of::<VaultFeeSplit>() as u64)
+                .ok_or(VaultError::ArithmeticOverflow)?,
+            &vault_fee_split_seeds,
+        )?;
+        let mut vault_fee_split_data = vault_fee_split.data.borrow_mut();
+        VaultFeeSplit::write_header(&mut vault_fee_split_data);
+        let vault_fee_split =
+            VaultFeeSplit::try_from_slice_unchecked_mut(&mut vault_fee_split_data)?;
+        *vault_fee_split = VaultFeeSplit::new(*vault_info.key, vault_fee_split_bump);
+    } else {
+        VaultFeeSplit::load(program_id, vault_fee_split, vault_info, true)?;
+    }
+
+    {
+        if recipients.len() > MAX_FEE_SPLIT_RECIPIENTS
+            || recipient_bps[recipients.len()..]
+                .iter()
+                .any(|bps| *bps != 0)
+        {
+            msg!("Fee split has a share without a recipient");
+            return Err(VaultError::VaultFeeSplitInvalidRecipients.into());
+        }
+        let recipient_bps = &recipient_bps[..recipients.len()];
+        let recipients: Vec<Pubkey> = recipients.iter().map(|recipient| *recipient.key).collect();
+
+        let mut vault_fee_split_data = vault_fee_split.data.borrow_mut();
+        let vault_fee_split =
+            VaultFeeSplit::try_from_slice_unchecked_mut(&mut vault_fee_split_data)?;
+        vault_fee_split.set_recipients(&recipients, recipient_bps)?;
+    }
+
+    // The fees are paid into the fee wallet's VRT token account, so it must exist
+    invoke(
+        &create_associated_token_account_idempotent(
+            admin.key,
+            vault_fee_split.key,
+            vrt_mint.key,
+            &spl_token::id(),
+        ),
+        &[
+            admin.clone(),
+            vault_fee_split_token_account.clone(),
+            vault_fee_split.clone(),
+            vrt_mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )?;
+
+    vault.fee_wallet = *vault_fee_split.key;
+    msg!("Fee wallet set to {:?}", vault_fee_split.key);
+
+    Ok(())
+}
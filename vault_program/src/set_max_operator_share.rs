@@ -0,0 +1,34 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set max operator share instruction: [`crate::VaultInstruction::SetMaxOperatorShare`]
+///
+/// Specification:
+/// - The vault admin must sign
+/// - The max operator share shall not exceed 10,000 bps; zero removes the limit
+/// - Existing delegations above the new limit are left untouched, only new delegations are checked
+pub fn process_set_max_operator_share(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_operator_share_bps: u16,
+) -> ProgramResult {
+    let [config, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    vault.check_admin(admin.key)?;
+
+    vault.set_max_operator_share_bps(max_operator_share_bps)?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set min holding period instruction: [`crate::VaultInstruction::SetMinHoldingPeriod`]
+///
+/// Specification:
+/// - The vault admin must sign
+/// - Zero removes the holding period
+/// - The holding period shall not exceed [`jito_vault_core::MAX_HOLDING_EPOCHS`]
+/// - The new period applies to the VRT tracked by existing deposit receipts, including past
+///   deposits. Stakers without a receipt, such as depositors from before any holding period, can
+///   still withdraw the VRT they hold
+pub fn process_set_min_holding_period(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_holding_epochs: u64,
+) -> ProgramResult {
+    let [config, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    vault.check_admin(admin.key)?;
+
+    vault.set_min_holding_epochs(min_holding_epochs)?;
+
+    Ok(())
+}
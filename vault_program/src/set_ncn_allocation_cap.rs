@@ -0,0 +1,43 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::ncn::Ncn;
+use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set NCN allocation cap instruction: [`crate::VaultInstruction::SetNcnAllocationCap`]
+///
+/// Specification:
+/// - The vault NCN admin must sign
+/// - The cap must not exceed 10,000 bps, a cap of zero removes the cap
+/// - The cap only bounds the stake the NCN counts, the operator delegations are unchanged
+pub fn process_set_ncn_allocation_cap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_allocation_bps: u16,
+) -> ProgramResult {
+    let [config, vault_info, ncn, vault_ncn_ticket, vault_ncn_admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    VaultNcnTicket::load(program_id, vault_ncn_ticket, vault_info, ncn, true)?;
+    let mut vault_ncn_ticket_data = vault_ncn_ticket.data.borrow_mut();
+    let vault_ncn_ticket =
+        VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
+    load_signer(vault_ncn_admin, false)?;
+
+    vault.check_ncn_admin(vault_ncn_admin.key)?;
+
+    vault_ncn_ticket.set_max_allocation_bps(max_allocation_bps)?;
+
+    Ok(())
+}
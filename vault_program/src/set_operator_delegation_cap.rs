@@ -0,0 +1,51 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_restaking_core::operator::Operator;
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set operator delegation cap instruction: [`crate::VaultInstruction::SetOperatorDelegationCap`]
+///
+/// Specification:
+/// - The vault admin must sign
+/// - A cap of zero removes the cap
+/// - An existing delegation above the new cap is left untouched, only new delegations are checked
+pub fn process_set_operator_delegation_cap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_delegation_amount: u64,
+) -> ProgramResult {
+    let [config, vault_info, operator, vault_operator_delegation, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    let vault_data = vault_info.data.borrow();
+    let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+    Operator::load(&config.restaking_program, operator, false)?;
+    VaultOperatorDelegation::load(
+        program_id,
+        vault_operator_delegation,
+        vault_info,
+        operator,
+        true,
+    )?;
+    let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+    let vault_operator_delegation =
+        VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
+    load_signer(admin, false)?;
+
+    vault.check_admin(admin.key)?;
+
+    vault_operator_delegation.set_max_delegation_amount(max_delegation_amount);
+
+    Ok(())
+}
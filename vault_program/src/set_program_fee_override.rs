@@ -0,0 +1,55 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::{error::VaultError, event::VaultEvent};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+/// Processes the set program fee override instruction: [`crate::VaultInstruction::SetProgramFeeOverride`]
+///
+/// Specification:
+/// - The config admin must sign
+/// - The override shall not exceed 10,000 bps. `None` clears the override and the vault is charged
+///   the config's program fee again
+/// - The override can be changed at most once per epoch, and a full epoch must pass between changes
+/// - The vault's program fee is updated to the override on the vault's next update
+/// - A [`VaultEvent::ProgramFeeOverrideChanged`] event is emitted
+pub fn process_set_program_fee_override(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    program_fee_bps: Option<u16>,
+) -> ProgramResult {
+    let [config, vault_info, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    if admin.key.ne(&config.admin) {
+        msg!("Config admin does not match");
+        return Err(VaultError::ConfigAdminInvalid.into());
+    }
+
+    let slot = Clock::get()?.slot;
+    vault.check_can_modify_program_fee_override(slot, config.epoch_length())?;
+
+    let old_program_fee_override_bps = vault.program_fee_override_bps();
+    vault.set_program_fee_override_bps(program_fee_bps, slot)?;
+
+    VaultEvent::ProgramFeeOverrideChanged {
+        vault: *vault_info.key,
+        old_program_fee_override_bps,
+        new_program_fee_override_bps: program_fee_bps,
+        slot,
+    }
+    .emit()?;
+
+    Ok(())
+}
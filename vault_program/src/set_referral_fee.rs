@@ -0,0 +1,34 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the set referral fee instruction: [`crate::VaultInstruction::SetReferralFee`]
+///
+/// Specification:
+/// - The vault fee admin must sign
+/// - The referral fee is a share of the deposit fee and shall not exceed 10,000 bps
+/// - Depositors are not affected by the change, so it takes effect immediately
+pub fn process_set_referral_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    referral_fee_bps: u16,
+) -> ProgramResult {
+    let [config, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    vault.check_fee_admin(admin.key)?;
+
+    vault.set_referral_fee_bps(referral_fee_bps)?;
+
+    Ok(())
+}
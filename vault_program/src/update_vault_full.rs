@@ -0,0 +1,131 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::get_epoch;
+use jito_vault_core::{
+    config::Config, delegation_state::DelegationState, vault::Vault,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::{error::VaultError, instruction::WithdrawalAllocationMethod};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+
+use crate::{
+    close_update_state_tracker::finish_vault_update,
+    crank_vault_update_state_tracker::update_vault_operator_delegation,
+    initialize_vault_update_state_tracker::start_vault_update,
+};
+
+/// Processes the update vault full instruction: [`crate::VaultInstruction::UpdateVaultFull`]
+///
+/// Specification:
+/// - Every [`VaultOperatorDelegation`] of the vault must be passed as a writable remaining account,
+///   in any order and each exactly once
+/// - The vault must have at most [`Vault::MAX_FULL_UPDATE_OPERATORS`] operators, larger vaults
+///   shall use the [`VaultUpdateStateTracker`] instructions
+/// - The update runs the same steps as initializing, cranking and closing a
+///   [`VaultUpdateStateTracker`], but no tracker account is created
+pub fn process_update_vault_full(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    withdrawal_allocation_method: WithdrawalAllocationMethod,
+) -> ProgramResult {
+    let [config, vault_info, vault_operator_delegations @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let slot = Clock::get()?.slot;
+
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, true)?;
+    let mut vault_data = vault_info.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+
+    let epoch_length = config.epoch_length();
+    if vault.check_update_state_ok(slot, epoch_length).is_ok() {
+        msg!("Vault update is not needed");
+        return Err(VaultError::VaultIsUpdated.into());
+    }
+    vault.check_is_paused()?;
+
+    // The update shall fit in a single transaction
+    if vault.operator_count() > Vault::MAX_FULL_UPDATE_OPERATORS {
+        msg!(
+            "Vault has {} operators, a full update supports at most {}. Use the VaultUpdateStateTracker instead",
+            vault.operator_count(),
+            Vault::MAX_FULL_UPDATE_OPERATORS
+        );
+        return Err(VaultError::VaultTooManyOperatorsForFullUpdate.into());
+    }
+
+    // Every VaultOperatorDelegation shall be updated
+    if vault_operator_delegations.len() as u64 != vault.operator_count() {
+        msg!(
+            "Expected {} vault operator delegations, received {}",
+            vault.operator_count(),
+            vault_operator_delegations.len()
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    start_vault_update(config, vault, slot)?;
+
+    // The tracker only lives for the duration of the instruction
+    let mut vault_update_state_tracker = VaultUpdateStateTracker::new(
+        *vault_info.key,
+        get_epoch(slot, epoch_length)?,
+        withdrawal_allocation_method as u8,
+    );
+    let mut operator_bitmap = vec![0; VaultUpdateStateTracker::bitmap_len(vault.operator_count())?];
+
+    for vault_operator_delegation in vault_operator_delegations {
+        VaultOperatorDelegation::load_with_stored_operator(
+            program_id,
+            vault_operator_delegation,
+            vault_info,
+            true,
+        )?;
+        let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
+        let vault_operator_delegation = VaultOperatorDelegation::try_from_slice_unchecked_mut(
+            &mut vault_operator_delegation_data,
+        )?;
+
+        update_vault_operator_delegation(
+            config,
+            vault,
+            vault_operator_delegation,
+            &mut vault_update_state_tracker,
+            &mut operator_bitmap,
+            slot,
+        )?;
+    }
+
+    finish_vault_update(
+        vault,
+        vault_update_state_tracker.delegation_state,
+        slot,
+        epoch_length,
+    )?;
+
+    Ok(())
+}
+
+/// Updates a vault with no operators in place, so deposits and withdrawals don't need a cranker
+/// at every epoch boundary. Does nothing if the vault has operators or is already up-to-date.
+pub fn update_vault_without_operators(
+    config: &Config,
+    vault: &mut Vault,
+    slot: u64,
+) -> ProgramResult {
+    let epoch_length = config.epoch_length();
+    if vault.operator_count() > 0 || !vault.is_update_needed(slot, epoch_length)? {
+        return Ok(());
+    }
+
+    msg!("Updating vault without operators");
+    start_vault_update(config, vault, slot)?;
+    finish_vault_update(vault, DelegationState::default(), slot, epoch_length)
+}
@@ -0,0 +1,69 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_restaking_core::{ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Copies the max slashable per epoch proposed by the NCN slasher admin onto the vault's slasher
+/// ticket, including an increase that is still pending. The restaking program invokes this
+/// instruction from `NcnSetMaxSlashablePerEpoch`; it stays permissionless so anyone can resync a
+/// ticket.
+///
+/// Instruction: [`crate::VaultInstruction::UpdateVaultNcnSlasherTicket`]
+pub fn process_update_vault_ncn_slasher_ticket(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault_info, ncn, slasher, ncn_vault_slasher_ticket, vault_ncn_slasher_ticket] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    Vault::load(program_id, vault_info, false)?;
+    Ncn::load(&config.restaking_program, ncn, false)?;
+    NcnVaultSlasherTicket::load(
+        &config.restaking_program,
+        ncn_vault_slasher_ticket,
+        ncn,
+        vault_info,
+        slasher,
+        false,
+    )?;
+    VaultNcnSlasherTicket::load(
+        program_id,
+        vault_ncn_slasher_ticket,
+        vault_info,
+        ncn,
+        slasher,
+        true,
+    )?;
+
+    let ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.data.borrow();
+    let ncn_vault_slasher_ticket =
+        NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
+    let mut vault_ncn_slasher_ticket_data = vault_ncn_slasher_ticket.data.borrow_mut();
+    let vault_ncn_slasher_ticket =
+        VaultNcnSlasherTicket::try_from_slice_unchecked_mut(&mut vault_ncn_slasher_ticket_data)?;
+
+    vault_ncn_slasher_ticket.sync_max_slashable_per_epoch(
+        ncn_vault_slasher_ticket.stored_max_slashable_per_epoch(),
+        ncn_vault_slasher_ticket.next_max_slashable_per_epoch(),
+        ncn_vault_slasher_ticket.next_max_slashable_epoch(),
+    );
+
+    msg!(
+        "Vault NCN slasher ticket max slashable per epoch synced to {}, pending {} from epoch {}",
+        vault_ncn_slasher_ticket.stored_max_slashable_per_epoch(),
+        vault_ncn_slasher_ticket.next_max_slashable_per_epoch(),
+        vault_ncn_slasher_ticket.next_max_slashable_epoch()
+    );
+
+    Ok(())
+}
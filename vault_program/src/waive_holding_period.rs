@@ -0,0 +1,41 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use jito_vault_sdk::error::VaultError;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the waive holding period instruction: [`crate::VaultInstruction::WaiveHoldingPeriod`]
+///
+/// Specification:
+/// - The vault admin must sign
+/// - The vault must be winding down
+/// - Once waived, stakers may enqueue withdrawals regardless of when they last deposited
+/// - The waiver cannot be reverted
+pub fn process_waive_holding_period(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [config, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    vault.check_admin(admin.key)?;
+
+    if !vault.is_winding_down() {
+        msg!("The holding period can only be waived while the vault is winding down");
+        return Err(VaultError::VaultIsNotWindingDown.into());
+    }
+
+    msg!("Holding period is waived");
+    vault.set_is_holding_period_waived(true);
+
+    Ok(())
+}
@@ -43,12 +43,17 @@ pub fn process_warmup_vault_ncn_slasher_ticket(
     vault.check_slasher_admin(vault_slasher_admin.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_winding_down()?;
 
     // The VaultNcnSlasherTicket shall be ready to be activated
-    if !vault_ncn_slasher_ticket
-        .state
-        .activate(Clock::get()?.slot, config.epoch_length())?
-    {
+    // The ticket transitions over the NCN's warmup period
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if !vault_ncn_slasher_ticket.state.activate_with_warmup_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.warmup_epochs(),
+    )? {
         msg!("Slasher is not ready to be activated");
         return Err(VaultError::VaultNcnSlasherTicketFailedWarmup.into());
     }
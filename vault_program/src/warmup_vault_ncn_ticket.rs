@@ -35,15 +35,20 @@ pub fn process_warmup_vault_ncn_ticket(
     // The vault shall be up-to-date before warming up the NCN
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
+    vault.check_is_winding_down()?;
 
     // The VaultNcnTicket shall be ready to be activated
     let mut vault_ncn_ticket_data = vault_ncn_ticket.data.borrow_mut();
     let vault_ncn_ticket =
         VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
-    if !vault_ncn_ticket
-        .state
-        .activate(Clock::get()?.slot, config.epoch_length())?
-    {
+    // The ticket transitions over the NCN's warmup period
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    if !vault_ncn_ticket.state.activate_with_warmup_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.warmup_epochs(),
+    )? {
         msg!("VaultNcnTicket is not ready to be activated");
         return Err(VaultError::VaultNcnTicketFailedWarmup.into());
     }
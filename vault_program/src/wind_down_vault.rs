@@ -0,0 +1,35 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::loader::load_signer;
+use jito_vault_core::{config::Config, vault::Vault};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Processes the wind down vault instruction: [`crate::VaultInstruction::WindDownVault`]
+///
+/// Specification:
+/// - The vault admin must sign
+/// - The vault shall not already be winding down
+/// - Once winding down, deposits, new delegations and NCN warmups are rejected
+/// - The next vault update shall force cooldown every operator delegation
+/// - Stakers may withdraw without the mint burn admin, and NCN tickets may be cooled down by anyone
+/// - Wind-down cannot be reverted
+pub fn process_wind_down_vault(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [config, vault, admin] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    Config::load(program_id, config, false)?;
+    Vault::load(program_id, vault, true)?;
+    let mut vault_data = vault.data.borrow_mut();
+    let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
+    load_signer(admin, false)?;
+
+    vault.check_admin(admin.key)?;
+    vault.check_is_winding_down()?;
+
+    msg!("Vault is now winding down");
+    vault.set_is_winding_down(true);
+
+    Ok(())
+}
@@ -123,6 +123,46 @@ pub enum VaultError {
     VrtOutCannotBeZero,
     #[error("NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate")]
     NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate,
+    #[error("VaultIsWindingDown")]
+    VaultIsWindingDown,
+    #[error("VaultIsNotWindingDown")]
+    VaultIsNotWindingDown,
+    #[error("VaultHasOutstandingVrt")]
+    VaultHasOutstandingVrt,
+    #[error("VaultHasOutstandingDelegation")]
+    VaultHasOutstandingDelegation,
+    #[error("VaultMaxOperatorShareInvalid")]
+    VaultMaxOperatorShareInvalid,
+    #[error("VaultMaxOperatorShareExceeded")]
+    VaultMaxOperatorShareExceeded,
+    #[error("VaultOperatorDelegationCapExceeded")]
+    VaultOperatorDelegationCapExceeded,
+    #[error("VaultNcnAllocationCapInvalid")]
+    VaultNcnAllocationCapInvalid,
+    #[error("VaultTooManyOperatorsForFullUpdate")]
+    VaultTooManyOperatorsForFullUpdate,
+    #[error("VaultHoldingPeriodNotElapsed")]
+    VaultHoldingPeriodNotElapsed,
+    #[error("VaultWithdrawalClaimNotWithdrawable")]
+    VaultWithdrawalClaimNotWithdrawable,
+    #[error("VaultFeeSplitInvalidRecipients")]
+    VaultFeeSplitInvalidRecipients,
+    #[error("VaultFeeSplitInvalidBps")]
+    VaultFeeSplitInvalidBps,
+    #[error("VaultProgramFeeOverrideChangeTooSoon")]
+    VaultProgramFeeOverrideChangeTooSoon,
+    #[error("VaultCreatorNotAllowlisted")]
+    VaultCreatorNotAllowlisted,
+    #[error("VaultCreationFeeTreasuryInvalid")]
+    VaultCreationFeeTreasuryInvalid,
+    #[error("VaultReferrerInvalid")]
+    VaultReferrerInvalid,
+    #[error("VaultDepositReceiptMissing")]
+    VaultDepositReceiptMissing,
+    #[error("VaultBeneficiarySignatureRequired")]
+    VaultBeneficiarySignatureRequired,
+    #[error("VaultMinHoldingEpochsInvalid")]
+    VaultMinHoldingEpochsInvalid,
     #[error("ArithmeticOverflow")]
     ArithmeticOverflow = 3000,
     #[error("ArithmeticUnderflow")]
@@ -0,0 +1,28 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, program_error::ProgramError, pubkey::Pubkey};
+
+/// Events logged by the vault program as program data, for changes indexers can't reconstruct
+/// from account state alone
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum VaultEvent {
+    /// The config admin set or cleared the program fee override of a vault. The vault's program
+    /// fee picks up the change on its next update.
+    ProgramFeeOverrideChanged {
+        vault: Pubkey,
+        /// The previous override, `None` if the vault was charged the config's program fee
+        old_program_fee_override_bps: Option<u16>,
+        /// The new override, `None` if the vault is now charged the config's program fee
+        new_program_fee_override_bps: Option<u16>,
+        /// The slot of the change
+        slot: u64,
+    },
+}
+
+impl VaultEvent {
+    /// Logs the borsh serialized event with `sol_log_data`
+    pub fn emit(&self) -> Result<(), ProgramError> {
+        let data = borsh::to_vec(self).map_err(|e| ProgramError::BorshIoError(e.to_string()))?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+}
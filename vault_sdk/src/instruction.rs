@@ -1,4 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+pub use jito_jsm_core::creation_policy::CreationPolicy;
 use shank::ShankInstruction;
 use solana_program::program_error::ProgramError;
 
@@ -29,6 +30,7 @@ pub enum VaultInstruction {
     #[account(10, name = "system_program")]
     #[account(11, name = "token_program")]
     #[account(12, name = "associated_token_program")]
+    #[account(13, writable, optional, name = "creation_policy_account", description = "The admin's creator allowlist entry or the creation fee treasury, depending on the config's creation policy")]
     InitializeVault {
         deposit_fee_bps: u16,
         withdrawal_fee_bps: u16,
@@ -125,10 +127,16 @@ pub enum VaultInstruction {
     #[account(3, writable, signer, name = "depositor")]
     #[account(4, writable, name = "depositor_token_account")]
     #[account(5, writable, name = "vault_token_account")]
-    #[account(6, writable, name = "depositor_vrt_token_account")]
+    #[account(6, writable, name = "depositor_vrt_token_account", description = "The VRT ATA of the beneficiary receiving the minted VRT, usually the depositor")]
     #[account(7, writable, name = "vault_fee_token_account")]
     #[account(8, name = "token_program")]
     #[account(9, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    #[account(10, writable, optional, name = "vault_deposit_receipt", description = "The deposit receipt of the beneficiary, required while the vault has a holding period")]
+    #[account(11, optional, name = "system_program", description = "Required to create the deposit receipt or the referral account")]
+    #[account(12, writable, optional, name = "vault_referral", description = "The referral account of the referrer, created on their first referred deposit")]
+    #[account(13, optional, name = "referrer", description = "The referrer the deposit is attributed to")]
+    #[account(14, writable, optional, name = "referrer_vrt_token_account", description = "The referrer's VRT token account receiving the referral share of the deposit fee")]
+    #[account(15, signer, optional, name = "beneficiary", description = "The beneficiary, whose signature lets a deposit restart their holding period")]
     MintTo {
         amount_in: u64,
         min_amount_out: u64,
@@ -146,6 +154,7 @@ pub enum VaultInstruction {
     #[account(7, name = "token_program")]
     #[account(8, name = "system_program")]
     #[account(9, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[account(10, optional, name = "vault_deposit_receipt", description = "The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist")]
     EnqueueWithdrawal {
         amount: u64,
     },
@@ -320,6 +329,194 @@ pub enum VaultInstruction {
     #[account(2, name = "new_admin")]
     SetConfigAdmin,
 
+    /// Puts the vault into wind-down, blocking deposits and new delegations while all
+    /// delegated assets are cooled down during the next vault update
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    WindDownVault,
+
+    /// Closes a vault that has finished winding down, along with its token account and any
+    /// VaultUpdateStateTrackers passed in as remaining accounts
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, name = "burn_vault_vrt_token_account")]
+    #[account(4, writable, signer, name = "admin")]
+    #[account(5, writable, name = "admin_token_account")]
+    #[account(6, name = "token_program")]
+    CloseVault,
+
+    /// Migrates any vault program account to its current layout version, reallocating it if needed
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+    /// Sets the maximum share of the vault's deposits that may be delegated to a single operator
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetMaxOperatorShare {
+        max_operator_share_bps: u16,
+    },
+
+    /// Sets the maximum amount that may be delegated to an operator
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "vault_operator_delegation")]
+    #[account(4, signer, name = "admin")]
+    SetOperatorDelegationCap {
+        max_delegation_amount: u64,
+    },
+
+    /// Sets the share of each operator's delegated security allocated to an NCN
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, writable, name = "vault_ncn_ticket")]
+    #[account(4, signer, name = "admin")]
+    SetNcnAllocationCap {
+        max_allocation_bps: u16,
+    },
+
+    /// Updates the vault and every VaultOperatorDelegation in a single instruction, without a
+    /// VaultUpdateStateTracker. Every VaultOperatorDelegation shall be passed in as a writable
+    /// remaining account.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    UpdateVaultFull { withdrawal_allocation_method: WithdrawalAllocationMethod },
+
+    /// Sets the number of epochs depositors shall hold their VRT before enqueueing a withdrawal
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetMinHoldingPeriod {
+        min_holding_epochs: u64,
+    },
+
+    /// Waives the holding period of a vault that is winding down
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    WaiveHoldingPeriod,
+
+    /// Enqueues a withdrawal of VRT tokens, minting transferable claim tokens for the current
+    /// epoch instead of creating a withdrawal ticket
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "vrt_mint")]
+    #[account(3, writable, name = "vault_withdrawal_claim_mint")]
+    #[account(4, writable, name = "vault_withdrawal_claim_mint_vrt_token_account")]
+    #[account(5, writable, signer, name = "staker")]
+    #[account(6, writable, name = "staker_vrt_token_account")]
+    #[account(7, writable, name = "staker_claim_token_account")]
+    #[account(8, name = "token_program")]
+    #[account(9, name = "system_program")]
+    #[account(10, name = "associated_token_program")]
+    #[account(11, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[account(12, optional, name = "vault_deposit_receipt", description = "The deposit receipt of the staker, required while the vault has a holding period even if it doesn't exist")]
+    EnqueueWithdrawalClaim {
+        amount: u64,
+    },
+
+    /// Burns claim tokens minted in the given epoch, returning funds to the claimant. Claims can
+    /// be burned after one full epoch has passed since they were minted.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, writable, name = "vault_token_account")]
+    #[account(3, writable, name = "vrt_mint")]
+    #[account(4, writable, name = "vault_withdrawal_claim_mint")]
+    #[account(5, writable, name = "vault_withdrawal_claim_mint_vrt_token_account")]
+    #[account(6, signer, name = "claimant")]
+    #[account(7, writable, name = "claimant_claim_token_account")]
+    #[account(8, writable, name = "claimant_token_account")]
+    #[account(9, writable, name = "vault_fee_token_account")]
+    #[account(10, writable, name = "program_fee_token_account")]
+    #[account(11, name = "token_program")]
+    #[account(12, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    BurnWithdrawalClaim {
+        epoch: u64,
+        amount: u64,
+    },
+
+    /// Creates or replaces the vault's fee split and makes it the vault's fee wallet. The
+    /// recipients are passed as remaining accounts, in the same order as their shares. Shares
+    /// past the last recipient must be zero.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, name = "vrt_mint")]
+    #[account(3, writable, name = "vault_fee_split")]
+    #[account(4, writable, name = "vault_fee_split_token_account")]
+    #[account(5, writable, signer, name = "admin")]
+    #[account(6, name = "token_program")]
+    #[account(7, name = "system_program")]
+    #[account(8, name = "associated_token_program")]
+    SetFeeSplit {
+        recipient_bps: [u16; 8],
+    },
+
+    /// Distributes the VRT accumulated by the vault's fee split to its recipients. The recipients'
+    /// VRT token accounts are passed as remaining accounts, in the fee split's order.
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "vrt_mint")]
+    #[account(3, name = "vault_fee_split")]
+    #[account(4, writable, name = "vault_fee_split_token_account")]
+    #[account(5, name = "token_program")]
+    DistributeFeeSplit,
+
+    /// Sets or clears the program fee charged to a single vault instead of the config's program
+    /// fee. Takes effect on the vault's next update.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetProgramFeeOverride {
+        program_fee_bps: Option<u16>,
+    },
+
+    /// Sets who may create vaults. The creation fee and treasury are only used by the fee policy.
+    #[account(0, writable, name = "config")]
+    #[account(1, signer, name = "admin")]
+    #[account(2, name = "creation_fee_treasury")]
+    SetCreationPolicy {
+        creation_policy: CreationPolicy,
+        creation_fee_lamports: u64,
+    },
+
+    /// Adds a creator to the allowlist used by the allowlist creation policy
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "creator_allowlist_entry")]
+    #[account(2, name = "creator")]
+    #[account(3, writable, signer, name = "admin")]
+    #[account(4, name = "system_program")]
+    InitializeCreatorAllowlistEntry,
+
+    /// Removes a creator from the allowlist used by the allowlist creation policy
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "creator_allowlist_entry")]
+    #[account(2, name = "creator")]
+    #[account(3, writable, signer, name = "admin")]
+    CloseCreatorAllowlistEntry,
+
+    /// Sets the share of the deposit fee minted to the referrer of a deposit
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    SetReferralFee {
+        referral_fee_bps: u16,
+    },
+
+    /// Copies the NCN's max slashable per epoch, and any pending increase, onto the vault's
+    /// slasher ticket
+    #[account(0, name = "config")]
+    #[account(1, name = "vault")]
+    #[account(2, name = "ncn")]
+    #[account(3, name = "slasher")]
+    #[account(4, name = "ncn_vault_slasher_ticket")]
+    #[account(5, writable, name = "vault_ncn_slasher_ticket")]
+    UpdateVaultNcnSlasherTicket,
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
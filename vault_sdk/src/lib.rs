@@ -1,4 +1,5 @@
 pub mod error;
+pub mod event;
 pub mod inline_mpl_token_metadata;
 pub mod instruction;
 pub mod sdk;
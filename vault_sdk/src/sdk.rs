@@ -2,12 +2,16 @@ use borsh::BorshSerialize;
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    system_program,
+    system_instruction, system_program,
 };
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+use spl_token::native_mint;
 
 use crate::{
     inline_mpl_token_metadata::{self},
-    instruction::{VaultAdminRole, VaultInstruction, WithdrawalAllocationMethod},
+    instruction::{CreationPolicy, VaultAdminRole, VaultInstruction, WithdrawalAllocationMethod},
 };
 
 pub fn initialize_config(
@@ -47,13 +51,14 @@ pub fn initialize_vault(
     burn_vault_vrt_token_account: &Pubkey,
     admin: &Pubkey,
     base: &Pubkey,
+    creation_policy_account: Option<&Pubkey>,
     deposit_fee_bps: u16,
     withdrawal_fee_bps: u16,
     reward_fee_bps: u16,
     decimals: u8,
     initialize_token_amount: u64,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*config, false),
         AccountMeta::new(*vault, false),
         AccountMeta::new(*vrt_mint, true),
@@ -68,6 +73,9 @@ pub fn initialize_vault(
         AccountMeta::new_readonly(spl_token::id(), false),
         AccountMeta::new_readonly(spl_associated_token_account::id(), false),
     ];
+    if let Some(creation_policy_account) = creation_policy_account {
+        accounts.push(AccountMeta::new(*creation_policy_account, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -179,6 +187,11 @@ pub fn mint_to(
     depositor_vrt_token_account: &Pubkey,
     vault_fee_token_account: &Pubkey,
     mint_signer: Option<&Pubkey>,
+    vault_deposit_receipt: Option<&Pubkey>,
+    vault_referral: Option<&Pubkey>,
+    referrer: Option<&Pubkey>,
+    referrer_vrt_token_account: Option<&Pubkey>,
+    beneficiary: Option<&Pubkey>,
     amount_in: u64,
     min_amount_out: u64,
 ) -> Instruction {
@@ -193,8 +206,39 @@ pub fn mint_to(
         AccountMeta::new(*vault_fee_token_account, false),
         AccountMeta::new_readonly(spl_token::id(), false),
     ];
+    // Missing optional accounts are passed as the program ID
+    let has_referral =
+        vault_referral.is_some() || referrer.is_some() || referrer_vrt_token_account.is_some();
+    let has_trailing_accounts =
+        vault_deposit_receipt.is_some() || has_referral || beneficiary.is_some();
     if let Some(signer) = mint_signer {
         accounts.push(AccountMeta::new_readonly(*signer, true));
+    } else if has_trailing_accounts {
+        accounts.push(AccountMeta::new_readonly(*program_id, false));
+    }
+    if has_trailing_accounts {
+        accounts.push(AccountMeta::new(
+            *vault_deposit_receipt.unwrap_or(program_id),
+            false,
+        ));
+        accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    }
+    if has_referral || beneficiary.is_some() {
+        accounts.push(AccountMeta::new(
+            *vault_referral.unwrap_or(program_id),
+            false,
+        ));
+        accounts.push(AccountMeta::new_readonly(
+            *referrer.unwrap_or(program_id),
+            false,
+        ));
+        accounts.push(AccountMeta::new(
+            *referrer_vrt_token_account.unwrap_or(program_id),
+            false,
+        ));
+    }
+    if let Some(beneficiary) = beneficiary {
+        accounts.push(AccountMeta::new_readonly(*beneficiary, true));
     }
     Instruction {
         program_id: *program_id,
@@ -208,6 +252,65 @@ pub fn mint_to(
     }
 }
 
+/// Deposits `amount_in` lamports into a vault whose supported mint is wSOL.
+///
+/// The lamports are wrapped into the depositor's wSOL ATA, which is created if needed and closed
+/// after the deposit. Any wSOL the depositor already held in the ATA is unwrapped as well.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_to_native(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vrt_mint: &Pubkey,
+    depositor: &Pubkey,
+    vault_token_account: &Pubkey,
+    depositor_vrt_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    mint_signer: Option<&Pubkey>,
+    vault_deposit_receipt: Option<&Pubkey>,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Vec<Instruction> {
+    let depositor_token_account = get_associated_token_address(depositor, &native_mint::id());
+    vec![
+        create_associated_token_account_idempotent(
+            depositor,
+            depositor,
+            &native_mint::id(),
+            &spl_token::id(),
+        ),
+        system_instruction::transfer(depositor, &depositor_token_account, amount_in),
+        spl_token::instruction::sync_native(&spl_token::id(), &depositor_token_account).unwrap(),
+        mint_to(
+            program_id,
+            config,
+            vault,
+            vrt_mint,
+            depositor,
+            &depositor_token_account,
+            vault_token_account,
+            depositor_vrt_token_account,
+            vault_fee_token_account,
+            mint_signer,
+            vault_deposit_receipt,
+            None,
+            None,
+            None,
+            None,
+            amount_in,
+            min_amount_out,
+        ),
+        spl_token::instruction::close_account(
+            &spl_token::id(),
+            &depositor_token_account,
+            depositor,
+            depositor,
+            &[],
+        )
+        .unwrap(),
+    ]
+}
+
 pub fn set_deposit_capacity(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -554,6 +657,7 @@ pub fn enqueue_withdrawal(
     staker_vrt_token_account: &Pubkey,
     base: &Pubkey,
     mint_burn_admin: Option<&Pubkey>,
+    vault_deposit_receipt: Option<&Pubkey>,
     amount: u64,
 ) -> Instruction {
     let mut accounts = vec![
@@ -567,9 +671,12 @@ pub fn enqueue_withdrawal(
         AccountMeta::new_readonly(spl_token::id(), false),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
-    if let Some(signer) = mint_burn_admin {
-        accounts.push(AccountMeta::new_readonly(*signer, true));
-    }
+    push_burn_signer_and_deposit_receipt(
+        program_id,
+        &mut accounts,
+        mint_burn_admin,
+        vault_deposit_receipt,
+    );
     Instruction {
         program_id: *program_id,
         accounts,
@@ -579,6 +686,24 @@ pub fn enqueue_withdrawal(
     }
 }
 
+/// Appends the optional burn signer and deposit receipt of the enqueue withdrawal instructions,
+/// passing a missing burn signer as the program ID when the receipt follows it
+fn push_burn_signer_and_deposit_receipt(
+    program_id: &Pubkey,
+    accounts: &mut Vec<AccountMeta>,
+    mint_burn_admin: Option<&Pubkey>,
+    vault_deposit_receipt: Option<&Pubkey>,
+) {
+    if let Some(signer) = mint_burn_admin {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    } else if vault_deposit_receipt.is_some() {
+        accounts.push(AccountMeta::new_readonly(*program_id, false));
+    }
+    if let Some(vault_deposit_receipt) = vault_deposit_receipt {
+        accounts.push(AccountMeta::new_readonly(*vault_deposit_receipt, false));
+    }
+}
+
 pub fn change_withdrawal_ticket_owner(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -642,6 +767,59 @@ pub fn burn_withdrawal_ticket(
     }
 }
 
+/// Burns a withdrawal ticket of a vault whose supported mint is wSOL and pays the staker out in
+/// lamports.
+///
+/// The withdrawn wSOL is received in the staker's wSOL ATA, which is created if needed and closed
+/// after the burn, so the staker must sign. Any wSOL the staker already held in the ATA is
+/// unwrapped as well.
+#[allow(clippy::too_many_arguments)]
+pub fn burn_withdrawal_ticket_native(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    vrt_mint: &Pubkey,
+    staker: &Pubkey,
+    vault_staker_withdrawal_ticket: &Pubkey,
+    vault_staker_withdrawal_ticket_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    program_fee_vrt_token_account: &Pubkey,
+    mint_burn_admin: Option<&Pubkey>,
+) -> Vec<Instruction> {
+    let staker_token_account = get_associated_token_address(staker, &native_mint::id());
+    vec![
+        create_associated_token_account_idempotent(
+            staker,
+            staker,
+            &native_mint::id(),
+            &spl_token::id(),
+        ),
+        burn_withdrawal_ticket(
+            program_id,
+            config,
+            vault,
+            vault_token_account,
+            vrt_mint,
+            staker,
+            &staker_token_account,
+            vault_staker_withdrawal_ticket,
+            vault_staker_withdrawal_ticket_token_account,
+            vault_fee_token_account,
+            program_fee_vrt_token_account,
+            mint_burn_admin,
+        ),
+        spl_token::instruction::close_account(
+            &spl_token::id(),
+            &staker_token_account,
+            staker,
+            staker,
+            &[],
+        )
+        .unwrap(),
+    ]
+}
+
 pub fn update_vault_balance(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -691,6 +869,33 @@ pub fn initialize_vault_update_state_tracker(
     }
 }
 
+pub fn update_vault_full(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_operator_delegations: &[Pubkey],
+    withdrawal_allocation_method: WithdrawalAllocationMethod,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+    ];
+    accounts.extend(
+        vault_operator_delegations
+            .iter()
+            .map(|vault_operator_delegation| AccountMeta::new(*vault_operator_delegation, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::UpdateVaultFull {
+            withdrawal_allocation_method,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
 pub fn close_vault_update_state_tracker(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -819,3 +1024,471 @@ pub fn set_config_admin(
         data: VaultInstruction::SetConfigAdmin.try_to_vec().unwrap(),
     }
 }
+
+pub fn wind_down_vault(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::WindDownVault.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn close_vault(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    burn_vault_vrt_token_account: &Pubkey,
+    admin: &Pubkey,
+    admin_token_account: &Pubkey,
+    vault_update_state_trackers: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new_readonly(*burn_vault_vrt_token_account, false),
+        AccountMeta::new(*admin, true),
+        AccountMeta::new(*admin_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    accounts.extend(
+        vault_update_state_trackers
+            .iter()
+            .map(|tracker| AccountMeta::new(*tracker, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CloseVault.try_to_vec().unwrap(),
+    }
+}
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_max_operator_share(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    max_operator_share_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetMaxOperatorShare {
+            max_operator_share_bps,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn set_operator_delegation_cap(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    operator: &Pubkey,
+    vault_operator_delegation: &Pubkey,
+    admin: &Pubkey,
+    max_delegation_amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*vault_operator_delegation, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetOperatorDelegationCap {
+            max_delegation_amount,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn set_ncn_allocation_cap(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    vault_ncn_ticket: &Pubkey,
+    admin: &Pubkey,
+    max_allocation_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*vault_ncn_ticket, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetNcnAllocationCap { max_allocation_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_min_holding_period(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    min_holding_epochs: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetMinHoldingPeriod { min_holding_epochs }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn waive_holding_period(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::WaiveHoldingPeriod.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn enqueue_withdrawal_claim(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vrt_mint: &Pubkey,
+    vault_withdrawal_claim_mint: &Pubkey,
+    vault_withdrawal_claim_mint_vrt_token_account: &Pubkey,
+    staker: &Pubkey,
+    staker_vrt_token_account: &Pubkey,
+    staker_claim_token_account: &Pubkey,
+    mint_burn_admin: Option<&Pubkey>,
+    vault_deposit_receipt: Option<&Pubkey>,
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*vrt_mint, false),
+        AccountMeta::new(*vault_withdrawal_claim_mint, false),
+        AccountMeta::new(*vault_withdrawal_claim_mint_vrt_token_account, false),
+        AccountMeta::new(*staker, true),
+        AccountMeta::new(*staker_vrt_token_account, false),
+        AccountMeta::new(*staker_claim_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+    ];
+    push_burn_signer_and_deposit_receipt(
+        program_id,
+        &mut accounts,
+        mint_burn_admin,
+        vault_deposit_receipt,
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::EnqueueWithdrawalClaim { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn burn_withdrawal_claim(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vault_token_account: &Pubkey,
+    vrt_mint: &Pubkey,
+    vault_withdrawal_claim_mint: &Pubkey,
+    vault_withdrawal_claim_mint_vrt_token_account: &Pubkey,
+    claimant: &Pubkey,
+    claimant_claim_token_account: &Pubkey,
+    claimant_token_account: &Pubkey,
+    vault_fee_token_account: &Pubkey,
+    program_fee_token_account: &Pubkey,
+    mint_burn_admin: Option<&Pubkey>,
+    epoch: u64,
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*vault_token_account, false),
+        AccountMeta::new(*vrt_mint, false),
+        AccountMeta::new(*vault_withdrawal_claim_mint, false),
+        AccountMeta::new(*vault_withdrawal_claim_mint_vrt_token_account, false),
+        AccountMeta::new_readonly(*claimant, true),
+        AccountMeta::new(*claimant_claim_token_account, false),
+        AccountMeta::new(*claimant_token_account, false),
+        AccountMeta::new(*vault_fee_token_account, false),
+        AccountMeta::new(*program_fee_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    if let Some(signer) = mint_burn_admin {
+        accounts.push(AccountMeta::new_readonly(*signer, true));
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::BurnWithdrawalClaim { epoch, amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn set_fee_split(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vrt_mint: &Pubkey,
+    vault_fee_split: &Pubkey,
+    vault_fee_split_token_account: &Pubkey,
+    admin: &Pubkey,
+    recipients: &[Pubkey],
+    recipient_bps: [u16; 8],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*vrt_mint, false),
+        AccountMeta::new(*vault_fee_split, false),
+        AccountMeta::new(*vault_fee_split_token_account, false),
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+    ];
+    accounts.extend(
+        recipients
+            .iter()
+            .map(|recipient| AccountMeta::new_readonly(*recipient, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetFeeSplit { recipient_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn distribute_fee_split(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    vrt_mint: &Pubkey,
+    vault_fee_split: &Pubkey,
+    vault_fee_split_token_account: &Pubkey,
+    recipient_token_accounts: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*vrt_mint, false),
+        AccountMeta::new_readonly(*vault_fee_split, false),
+        AccountMeta::new(*vault_fee_split_token_account, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    accounts.extend(
+        recipient_token_accounts
+            .iter()
+            .map(|recipient_token_account| AccountMeta::new(*recipient_token_account, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::DistributeFeeSplit.try_to_vec().unwrap(),
+    }
+}
+
+pub fn set_program_fee_override(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    program_fee_bps: Option<u16>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetProgramFeeOverride { program_fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_creation_policy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+    creation_fee_treasury: &Pubkey,
+    creation_policy: CreationPolicy,
+    creation_fee_lamports: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*creation_fee_treasury, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetCreationPolicy {
+            creation_policy,
+            creation_fee_lamports,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn initialize_creator_allowlist_entry(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    creator_allowlist_entry: &Pubkey,
+    creator: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*creator_allowlist_entry, false),
+        AccountMeta::new_readonly(*creator, false),
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::InitializeCreatorAllowlistEntry
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn close_creator_allowlist_entry(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    creator_allowlist_entry: &Pubkey,
+    creator: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*creator_allowlist_entry, false),
+        AccountMeta::new_readonly(*creator, false),
+        AccountMeta::new(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::CloseCreatorAllowlistEntry
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn set_referral_fee(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    admin: &Pubkey,
+    referral_fee_bps: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::SetReferralFee { referral_fee_bps }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn update_vault_ncn_slasher_ticket(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    ncn: &Pubkey,
+    slasher: &Pubkey,
+    ncn_vault_slasher_ticket: &Pubkey,
+    vault_ncn_slasher_ticket: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*vault, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*slasher, false),
+        AccountMeta::new_readonly(*ncn_vault_slasher_ticket, false),
+        AccountMeta::new(*vault_ncn_slasher_ticket, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::UpdateVaultNcnSlasherTicket
+            .try_to_vec()
+            .unwrap(),
+    }
+}